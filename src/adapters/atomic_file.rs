@@ -0,0 +1,40 @@
+use std::fs::{self, File};
+use std::io::{self, Write as _};
+use std::path::Path;
+
+/// Atomically replace a file's contents
+///
+/// Writes to a `.tmp` sibling, syncs it to disk, then renames it over `path`,
+/// so readers (and a restart after a crash) see either the old or the new
+/// contents, never a partial write.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    let mut tmp = File::create(&tmp_path)?;
+    tmp.write_all(contents)?;
+    tmp.sync_data()?;
+    fs::rename(&tmp_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempPath;
+
+    #[test]
+    fn test_write_atomic_replaces_contents() {
+        let file = TempPath::new("atomic-file-replace");
+
+        write_atomic(&file.0, b"first").unwrap();
+        write_atomic(&file.0, b"second").unwrap();
+
+        assert_eq!(fs::read(&file.0).unwrap(), b"second");
+        assert!(!file.0.with_extension("tmp").exists());
+    }
+
+    #[test]
+    fn test_write_atomic_missing_directory_fails() {
+        let dir = TempPath::new("atomic-file-missing-dir");
+
+        assert!(write_atomic(&dir.0.join("file.json"), b"[]").is_err());
+    }
+}
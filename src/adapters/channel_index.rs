@@ -0,0 +1,155 @@
+use serenity::model::id::{ChannelId, GuildId};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+/// Bounded channel → guild index with least-recently-used eviction
+///
+/// Remembers which guild a channel belongs to, so lookups without a
+/// `guild_id` can go straight to that guild instead of scanning every cached
+/// guild. Holds at most `max_entries` channels; inserting beyond that evicts
+/// the least recently used entry. A `max_entries` of 0 disables the index.
+pub struct ChannelIndex {
+    max_entries: usize,
+    state: Mutex<IndexState>,
+}
+
+/// Entries plus their recency order
+#[derive(Default)]
+struct IndexState {
+    /// Channel → (guild, last use stamp)
+    entries: HashMap<ChannelId, (GuildId, u64)>,
+    /// Last use stamp → channel, oldest first
+    recency: BTreeMap<u64, ChannelId>,
+    next_stamp: u64,
+}
+
+impl IndexState {
+    /// Mark `channel_id` as just used, returning its new stamp
+    fn touch(&mut self, channel_id: ChannelId, old_stamp: Option<u64>) -> u64 {
+        if let Some(old_stamp) = old_stamp {
+            self.recency.remove(&old_stamp);
+        }
+        let stamp = self.next_stamp;
+        self.next_stamp += 1;
+        self.recency.insert(stamp, channel_id);
+        stamp
+    }
+}
+
+impl ChannelIndex {
+    /// Create an empty index holding at most `max_entries` channels (0 disables)
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            state: Mutex::new(IndexState::default()),
+        }
+    }
+
+    /// Guild of an indexed channel, marking it as recently used
+    pub fn get(&self, channel_id: ChannelId) -> Option<GuildId> {
+        let mut state = self.state.lock().unwrap();
+        let (guild_id, old_stamp) = *state.entries.get(&channel_id)?;
+        let stamp = state.touch(channel_id, Some(old_stamp));
+        state.entries.insert(channel_id, (guild_id, stamp));
+        Some(guild_id)
+    }
+
+    /// Record the guild of a channel, evicting the least recently used entry when full
+    pub fn insert(&self, channel_id: ChannelId, guild_id: GuildId) {
+        if self.max_entries == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        let old_stamp = state.entries.get(&channel_id).map(|&(_, stamp)| stamp);
+        if old_stamp.is_none()
+            && state.entries.len() >= self.max_entries
+            && let Some((_, evicted)) = state.recency.pop_first()
+        {
+            state.entries.remove(&evicted);
+        }
+        let stamp = state.touch(channel_id, old_stamp);
+        state.entries.insert(channel_id, (guild_id, stamp));
+    }
+
+    /// Forget a channel (e.g. when its guild no longer holds it)
+    pub fn remove(&self, channel_id: ChannelId) {
+        let mut state = self.state.lock().unwrap();
+        if let Some((_, stamp)) = state.entries.remove(&channel_id) {
+            state.recency.remove(&stamp);
+        }
+    }
+}
+
+#[cfg(test)]
+impl ChannelIndex {
+    /// Number of indexed channels
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Whether no channels are indexed
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_get() {
+        let index = ChannelIndex::new(10);
+        index.insert(ChannelId::new(1), GuildId::new(100));
+
+        assert_eq!(index.get(ChannelId::new(1)), Some(GuildId::new(100)));
+        assert_eq!(index.get(ChannelId::new(2)), None);
+    }
+
+    #[test]
+    fn test_insert_beyond_cap_evicts_least_recently_used() {
+        let index = ChannelIndex::new(2);
+        index.insert(ChannelId::new(1), GuildId::new(100));
+        index.insert(ChannelId::new(2), GuildId::new(100));
+
+        // Using channel 1 makes channel 2 the eviction candidate
+        index.get(ChannelId::new(1));
+        index.insert(ChannelId::new(3), GuildId::new(300));
+
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.get(ChannelId::new(1)), Some(GuildId::new(100)));
+        assert_eq!(index.get(ChannelId::new(2)), None);
+        assert_eq!(index.get(ChannelId::new(3)), Some(GuildId::new(300)));
+    }
+
+    #[test]
+    fn test_reinsert_updates_without_evicting() {
+        let index = ChannelIndex::new(2);
+        index.insert(ChannelId::new(1), GuildId::new(100));
+        index.insert(ChannelId::new(2), GuildId::new(200));
+        index.insert(ChannelId::new(1), GuildId::new(101));
+
+        assert_eq!(index.len(), 2);
+        assert_eq!(index.get(ChannelId::new(1)), Some(GuildId::new(101)));
+        assert_eq!(index.get(ChannelId::new(2)), Some(GuildId::new(200)));
+    }
+
+    #[test]
+    fn test_remove() {
+        let index = ChannelIndex::new(2);
+        index.insert(ChannelId::new(1), GuildId::new(100));
+        index.remove(ChannelId::new(1));
+
+        assert!(index.is_empty());
+        assert_eq!(index.get(ChannelId::new(1)), None);
+    }
+
+    #[test]
+    fn test_zero_cap_disables_index() {
+        let index = ChannelIndex::new(0);
+        index.insert(ChannelId::new(1), GuildId::new(100));
+
+        assert!(index.is_empty());
+    }
+}
@@ -1,6 +1,20 @@
 use serenity::async_trait;
+use std::collections::HashMap;
 use serenity::model::channel::GuildChannel;
-use serenity::model::id::{ChannelId, GuildId};
+use serenity::model::id::{ChannelId, GuildId, RoleId};
+#[cfg(feature = "actions")]
+use serenity::model::permissions::Permissions;
+
+/// Guild details resolved from cache, for enriching event payloads
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GuildInfo {
+    /// Channel where Discord posts system messages (e.g. member joins)
+    pub system_channel_id: Option<ChannelId>,
+    /// Rules channel of a community guild
+    pub rules_channel_id: Option<ChannelId>,
+    /// Names of the guild's roles
+    pub role_names: HashMap<RoleId, String>,
+}
 
 /// Interface for retrieving channel information
 #[async_trait]
@@ -23,6 +37,7 @@ pub trait ChannelInfoProvider: Send + Sync {
     /// Implementations that use cache (like SerenityChannelInfoProvider) should hold
     /// Arc<Cache> and Arc<Http> internally. These are automatically updated/maintained
     /// by Serenity's event loop and never change during Client lifetime.
+    #[cfg(feature = "actions")]
     async fn is_thread(
         &self,
         guild_id: Option<GuildId>,
@@ -51,4 +66,37 @@ pub trait ChannelInfoProvider: Send + Sync {
         guild_id: Option<GuildId>,
         channel_id: ChannelId,
     ) -> Result<Option<GuildChannel>, serenity::Error>;
+
+    /// Get guild information from cache
+    ///
+    /// # Returns
+    ///
+    /// `Some(GuildInfo)` if the guild is cached, `None` otherwise
+    ///
+    /// # Implementation Note
+    ///
+    /// Cache only: never falls back to the API.
+    fn get_guild(&self, guild_id: GuildId) -> Option<GuildInfo>;
+
+    /// Get the bot's permissions in a guild channel from cache
+    ///
+    /// # Arguments
+    ///
+    /// * `guild_id` - The guild containing the channel
+    /// * `channel_id` - The channel (or thread) to check
+    ///
+    /// # Returns
+    ///
+    /// The bot's effective permissions (role permissions plus channel overwrites),
+    /// or `None` when the guild, channel, or bot member is not cached.
+    ///
+    /// For threads, overwrites come from the parent channel, and
+    /// `SEND_MESSAGES` reflects `SEND_MESSAGES_IN_THREADS` (the permission that
+    /// governs posting in threads).
+    ///
+    /// # Implementation Note
+    ///
+    /// Cache only: never falls back to the API.
+    #[cfg(feature = "actions")]
+    fn bot_permissions(&self, guild_id: GuildId, channel_id: ChannelId) -> Option<Permissions>;
 }
@@ -0,0 +1,485 @@
+use super::event_response::EventResponse;
+use super::event_sender_trait::{Delivery, EventSender};
+use crate::error::GatehookError;
+use serde::Serialize;
+use serenity::async_trait;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+/// EventSender decorator pausing delivery while the webhook endpoint is failing
+///
+/// After `failure_threshold` consecutive delivery failures (transport errors,
+/// or 5xx/429 responses per `Delivery::accepted`) the breaker opens
+/// for `cooldown`. While open, events are not sent: up to `buffer_size` are
+/// held in memory and the rest are dropped (counted and logged). After the
+/// cooldown the breaker is half-open: the next event is sent as the only
+/// probe, and events arriving while it is in flight are held as while open.
+/// Success closes the breaker and the buffered events are replayed by
+/// `replay_backlog()` (run in the background by the bridge), failure reopens it.
+///
+/// A `failure_threshold` of 0 disables the breaker (events pass straight through).
+///
+/// # Delivery Semantics
+///
+/// - Buffered events return `Ok(None)` (no actions are executed for them)
+/// - Dropped events return `GatehookError::CircuitOpen`
+/// - Buffered events are replayed oldest first; replay stops at the first failure
+/// - Actions in responses to replayed events are ignored (the triggering context is gone)
+/// - The buffer is in memory only and is lost on restart
+///
+/// Behind an `OutboxEventSender` (`with_outbox_backed(true)`), nothing is
+/// buffered: events arriving while open fail with `CircuitOpen`, so the outbox
+/// keeps them on disk and replays them in order.
+pub struct CircuitBreakerEventSender<S: EventSender> {
+    inner: S,
+    failure_threshold: u32,
+    cooldown: Duration,
+    buffer_size: usize,
+    /// Whether an outer outbox keeps the events refused while open
+    outbox_backed: bool,
+    state: Mutex<BreakerState>,
+    /// Events held while the breaker is open (handler, payload)
+    buffer: Mutex<VecDeque<(String, serde_json::Value)>>,
+    /// Events dropped because the buffer was full
+    dropped: AtomicU64,
+    /// Serializes replays so each buffered event is delivered once
+    replay_lock: tokio::sync::Mutex<()>,
+}
+
+/// Failure tracking for the breaker
+#[derive(Debug, Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    /// Set while the breaker is open or half-open (cooldown over)
+    open_until: Option<Instant>,
+    /// Whether the half-open probe is in flight
+    probing: bool,
+}
+
+/// How `deliver` handles an event, given the breaker state
+#[derive(Debug, PartialEq)]
+enum Admission {
+    /// Closed: send the event
+    Send,
+    /// Half-open: send the event as the only probe
+    Probe,
+    /// Open, or half-open with a probe in flight: hold the event
+    Hold,
+}
+
+/// Ends the probe when the probing delivery finishes or is dropped (e.g. by a deadline)
+struct ProbeGuard<'a> {
+    state: &'a Mutex<BreakerState>,
+}
+
+impl Drop for ProbeGuard<'_> {
+    fn drop(&mut self) {
+        self.state.lock().unwrap().probing = false;
+    }
+}
+
+impl<S: EventSender> CircuitBreakerEventSender<S> {
+    /// Create a new CircuitBreakerEventSender
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The sender that performs the actual delivery
+    /// * `failure_threshold` - Consecutive failures that open the breaker (0 disables it)
+    /// * `cooldown` - How long the breaker stays open before probing again
+    /// * `buffer_size` - Maximum events held while open (0 drops them all)
+    pub fn new(inner: S, failure_threshold: u32, cooldown: Duration, buffer_size: usize) -> Self {
+        Self {
+            inner,
+            failure_threshold,
+            cooldown,
+            buffer_size,
+            outbox_backed: false,
+            state: Mutex::new(BreakerState::default()),
+            buffer: Mutex::new(VecDeque::new()),
+            dropped: AtomicU64::new(0),
+            replay_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Leave events refused while open to an outer outbox instead of buffering or dropping them
+    pub fn with_outbox_backed(mut self, outbox_backed: bool) -> Self {
+        self.outbox_backed = outbox_backed;
+        self
+    }
+
+    /// Whether the breaker is open or half-open
+    #[cfg(test)]
+    fn is_open(&self) -> bool {
+        self.state.lock().unwrap().open_until.is_some()
+    }
+
+    /// Decide how to handle an event, starting the probe once the cooldown is over
+    fn admit(&self) -> Admission {
+        let mut state = self.state.lock().unwrap();
+        match state.open_until {
+            None => Admission::Send,
+            Some(open_until) if Instant::now() < open_until || state.probing => Admission::Hold,
+            Some(_) => {
+                state.probing = true;
+                debug!("Circuit breaker cooldown over, sending probe");
+                Admission::Probe
+            }
+        }
+    }
+
+    /// Record a delivery result, opening or closing the breaker
+    ///
+    /// Returns `true` if the breaker was open (or half-open) and is now closed.
+    fn record(&self, success: bool) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if success {
+            let recovered = state.open_until.is_some();
+            *state = BreakerState::default();
+            return recovered;
+        }
+
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        if state.consecutive_failures >= self.failure_threshold {
+            if state.open_until.is_none() {
+                warn!(
+                    failures = state.consecutive_failures,
+                    cooldown_secs = self.cooldown.as_secs(),
+                    "Webhook delivery failing, opening circuit breaker"
+                );
+            }
+            state.open_until = Some(Instant::now() + self.cooldown);
+            state.probing = false;
+        }
+        false
+    }
+
+    /// Buffer an event while the breaker is open, dropping it if the buffer is full
+    fn hold(&self, handler: &str, payload: serde_json::Value) -> Result<Option<EventResponse>, GatehookError> {
+        if self.outbox_backed {
+            debug!(%handler, "Circuit breaker open, event left in the outbox");
+            return Err(GatehookError::CircuitOpen);
+        }
+
+        let mut buffer = self.buffer.lock().unwrap();
+        if buffer.len() < self.buffer_size {
+            buffer.push_back((handler.to_string(), payload));
+            return Ok(None);
+        }
+        drop(buffer);
+
+        let dropped = self.dropped.fetch_add(1, Ordering::SeqCst) + 1;
+        warn!(%handler, dropped, "Circuit breaker open and buffer full, dropping event");
+        Err(GatehookError::CircuitOpen)
+    }
+
+    /// Deliver buffered events after the breaker closed
+    ///
+    /// Returns the number of events delivered. Skips while the breaker is not
+    /// closed (the probe decides) or if another replay is running.
+    /// Each event is removed from the buffer only once delivered, so a replay
+    /// cancelled mid-send (e.g. by a `WEBHOOK_DEADLINE_<HANDLER>_MS` deadline)
+    /// loses nothing.
+    async fn replay(&self) -> usize {
+        if self.state.lock().unwrap().open_until.is_some() {
+            return 0;
+        }
+        let Ok(_guard) = self.replay_lock.try_lock() else {
+            return 0;
+        };
+
+        let mut delivered = 0;
+        loop {
+            let Some((handler, payload)) = self.buffer.lock().unwrap().front().cloned() else {
+                break;
+            };
+
+            match self.inner.deliver(&handler, &payload).await {
+                Ok(delivery) if delivery.accepted() => {
+                    // Only replay pops, and replays are serialized: the front is still this event
+                    self.buffer.lock().unwrap().pop_front();
+                    self.record(true);
+                    if delivery.response.is_some_and(|response| !response.actions.is_empty()) {
+                        warn!(%handler, "Replayed event received actions from webhook, ignoring");
+                    }
+                    delivered += 1;
+                }
+                Ok(delivery) => {
+                    self.record(false);
+                    warn!(
+                        status = delivery.status,
+                        %handler,
+                        delivered,
+                        "Buffered event replay stopped, webhook endpoint still rejecting events"
+                    );
+                    break;
+                }
+                Err(err) => {
+                    self.record(false);
+                    warn!(?err, %handler, delivered, "Buffered event replay stopped, webhook delivery still failing");
+                    break;
+                }
+            }
+        }
+
+        if delivered > 0 {
+            info!(delivered, "Replayed events buffered while circuit breaker was open");
+        }
+        delivered
+    }
+}
+
+#[async_trait]
+impl<S: EventSender> EventSender for CircuitBreakerEventSender<S> {
+    async fn send<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        Ok(self.deliver(handler, payload).await?.response)
+    }
+
+    async fn deliver<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Delivery, GatehookError> {
+        if self.failure_threshold == 0 {
+            return self.inner.deliver(handler, payload).await;
+        }
+
+        let _probe = match self.admit() {
+            Admission::Send => None,
+            Admission::Probe => Some(ProbeGuard { state: &self.state }),
+            Admission::Hold => {
+                let response = self.hold(handler, serde_json::to_value(payload)?)?;
+                return Ok(Delivery { status: None, response });
+            }
+        };
+
+        let result = self.inner.deliver(handler, payload).await;
+        let accepted = result.as_ref().is_ok_and(Delivery::accepted);
+        if self.record(accepted) {
+            info!("Webhook delivery recovered, closing circuit breaker");
+        }
+
+        result
+    }
+
+    fn has_backlog(&self) -> bool {
+        !self.buffer.lock().unwrap().is_empty() || self.inner.has_backlog()
+    }
+
+    async fn replay_backlog(&self) {
+        self.replay().await;
+        self.inner.replay_backlog().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::RecordingSender;
+    use serde_json::json;
+    use std::sync::Arc;
+
+    const COOLDOWN: Duration = Duration::from_millis(50);
+
+    #[tokio::test]
+    async fn test_disabled_passes_through() {
+        let sender = CircuitBreakerEventSender::new(RecordingSender::default(), 0, COOLDOWN, 10);
+        sender.inner.set_fail(true);
+
+        for _ in 0..3 {
+            assert!(sender.send("message", &json!({"n": 1})).await.is_err());
+        }
+
+        assert!(!sender.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_opens_after_threshold() {
+        let sender = CircuitBreakerEventSender::new(RecordingSender::default(), 2, COOLDOWN, 10);
+        sender.inner.set_fail(true);
+
+        assert!(sender.send("message", &json!({"n": 1})).await.is_err());
+        assert!(!sender.is_open());
+        assert!(sender.send("message", &json!({"n": 2})).await.is_err());
+
+        assert!(sender.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_server_errors_count_as_failures() {
+        let sender = CircuitBreakerEventSender::new(RecordingSender::default(), 2, COOLDOWN, 10);
+
+        // A 4xx is the endpoint's answer, not an outage
+        sender.inner.set_status(404);
+        assert!(sender.send("message", &json!({"n": 1})).await.is_ok());
+        assert!(sender.send("message", &json!({"n": 2})).await.is_ok());
+        assert!(!sender.is_open());
+
+        sender.inner.set_status(503);
+        assert!(sender.send("message", &json!({"n": 3})).await.is_ok());
+        assert!(sender.send("message", &json!({"n": 4})).await.is_ok());
+        assert!(sender.is_open());
+
+        // Open: the next event is held instead of sent
+        assert!(sender.send("message", &json!({"n": 5})).await.unwrap().is_none());
+        assert_eq!(sender.inner.sent().len(), 4);
+        assert_eq!(sender.buffer.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_buffers_while_open_and_replays_on_close() {
+        let sender = CircuitBreakerEventSender::new(RecordingSender::default(), 1, COOLDOWN, 10);
+
+        // Endpoint down: breaker opens
+        sender.inner.set_fail(true);
+        assert!(sender.send("message", &json!({"n": 0})).await.is_err());
+        assert!(sender.is_open());
+
+        // Open: events are buffered, not sent
+        sender.inner.set_fail(false);
+        for n in 1..=3 {
+            assert!(sender.send("message", &json!({"n": n})).await.unwrap().is_none());
+        }
+        assert!(sender.inner.sent().is_empty());
+
+        // Cooldown over: the probe succeeds without waiting for the buffer
+        tokio::time::sleep(COOLDOWN).await;
+        assert!(sender.send("message", &json!({"n": 4})).await.is_ok());
+        assert_eq!(sender.inner.sent().len(), 1);
+        assert!(sender.has_backlog());
+
+        // The bridge then replays the buffer in order
+        sender.replay_backlog().await;
+
+        let sent: Vec<_> = sender.inner.sent().into_iter().map(|(_, payload)| payload["n"].clone()).collect();
+        assert_eq!(sent, vec![json!(4), json!(1), json!(2), json!(3)]);
+        assert!(!sender.is_open());
+        assert!(!sender.has_backlog());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_replay_keeps_buffer() {
+        let sender = CircuitBreakerEventSender::new(RecordingSender::default(), 1, COOLDOWN, 10);
+        sender.inner.set_fail(true);
+        assert!(sender.send("message", &json!({"n": 0})).await.is_err());
+        assert!(sender.send("message", &json!({"n": 1})).await.is_ok());
+        sender.inner.set_fail(false);
+        tokio::time::sleep(COOLDOWN).await;
+        assert!(sender.send("message", &json!({"n": 2})).await.is_ok());
+
+        // The event deadline fires while a buffered event is being replayed
+        sender.inner.set_delay(Duration::from_secs(5));
+        assert!(tokio::time::timeout(Duration::from_millis(20), sender.replay()).await.is_err());
+        assert_eq!(sender.buffer.lock().unwrap().len(), 1);
+
+        sender.inner.set_delay(Duration::ZERO);
+        assert_eq!(sender.replay().await, 1);
+        let sent: Vec<_> = sender.inner.sent().into_iter().map(|(_, payload)| payload["n"].clone()).collect();
+        assert_eq!(sent, vec![json!(2), json!(1)]);
+    }
+
+    #[tokio::test]
+    async fn test_replay_waits_for_the_breaker_to_close() {
+        let sender = CircuitBreakerEventSender::new(RecordingSender::default(), 1, COOLDOWN, 10);
+        sender.inner.set_fail(true);
+        assert!(sender.send("message", &json!({"n": 0})).await.is_err());
+        assert!(sender.send("message", &json!({"n": 1})).await.is_ok());
+        sender.inner.set_fail(false);
+
+        // Open, then half-open: only the probe may reach the endpoint
+        assert_eq!(sender.replay().await, 0);
+        tokio::time::sleep(COOLDOWN).await;
+        assert_eq!(sender.replay().await, 0);
+
+        assert!(sender.inner.sent().is_empty());
+        assert_eq!(sender.buffer.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_sends_a_single_probe() {
+        let sender = Arc::new(CircuitBreakerEventSender::new(RecordingSender::default(), 1, COOLDOWN, 10));
+        sender.inner.set_fail(true);
+        assert!(sender.send("message", &json!({"n": 0})).await.is_err());
+        tokio::time::sleep(COOLDOWN).await;
+
+        // The probe is in flight (slow endpoint) when more events arrive
+        sender.inner.set_fail(false);
+        sender.inner.set_delay(Duration::from_millis(100));
+        let probe = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.send("message", &json!({"n": 1})).await }
+        });
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        for n in 2..=3 {
+            assert!(sender.send("message", &json!({"n": n})).await.is_ok());
+        }
+        assert_eq!(sender.buffer.lock().unwrap().len(), 2);
+
+        // The probe succeeds: the breaker closes and the held events are replayed
+        assert!(probe.await.unwrap().is_ok());
+        assert!(!sender.is_open());
+        sender.inner.set_delay(Duration::ZERO);
+        sender.replay_backlog().await;
+
+        let sent: Vec<_> = sender.inner.sent().into_iter().map(|(_, payload)| payload["n"].clone()).collect();
+        assert_eq!(sent, vec![json!(1), json!(2), json!(3)]);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_probe_lets_the_next_event_probe() {
+        let sender = CircuitBreakerEventSender::new(RecordingSender::default(), 1, COOLDOWN, 10);
+        sender.inner.set_fail(true);
+        assert!(sender.send("message", &json!({"n": 0})).await.is_err());
+        tokio::time::sleep(COOLDOWN).await;
+
+        // The event deadline fires while the probe is in flight
+        sender.inner.set_fail(false);
+        sender.inner.set_delay(Duration::from_secs(5));
+        let payload = json!({"n": 1});
+        assert!(tokio::time::timeout(Duration::from_millis(20), sender.send("message", &payload)).await.is_err());
+
+        sender.inner.set_delay(Duration::ZERO);
+        assert!(sender.send("message", &json!({"n": 2})).await.is_ok());
+        assert!(!sender.is_open());
+        assert_eq!(sender.inner.sent(), vec![("message".to_string(), json!({"n": 2}))]);
+    }
+
+    #[tokio::test]
+    async fn test_drops_excess_events_with_counter() {
+        let sender = CircuitBreakerEventSender::new(RecordingSender::default(), 1, COOLDOWN, 2);
+        sender.inner.set_fail(true);
+        assert!(sender.send("message", &json!({"n": 0})).await.is_err());
+
+        let results = [
+            sender.send("message", &json!({"n": 1})).await,
+            sender.send("message", &json!({"n": 2})).await,
+            sender.send("message", &json!({"n": 3})).await,
+        ];
+
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert!(matches!(results[2], Err(GatehookError::CircuitOpen)));
+        assert_eq!(sender.buffer.lock().unwrap().len(), 2);
+        assert_eq!(sender.dropped.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_probe_reopens_and_keeps_buffer() {
+        let sender = CircuitBreakerEventSender::new(RecordingSender::default(), 1, COOLDOWN, 10);
+        sender.inner.set_fail(true);
+        assert!(sender.send("message", &json!({"n": 0})).await.is_err());
+        assert!(sender.send("message", &json!({"n": 1})).await.is_ok());
+
+        tokio::time::sleep(COOLDOWN).await;
+        assert!(sender.send("message", &json!({"n": 2})).await.is_err());
+
+        assert!(sender.is_open());
+        assert_eq!(sender.buffer.lock().unwrap().len(), 1);
+    }
+}
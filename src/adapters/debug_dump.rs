@@ -0,0 +1,197 @@
+use crate::error::GatehookError;
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use std::fs::{self, File};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::warn;
+
+/// File name suffix of dumped payloads
+const DUMP_SUFFIX: &str = ".json.gz";
+
+/// Directory of gzipped outgoing payloads, kept for replay/debugging
+///
+/// Each payload is written to its own file named
+/// `<unix millis>-<sequence>-<handler>.json.gz`, so names sort oldest first.
+/// After every write the oldest dumps are deleted until both bounds hold
+/// (the newest dump is always kept).
+///
+/// # Bounds
+///
+/// - `max_files` - Maximum number of dump files (0 = unlimited)
+/// - `max_bytes` - Maximum total size of dump files in bytes (0 = unlimited)
+pub struct DebugDump {
+    dir: PathBuf,
+    max_files: usize,
+    max_bytes: u64,
+    /// Sequence number of the next dump (also serializes writes and pruning)
+    next_seq: Mutex<u64>,
+}
+
+impl DebugDump {
+    /// Open (or create) a dump directory
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Directory the dump files are written to
+    /// * `max_files` - Maximum number of dump files kept (0 = unlimited)
+    /// * `max_bytes` - Maximum total size of dump files kept (0 = unlimited)
+    pub fn open(dir: impl Into<PathBuf>, max_files: usize, max_bytes: u64) -> Result<Self, GatehookError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            max_files,
+            max_bytes,
+            next_seq: Mutex::new(0),
+        })
+    }
+
+    /// Write a gzipped JSON payload and return the path of the dump file
+    pub fn write(&self, handler: &str, payload: &serde_json::Value) -> Result<PathBuf, GatehookError> {
+        let mut next_seq = self.next_seq.lock().unwrap();
+
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let path = self
+            .dir
+            .join(format!("{millis:013}-{:06}-{handler}{DUMP_SUFFIX}", *next_seq));
+
+        let mut encoder = GzEncoder::new(File::create(&path)?, Compression::default());
+        serde_json::to_writer(&mut encoder, payload)?;
+        encoder.finish()?.flush()?;
+
+        *next_seq += 1;
+        self.prune()?;
+        Ok(path)
+    }
+
+    /// List dump files with their sizes, oldest first
+    pub fn files(&self) -> Result<Vec<(PathBuf, u64)>, GatehookError> {
+        let mut files = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            if is_dump_file(&entry.path()) {
+                files.push((entry.path(), entry.metadata()?.len()));
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Delete the oldest dumps until `max_files` and `max_bytes` hold
+    fn prune(&self) -> Result<(), GatehookError> {
+        if self.max_files == 0 && self.max_bytes == 0 {
+            return Ok(());
+        }
+
+        let files = self.files()?;
+        let mut count = files.len();
+        let mut total: u64 = files.iter().map(|(_, size)| size).sum();
+
+        for (path, size) in &files[..files.len().saturating_sub(1)] {
+            let over_files = self.max_files > 0 && count > self.max_files;
+            let over_bytes = self.max_bytes > 0 && total > self.max_bytes;
+            if !over_files && !over_bytes {
+                break;
+            }
+
+            if let Err(err) = fs::remove_file(path) {
+                warn!(?err, path = %path.display(), "Failed to delete old debug dump");
+                continue;
+            }
+            count -= 1;
+            total -= size;
+        }
+
+        Ok(())
+    }
+}
+
+fn is_dump_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(DUMP_SUFFIX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempPath;
+    use flate2::read::GzDecoder;
+    use serde_json::json;
+
+    fn read_dump(path: &Path) -> serde_json::Value {
+        serde_json::from_reader(GzDecoder::new(File::open(path).unwrap())).unwrap()
+    }
+
+    #[test]
+    fn test_debug_dump_writes_readable_gzipped_payload() {
+        let dir = TempPath::new("debug-dump-write");
+        let dump = DebugDump::open(&dir.0, 0, 0).unwrap();
+
+        let path = dump.write("message", &json!({"content": "hello"})).unwrap();
+
+        let name = path.file_name().unwrap().to_str().unwrap();
+        assert!(name.ends_with("-000000-message.json.gz"), "unexpected name {name}");
+        assert_eq!(read_dump(&path), json!({"content": "hello"}));
+    }
+
+    #[test]
+    fn test_debug_dump_files_sorted_oldest_first() {
+        let dir = TempPath::new("debug-dump-order");
+        let dump = DebugDump::open(&dir.0, 0, 0).unwrap();
+
+        for n in 0..3 {
+            dump.write("message", &json!({"n": n})).unwrap();
+        }
+
+        let payloads: Vec<_> = dump.files().unwrap().iter().map(|(path, _)| read_dump(path)).collect();
+        assert_eq!(payloads, vec![json!({"n": 0}), json!({"n": 1}), json!({"n": 2})]);
+    }
+
+    #[test]
+    fn test_debug_dump_prunes_oldest_beyond_max_files() {
+        let dir = TempPath::new("debug-dump-max-files");
+        let dump = DebugDump::open(&dir.0, 2, 0).unwrap();
+
+        for n in 0..5 {
+            dump.write("message", &json!({"n": n})).unwrap();
+        }
+
+        let payloads: Vec<_> = dump.files().unwrap().iter().map(|(path, _)| read_dump(path)).collect();
+        assert_eq!(payloads, vec![json!({"n": 3}), json!({"n": 4})]);
+    }
+
+    #[test]
+    fn test_debug_dump_prunes_beyond_max_bytes_keeping_newest() {
+        let dir = TempPath::new("debug-dump-max-bytes");
+        // Smaller than a single gzipped payload
+        let dump = DebugDump::open(&dir.0, 0, 1).unwrap();
+
+        dump.write("message", &json!({"n": 0})).unwrap();
+        let newest = dump.write("message", &json!({"n": 1})).unwrap();
+
+        let files = dump.files().unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].0, newest);
+    }
+
+    #[test]
+    fn test_debug_dump_ignores_other_files() {
+        let dir = TempPath::new("debug-dump-other");
+        let dump = DebugDump::open(&dir.0, 1, 0).unwrap();
+        fs::write(dir.0.join("notes.txt"), "keep me").unwrap();
+
+        dump.write("message", &json!(1)).unwrap();
+        dump.write("message", &json!(2)).unwrap();
+
+        assert_eq!(dump.files().unwrap().len(), 1);
+        assert!(dir.0.join("notes.txt").exists());
+    }
+}
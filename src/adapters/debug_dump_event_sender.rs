@@ -0,0 +1,107 @@
+use super::debug_dump::DebugDump;
+use super::event_response::EventResponse;
+use super::event_sender_trait::{Delivery, EventSender};
+use crate::error::GatehookError;
+use serde::Serialize;
+use serenity::async_trait;
+use std::sync::Arc;
+use tracing::{debug, error};
+
+/// EventSender decorator writing every outgoing payload to a debug dump
+///
+/// Payloads are dumped (gzipped JSON) right before the inner sender is
+/// called, whether or not delivery then succeeds. A failed dump is logged
+/// and does not prevent delivery.
+///
+/// Without a dump (`DEBUG_DUMP_DIR` unset), events pass straight through.
+pub struct DebugDumpEventSender<S: EventSender> {
+    inner: S,
+    dump: Option<Arc<DebugDump>>,
+}
+
+impl<S: EventSender> DebugDumpEventSender<S> {
+    /// Create a new DebugDumpEventSender
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The sender that performs the actual delivery
+    /// * `dump` - Dump directory for outgoing payloads (`None` to pass through)
+    pub fn new(inner: S, dump: Option<Arc<DebugDump>>) -> Self {
+        Self { inner, dump }
+    }
+}
+
+#[async_trait]
+impl<S: EventSender> EventSender for DebugDumpEventSender<S> {
+    async fn send<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        Ok(self.deliver(handler, payload).await?.response)
+    }
+
+    async fn deliver<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Delivery, GatehookError> {
+        let Some(dump) = &self.dump else {
+            return self.inner.deliver(handler, payload).await;
+        };
+
+        let payload = serde_json::to_value(payload)?;
+        match dump.write(handler, &payload) {
+            Ok(path) => debug!(%handler, path = %path.display(), "Dumped outgoing payload"),
+            Err(err) => error!(?err, %handler, "Failed to dump outgoing payload"),
+        }
+
+        self.inner.deliver(handler, &payload).await
+    }
+
+    fn has_backlog(&self) -> bool {
+        self.inner.has_backlog()
+    }
+
+    async fn replay_backlog(&self) {
+        self.inner.replay_backlog().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{RecordingSender, TempPath};
+    use flate2::read::GzDecoder;
+    use serde_json::json;
+    use std::fs::File;
+
+    #[tokio::test]
+    async fn test_send_without_dump_passes_through() {
+        let sender = DebugDumpEventSender::new(RecordingSender::default(), None);
+
+        let result = sender.send("message", &json!({"n": 1})).await;
+
+        assert!(result.is_ok());
+        assert_eq!(*sender.inner.sent(), vec![("message".to_string(), json!({"n": 1}))]);
+    }
+
+    #[tokio::test]
+    async fn test_send_dumps_payload_and_delivers() {
+        let dir = TempPath::new("dump-sender-send");
+        let dump = Arc::new(DebugDump::open(&dir.0, 0, 0).unwrap());
+        let sender = DebugDumpEventSender::new(RecordingSender::default(), Some(dump.clone()));
+
+        let result = sender.send("reaction_add", &json!({"emoji": "👍"})).await;
+
+        assert!(result.is_ok());
+        assert_eq!(sender.inner.sent().len(), 1);
+
+        let files = dump.files().unwrap();
+        assert_eq!(files.len(), 1);
+        assert!(files[0].0.to_str().unwrap().ends_with("-reaction_add.json.gz"));
+        let dumped: serde_json::Value =
+            serde_json::from_reader(GzDecoder::new(File::open(&files[0].0).unwrap())).unwrap();
+        assert_eq!(dumped, json!({"emoji": "👍"}));
+    }
+}
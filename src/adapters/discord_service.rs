@@ -1,6 +1,12 @@
+#[cfg(feature = "actions")]
+use crate::adapters::EmbedParams;
 use serenity::async_trait;
-use serenity::model::channel::{GuildChannel, Message};
+#[cfg(feature = "actions")]
+use serenity::model::channel::{Attachment, ChannelType, GuildChannel};
+use serenity::model::channel::Message;
 use serenity::model::id::{ChannelId, MessageId};
+#[cfg(feature = "actions")]
+use serenity::model::id::{GuildId, InteractionId, RoleId, UserId, WebhookId};
 
 /// Interface for Discord operations
 ///
@@ -15,6 +21,7 @@ pub trait DiscordService: Send + Sync {
     /// * `channel_id` - The channel where the message was sent
     /// * `message_id` - The message to react to
     /// * `emoji` - The emoji to react with (Unicode or custom emoji format)
+    #[cfg(feature = "actions")]
     async fn react_to_message(
         &self,
         channel_id: ChannelId,
@@ -30,12 +37,34 @@ pub trait DiscordService: Send + Sync {
     /// * `message_id` - The message to create a thread from
     /// * `name` - The thread name
     /// * `auto_archive_duration` - Auto-archive duration in minutes (60, 1440, 4320, 10080)
+    /// * `rate_limit_per_user` - Slowmode in seconds (`None` keeps Discord's default)
+    #[cfg(feature = "actions")]
     async fn create_thread_from_message(
         &self,
         channel_id: ChannelId,
         message_id: MessageId,
         name: &str,
         auto_archive_duration: u16,
+        rate_limit_per_user: Option<u16>,
+    ) -> Result<GuildChannel, serenity::Error>;
+
+    /// Create a private thread in a channel (not attached to any message)
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_id` - The channel to create the thread in
+    /// * `name` - The thread name
+    /// * `auto_archive_duration` - Auto-archive duration in minutes (60, 1440, 4320, 10080)
+    /// * `invitable` - Whether non-moderators may add others (`None` keeps Discord's default)
+    /// * `rate_limit_per_user` - Slowmode in seconds (`None` keeps Discord's default)
+    #[cfg(feature = "actions")]
+    async fn create_private_thread(
+        &self,
+        channel_id: ChannelId,
+        name: &str,
+        auto_archive_duration: u16,
+        invitable: Option<bool>,
+        rate_limit_per_user: Option<u16>,
     ) -> Result<GuildChannel, serenity::Error>;
 
     /// Send a message to a channel
@@ -44,10 +73,38 @@ pub trait DiscordService: Send + Sync {
     ///
     /// * `channel_id` - The channel to send the message to
     /// * `content` - The message content
+    /// * `allow_mass_mentions` - Whether `@everyone`/`@here` may ping
+    #[cfg(feature = "actions")]
     async fn send_message_to_channel(
         &self,
         channel_id: ChannelId,
         content: &str,
+        allow_mass_mentions: bool,
+    ) -> Result<Message, serenity::Error>;
+
+    /// Open (or reuse) the direct message channel with a user
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user to message
+    #[cfg(feature = "actions")]
+    async fn create_dm_channel(&self, user_id: UserId) -> Result<ChannelId, serenity::Error>;
+
+    /// Send a message with attachments to a channel
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_id` - The channel to send the message to
+    /// * `content` - The message content
+    /// * `attachments` - Existing attachments to download and re-upload
+    /// * `allow_mass_mentions` - Whether `@everyone`/`@here` may ping
+    #[cfg(feature = "actions")]
+    async fn send_message_with_attachments(
+        &self,
+        channel_id: ChannelId,
+        content: &str,
+        attachments: &[Attachment],
+        allow_mass_mentions: bool,
     ) -> Result<Message, serenity::Error>;
 
     /// Reply to a message in a specific channel
@@ -58,14 +115,218 @@ pub trait DiscordService: Send + Sync {
     /// * `message_id` - The message to reply to
     /// * `content` - The reply content
     /// * `mention` - Whether to mention the user
+    /// * `allow_mass_mentions` - Whether `@everyone`/`@here` may ping
+    #[cfg(feature = "actions")]
     async fn reply_in_channel(
         &self,
         channel_id: ChannelId,
         message_id: MessageId,
         content: &str,
         mention: bool,
+        allow_mass_mentions: bool,
+    ) -> Result<Message, serenity::Error>;
+
+    /// Reply to a message with a single file attachment and no text content
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_id` - The channel to send the reply in
+    /// * `message_id` - The message to reply to
+    /// * `filename` - The attachment's file name
+    /// * `data` - The attachment's contents
+    /// * `mention` - Whether to mention the user
+    #[cfg(feature = "actions")]
+    async fn reply_with_file_in_channel(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        filename: &str,
+        data: Vec<u8>,
+        mention: bool,
     ) -> Result<Message, serenity::Error>;
 
+    /// Delete a message
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_id` - The channel where the message is located
+    /// * `message_id` - The message to delete
+    #[cfg(feature = "actions")]
+    async fn delete_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), serenity::Error>;
+
+    /// Edit the content and/or embed of a message sent by the bot
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_id` - The channel where the message is located
+    /// * `message_id` - The message to edit
+    /// * `content` - The new message content (`None` leaves it unchanged)
+    /// * `embed` - Embed replacing the message's embeds (`None` leaves them unchanged)
+    /// * `allow_mass_mentions` - Whether `@everyone`/`@here` may ping
+    #[cfg(feature = "actions")]
+    async fn edit_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        content: Option<&str>,
+        embed: Option<&EmbedParams>,
+        allow_mass_mentions: bool,
+    ) -> Result<Message, serenity::Error>;
+
+    /// Post a message through a Discord channel webhook (not as the bot user)
+    ///
+    /// # Arguments
+    ///
+    /// * `webhook_id` - The webhook to execute
+    /// * `token` - The webhook's token
+    /// * `username` - Display name overriding the webhook's default
+    /// * `avatar_url` - Avatar URL overriding the webhook's default
+    /// * `content` - The message content
+    /// * `allow_mass_mentions` - Whether `@everyone`/`@here` may ping
+    #[cfg(feature = "actions")]
+    async fn execute_webhook(
+        &self,
+        webhook_id: WebhookId,
+        token: &str,
+        username: Option<&str>,
+        avatar_url: Option<&str>,
+        content: &str,
+        allow_mass_mentions: bool,
+    ) -> Result<(), serenity::Error>;
+
+    /// Hide the link embeds of a message (sets the `SUPPRESS_EMBEDS` flag)
+    ///
+    /// Requires the message to be the bot's own, or Manage Messages.
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_id` - The channel where the message is located
+    /// * `message_id` - The message whose embeds to suppress
+    #[cfg(feature = "actions")]
+    async fn suppress_embeds(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), serenity::Error>;
+
+    /// Remove all reactions from a message
+    ///
+    /// Requires Manage Messages (guild channels only).
+    ///
+    /// # Arguments
+    ///
+    /// * `channel_id` - The channel where the message is located
+    /// * `message_id` - The message whose reactions to clear
+    #[cfg(feature = "actions")]
+    async fn remove_all_reactions(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), serenity::Error>;
+
+    /// Leave a guild
+    ///
+    /// # Arguments
+    ///
+    /// * `guild_id` - The guild to leave
+    #[cfg(feature = "actions")]
+    async fn leave_guild(&self, guild_id: GuildId) -> Result<(), serenity::Error>;
+
+    /// Connect the bot to a voice channel
+    ///
+    /// Only joins the channel at the gateway level; audio is left to a
+    /// separate voice integration.
+    ///
+    /// # Arguments
+    ///
+    /// * `guild_id` - The guild of the voice channel
+    /// * `channel_id` - The voice channel to join
+    #[cfg(feature = "actions")]
+    async fn join_voice_channel(&self, guild_id: GuildId, channel_id: ChannelId) -> Result<(), serenity::Error>;
+
+    /// Create a channel in a guild
+    ///
+    /// # Arguments
+    ///
+    /// * `guild_id` - The guild to create the channel in
+    /// * `name` - The channel name
+    /// * `kind` - The channel type
+    /// * `parent_id` - Optional category to create the channel in
+    /// * `topic` - Optional channel topic
+    #[cfg(feature = "actions")]
+    async fn create_channel(
+        &self,
+        guild_id: GuildId,
+        name: &str,
+        kind: ChannelType,
+        parent_id: Option<ChannelId>,
+        topic: Option<&str>,
+    ) -> Result<GuildChannel, serenity::Error>;
+
+    /// Add a role to a guild member
+    ///
+    /// # Arguments
+    ///
+    /// * `guild_id` - The guild the member belongs to
+    /// * `user_id` - The member to add the role to
+    /// * `role_id` - The role to add
+    #[cfg(feature = "actions")]
+    async fn add_member_role(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId,
+    ) -> Result<(), serenity::Error>;
+
+    /// Remove a role from a guild member
+    ///
+    /// # Arguments
+    ///
+    /// * `guild_id` - The guild the member belongs to
+    /// * `user_id` - The member to remove the role from
+    /// * `role_id` - The role to remove
+    #[cfg(feature = "actions")]
+    async fn remove_member_role(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId,
+    ) -> Result<(), serenity::Error>;
+
+    /// Acknowledge a component interaction without changing its message (deferred update)
+    ///
+    /// # Arguments
+    ///
+    /// * `interaction_id` - The interaction to respond to
+    /// * `token` - The interaction's token
+    #[cfg(feature = "actions")]
+    async fn acknowledge_interaction(
+        &self,
+        interaction_id: InteractionId,
+        token: &str,
+    ) -> Result<(), serenity::Error>;
+
+    /// Respond to a component interaction by updating the content of its message
+    ///
+    /// # Arguments
+    ///
+    /// * `interaction_id` - The interaction to respond to
+    /// * `token` - The interaction's token
+    /// * `content` - The new message content
+    /// * `allow_mass_mentions` - Whether `@everyone`/`@here` may ping
+    #[cfg(feature = "actions")]
+    async fn update_interaction_message(
+        &self,
+        interaction_id: InteractionId,
+        token: &str,
+        content: &str,
+        allow_mass_mentions: bool,
+    ) -> Result<(), serenity::Error>;
+
     /// Get a message by ID
     ///
     /// # Arguments
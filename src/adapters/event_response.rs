@@ -1,26 +1,105 @@
 use serde::Deserialize;
+use serenity::model::channel::ChannelType;
 
 /// Response from webhook endpoint
 ///
 /// The response returned from the webhook endpoint after sending a Discord event.
 /// Contains a list of actions for the bot to execute.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[serde(from = "RawEventResponse")]
 pub struct EventResponse {
     /// List of actions to execute
     ///
     /// If empty or the field is missing, no actions will be performed.
-    #[serde(default)]
     pub actions: Vec<ResponseAction>,
+    /// Optional `idempotency_key` of each action, by index into `actions`
+    ///
+    /// Missing trailing entries mean no key, so responses built in code can
+    /// leave this empty. Keep it aligned when reordering `actions`.
+    pub idempotency_keys: Vec<Option<String>>,
+    /// Number of leading actions added by gatehook itself (e.g. the
+    /// auto-reaction), which do not count toward `max_actions`
+    pub exempt_actions: usize,
+}
+
+impl EventResponse {
+    /// Idempotency key of the action at `index`, if the webhook set one
+    pub fn idempotency_key(&self, index: usize) -> Option<&str> {
+        self.idempotency_keys.get(index)?.as_deref()
+    }
+
+    /// Append the actions of `other`, keeping idempotency keys aligned
+    pub fn append(&mut self, other: EventResponse) {
+        self.idempotency_keys.resize(self.actions.len(), None);
+        self.idempotency_keys.extend(other.idempotency_keys);
+        self.actions.extend(other.actions);
+    }
+}
+
+impl From<Vec<ResponseAction>> for EventResponse {
+    fn from(actions: Vec<ResponseAction>) -> Self {
+        Self {
+            actions,
+            idempotency_keys: Vec::new(),
+            exempt_actions: 0,
+        }
+    }
+}
+
+/// Wire format of `EventResponse`, with the keys still inside each action
+#[derive(Deserialize)]
+struct RawEventResponse {
+    #[serde(default)]
+    actions: Vec<RawAction>,
+}
+
+#[derive(Deserialize)]
+struct RawAction {
+    #[serde(default)]
+    idempotency_key: Option<String>,
+    #[serde(flatten)]
+    action: ResponseAction,
+}
+
+impl From<RawEventResponse> for EventResponse {
+    fn from(raw: RawEventResponse) -> Self {
+        let (actions, idempotency_keys) = raw
+            .actions
+            .into_iter()
+            .map(|raw| (raw.action, raw.idempotency_key))
+            .unzip();
+        Self {
+            actions,
+            idempotency_keys,
+            exempt_actions: 0,
+        }
+    }
 }
 
 /// Parameters for Reply action
 #[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct ReplyParams {
-    /// Reply content (any length accepted, truncated at execution if needed)
+    /// Reply content (any length accepted, truncated or uploaded as a file at execution if needed)
     pub content: String,
     /// Whether to ping/mention the user (default: false)
     #[serde(default)]
     pub mention: bool,
+    /// Whether to delete the original message after replying (default: false)
+    ///
+    /// The original message is only deleted if the reply succeeds.
+    #[serde(default)]
+    pub delete_original: bool,
+    /// Whether `@everyone`/`@here` in the content may ping (default: false)
+    ///
+    /// Only relevant while `SUPPRESS_MASS_MENTIONS` is enabled (the default).
+    #[serde(default)]
+    pub allow_mass_mentions: bool,
+    /// Whether to upload over-length content as a `.txt` attachment instead of
+    /// truncating it (default: false)
+    ///
+    /// Content within Discord's 2000 character limit is always sent as a normal reply.
+    #[serde(default)]
+    pub as_file: bool,
 }
 
 /// Parameters for React action
@@ -31,7 +110,14 @@ pub struct ReactParams {
     /// Can be:
     /// - Unicode emoji (e.g., "👍", "🎉")
     /// - Custom emoji in format "name:id" (e.g., "customemoji:123456789")
+    /// - `"$trigger"`: the emoji of the triggering reaction (reaction events only)
     pub emoji: String,
+    /// Message to react to instead of the event target (snowflake string)
+    #[serde(default)]
+    pub message_id: Option<String>,
+    /// Channel containing `message_id` (defaults to the event target's channel)
+    #[serde(default)]
+    pub channel_id: Option<String>,
 }
 
 /// Parameters for Thread action
@@ -47,6 +133,234 @@ pub struct ThreadParams {
     /// Valid values: 60, 1440, 4320, 10080
     #[serde(default = "default_auto_archive")]
     pub auto_archive_duration: u16,
+    /// Message to start the thread on instead of the event target (snowflake string)
+    #[serde(default)]
+    pub message_id: Option<String>,
+    /// Channel containing `message_id` (defaults to the event target's channel)
+    #[serde(default)]
+    pub channel_id: Option<String>,
+    /// Create a private thread instead of a public one (default: false)
+    ///
+    /// Private threads are not attached to the target message. Channels that
+    /// do not support them (announcement channels) get a public thread instead.
+    #[serde(default)]
+    pub private: bool,
+    /// Whether `@everyone`/`@here` in the content may ping (default: false)
+    ///
+    /// Only relevant while `SUPPRESS_MASS_MENTIONS` is enabled (the default).
+    #[serde(default)]
+    pub allow_mass_mentions: bool,
+    /// Whether non-moderators may add others to a private thread
+    /// (Discord default: true; ignored for public threads)
+    #[serde(default)]
+    pub invitable: Option<bool>,
+    /// Slowmode for the new thread in seconds (clamped to 0-21600)
+    #[serde(default)]
+    pub rate_limit_per_user: Option<u16>,
+}
+
+/// Parameters for Edit action
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct EditParams {
+    /// Message to edit (snowflake string), or `"$last"` for the message most
+    /// recently sent by an earlier action in the same response
+    pub message_id: String,
+    /// Channel containing `message_id` (defaults to the event target's channel;
+    /// ignored for `"$last"`)
+    #[serde(default)]
+    pub channel_id: Option<String>,
+    /// New message content (any length accepted, truncated at execution if needed)
+    ///
+    /// Omitted to leave the content unchanged (e.g. an embed-only edit).
+    #[serde(default)]
+    pub content: Option<String>,
+    /// Embed replacing the message's embeds (omitted to leave them unchanged)
+    ///
+    /// An edit with neither `content` nor `embed` is skipped.
+    #[serde(default)]
+    pub embed: Option<EmbedParams>,
+    /// Whether `@everyone`/`@here` in the content may ping (default: false)
+    ///
+    /// Only relevant while `SUPPRESS_MASS_MENTIONS` is enabled (the default).
+    #[serde(default)]
+    pub allow_mass_mentions: bool,
+}
+
+/// Embed attached to a message by an action
+///
+/// Values are passed to Discord as-is; Discord rejects embeds exceeding its
+/// limits (e.g. title 256, description 4096 characters, 25 fields).
+#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+pub struct EmbedParams {
+    /// Title of the embed
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Main text of the embed
+    #[serde(default)]
+    pub description: Option<String>,
+    /// URL the title links to
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Color of the embed's side bar (e.g. `5814783` for `0x58B9FF`)
+    #[serde(default)]
+    pub color: Option<u32>,
+    /// Name/value fields shown below the description
+    #[serde(default)]
+    pub fields: Vec<EmbedFieldParams>,
+    /// Footer text
+    #[serde(default)]
+    pub footer: Option<String>,
+}
+
+/// Field of an embed
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct EmbedFieldParams {
+    /// Field name
+    pub name: String,
+    /// Field value
+    pub value: String,
+    /// Whether the field may be shown next to other inline fields (default: false)
+    #[serde(default)]
+    pub inline: bool,
+}
+
+/// Parameters for SendMessage action
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct SendMessageParams {
+    /// Channel to post to (snowflake string, defaults to the event target's channel)
+    #[serde(default)]
+    pub channel_id: Option<String>,
+    /// Message content (any length accepted, truncated at execution if needed)
+    pub content: String,
+    /// Whether `@everyone`/`@here` in the content may ping (default: false)
+    ///
+    /// Only relevant while `SUPPRESS_MASS_MENTIONS` is enabled (the default).
+    #[serde(default)]
+    pub allow_mass_mentions: bool,
+}
+
+/// Parameters for WebhookMessage action
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct WebhookMessageParams {
+    /// Discord channel webhook URL (`https://discord.com/api/webhooks/{id}/{token}`)
+    pub webhook_url: String,
+    /// Display name overriding the webhook's default
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Avatar image URL overriding the webhook's default
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+    /// Message content (any length accepted, truncated at execution if needed)
+    pub content: String,
+    /// Whether `@everyone`/`@here` in the content may ping (default: false)
+    ///
+    /// Only relevant while `SUPPRESS_MASS_MENTIONS` is enabled (the default).
+    #[serde(default)]
+    pub allow_mass_mentions: bool,
+}
+
+/// Parameters for Forward action
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ForwardParams {
+    /// Channel to repost the event message to (snowflake string)
+    pub to_channel_id: String,
+    /// Re-upload the message's attachments along with its content (default: false)
+    #[serde(default)]
+    pub include_attachments: bool,
+}
+
+/// Parameters for ToggleRole action
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct ToggleRoleParams {
+    /// Role to add on `reaction_add` and remove on `reaction_remove` (snowflake string)
+    pub role_id: String,
+}
+
+/// Parameters for LeaveGuild action
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct LeaveGuildParams {
+    /// Guild to leave (snowflake string, defaults to the event's guild)
+    #[serde(default)]
+    pub guild_id: Option<String>,
+}
+
+/// Parameters for JoinVoice action
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct JoinVoiceParams {
+    /// Voice channel of the event's guild to connect to (snowflake string)
+    pub channel_id: String,
+}
+
+/// Parameters for CreateChannel action
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct CreateChannelParams {
+    /// Name of the new channel
+    pub name: String,
+    /// Channel type (default: `text`)
+    #[serde(default)]
+    pub kind: ChannelKind,
+    /// Category to create the channel in (snowflake string)
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    /// Channel topic (text, announcement and forum channels)
+    #[serde(default)]
+    pub topic: Option<String>,
+}
+
+/// Channel type of a CreateChannel action
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelKind {
+    #[default]
+    Text,
+    Voice,
+    Category,
+    Announcement,
+    Stage,
+    Forum,
+}
+
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+impl ChannelKind {
+    /// Discord channel type to create
+    pub fn channel_type(self) -> ChannelType {
+        match self {
+            Self::Text => ChannelType::Text,
+            Self::Voice => ChannelType::Voice,
+            Self::Category => ChannelType::Category,
+            Self::Announcement => ChannelType::News,
+            Self::Stage => ChannelType::Stage,
+            Self::Forum => ChannelType::Forum,
+        }
+    }
+}
+
+/// Parameters for InteractionResponse action
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct InteractionResponseParams {
+    /// How to respond (default: `deferred_update`)
+    #[serde(default)]
+    pub kind: InteractionResponseKind,
+    /// New content of the component's message (required for `update`,
+    /// any length accepted, truncated at execution if needed)
+    #[serde(default)]
+    pub content: Option<String>,
+    /// Whether `@everyone`/`@here` in the content may ping (default: false)
+    ///
+    /// Only relevant while `SUPPRESS_MASS_MENTIONS` is enabled (the default).
+    #[serde(default)]
+    pub allow_mass_mentions: bool,
+}
+
+/// Response type of an InteractionResponse action
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum InteractionResponseKind {
+    /// Acknowledge the interaction without changing the message
+    #[default]
+    DeferredUpdate,
+    /// Replace the content of the message the component is attached to
+    Update,
 }
 
 /// Action to execute in response to a Discord event
@@ -59,6 +373,59 @@ pub enum ResponseAction {
     React(ReactParams),
     /// Create thread or post to existing thread (MESSAGE_GUILD only)
     Thread(ThreadParams),
+    /// Edit a message previously sent by the bot
+    Edit(EditParams),
+    /// Post a standalone message to a channel (no message context required)
+    SendMessage(SendMessageParams),
+    /// Post a message through a Discord channel webhook (custom name/avatar, no message context required)
+    WebhookMessage(WebhookMessageParams),
+    /// Repost the event message to another channel (message events only)
+    Forward(ForwardParams),
+    /// Add (reaction added) or remove (reaction removed) a role for the reacting user
+    ToggleRole(ToggleRoleParams),
+    /// Hide the link embeds of the target message (requires message context)
+    SuppressEmbeds,
+    /// Clear every reaction from the target message (guild events only)
+    RemoveAllReactions,
+    /// Make the bot leave a guild (only when listed in `ALLOWED_ACTIONS`)
+    LeaveGuild(LeaveGuildParams),
+    /// Connect the bot to a voice channel (only when listed in `ALLOWED_ACTIONS`)
+    JoinVoice(JoinVoiceParams),
+    /// Create a channel in the event's guild (guild events only)
+    CreateChannel(CreateChannelParams),
+    /// Acknowledge a component interaction or update its message (interaction events only)
+    InteractionResponse(InteractionResponseParams),
+}
+
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+impl ResponseAction {
+    /// Action name as used in the `type` field (e.g. `"reply"`, `"leave_guild"`)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Reply(_) => "reply",
+            Self::React(_) => "react",
+            Self::Thread(_) => "thread",
+            Self::Edit(_) => "edit",
+            Self::SendMessage(_) => "send_message",
+            Self::WebhookMessage(_) => "webhook_message",
+            Self::Forward(_) => "forward",
+            Self::ToggleRole(_) => "toggle_role",
+            Self::SuppressEmbeds => "suppress_embeds",
+            Self::RemoveAllReactions => "remove_all_reactions",
+            Self::LeaveGuild(_) => "leave_guild",
+            Self::JoinVoice(_) => "join_voice",
+            Self::CreateChannel(_) => "create_channel",
+            Self::InteractionResponse(_) => "interaction_response",
+        }
+    }
+
+    /// Whether the action can run without a live target message
+    ///
+    /// Only these actions are executed for delete/update events
+    /// (see `CONTEXTLESS_ACTIONS`).
+    pub fn supports_contextless(&self) -> bool {
+        matches!(self, Self::SendMessage(_) | Self::WebhookMessage(_) | Self::Edit(_))
+    }
 }
 
 /// Default auto-archive duration (1440 minutes = 24 hours)
@@ -80,6 +447,23 @@ mod tests {
         assert_eq!(response.actions.len(), expected_len);
     }
 
+    #[test]
+    fn test_parse_idempotency_keys() {
+        let json = r#"{"actions":[
+            {"type":"reply","content":"Hello","idempotency_key":"order-42"},
+            {"type":"suppress_embeds"}
+        ]}"#;
+
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.actions.len(), 2);
+        assert!(matches!(&response.actions[0], ResponseAction::Reply(params) if params.content == "Hello"));
+        assert_eq!(response.actions[1], ResponseAction::SuppressEmbeds);
+        assert_eq!(response.idempotency_key(0), Some("order-42"));
+        assert_eq!(response.idempotency_key(1), None);
+        assert_eq!(response.idempotency_key(2), None);
+    }
+
     #[rstest]
     #[case::without_mention(
         r#"{"actions":[{"type":"reply","content":"Hello"}]}"#,
@@ -113,6 +497,321 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case::default(r#"{"actions":[{"type":"reply","content":"Hello"}]}"#, false)]
+    #[case::enabled(
+        r#"{"actions":[{"type":"reply","content":"Removed","delete_original":true}]}"#,
+        true
+    )]
+    fn test_parse_reply_delete_original(#[case] json: &str, #[case] expected: bool) {
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        match &response.actions[0] {
+            ResponseAction::Reply(params) => {
+                assert_eq!(params.delete_original, expected);
+            }
+            _ => panic!("Expected Reply action"),
+        }
+    }
+
+    #[rstest]
+    #[case::default(r#"{"actions":[{"type":"reply","content":"Hello"}]}"#, false)]
+    #[case::enabled(r#"{"actions":[{"type":"reply","content":"Log","as_file":true}]}"#, true)]
+    fn test_parse_reply_as_file(#[case] json: &str, #[case] expected: bool) {
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        match &response.actions[0] {
+            ResponseAction::Reply(params) => {
+                assert_eq!(params.as_file, expected);
+            }
+            _ => panic!("Expected Reply action"),
+        }
+    }
+
+    #[rstest]
+    #[case::default(r#"{"actions":[{"type":"thread","content":"Hi"}]}"#, false)]
+    #[case::private(r#"{"actions":[{"type":"thread","content":"Hi","private":true}]}"#, true)]
+    fn test_parse_thread_private(#[case] json: &str, #[case] expected: bool) {
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        match &response.actions[0] {
+            ResponseAction::Thread(params) => assert_eq!(params.private, expected),
+            _ => panic!("Expected Thread action"),
+        }
+    }
+
+    #[rstest]
+    #[case::reply_default(r#"{"actions":[{"type":"reply","content":"Hi"}]}"#, false)]
+    #[case::reply_enabled(r#"{"actions":[{"type":"reply","content":"Hi","allow_mass_mentions":true}]}"#, true)]
+    #[case::thread_default(r#"{"actions":[{"type":"thread","content":"Hi"}]}"#, false)]
+    #[case::thread_enabled(r#"{"actions":[{"type":"thread","content":"Hi","allow_mass_mentions":true}]}"#, true)]
+    fn test_parse_allow_mass_mentions(#[case] json: &str, #[case] expected: bool) {
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        let allow_mass_mentions = match &response.actions[0] {
+            ResponseAction::Reply(params) => params.allow_mass_mentions,
+            ResponseAction::Thread(params) => params.allow_mass_mentions,
+            _ => panic!("Expected Reply or Thread action"),
+        };
+        assert_eq!(allow_mass_mentions, expected);
+    }
+
+    #[test]
+    fn test_parse_edit_last() {
+        let json = r#"{"actions":[{"type":"edit","message_id":"$last","content":"Done"}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions[0],
+            ResponseAction::Edit(EditParams {
+                message_id: "$last".to_string(),
+                channel_id: None,
+                content: Some("Done".to_string()),
+                embed: None,
+                allow_mass_mentions: false,
+            })
+        );
+        assert_eq!(response.actions[0].name(), "edit");
+    }
+
+    #[test]
+    fn test_parse_edit_embed() {
+        let json = r#"{"actions":[{"type":"edit","message_id":"999","embed":{
+            "title":"Status","description":"All good","color":5814783,
+            "fields":[{"name":"Build","value":"passed","inline":true},{"name":"Tests","value":"812"}],
+            "footer":"CI"
+        }}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions[0],
+            ResponseAction::Edit(EditParams {
+                message_id: "999".to_string(),
+                channel_id: None,
+                content: None,
+                embed: Some(EmbedParams {
+                    title: Some("Status".to_string()),
+                    description: Some("All good".to_string()),
+                    url: None,
+                    color: Some(5814783),
+                    fields: vec![
+                        EmbedFieldParams {
+                            name: "Build".to_string(),
+                            value: "passed".to_string(),
+                            inline: true,
+                        },
+                        EmbedFieldParams {
+                            name: "Tests".to_string(),
+                            value: "812".to_string(),
+                            inline: false,
+                        },
+                    ],
+                    footer: Some("CI".to_string()),
+                }),
+                allow_mass_mentions: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_send_message() {
+        let json = r#"{"actions":[{"type":"send_message","channel_id":"999","content":"Deleted"}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions[0],
+            ResponseAction::SendMessage(SendMessageParams {
+                channel_id: Some("999".to_string()),
+                content: "Deleted".to_string(),
+                allow_mass_mentions: false,
+            })
+        );
+        assert_eq!(response.actions[0].name(), "send_message");
+    }
+
+    #[test]
+    fn test_parse_webhook_message() {
+        let json = r#"{"actions":[{"type":"webhook_message","webhook_url":"https://discord.com/api/webhooks/1/abc","username":"Bot Persona","content":"Hi"}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions[0],
+            ResponseAction::WebhookMessage(WebhookMessageParams {
+                webhook_url: "https://discord.com/api/webhooks/1/abc".to_string(),
+                username: Some("Bot Persona".to_string()),
+                avatar_url: None,
+                content: "Hi".to_string(),
+                allow_mass_mentions: false,
+            })
+        );
+        assert_eq!(response.actions[0].name(), "webhook_message");
+    }
+
+    #[rstest]
+    #[case::default(r#"{"actions":[{"type":"forward","to_channel_id":"999"}]}"#, false)]
+    #[case::with_attachments(
+        r#"{"actions":[{"type":"forward","to_channel_id":"999","include_attachments":true}]}"#,
+        true
+    )]
+    fn test_parse_forward(#[case] json: &str, #[case] include_attachments: bool) {
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions[0],
+            ResponseAction::Forward(ForwardParams {
+                to_channel_id: "999".to_string(),
+                include_attachments,
+            })
+        );
+        assert_eq!(response.actions[0].name(), "forward");
+    }
+
+    #[rstest]
+    #[case::default(
+        r#"{"actions":[{"type":"interaction_response"}]}"#,
+        InteractionResponseKind::DeferredUpdate,
+        None
+    )]
+    #[case::update(
+        r#"{"actions":[{"type":"interaction_response","kind":"update","content":"Done"}]}"#,
+        InteractionResponseKind::Update,
+        Some("Done")
+    )]
+    fn test_parse_interaction_response(
+        #[case] json: &str,
+        #[case] kind: InteractionResponseKind,
+        #[case] content: Option<&str>,
+    ) {
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions[0],
+            ResponseAction::InteractionResponse(InteractionResponseParams {
+                kind,
+                content: content.map(str::to_string),
+                allow_mass_mentions: false,
+            })
+        );
+        assert_eq!(response.actions[0].name(), "interaction_response");
+    }
+
+    #[rstest]
+    #[case::send_message(r#"{"type":"send_message","content":"Hi"}"#, true)]
+    #[case::edit(r#"{"type":"edit","message_id":"1","content":"Hi"}"#, true)]
+    #[case::webhook_message(r#"{"type":"webhook_message","webhook_url":"u","content":"Hi"}"#, true)]
+    #[case::reply(r#"{"type":"reply","content":"Hi"}"#, false)]
+    #[case::react(r#"{"type":"react","emoji":"👍"}"#, false)]
+    #[case::leave_guild(r#"{"type":"leave_guild"}"#, false)]
+    #[case::join_voice(r#"{"type":"join_voice","channel_id":"555"}"#, false)]
+    fn test_supports_contextless(#[case] json: &str, #[case] expected: bool) {
+        let action: ResponseAction = serde_json::from_str(json).unwrap();
+
+        assert_eq!(action.supports_contextless(), expected);
+    }
+
+    #[test]
+    fn test_parse_join_voice() {
+        let json = r#"{"actions":[{"type":"join_voice","channel_id":"555"}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions[0],
+            ResponseAction::JoinVoice(JoinVoiceParams {
+                channel_id: "555".to_string(),
+            })
+        );
+        assert_eq!(response.actions[0].name(), "join_voice");
+    }
+
+    #[test]
+    fn test_parse_join_voice_without_channel_fails() {
+        let json = r#"{"actions":[{"type":"join_voice"}]}"#;
+
+        assert!(serde_json::from_str::<EventResponse>(json).is_err());
+    }
+
+    #[rstest]
+    #[case::default_kind(r#"{"type":"create_channel","name":"ticket-1"}"#, ChannelKind::Text, None, None)]
+    #[case::full(
+        r#"{"type":"create_channel","name":"ticket-1","kind":"forum","parent_id":"777","topic":"Help"}"#,
+        ChannelKind::Forum,
+        Some("777"),
+        Some("Help")
+    )]
+    fn test_parse_create_channel(
+        #[case] json: &str,
+        #[case] kind: ChannelKind,
+        #[case] parent_id: Option<&str>,
+        #[case] topic: Option<&str>,
+    ) {
+        let action: ResponseAction = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            action,
+            ResponseAction::CreateChannel(CreateChannelParams {
+                name: "ticket-1".to_string(),
+                kind,
+                parent_id: parent_id.map(str::to_string),
+                topic: topic.map(str::to_string),
+            })
+        );
+        assert_eq!(action.name(), "create_channel");
+    }
+
+    #[test]
+    fn test_parse_create_channel_unknown_kind_fails() {
+        let json = r#"{"actions":[{"type":"create_channel","name":"ticket-1","kind":"dm"}]}"#;
+
+        assert!(serde_json::from_str::<EventResponse>(json).is_err());
+    }
+
+    #[test]
+    fn test_parse_toggle_role() {
+        let json = r#"{"actions":[{"type":"toggle_role","role_id":"444"}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions[0],
+            ResponseAction::ToggleRole(ToggleRoleParams {
+                role_id: "444".to_string(),
+            })
+        );
+        assert_eq!(response.actions[0].name(), "toggle_role");
+    }
+
+    #[test]
+    fn test_parse_suppress_embeds() {
+        let json = r#"{"actions":[{"type":"suppress_embeds"}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.actions[0], ResponseAction::SuppressEmbeds);
+        assert_eq!(response.actions[0].name(), "suppress_embeds");
+    }
+
+    #[test]
+    fn test_parse_remove_all_reactions() {
+        let json = r#"{"actions":[{"type":"remove_all_reactions"}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(response.actions[0], ResponseAction::RemoveAllReactions);
+        assert_eq!(response.actions[0].name(), "remove_all_reactions");
+    }
+
+    #[rstest]
+    #[case::default(r#"{"actions":[{"type":"leave_guild"}]}"#, None)]
+    #[case::explicit(r#"{"actions":[{"type":"leave_guild","guild_id":"333"}]}"#, Some("333"))]
+    fn test_parse_leave_guild(#[case] json: &str, #[case] expected: Option<&str>) {
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions[0],
+            ResponseAction::LeaveGuild(LeaveGuildParams {
+                guild_id: expected.map(str::to_string)
+            })
+        );
+        assert_eq!(response.actions[0].name(), "leave_guild");
+    }
+
     #[test]
     fn test_parse_multiple_actions() {
         let json = r#"{
@@ -159,6 +858,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_react_action_with_target_override() {
+        let json = r#"{"actions":[{"type":"react","emoji":"👍","message_id":"111","channel_id":"222"}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.actions,
+            vec![ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: Some("111".to_string()),
+                channel_id: Some("222".to_string()),
+            })]
+        );
+    }
+
     #[rstest]
     #[case::with_name(
         r#"{"actions":[{"type":"thread","name":"Discussion","content":"Let's talk"}]}"#,
@@ -225,6 +939,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_thread_target_override() {
+        let json = r#"{"actions":[{"type":"thread","content":"Test","message_id":"123","channel_id":"456"}]}"#;
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        match &response.actions[0] {
+            ResponseAction::Thread(params) => {
+                assert_eq!(params.message_id.as_deref(), Some("123"));
+                assert_eq!(params.channel_id.as_deref(), Some("456"));
+            }
+            _ => panic!("Expected Thread action"),
+        }
+    }
+
+    #[rstest]
+    #[case::default(r#"{"actions":[{"type":"thread","content":"Hi"}]}"#, None, None)]
+    #[case::set(
+        r#"{"actions":[{"type":"thread","content":"Hi","private":true,"invitable":false,"rate_limit_per_user":30}]}"#,
+        Some(false),
+        Some(30)
+    )]
+    fn test_parse_thread_invitable_and_rate_limit(
+        #[case] json: &str,
+        #[case] invitable: Option<bool>,
+        #[case] rate_limit_per_user: Option<u16>,
+    ) {
+        let response: EventResponse = serde_json::from_str(json).unwrap();
+
+        match &response.actions[0] {
+            ResponseAction::Thread(params) => {
+                assert_eq!(params.invitable, invitable);
+                assert_eq!(params.rate_limit_per_user, rate_limit_per_user);
+            }
+            _ => panic!("Expected Thread action"),
+        }
+    }
+
     #[test]
     fn test_parse_thread_invalid_auto_archive_duration() {
         // Invalid duration values are accepted as-is (validated at execution time)
@@ -1,10 +1,40 @@
 use crate::adapters::event_response::EventResponse;
+use crate::error::GatehookError;
 use serde::Serialize;
 use serenity::async_trait;
 
+/// Version of the event payload schema
+///
+/// Added to every payload as `schema_version` and sent as the
+/// `X-Gatehook-Schema` header. Bump it when a payload shape changes
+/// incompatibly so consumers can branch on it.
+pub const PAYLOAD_SCHEMA_VERSION: u32 = 1;
+
+/// Outcome of delivering an event: the endpoint's HTTP status and parsed response
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Delivery {
+    /// HTTP status returned by the endpoint (`None` when the sender has no status to report)
+    pub status: Option<u16>,
+    /// Parsed response body (`None` if empty or unparseable)
+    pub response: Option<EventResponse>,
+}
+
+impl Delivery {
+    /// Whether the endpoint accepted the event
+    ///
+    /// Server errors (5xx) and `429 Too Many Requests` mean the event was not
+    /// processed and should be delivered again later. Other statuses,
+    /// including client errors, count as accepted: retrying would not help.
+    pub fn accepted(&self) -> bool {
+        !self.status.is_some_and(|status| status >= 500 || status == 429)
+    }
+}
+
 /// Interface for sending events to external endpoints
+///
+/// Senders are `'static` so a backlog replay can run as a background task.
 #[async_trait]
-pub trait EventSender: Send + Sync {
+pub trait EventSender: Send + Sync + 'static {
     /// Send an event and receive response
     ///
     /// # Arguments
@@ -21,5 +51,51 @@ pub trait EventSender: Send + Sync {
         &self,
         handler: &str,
         payload: &T,
-    ) -> anyhow::Result<Option<EventResponse>>;
+    ) -> Result<Option<EventResponse>, GatehookError>;
+
+    /// Send an event and receive the delivery outcome, including the HTTP status
+    ///
+    /// Used by decorators that must know whether the endpoint accepted the
+    /// event (outbox, circuit breaker). Senders reporting no status keep the
+    /// default, which wraps `send` (the event counts as accepted).
+    async fn deliver<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Delivery, GatehookError> {
+        Ok(Delivery {
+            status: None,
+            response: self.send(handler, payload).await?,
+        })
+    }
+
+    /// Whether events are waiting to be redelivered (outbox, circuit breaker buffer)
+    fn has_backlog(&self) -> bool {
+        false
+    }
+
+    /// Redeliver waiting events, oldest first
+    ///
+    /// Run in the background after a live delivery succeeds, so the live
+    /// event's response is not held up by the replay. Failures are logged.
+    async fn replay_backlog(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::no_status(None, true)]
+    #[case::ok(Some(200), true)]
+    #[case::client_error(Some(400), true)]
+    #[case::too_many_requests(Some(429), false)]
+    #[case::server_error(Some(500), false)]
+    #[case::unavailable(Some(503), false)]
+    fn test_delivery_accepted(#[case] status: Option<u16>, #[case] expected: bool) {
+        let delivery = Delivery { status, response: None };
+
+        assert_eq!(delivery.accepted(), expected);
+    }
 }
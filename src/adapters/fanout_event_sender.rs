@@ -0,0 +1,309 @@
+use super::event_response::EventResponse;
+use super::event_sender_trait::{Delivery, EventSender};
+use crate::error::GatehookError;
+use serde::{Deserialize, Serialize};
+use serenity::async_trait;
+use tracing::{error, warn};
+
+/// How events are dispatched to the primary and mirror endpoints
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FanoutMode {
+    /// Send to all endpoints at once (default)
+    #[default]
+    Concurrent,
+    /// Send to one endpoint after another, primary first, in configured order
+    Sequential,
+}
+
+/// How failed mirror deliveries are treated
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FanoutFailure {
+    /// Log mirror failures as warnings and carry on (default)
+    #[default]
+    BestEffort,
+    /// Log mirror failures as errors, optionally dropping the primary's actions
+    AllRequired,
+}
+
+/// EventSender decorator copying every event to mirror endpoints
+///
+/// The primary sender's response (and status) drives action execution and
+/// delivery tracking; mirror responses are ignored. A primary failure is
+/// returned as usual (so the outbox and circuit breaker see it), while mirror
+/// failures (transport errors, or 5xx/429 responses per `Delivery::accepted`)
+/// are only logged according to the [`FanoutFailure`] policy. With
+/// `AllRequired` and `suppress_actions_on_failure`, a mirror failure also
+/// drops the primary's actions (`Ok(None)`).
+///
+/// Without mirrors (`FANOUT_ENDPOINTS` unset), events pass straight through.
+pub struct FanoutEventSender<S: EventSender> {
+    primary: S,
+    mirrors: Vec<S>,
+    mode: FanoutMode,
+    failure: FanoutFailure,
+    suppress_actions_on_failure: bool,
+}
+
+impl<S: EventSender> FanoutEventSender<S> {
+    /// Create a new FanoutEventSender
+    ///
+    /// # Arguments
+    ///
+    /// * `primary` - The sender whose response is used
+    /// * `mirrors` - Senders receiving a copy of every event
+    pub fn new(primary: S, mirrors: Vec<S>) -> Self {
+        Self {
+            primary,
+            mirrors,
+            mode: FanoutMode::default(),
+            failure: FanoutFailure::default(),
+            suppress_actions_on_failure: false,
+        }
+    }
+
+    /// Set how events are dispatched (default: concurrent)
+    pub fn with_mode(mut self, mode: FanoutMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set how mirror failures are treated (default: best effort)
+    ///
+    /// `suppress_actions_on_failure` only applies to `AllRequired`.
+    pub fn with_failure(mut self, failure: FanoutFailure, suppress_actions_on_failure: bool) -> Self {
+        self.failure = failure;
+        self.suppress_actions_on_failure = suppress_actions_on_failure;
+        self
+    }
+}
+
+#[async_trait]
+impl<S: EventSender> EventSender for FanoutEventSender<S> {
+    async fn send<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        Ok(self.deliver(handler, payload).await?.response)
+    }
+
+    async fn deliver<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Delivery, GatehookError> {
+        if self.mirrors.is_empty() {
+            return self.primary.deliver(handler, payload).await;
+        }
+
+        let payload = serde_json::to_value(payload)?;
+        let (primary_result, mirror_results) = match self.mode {
+            FanoutMode::Concurrent => {
+                futures::join!(
+                    self.primary.deliver(handler, &payload),
+                    futures::future::join_all(self.mirrors.iter().map(|mirror| mirror.deliver(handler, &payload)))
+                )
+            }
+            FanoutMode::Sequential => {
+                let primary_result = self.primary.deliver(handler, &payload).await;
+                let mut mirror_results = Vec::with_capacity(self.mirrors.len());
+                for mirror in &self.mirrors {
+                    mirror_results.push(mirror.deliver(handler, &payload).await);
+                }
+                (primary_result, mirror_results)
+            }
+        };
+
+        let mut mirror_failed = false;
+        for (mirror, result) in mirror_results.iter().enumerate() {
+            let (err, status) = match result {
+                Ok(delivery) if delivery.accepted() => continue,
+                Ok(delivery) => (None, delivery.status),
+                Err(err) => (Some(err), None),
+            };
+            mirror_failed = true;
+            match self.failure {
+                FanoutFailure::BestEffort => {
+                    warn!(?err, status, %handler, mirror, "Failed to deliver event to fan-out endpoint")
+                }
+                FanoutFailure::AllRequired => {
+                    error!(?err, status, %handler, mirror, "Failed to deliver event to required fan-out endpoint")
+                }
+            }
+        }
+
+        let delivery = primary_result?;
+        if mirror_failed && self.failure == FanoutFailure::AllRequired && self.suppress_actions_on_failure {
+            warn!(%handler, "Fan-out delivery incomplete, skipping actions from response");
+            return Ok(Delivery {
+                response: None,
+                ..delivery
+            });
+        }
+        Ok(delivery)
+    }
+
+    fn has_backlog(&self) -> bool {
+        self.primary.has_backlog()
+    }
+
+    async fn replay_backlog(&self) {
+        self.primary.replay_backlog().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    /// Sender appending start/end markers to a shared log, after a delay, optionally failing
+    struct LoggingSender {
+        name: &'static str,
+        delay: Duration,
+        fail: bool,
+        status: Option<u16>,
+        log: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl LoggingSender {
+        fn new(name: &'static str, delay_ms: u64, fail: bool, log: &Arc<Mutex<Vec<String>>>) -> Self {
+            Self {
+                name,
+                delay: Duration::from_millis(delay_ms),
+                fail,
+                status: None,
+                log: log.clone(),
+            }
+        }
+
+        /// Answer with an HTTP status instead of failing
+        fn with_status(mut self, status: u16) -> Self {
+            self.status = Some(status);
+            self
+        }
+    }
+
+    #[async_trait]
+    impl EventSender for LoggingSender {
+        async fn send<T: Serialize + Send + Sync>(
+            &self,
+            handler: &str,
+            payload: &T,
+        ) -> Result<Option<EventResponse>, GatehookError> {
+            Ok(self.deliver(handler, payload).await?.response)
+        }
+
+        async fn deliver<T: Serialize + Send + Sync>(
+            &self,
+            _handler: &str,
+            _payload: &T,
+        ) -> Result<Delivery, GatehookError> {
+            self.log.lock().unwrap().push(format!("{} start", self.name));
+            tokio::time::sleep(self.delay).await;
+            self.log.lock().unwrap().push(format!("{} end", self.name));
+            if self.fail {
+                return Err(std::io::Error::other("endpoint unreachable").into());
+            }
+            Ok(Delivery {
+                status: self.status,
+                response: Some(EventResponse::default()),
+            })
+        }
+    }
+
+    #[rstest]
+    #[case::concurrent(FanoutMode::Concurrent, ["primary start", "mirror start", "mirror end", "primary end"])]
+    #[case::sequential(FanoutMode::Sequential, ["primary start", "primary end", "mirror start", "mirror end"])]
+    #[tokio::test(start_paused = true)]
+    async fn test_dispatch_order(#[case] mode: FanoutMode, #[case] expected: [&str; 4]) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let sender = FanoutEventSender::new(
+            LoggingSender::new("primary", 50, false, &log),
+            vec![LoggingSender::new("mirror", 10, false, &log)],
+        )
+        .with_mode(mode);
+
+        let response = sender.send("message", &json!({"n": 1})).await.unwrap();
+
+        assert!(response.is_some());
+        assert_eq!(*log.lock().unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::best_effort(FanoutFailure::BestEffort, true, true)]
+    #[case::all_required(FanoutFailure::AllRequired, false, true)]
+    #[case::all_required_suppressing_actions(FanoutFailure::AllRequired, true, false)]
+    #[tokio::test]
+    async fn test_mirror_failure_policy(
+        #[case] failure: FanoutFailure,
+        #[case] suppress_actions: bool,
+        #[case] expect_response: bool,
+    ) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let sender = FanoutEventSender::new(
+            LoggingSender::new("primary", 0, false, &log),
+            vec![LoggingSender::new("mirror", 0, true, &log)],
+        )
+        .with_failure(failure, suppress_actions);
+
+        let response = sender.send("message", &json!({"n": 1})).await.unwrap();
+
+        assert_eq!(response.is_some(), expect_response);
+    }
+
+    #[rstest]
+    #[case::server_error(500, false)]
+    #[case::unavailable(503, false)]
+    #[case::rate_limited(429, false)]
+    #[case::ok(200, true)]
+    #[case::client_error(400, true)]
+    #[tokio::test]
+    async fn test_mirror_error_status_is_a_failure(#[case] status: u16, #[case] expect_response: bool) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let sender = FanoutEventSender::new(
+            LoggingSender::new("primary", 0, false, &log),
+            vec![LoggingSender::new("mirror", 0, false, &log).with_status(status)],
+        )
+        .with_failure(FanoutFailure::AllRequired, true);
+
+        let response = sender.send("message", &json!({"n": 1})).await.unwrap();
+
+        assert_eq!(response.is_some(), expect_response);
+    }
+
+    #[rstest]
+    #[case::best_effort(FanoutFailure::BestEffort)]
+    #[case::all_required(FanoutFailure::AllRequired)]
+    #[tokio::test]
+    async fn test_primary_failure_is_returned(#[case] failure: FanoutFailure) {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let sender = FanoutEventSender::new(
+            LoggingSender::new("primary", 0, true, &log),
+            vec![LoggingSender::new("mirror", 0, false, &log)],
+        )
+        .with_failure(failure, true);
+
+        let result = sender.send("message", &json!({"n": 1})).await;
+
+        assert!(result.is_err());
+        assert!(log.lock().unwrap().contains(&"mirror end".to_string()), "Mirrors still receive the event");
+    }
+
+    #[tokio::test]
+    async fn test_without_mirrors_passes_through() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let sender: FanoutEventSender<LoggingSender> =
+            FanoutEventSender::new(LoggingSender::new("primary", 0, false, &log), Vec::new());
+
+        let response = sender.send("message", &json!({"n": 1})).await.unwrap();
+
+        assert!(response.is_some());
+        assert_eq!(*log.lock().unwrap(), ["primary start", "primary end"]);
+    }
+}
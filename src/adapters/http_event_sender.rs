@@ -1,16 +1,91 @@
 use super::event_response::EventResponse;
-use super::event_sender_trait::EventSender;
-use anyhow::Context as _;
-use serde::Serialize;
+use super::event_sender_trait::{Delivery, EventSender, PAYLOAD_SCHEMA_VERSION};
+use crate::error::GatehookError;
+use reqwest::header::{self, HeaderMap, HeaderName};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serenity::async_trait;
+use serenity::model::id::GuildId;
+use std::collections::HashMap;
+use std::time::Duration;
 use tracing::{error, info, warn};
 use url::Url;
 
+/// Header carrying `PAYLOAD_SCHEMA_VERSION`
+const SCHEMA_HEADER: HeaderName = HeaderName::from_static("x-gatehook-schema");
+
+/// Headers set by gatehook itself, which custom headers may not override
+const RESERVED_HEADERS: [HeaderName; 5] = [
+    header::CONTENT_TYPE,
+    header::CONTENT_LENGTH,
+    header::HOST,
+    header::TRANSFER_ENCODING,
+    SCHEMA_HEADER,
+];
+
+/// Handler name used for parse error diagnostics
+const PARSE_ERROR_HANDLER: &str = "parse_error";
+
+/// Maximum characters of the response body included in a parse error diagnostic
+const PARSE_ERROR_SNIPPET_CHARS: usize = 200;
+
+/// Replacement for custom header values found in a diagnostic snippet
+const REDACTED: &str = "[REDACTED]";
+
+/// Statuses whose `Retry-After` header is honored with a retry
+const RETRY_AFTER_STATUSES: [reqwest::StatusCode; 2] = [
+    reqwest::StatusCode::TOO_MANY_REQUESTS,
+    reqwest::StatusCode::SERVICE_UNAVAILABLE,
+];
+
+/// Diagnostic reported to the endpoint when a success response cannot be parsed
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "handler": "message",
+///   "error": "parse",
+///   "snippet": "..."     // first 200 chars of the response body
+/// }
+/// ```
+#[derive(Debug, Serialize, PartialEq)]
+struct ParseErrorDiagnostic<'a> {
+    /// Handler whose response could not be parsed
+    handler: &'a str,
+    /// Error kind (always `parse`)
+    error: &'static str,
+    /// Start of the response body, with custom header values redacted
+    snippet: String,
+}
+
+/// Which HTTP response statuses may yield actions
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActionsOnStatus {
+    /// Execute actions from any response whose body parses (default)
+    #[default]
+    Any,
+    /// Execute actions only from 2xx responses
+    SuccessOnly,
+}
+
 /// Implementation for sending events via HTTP
 pub struct HttpEventSender {
     client: reqwest::Client,
     endpoint: Url,
     max_response_body_size: usize,
+    actions_on_status: ActionsOnStatus,
+    /// Custom headers sent with every request
+    headers: HeaderMap,
+    /// Custom headers per handler name, overriding `headers`
+    handler_headers: HashMap<String, HeaderMap>,
+    report_parse_errors: bool,
+    /// Endpoints overriding `endpoint` for events from specific guilds
+    guild_endpoints: HashMap<GuildId, Url>,
+    /// Shortest `Retry-After` delay honored
+    retry_after_min: Duration,
+    /// Longest `Retry-After` delay honored (zero disables the retry)
+    retry_after_max: Duration,
 }
 
 impl HttpEventSender {
@@ -29,21 +104,203 @@ impl HttpEventSender {
         timeout_secs: u64,
         connect_timeout_secs: u64,
         max_response_body_size: usize,
-    ) -> anyhow::Result<Self> {
+    ) -> Result<Self, GatehookError> {
         let client = reqwest::ClientBuilder::new()
             .danger_accept_invalid_certs(insecure_mode)
             .timeout(std::time::Duration::from_secs(timeout_secs))
             .connect_timeout(std::time::Duration::from_secs(connect_timeout_secs))
-            .build()
-            .context("Building HTTP Client")?;
+            .build()?;
 
         Ok(Self {
             client,
             endpoint,
             max_response_body_size,
+            actions_on_status: ActionsOnStatus::default(),
+            headers: HeaderMap::new(),
+            handler_headers: HashMap::new(),
+            report_parse_errors: false,
+            guild_endpoints: HashMap::new(),
+            retry_after_min: Duration::ZERO,
+            retry_after_max: Duration::ZERO,
         })
     }
 
+    /// Set which response statuses may yield actions (default: any)
+    pub fn with_actions_on_status(mut self, actions_on_status: ActionsOnStatus) -> Self {
+        self.actions_on_status = actions_on_status;
+        self
+    }
+
+    /// Report unparseable success responses back to the endpoint (default: false)
+    ///
+    /// When a 2xx response has a non-empty body that is not a valid
+    /// `EventResponse`, a `ParseErrorDiagnostic` is POSTed to the endpoint
+    /// with `?handler=parse_error`. The snippet is capped at 200 characters and
+    /// custom header values (which may carry credentials) are redacted from it.
+    /// Delivery is best effort: failures are logged and the diagnostic's
+    /// response is ignored.
+    pub fn with_report_parse_errors(mut self, report_parse_errors: bool) -> Self {
+        self.report_parse_errors = report_parse_errors;
+        self
+    }
+
+    /// Retry once when the endpoint asks to via `Retry-After` (default: disabled)
+    ///
+    /// A 429 or 503 response with a `Retry-After` header in seconds is retried
+    /// once after that delay, clamped to `[min, max]` (clamping is logged) so
+    /// a buggy endpoint cannot stall delivery or make gatehook retry in a
+    /// tight loop. HTTP-date values are not supported. A `max` of zero
+    /// disables the retry; when `min` exceeds `max`, `max` wins.
+    pub fn with_retry_after(mut self, min: Duration, max: Duration) -> Self {
+        self.retry_after_min = min;
+        self.retry_after_max = max;
+        self
+    }
+
+    /// Delay before retrying a response, if it asks for a retry and retries are enabled
+    fn retry_delay(&self, handler: &str, response: &reqwest::Response) -> Option<Duration> {
+        if self.retry_after_max.is_zero() || !RETRY_AFTER_STATUSES.contains(&response.status()) {
+            return None;
+        }
+        let requested = parse_retry_after(response.headers().get(header::RETRY_AFTER)?.to_str().ok()?)?;
+        Some(clamp_retry_after(handler, requested, self.retry_after_min, self.retry_after_max))
+    }
+
+    /// POST a payload to an endpoint
+    async fn post(&self, endpoint: &Url, handler: &str, payload: &Value) -> Result<reqwest::Response, GatehookError> {
+        Ok(self
+            .client
+            .post(endpoint.clone())
+            .query(&[("handler", handler)])
+            .headers(self.request_headers(handler))
+            .json(payload)
+            .send()
+            .await?)
+    }
+
+    /// Route events from specific guilds to their own endpoints
+    ///
+    /// The guild is read from the payload: a top-level `guild_id`, or the
+    /// `guild_id` of a top-level object (e.g. `message.guild_id`,
+    /// `reaction.guild_id`). Events without a guild (DMs, `ready`) and
+    /// guilds without an override use the default endpoint.
+    pub fn with_guild_endpoints(mut self, guild_endpoints: HashMap<GuildId, Url>) -> Self {
+        self.guild_endpoints = guild_endpoints;
+        self
+    }
+
+    /// Endpoint for a serialized payload (guild override or default)
+    fn endpoint_for(&self, payload: &Value) -> &Url {
+        if self.guild_endpoints.is_empty() {
+            return &self.endpoint;
+        }
+        Self::payload_guild_id(payload)
+            .and_then(|guild_id| self.guild_endpoints.get(&guild_id))
+            .unwrap_or(&self.endpoint)
+    }
+
+    /// Guild an event payload belongs to, if any
+    fn payload_guild_id(payload: &Value) -> Option<GuildId> {
+        let object = payload.as_object()?;
+        let guild_id = object.get("guild_id").filter(|guild_id| !guild_id.is_null()).or_else(|| {
+            object
+                .values()
+                .find_map(|value| value.get("guild_id").filter(|guild_id| !guild_id.is_null()))
+        })?;
+        serde_json::from_value(guild_id.clone()).ok()
+    }
+
+    /// Set custom request headers
+    ///
+    /// # Precedence
+    ///
+    /// Client defaults < `headers` < `handler_headers[handler]` < reserved headers.
+    /// Reserved headers (`Content-Type`, `Content-Length`, `Host`,
+    /// `Transfer-Encoding`, `X-Gatehook-Schema`) are always set by gatehook;
+    /// custom values for them are dropped with a warning.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - Headers sent with every request
+    /// * `handler_headers` - Headers per handler name (e.g. `"message"`)
+    pub fn with_headers(
+        mut self,
+        headers: HeaderMap,
+        handler_headers: HashMap<String, HeaderMap>,
+    ) -> Self {
+        self.headers = Self::without_reserved(headers);
+        self.handler_headers = handler_headers
+            .into_iter()
+            .map(|(handler, headers)| (handler, Self::without_reserved(headers)))
+            .collect();
+        self
+    }
+
+    /// Drop reserved headers from a custom header map
+    fn without_reserved(mut headers: HeaderMap) -> HeaderMap {
+        for name in &RESERVED_HEADERS {
+            if headers.remove(name).is_some() {
+                warn!(header = %name, "Custom header is reserved by gatehook, ignoring");
+            }
+        }
+        headers
+    }
+
+    /// Request headers for a handler (per-handler values replace global ones)
+    ///
+    /// Includes the `X-Gatehook-Schema` header.
+    fn request_headers(&self, handler: &str) -> HeaderMap {
+        let mut headers = self.headers.clone();
+        if let Some(handler_headers) = self.handler_headers.get(handler) {
+            for (name, value) in handler_headers {
+                headers.insert(name, value.clone());
+            }
+        }
+        headers.insert(SCHEMA_HEADER, PAYLOAD_SCHEMA_VERSION.into());
+        headers
+    }
+
+    /// Build the diagnostic for an unparseable response body
+    fn parse_error_diagnostic<'a>(&self, handler: &'a str, body: &[u8]) -> ParseErrorDiagnostic<'a> {
+        // Redact before truncating so a secret cut at the boundary cannot leak partially
+        let mut snippet = String::from_utf8_lossy(body).into_owned();
+
+        let custom_headers = self
+            .headers
+            .values()
+            .chain(self.handler_headers.values().flat_map(HeaderMap::values));
+        for value in custom_headers {
+            if let Ok(value) = value.to_str()
+                && !value.is_empty()
+            {
+                snippet = snippet.replace(value, REDACTED);
+            }
+        }
+
+        ParseErrorDiagnostic {
+            handler,
+            error: "parse",
+            snippet: snippet.chars().take(PARSE_ERROR_SNIPPET_CHARS).collect(),
+        }
+    }
+
+    /// POST a parse error diagnostic to the endpoint (best effort)
+    async fn report_parse_error(&self, endpoint: &Url, handler: &str, body: &[u8]) {
+        let diagnostic = self.parse_error_diagnostic(handler, body);
+        let result = self
+            .client
+            .post(endpoint.clone())
+            .query(&[("handler", PARSE_ERROR_HANDLER)])
+            .headers(self.request_headers(PARSE_ERROR_HANDLER))
+            .json(&diagnostic)
+            .send()
+            .await;
+
+        if let Err(err) = result {
+            warn!(?err, %handler, "Failed to report response parse error to HTTP endpoint");
+        }
+    }
+
     /// Get the endpoint URL (for testing)
     #[cfg(test)]
     pub fn endpoint(&self) -> &Url {
@@ -57,16 +314,35 @@ impl EventSender for HttpEventSender {
         &self,
         handler: &str,
         payload: &T,
-    ) -> anyhow::Result<Option<EventResponse>> {
-        let mut response = self
-            .client
-            .post(self.endpoint.clone())
-            .query(&[("handler", handler)])
-            .json(payload)
-            .send()
-            .await?;
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        Ok(self.deliver(handler, payload).await?.response)
+    }
+
+    async fn deliver<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Delivery, GatehookError> {
+        let payload = serde_json::to_value(payload)?;
+        let endpoint = self.endpoint_for(&payload);
+
+        let mut response = self.post(endpoint, handler, &payload).await?;
+        if let Some(delay) = self.retry_delay(handler, &response) {
+            warn!(
+                %handler,
+                status = %response.status(),
+                delay_ms = delay.as_millis() as u64,
+                "HTTP endpoint asked to retry later, retrying once after Retry-After delay"
+            );
+            tokio::time::sleep(delay).await;
+            response = self.post(endpoint, handler, &payload).await?;
+        }
 
         let status = response.status();
+        let delivery = |response| Delivery {
+            status: Some(status.as_u16()),
+            response,
+        };
 
         // Read response body with streaming (DoS protection)
         let mut body = Vec::new();
@@ -81,7 +357,7 @@ impl EventSender for HttpEventSender {
                     max_size = self.max_response_body_size,
                     "Response body exceeds limit during streaming, rejecting"
                 );
-                return Ok(None);
+                return Ok(delivery(None));
             }
             body.extend_from_slice(&chunk);
         }
@@ -97,6 +373,14 @@ impl EventSender for HttpEventSender {
                         actions = action_count,
                         "HTTP endpoint returned success status, response body parsed"
                     );
+                } else if self.actions_on_status == ActionsOnStatus::SuccessOnly {
+                    warn!(
+                        %handler,
+                        %status,
+                        actions = action_count,
+                        "HTTP endpoint returned non-success status, ignoring response actions (ACTIONS_ON_STATUS=success_only)"
+                    );
+                    return Ok(delivery(None));
                 } else {
                     warn!(
                         %handler,
@@ -105,7 +389,7 @@ impl EventSender for HttpEventSender {
                         "HTTP endpoint returned non-success status, response body parsed"
                     );
                 }
-                Ok(Some(event_response))
+                Ok(delivery(Some(event_response)))
             }
             Err(err) => {
                 if status.is_success() {
@@ -115,6 +399,9 @@ impl EventSender for HttpEventSender {
                         %status,
                         "HTTP endpoint returned success status, response body could not be parsed"
                     );
+                    if self.report_parse_errors && !body.trim_ascii().is_empty() {
+                        self.report_parse_error(endpoint, handler, &body).await;
+                    }
                 } else {
                     error!(
                         ?err,
@@ -123,16 +410,297 @@ impl EventSender for HttpEventSender {
                         "HTTP endpoint returned non-success status, response body could not be parsed"
                     );
                 }
-                Ok(None)
+                Ok(delivery(None))
             }
         }
     }
 }
 
+/// Parse a `Retry-After` value in (possibly fractional) seconds
+///
+/// Returns `None` for negative or non-numeric values (including HTTP-dates).
+/// Values too large for a `Duration` saturate.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    let secs = value.trim().parse::<f64>().ok().filter(|secs| *secs >= 0.0)?;
+    Some(Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX))
+}
+
+/// Clamp a requested `Retry-After` delay to `[min, max]`, logging when it is adjusted
+fn clamp_retry_after(handler: &str, requested: Duration, min: Duration, max: Duration) -> Duration {
+    let clamped = requested.max(min).min(max);
+    if clamped != requested {
+        warn!(
+            %handler,
+            requested_ms = u64::try_from(requested.as_millis()).unwrap_or(u64::MAX),
+            clamped_ms = clamped.as_millis() as u64,
+            "Retry-After delay outside RETRY_AFTER_MIN_MS..RETRY_AFTER_MAX_SECS, clamping"
+        );
+    }
+    clamped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rstest::rstest;
+    use tokio::io::{AsyncReadExt as _, AsyncWriteExt as _};
+    use tokio::net::TcpListener;
+
+    /// Serve one HTTP response per connection, in order, on an ephemeral port
+    ///
+    /// Returns the URL and a handle yielding the received requests (lowercased).
+    async fn serve(
+        responses: Vec<(&'static str, &'static str)>,
+    ) -> (Url, tokio::task::JoinHandle<Vec<String>>) {
+        serve_with_headers(responses.into_iter().map(|(status, body)| (status, "", body)).collect()).await
+    }
+
+    /// Like `serve`, with extra header lines (e.g. `"retry-after: 1\r\n"`) per response
+    async fn serve_with_headers(
+        responses: Vec<(&'static str, &'static str, &'static str)>,
+    ) -> (Url, tokio::task::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for (status, headers, body) in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                requests.push(read_request(&mut stream).await);
+                let response = format!(
+                    "HTTP/1.1 {status}\r\n{headers}content-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+            requests
+        });
+        (Url::parse(&format!("http://{addr}/webhook")).unwrap(), handle)
+    }
+
+    /// Serve a single HTTP response on an ephemeral port
+    ///
+    /// Returns the URL and a handle yielding the received request (lowercased).
+    async fn serve_once(status: &'static str, body: &'static str) -> (Url, tokio::task::JoinHandle<String>) {
+        let (url, handle) = serve(vec![(status, body)]).await;
+        (url, tokio::spawn(async move { handle.await.unwrap().remove(0) }))
+    }
+
+    /// Read a request head and its `content-length` body (lowercased)
+    async fn read_request(stream: &mut tokio::net::TcpStream) -> String {
+        let mut request = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let len = stream.read(&mut chunk).await.unwrap_or(0);
+            if len == 0 {
+                break;
+            }
+            request.extend_from_slice(&chunk[..len]);
+
+            let text = String::from_utf8_lossy(&request).to_lowercase();
+            if let Some(head_end) = text.find("\r\n\r\n") {
+                let content_length = text[..head_end]
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length: "))
+                    .and_then(|value| value.trim().parse::<usize>().ok())
+                    .unwrap_or(0);
+                if request.len() >= head_end + 4 + content_length {
+                    break;
+                }
+            }
+        }
+        String::from_utf8_lossy(&request).to_lowercase()
+    }
+
+    fn header_map(headers: &[(&str, &str)]) -> HeaderMap {
+        headers
+            .iter()
+            .map(|(name, value)| (name.parse().unwrap(), value.parse().unwrap()))
+            .collect()
+    }
+
+    #[rstest]
+    #[case::global_only("reaction_add", "x-env: prod", "x-team: core")]
+    #[case::handler_overrides_global("message", "x-env: staging", "x-team: core")]
+    #[tokio::test]
+    async fn test_send_merges_custom_headers(
+        #[case] handler: &str,
+        #[case] expected_env: &str,
+        #[case] expected_team: &str,
+    ) {
+        let (url, request) = serve_once("204 No Content", "").await;
+        let sender = HttpEventSender::new(url, false, 5, 5, 131_072)
+            .unwrap()
+            .with_headers(
+                header_map(&[("X-Env", "prod"), ("X-Team", "core")]),
+                HashMap::from([("message".to_string(), header_map(&[("X-Env", "staging")]))]),
+            );
+
+        sender.send(handler, &serde_json::json!({})).await.unwrap();
+
+        let request = request.await.unwrap();
+        assert!(request.contains(expected_env), "{request}");
+        assert!(request.contains(expected_team), "{request}");
+    }
+
+    #[tokio::test]
+    async fn test_send_reserved_headers_not_overridden() {
+        let (url, request) = serve_once("204 No Content", "").await;
+        let sender = HttpEventSender::new(url, false, 5, 5, 131_072)
+            .unwrap()
+            .with_headers(
+                header_map(&[("Content-Type", "text/plain"), ("X-Env", "prod")]),
+                HashMap::from([("message".to_string(), header_map(&[("Host", "evil.example")]))]),
+            );
+
+        sender.send("message", &serde_json::json!({})).await.unwrap();
+
+        let request = request.await.unwrap();
+        assert!(request.contains("content-type: application/json"), "{request}");
+        assert!(!request.contains("text/plain"), "{request}");
+        assert!(!request.contains("evil.example"), "{request}");
+        assert!(request.contains("x-env: prod"), "{request}");
+    }
+
+    #[tokio::test]
+    async fn test_send_includes_schema_header() {
+        let (url, request) = serve_once("204 No Content", "").await;
+        let sender = HttpEventSender::new(url, false, 5, 5, 131_072)
+            .unwrap()
+            .with_headers(header_map(&[("X-Gatehook-Schema", "999")]), HashMap::new());
+
+        sender.send("message", &serde_json::json!({})).await.unwrap();
+
+        let request = request.await.unwrap();
+        assert!(
+            request.contains(&format!("x-gatehook-schema: {PAYLOAD_SCHEMA_VERSION}\r\n")),
+            "{request}"
+        );
+        assert!(!request.contains("x-gatehook-schema: 999"), "{request}");
+    }
+
+    const ACTIONS_BODY: &str = r#"{"actions":[{"type":"react","emoji":"👍"}]}"#;
+
+    #[rstest]
+    #[case::success_any("200 OK", ActionsOnStatus::Any, true)]
+    #[case::success_success_only("200 OK", ActionsOnStatus::SuccessOnly, true)]
+    #[case::error_any("500 Internal Server Error", ActionsOnStatus::Any, true)]
+    #[case::error_success_only("500 Internal Server Error", ActionsOnStatus::SuccessOnly, false)]
+    #[tokio::test]
+    async fn test_send_actions_on_status(
+        #[case] status: &'static str,
+        #[case] actions_on_status: ActionsOnStatus,
+        #[case] honored: bool,
+    ) {
+        let (url, _request) = serve_once(status, ACTIONS_BODY).await;
+        let sender = HttpEventSender::new(url, false, 5, 5, 131_072)
+            .unwrap()
+            .with_actions_on_status(actions_on_status);
+
+        let response = sender.send("message", &serde_json::json!({})).await.unwrap();
+
+        assert_eq!(response.is_some_and(|response| response.actions.len() == 1), honored);
+    }
+
+    #[rstest]
+    #[case::ok("200 OK", "", true)]
+    #[case::unavailable_unparseable("503 Service Unavailable", "<html>down</html>", false)]
+    #[case::error_with_actions("500 Internal Server Error", ACTIONS_BODY, false)]
+    #[case::rate_limited("429 Too Many Requests", "", false)]
+    #[tokio::test]
+    async fn test_deliver_reports_status(#[case] status: &'static str, #[case] body: &'static str, #[case] accepted: bool) {
+        let (url, _request) = serve_once(status, body).await;
+        let sender = HttpEventSender::new(url, false, 5, 5, 131_072).unwrap();
+
+        let delivery = sender.deliver("message", &serde_json::json!({})).await.unwrap();
+
+        assert_eq!(delivery.status.map(|status| status.to_string()), status.split(' ').next().map(str::to_string));
+        assert_eq!(delivery.accepted(), accepted);
+    }
+
+    #[rstest]
+    #[case::enabled(true, 2)]
+    #[case::disabled(false, 1)]
+    #[tokio::test]
+    async fn test_send_reports_parse_error(#[case] report_parse_errors: bool, #[case] expected_requests: usize) {
+        let responses = [("200 OK", "<html>oops</html>"), ("204 No Content", "")];
+        let (url, requests) = serve(responses[..expected_requests].to_vec()).await;
+        let sender = HttpEventSender::new(url, false, 5, 5, 131_072)
+            .unwrap()
+            .with_report_parse_errors(report_parse_errors);
+
+        let response = sender.send("message", &serde_json::json!({})).await.unwrap();
+
+        assert!(response.is_none());
+        let requests = requests.await.unwrap();
+        assert_eq!(requests.len(), expected_requests);
+        if report_parse_errors {
+            assert!(requests[1].contains("?handler=parse_error"), "{}", requests[1]);
+            let body = requests[1].split("\r\n\r\n").nth(1).unwrap();
+            let diagnostic: serde_json::Value = serde_json::from_str(body).unwrap();
+            assert_eq!(
+                diagnostic,
+                serde_json::json!({"handler": "message", "error": "parse", "snippet": "<html>oops</html>"})
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_valid_response_not_reported() {
+        let (url, requests) = serve(vec![("200 OK", ACTIONS_BODY)]).await;
+        let sender = HttpEventSender::new(url, false, 5, 5, 131_072)
+            .unwrap()
+            .with_report_parse_errors(true);
+
+        let response = sender.send("message", &serde_json::json!({})).await.unwrap();
+
+        assert!(response.is_some());
+        assert_eq!(requests.await.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_parse_error_diagnostic_truncates_and_redacts() {
+        let url = Url::parse("https://example.com/webhook").unwrap();
+        let sender = HttpEventSender::new(url, false, 300, 10, 131_072)
+            .unwrap()
+            .with_headers(
+                header_map(&[("Authorization", "Bearer s3cret")]),
+                HashMap::from([("message".to_string(), header_map(&[("X-Api-Key", "k3y")]))]),
+            );
+        let body = format!("denied Bearer s3cret k3y {}", "é".repeat(300));
+
+        let diagnostic = sender.parse_error_diagnostic("message", body.as_bytes());
+
+        assert_eq!(diagnostic.handler, "message");
+        assert_eq!(diagnostic.error, "parse");
+        assert!(diagnostic.snippet.starts_with("denied [REDACTED] [REDACTED] é"));
+        assert!(!diagnostic.snippet.contains("s3cret"));
+        assert_eq!(diagnostic.snippet.chars().count(), PARSE_ERROR_SNIPPET_CHARS);
+    }
+
+    #[rstest]
+    #[case::configured_guild(serde_json::json!({"message": {"guild_id": "777"}}), true)]
+    #[case::top_level_guild_id(serde_json::json!({"guild_id": "777", "message_id": "1"}), true)]
+    #[case::other_guild(serde_json::json!({"message": {"guild_id": "888"}}), false)]
+    #[case::direct_message(serde_json::json!({"message": {"guild_id": null}}), false)]
+    #[case::no_guild(serde_json::json!({"ready": {"guilds": []}}), false)]
+    #[tokio::test]
+    async fn test_send_routes_by_guild(#[case] payload: serde_json::Value, #[case] guild_routed: bool) {
+        let (default_url, default_request) = serve_once("204 No Content", "").await;
+        let (guild_url, guild_request) = serve_once("204 No Content", "").await;
+        let sender = HttpEventSender::new(default_url, false, 5, 5, 131_072)
+            .unwrap()
+            .with_guild_endpoints(HashMap::from([(GuildId::new(777), guild_url)]));
+
+        sender.send("message", &payload).await.unwrap();
+
+        let (received, idle) = if guild_routed {
+            (guild_request, default_request)
+        } else {
+            (default_request, guild_request)
+        };
+        assert!(received.await.unwrap().contains("handler=message"));
+        assert!(!idle.is_finished(), "Only one endpoint should receive the event");
+    }
 
     #[rstest]
     #[case(false)]
@@ -150,4 +718,62 @@ mod tests {
         let sender = HttpEventSender::new(url, false, 300, 10, 131_072).unwrap();
         assert_eq!(sender.endpoint().as_str(), url_str);
     }
+
+    #[rstest]
+    #[case::tiny_raised_to_floor(Duration::ZERO, Duration::from_millis(100))]
+    #[case::within_bounds(Duration::from_secs(2), Duration::from_secs(2))]
+    #[case::oversized_capped(Duration::from_secs(3600), Duration::from_secs(5))]
+    #[case::saturated_capped(Duration::MAX, Duration::from_secs(5))]
+    fn test_clamp_retry_after(#[case] requested: Duration, #[case] expected: Duration) {
+        let clamped = clamp_retry_after("message", requested, Duration::from_millis(100), Duration::from_secs(5));
+
+        assert_eq!(clamped, expected);
+    }
+
+    #[rstest]
+    #[case::seconds("120", Some(Duration::from_secs(120)))]
+    #[case::fractional(" 0.25 ", Some(Duration::from_millis(250)))]
+    #[case::huge("1e300", Some(Duration::MAX))]
+    #[case::negative("-1", None)]
+    #[case::http_date("Wed, 21 Oct 2015 07:28:00 GMT", None)]
+    fn test_parse_retry_after(#[case] value: &str, #[case] expected: Option<Duration>) {
+        assert_eq!(parse_retry_after(value), expected);
+    }
+
+    #[rstest]
+    #[case::tiny_raised_to_floor("retry-after: 0\r\n", 2, Duration::from_millis(200))]
+    #[case::oversized_capped("retry-after: 86400\r\n", 2, Duration::from_secs(1))]
+    #[case::without_header("", 1, Duration::ZERO)]
+    #[tokio::test]
+    async fn test_send_honors_clamped_retry_after(
+        #[case] retry_after: &'static str,
+        #[case] expected_requests: usize,
+        #[case] expected_delay: Duration,
+    ) {
+        let responses = [("429 Too Many Requests", retry_after, ""), ("200 OK", "", ACTIONS_BODY)];
+        let (url, requests) = serve_with_headers(responses[..expected_requests].to_vec()).await;
+        let sender = HttpEventSender::new(url, false, 5, 5, 131_072)
+            .unwrap()
+            .with_retry_after(Duration::from_millis(200), Duration::from_secs(1));
+
+        let started = std::time::Instant::now();
+        let response = sender.send("message", &serde_json::json!({})).await.unwrap();
+        let elapsed = started.elapsed();
+
+        assert_eq!(requests.await.unwrap().len(), expected_requests);
+        assert_eq!(response.is_some(), expected_requests == 2);
+        assert!(elapsed >= expected_delay, "{elapsed:?}");
+        assert!(elapsed < expected_delay + Duration::from_secs(2), "{elapsed:?}");
+    }
+
+    #[tokio::test]
+    async fn test_send_retry_after_disabled_by_default() {
+        let (url, requests) = serve_with_headers(vec![("429 Too Many Requests", "retry-after: 0\r\n", "")]).await;
+        let sender = HttpEventSender::new(url, false, 5, 5, 131_072).unwrap();
+
+        let response = sender.send("message", &serde_json::json!({})).await.unwrap();
+
+        assert!(response.is_none());
+        assert_eq!(requests.await.unwrap().len(), 1);
+    }
 }
@@ -7,15 +7,35 @@ pub mod event_sender_trait;
 pub mod event_response;
 
 // Implementations
+pub mod atomic_file;
+pub mod channel_index;
+pub mod circuit_breaker_event_sender;
+pub mod debug_dump;
+pub mod debug_dump_event_sender;
+pub mod fanout_event_sender;
 pub mod http_event_sender;
+pub mod outbox;
+pub mod outbox_event_sender;
 pub mod serenity_channel_info_provider;
 pub mod serenity_discord_service;
 
 // Re-exports for convenience
-pub use channel_info_provider::ChannelInfoProvider;
+pub use channel_info_provider::{ChannelInfoProvider, GuildInfo};
+pub use circuit_breaker_event_sender::CircuitBreakerEventSender;
+pub use debug_dump::DebugDump;
+pub use debug_dump_event_sender::DebugDumpEventSender;
 pub use discord_service::DiscordService;
-pub use event_response::{EventResponse, ReactParams, ReplyParams, ResponseAction, ThreadParams};
-pub use event_sender_trait::EventSender;
-pub use http_event_sender::HttpEventSender;
+pub use event_response::EventResponse;
+#[cfg(feature = "actions")]
+pub use event_response::{
+    CreateChannelParams, EditParams, EmbedParams, ForwardParams, InteractionResponseKind,
+    InteractionResponseParams, JoinVoiceParams, LeaveGuildParams, ReactParams, ReplyParams, ResponseAction,
+    SendMessageParams, ThreadParams, ToggleRoleParams, WebhookMessageParams,
+};
+pub use event_sender_trait::{Delivery, EventSender, PAYLOAD_SCHEMA_VERSION};
+pub use fanout_event_sender::{FanoutEventSender, FanoutFailure, FanoutMode};
+pub use http_event_sender::{ActionsOnStatus, HttpEventSender};
+pub use outbox::Outbox;
+pub use outbox_event_sender::OutboxEventSender;
 pub use serenity_channel_info_provider::SerenityChannelInfoProvider;
-pub use serenity_discord_service::SerenityDiscordService;
+pub use serenity_discord_service::{DefaultAllowedMentions, SerenityDiscordService};
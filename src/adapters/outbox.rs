@@ -0,0 +1,357 @@
+use crate::adapters::atomic_file::write_atomic;
+use crate::error::GatehookError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::warn;
+
+/// Tombstones kept before the file is compacted (once they also outnumber live records)
+const COMPACT_TOMBSTONES: usize = 1024;
+
+/// An event persisted in the outbox, awaiting delivery
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxRecord {
+    /// Monotonically increasing record ID (unique within the outbox file)
+    pub id: u64,
+    /// Handler name the event is delivered under (e.g., "message")
+    pub handler: String,
+    /// Serialized event payload
+    pub payload: serde_json::Value,
+}
+
+/// A line of the outbox file: a record, or a tombstone marking one delivered
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum OutboxLine {
+    Record(OutboxRecord),
+    Removed { removed: u64 },
+}
+
+/// Disk-backed outbox for at-least-once webhook delivery
+///
+/// Records are stored as JSON lines in an append-only file. Appends are synced
+/// to disk before returning, so an enqueued record survives a crash. Removal
+/// appends a tombstone line instead of rewriting the file, so it costs the same
+/// whatever the backlog; the file is compacted atomically (write to a temporary
+/// file, then rename) once it holds no live record, or once tombstones pile up.
+///
+/// All methods do blocking file I/O; async callers should run them on a
+/// blocking thread (see `OutboxEventSender`).
+///
+/// # Semantics
+///
+/// - Records are identified by `id`; duplicate IDs found on load are dropped
+/// - Removing a record that is already removed is a no-op
+/// - A truncated trailing line (torn write during a crash) is skipped with a warning
+/// - Tombstones are not synced: one lost in a crash only redelivers its event
+/// - All file operations are serialized through an internal lock
+pub struct Outbox {
+    path: PathBuf,
+    state: Mutex<OutboxState>,
+}
+
+/// State kept in memory so appends and removals need not read the file
+struct OutboxState {
+    next_id: u64,
+    /// IDs of records without a tombstone
+    live: HashSet<u64>,
+    /// Tombstones written since the last compaction
+    tombstones: usize,
+}
+
+impl Outbox {
+    /// Open (or create) an outbox file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - Path of the JSON lines file backing the outbox
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, GatehookError> {
+        let path = path.into();
+        OpenOptions::new().create(true).append(true).open(&path)?;
+
+        // Compact on open: drops torn/duplicate lines and tombstones so later appends start on a clean line
+        let records = read_records(&path)?;
+        write_records(&path, &records)?;
+
+        let next_id = records
+            .iter()
+            .map(|record| record.id + 1)
+            .max()
+            .unwrap_or(0);
+
+        Ok(Self {
+            path,
+            state: Mutex::new(OutboxState {
+                next_id,
+                live: records.iter().map(|record| record.id).collect(),
+                tombstones: 0,
+            }),
+        })
+    }
+
+    /// Persist an event and return its record ID
+    pub fn enqueue(&self, handler: &str, payload: &serde_json::Value) -> Result<u64, GatehookError> {
+        let mut state = self.state.lock().unwrap();
+
+        let record = OutboxRecord {
+            id: state.next_id,
+            handler: handler.to_string(),
+            payload: payload.clone(),
+        };
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+
+        let mut file = OpenOptions::new().append(true).open(&self.path)?;
+        file.write_all(&line)?;
+        file.sync_data()?;
+
+        state.next_id += 1;
+        state.live.insert(record.id);
+        Ok(record.id)
+    }
+
+    /// List records awaiting delivery, oldest first
+    pub fn pending(&self) -> Result<Vec<OutboxRecord>, GatehookError> {
+        let _guard = self.state.lock().unwrap();
+        read_records(&self.path)
+    }
+
+    /// Whether every record has been removed
+    pub fn is_empty(&self) -> bool {
+        self.state.lock().unwrap().live.is_empty()
+    }
+
+    /// Whether the record `id` is still awaiting delivery
+    pub fn contains(&self, id: u64) -> bool {
+        self.state.lock().unwrap().live.contains(&id)
+    }
+
+    /// Remove a delivered record
+    pub fn remove(&self, id: u64) -> Result<(), GatehookError> {
+        self.remove_all(&[id])
+    }
+
+    /// Remove delivered records with a single append
+    ///
+    /// IDs that are not live (already removed, e.g. by a concurrent delivery) are skipped.
+    pub fn remove_all(&self, ids: &[u64]) -> Result<(), GatehookError> {
+        let mut state = self.state.lock().unwrap();
+        let ids: Vec<u64> = ids.iter().copied().filter(|id| state.live.contains(id)).collect();
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut lines = Vec::new();
+        for &removed in &ids {
+            lines.extend(serde_json::to_vec(&OutboxLine::Removed { removed })?);
+            lines.push(b'\n');
+        }
+        OpenOptions::new().append(true).open(&self.path)?.write_all(&lines)?;
+        for id in &ids {
+            state.live.remove(id);
+        }
+        state.tombstones += ids.len();
+
+        if state.live.is_empty() {
+            write_records(&self.path, &[])?;
+            state.tombstones = 0;
+        } else if state.tombstones >= COMPACT_TOMBSTONES && state.tombstones > state.live.len() {
+            let records = read_records(&self.path)?;
+            write_records(&self.path, &records)?;
+            state.live = records.iter().map(|record| record.id).collect();
+            state.tombstones = 0;
+        }
+        Ok(())
+    }
+}
+
+/// Atomically replace the outbox file contents with the given records
+fn write_records(path: &Path, records: &[OutboxRecord]) -> Result<(), GatehookError> {
+    let mut contents = Vec::new();
+    for record in records {
+        contents.extend(serde_json::to_vec(record)?);
+        contents.push(b'\n');
+    }
+
+    write_atomic(path, &contents)?;
+
+    Ok(())
+}
+
+/// Read all valid records without a tombstone, skipping malformed lines and duplicate IDs
+fn read_records(path: &Path) -> Result<Vec<OutboxRecord>, GatehookError> {
+    let contents = fs::read_to_string(path)?;
+    let mut seen = HashSet::new();
+    let mut removed = HashSet::new();
+    let mut records = Vec::new();
+
+    for (index, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<OutboxLine>(line) {
+            Ok(OutboxLine::Record(record)) if seen.insert(record.id) => records.push(record),
+            Ok(OutboxLine::Record(record)) => {
+                warn!(id = record.id, "Skipping duplicate outbox record");
+            }
+            Ok(OutboxLine::Removed { removed: id }) => {
+                removed.insert(id);
+            }
+            Err(err) => {
+                warn!(?err, line = index + 1, "Skipping malformed outbox record");
+            }
+        }
+    }
+
+    records.retain(|record| !removed.contains(&record.id));
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempPath;
+    use serde_json::json;
+
+    #[test]
+    fn test_outbox_enqueue_and_pending() {
+        let path = TempPath::new("outbox-enqueue.jsonl");
+        let outbox = Outbox::open(&path.0).unwrap();
+
+        let first = outbox.enqueue("message", &json!({"content": "a"})).unwrap();
+        let second = outbox.enqueue("ready", &json!({"ready": {}})).unwrap();
+
+        assert_eq!((first, second), (0, 1));
+        let pending = outbox.pending().unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[0].handler, "message");
+        assert_eq!(pending[0].payload, json!({"content": "a"}));
+        assert_eq!(pending[1].handler, "ready");
+    }
+
+    #[test]
+    fn test_outbox_remove() {
+        let path = TempPath::new("outbox-remove.jsonl");
+        let outbox = Outbox::open(&path.0).unwrap();
+
+        let first = outbox.enqueue("message", &json!(1)).unwrap();
+        let second = outbox.enqueue("message", &json!(2)).unwrap();
+        outbox.remove(first).unwrap();
+
+        let pending = outbox.pending().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, second);
+    }
+
+    #[test]
+    fn test_outbox_remove_appends_tombstones() {
+        let path = TempPath::new("outbox-tombstones.jsonl");
+        {
+            let outbox = Outbox::open(&path.0).unwrap();
+            let ids: Vec<u64> = (0..3).map(|n| outbox.enqueue("message", &json!(n)).unwrap()).collect();
+            outbox.remove_all(&ids[..2]).unwrap();
+
+            // Records are not rewritten: two tombstones follow the three records
+            assert_eq!(fs::read_to_string(&path.0).unwrap().lines().count(), 5);
+            assert!(!outbox.is_empty());
+        }
+
+        // Tombstones survive a restart, which compacts them away
+        let outbox = Outbox::open(&path.0).unwrap();
+        let pending = outbox.pending().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].payload, json!(2));
+        assert_eq!(fs::read_to_string(&path.0).unwrap().lines().count(), 1);
+    }
+
+    #[test]
+    fn test_outbox_compacts_when_empty() {
+        let path = TempPath::new("outbox-compact.jsonl");
+        let outbox = Outbox::open(&path.0).unwrap();
+
+        let first = outbox.enqueue("message", &json!(1)).unwrap();
+        let second = outbox.enqueue("message", &json!(2)).unwrap();
+        outbox.remove(first).unwrap();
+        outbox.remove(second).unwrap();
+
+        assert!(outbox.is_empty());
+        assert_eq!(fs::read_to_string(&path.0).unwrap(), "");
+        assert_eq!(outbox.enqueue("message", &json!(3)).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_outbox_remove_twice_keeps_other_records() {
+        let path = TempPath::new("outbox-remove-twice.jsonl");
+        let outbox = Outbox::open(&path.0).unwrap();
+
+        let first = outbox.enqueue("message", &json!(1)).unwrap();
+        let second = outbox.enqueue("message", &json!(2)).unwrap();
+        outbox.remove(first).unwrap();
+        outbox.remove_all(&[first, first]).unwrap();
+
+        // The repeated removal does not count against the live record
+        assert!(!outbox.is_empty());
+        assert!(!outbox.contains(first));
+        let pending = outbox.pending().unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, second);
+    }
+
+    #[test]
+    fn test_outbox_compacts_piled_up_tombstones() {
+        let path = TempPath::new("outbox-compact-tombstones.jsonl");
+        let outbox = Outbox::open(&path.0).unwrap();
+
+        let ids: Vec<u64> = (0..=COMPACT_TOMBSTONES)
+            .map(|n| outbox.enqueue("message", &json!(n)).unwrap())
+            .collect();
+        outbox.remove_all(&ids[..COMPACT_TOMBSTONES]).unwrap();
+
+        // Only the live record is left in the file
+        assert_eq!(fs::read_to_string(&path.0).unwrap().lines().count(), 1);
+        assert_eq!(outbox.pending().unwrap()[0].id, ids[COMPACT_TOMBSTONES]);
+    }
+
+    #[test]
+    fn test_outbox_survives_reopen_and_continues_ids() {
+        let path = TempPath::new("outbox-reopen.jsonl");
+        {
+            let outbox = Outbox::open(&path.0).unwrap();
+            outbox.enqueue("message", &json!(1)).unwrap();
+            outbox.enqueue("message", &json!(2)).unwrap();
+        }
+
+        let outbox = Outbox::open(&path.0).unwrap();
+        assert_eq!(outbox.pending().unwrap().len(), 2);
+        assert_eq!(outbox.enqueue("message", &json!(3)).unwrap(), 2);
+    }
+
+    #[test]
+    fn test_outbox_skips_malformed_and_duplicate_records() {
+        let path = TempPath::new("outbox-malformed.jsonl");
+        fs::write(
+            &path.0,
+            concat!(
+                r#"{"id":0,"handler":"message","payload":1}"#, "\n",
+                r#"{"id":0,"handler":"message","payload":1}"#, "\n",
+                r#"{"id":1,"handler":"mess"#,
+            ),
+        )
+        .unwrap();
+
+        let outbox = Outbox::open(&path.0).unwrap();
+        let pending = outbox.pending().unwrap();
+
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, 0);
+        assert_eq!(outbox.enqueue("message", &json!(2)).unwrap(), 1);
+
+        // The appended record is readable after the torn line was dropped
+        let pending = outbox.pending().unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[1].payload, json!(2));
+    }
+}
@@ -0,0 +1,528 @@
+use super::event_response::EventResponse;
+use super::event_sender_trait::{Delivery, EventSender};
+use super::outbox::Outbox;
+use crate::error::GatehookError;
+use serde::Serialize;
+use serenity::async_trait;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::{error, info, warn};
+
+/// Delivered records removed from the outbox at once during replay
+const REPLAY_BATCH: usize = 100;
+
+/// Run blocking outbox file I/O off the async runtime
+async fn blocking<T, F>(outbox: &Arc<Outbox>, op: F) -> Result<T, GatehookError>
+where
+    T: Send + 'static,
+    F: FnOnce(&Outbox) -> Result<T, GatehookError> + Send + 'static,
+{
+    let outbox = outbox.clone();
+    tokio::task::spawn_blocking(move || op(&outbox))
+        .await
+        .map_err(std::io::Error::other)?
+}
+
+/// EventSender decorator providing at-least-once delivery via a disk outbox
+///
+/// Every event is written to the outbox before delivery and removed once the
+/// endpoint accepts it (see `Delivery::accepted`). Events whose delivery fails
+/// (transport errors, 5xx or 429 responses) stay in the outbox and are replayed:
+/// - on startup, via `replay()`
+/// - on recovery, via `replay_backlog()` in a background task started after
+///   the next successful delivery
+///
+/// Without an outbox (`OUTBOX_PATH` unset), events pass straight through.
+///
+/// Outbox file I/O runs on tokio's blocking thread pool, and replay removes
+/// delivered records in batches of `REPLAY_BATCH`.
+///
+/// # Delivery Semantics
+///
+/// - At-least-once: a crash after delivery but before removal redelivers the
+///   event on replay, so webhook consumers should be idempotent
+/// - Replayed events are delivered oldest first; replay stops at the first failure
+/// - Actions in responses to replayed events are ignored (the triggering context is gone)
+pub struct OutboxEventSender<S: EventSender> {
+    inner: S,
+    outbox: Option<Arc<Outbox>>,
+    /// Records currently being delivered by `deliver` (skipped by replay)
+    in_flight: Mutex<HashSet<u64>>,
+    /// Whether the outbox may hold undelivered records
+    backlog: AtomicBool,
+    /// Serializes replays so each record is delivered by one replay at a time
+    replay_lock: tokio::sync::Mutex<()>,
+}
+
+impl<S: EventSender> OutboxEventSender<S> {
+    /// Create a new OutboxEventSender
+    ///
+    /// # Arguments
+    ///
+    /// * `inner` - The sender that performs the actual delivery
+    /// * `outbox` - Disk outbox for undelivered events (`None` to pass through)
+    pub fn new(inner: S, outbox: Option<Arc<Outbox>>) -> Self {
+        Self {
+            inner,
+            // Records may be left over from a previous run
+            backlog: AtomicBool::new(outbox.is_some()),
+            outbox,
+            in_flight: Mutex::new(HashSet::new()),
+            replay_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Redeliver events persisted in the outbox
+    ///
+    /// Returns the number of events delivered. Skips if another replay is running.
+    pub async fn replay(&self) -> Result<usize, GatehookError> {
+        let Some(outbox) = &self.outbox else {
+            return Ok(0);
+        };
+        let Ok(_guard) = self.replay_lock.try_lock() else {
+            return Ok(0);
+        };
+
+        let mut delivered = 0;
+        let mut removed = Vec::new();
+        let result = self.replay_records(outbox, &mut delivered, &mut removed).await;
+        // Remove what was delivered, also when replay stopped early
+        let removal = blocking(outbox, move |outbox| outbox.remove_all(&removed)).await;
+        result.and(removal)?;
+
+        self.backlog.store(!outbox.is_empty(), Ordering::SeqCst);
+
+        if delivered > 0 {
+            info!(delivered, "Replayed events from outbox");
+        }
+        Ok(delivered)
+    }
+
+    /// Deliver pending records oldest first, collecting the delivered IDs in `removed`
+    ///
+    /// Flushes `removed` to the outbox every `REPLAY_BATCH` records, so a
+    /// cancelled replay redelivers at most one batch.
+    async fn replay_records(
+        &self,
+        outbox: &Arc<Outbox>,
+        delivered: &mut usize,
+        removed: &mut Vec<u64>,
+    ) -> Result<(), GatehookError> {
+        for record in blocking(outbox, Outbox::pending).await? {
+            // Skip records `deliver` is sending, or delivered since the snapshot was taken
+            if self.in_flight.lock().unwrap().contains(&record.id) || !outbox.contains(record.id) {
+                continue;
+            }
+
+            match self.inner.deliver(&record.handler, &record.payload).await {
+                Ok(delivery) if delivery.accepted() => {
+                    if delivery.response.is_some_and(|response| !response.actions.is_empty()) {
+                        warn!(
+                            handler = %record.handler,
+                            outbox_id = record.id,
+                            "Replayed event received actions from webhook, ignoring"
+                        );
+                    }
+                    removed.push(record.id);
+                    *delivered += 1;
+                    if removed.len() >= REPLAY_BATCH {
+                        let batch = std::mem::take(removed);
+                        blocking(outbox, move |outbox| outbox.remove_all(&batch)).await?;
+                    }
+                }
+                Ok(delivery) => {
+                    warn!(
+                        status = delivery.status,
+                        handler = %record.handler,
+                        outbox_id = record.id,
+                        delivered = *delivered,
+                        "Outbox replay stopped, webhook endpoint still rejecting events"
+                    );
+                    return Ok(());
+                }
+                Err(err) => {
+                    warn!(
+                        ?err,
+                        handler = %record.handler,
+                        outbox_id = record.id,
+                        delivered = *delivered,
+                        "Outbox replay stopped, webhook delivery still failing"
+                    );
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+
+}
+
+/// Marks an outbox record as in flight, unmarking it when dropped
+///
+/// `deliver` can be cancelled mid-send (e.g. by a `WEBHOOK_DEADLINE_<HANDLER>_MS`
+/// deadline), so the record is released on drop instead of after the await. A
+/// delivery dropped before it settled leaves its record in the outbox and flags
+/// the backlog. A delivered record stays marked until it is removed, so replay
+/// never sends it again.
+struct InFlightGuard<'a, S: EventSender> {
+    sender: &'a OutboxEventSender<S>,
+    id: u64,
+    settled: bool,
+}
+
+impl<'a, S: EventSender> InFlightGuard<'a, S> {
+    fn new(sender: &'a OutboxEventSender<S>, id: u64) -> Self {
+        sender.in_flight.lock().unwrap().insert(id);
+        Self { sender, id, settled: false }
+    }
+}
+
+impl<S: EventSender> Drop for InFlightGuard<'_, S> {
+    fn drop(&mut self) {
+        self.sender.in_flight.lock().unwrap().remove(&self.id);
+        if !self.settled {
+            self.sender.backlog.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Flags the backlog when dropped before the enqueue settled
+///
+/// The blocking write keeps running if `deliver` is cancelled while awaiting
+/// it, so the record may be persisted without anyone delivering it.
+struct EnqueueGuard<'a> {
+    backlog: &'a AtomicBool,
+    settled: bool,
+}
+
+impl Drop for EnqueueGuard<'_> {
+    fn drop(&mut self) {
+        if !self.settled {
+            self.backlog.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+#[async_trait]
+impl<S: EventSender> EventSender for OutboxEventSender<S> {
+    async fn send<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        Ok(self.deliver(handler, payload).await?.response)
+    }
+
+    async fn deliver<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Delivery, GatehookError> {
+        let Some(outbox) = &self.outbox else {
+            return self.inner.deliver(handler, payload).await;
+        };
+
+        let payload = serde_json::to_value(payload)?;
+
+        // Persist before delivery; if the disk fails, still try to deliver
+        let record = (handler.to_string(), payload.clone());
+        let mut enqueue = EnqueueGuard { backlog: &self.backlog, settled: false };
+        let enqueued = blocking(outbox, move |outbox| outbox.enqueue(&record.0, &record.1)).await;
+        enqueue.settled = true;
+        let id = match enqueued {
+            Ok(id) => id,
+            Err(err) => {
+                error!(?err, %handler, "Failed to persist event to outbox, delivering without it");
+                return self.inner.deliver(handler, &payload).await;
+            }
+        };
+
+        let mut guard = InFlightGuard::new(self, id);
+        let result = self.inner.deliver(handler, &payload).await;
+        guard.settled = true;
+
+        match &result {
+            Ok(delivery) if delivery.accepted() => {
+                if let Err(err) = blocking(outbox, move |outbox| outbox.remove(id)).await {
+                    error!(?err, %handler, outbox_id = id, "Failed to remove delivered event from outbox");
+                }
+            }
+            Ok(delivery) => {
+                self.backlog.store(true, Ordering::SeqCst);
+                warn!(
+                    status = delivery.status,
+                    %handler,
+                    outbox_id = id,
+                    "Webhook endpoint rejected the event, kept in outbox for replay"
+                );
+            }
+            Err(err) => {
+                self.backlog.store(true, Ordering::SeqCst);
+                warn!(
+                    ?err,
+                    %handler,
+                    outbox_id = id,
+                    "Webhook delivery failed, event kept in outbox for replay"
+                );
+            }
+        }
+        drop(guard);
+
+        result
+    }
+
+    fn has_backlog(&self) -> bool {
+        self.backlog.load(Ordering::SeqCst) || self.inner.has_backlog()
+    }
+
+    async fn replay_backlog(&self) {
+        if let Err(err) = self.replay().await {
+            error!(?err, "Failed to replay events from outbox");
+        }
+        self.inner.replay_backlog().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{RecordingSender, TempPath};
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn open(path: &TempPath) -> Arc<Outbox> {
+        Arc::new(Outbox::open(&path.0).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_send_without_outbox_passes_through() {
+        let sender = OutboxEventSender::new(RecordingSender::default(), None);
+
+        let result = sender.send("message", &json!({"n": 1})).await;
+
+        assert!(result.is_ok());
+        assert_eq!(sender.inner.sent(), vec![("message".to_string(), json!({"n": 1}))]);
+        assert_eq!(sender.replay().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_send_success_leaves_outbox_empty() {
+        let path = TempPath::new("outbox-sender-success.jsonl");
+        let outbox = open(&path);
+        let sender = OutboxEventSender::new(RecordingSender::default(), Some(outbox.clone()));
+
+        let result = sender.send("message", &json!({"n": 1})).await;
+
+        assert!(result.is_ok());
+        assert_eq!(sender.inner.sent().len(), 1);
+        assert!(outbox.pending().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_failure_keeps_event_and_recovers() {
+        let path = TempPath::new("outbox-sender-recover.jsonl");
+        let outbox = open(&path);
+        let sender = OutboxEventSender::new(RecordingSender::default(), Some(outbox.clone()));
+
+        // Endpoint down: event stays in the outbox
+        sender.inner.set_fail(true);
+        assert!(sender.send("message", &json!({"n": 1})).await.is_err());
+        assert_eq!(outbox.pending().unwrap().len(), 1);
+
+        // Endpoint back: the live event is delivered without waiting for the backlog
+        sender.inner.set_fail(false);
+        assert!(sender.send("message", &json!({"n": 2})).await.is_ok());
+        assert_eq!(sender.inner.sent(), vec![("message".to_string(), json!({"n": 2}))]);
+        assert!(sender.has_backlog());
+
+        // The bridge then drains the backlog in the background
+        sender.replay_backlog().await;
+
+        assert!(!sender.has_backlog());
+        assert_eq!(
+            sender.inner.sent(),
+            vec![
+                ("message".to_string(), json!({"n": 2})),
+                ("message".to_string(), json!({"n": 1})),
+            ]
+        );
+        assert!(outbox.pending().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_server_error_keeps_event_in_outbox() {
+        let path = TempPath::new("outbox-sender-server-error.jsonl");
+        let outbox = open(&path);
+        let sender = OutboxEventSender::new(RecordingSender::default(), Some(outbox.clone()));
+
+        // Endpoint answers 503: the response is returned, but the event is not delivered
+        sender.inner.set_status(503);
+        assert!(sender.send("message", &json!({"n": 1})).await.is_ok());
+        assert_eq!(outbox.pending().unwrap().len(), 1);
+
+        // Replay keeps it while the endpoint still rejects events
+        assert_eq!(sender.replay().await.unwrap(), 0);
+        assert_eq!(outbox.pending().unwrap().len(), 1);
+
+        sender.inner.set_status(200);
+        assert_eq!(sender.replay().await.unwrap(), 1);
+        assert!(outbox.pending().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_send_keeps_event_for_replay() {
+        let path = TempPath::new("outbox-sender-cancelled.jsonl");
+        let outbox = open(&path);
+        let sender = OutboxEventSender::new(RecordingSender::default(), Some(outbox.clone()));
+
+        // The event deadline fires while the webhook request is pending
+        sender.inner.set_delay(Duration::from_secs(5));
+        let payload = json!({"n": 1});
+        let deadline = tokio::time::timeout(Duration::from_millis(20), sender.send("message", &payload));
+        assert!(deadline.await.is_err());
+
+        // The record is no longer marked in flight, so replay delivers it
+        assert!(sender.in_flight.lock().unwrap().is_empty());
+        assert!(sender.backlog.load(Ordering::SeqCst));
+        assert_eq!(outbox.pending().unwrap().len(), 1);
+
+        sender.inner.set_delay(Duration::ZERO);
+        assert_eq!(sender.replay().await.unwrap(), 1);
+        assert_eq!(sender.inner.sent(), vec![("message".to_string(), json!({"n": 1}))]);
+        assert!(outbox.pending().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replay_during_live_delivery_keeps_new_records() {
+        let path = TempPath::new("outbox-sender-replay-race.jsonl");
+        let outbox = open(&path);
+        outbox.enqueue("message", &json!({"n": "a"})).unwrap();
+        let sender = Arc::new(OutboxEventSender::new(RecordingSender::default(), Some(outbox.clone())));
+        sender.inner.set_delay(Duration::from_millis(50));
+
+        // Live event Y is in flight when the replay of backlog A starts
+        let live = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.send("message", &json!({"n": "y"})).await }
+        });
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let replay = tokio::spawn({
+            let sender = sender.clone();
+            async move { sender.replay().await }
+        });
+
+        // Z is persisted during the replay and not delivered yet
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let z = outbox.enqueue("message", &json!({"n": "z"})).unwrap();
+
+        assert!(live.await.unwrap().is_ok());
+        assert_eq!(replay.await.unwrap().unwrap(), 1);
+
+        // Y is delivered once, and Z is still pending
+        let sent: Vec<_> = sender.inner.sent().into_iter().map(|(_, payload)| payload["n"].clone()).collect();
+        assert_eq!(sent, vec![json!("y"), json!("a")]);
+        let pending: Vec<u64> = outbox.pending().unwrap().iter().map(|record| record.id).collect();
+        assert_eq!(pending, vec![z]);
+        assert!(!outbox.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_open_breaker_keeps_events_in_outbox_in_order() {
+        use crate::adapters::CircuitBreakerEventSender;
+
+        let path = TempPath::new("outbox-sender-breaker.jsonl");
+        let outbox = open(&path);
+        let endpoint = RecordingSender::default();
+        let breaker = CircuitBreakerEventSender::new(endpoint.clone(), 1, Duration::from_millis(50), 10)
+            .with_outbox_backed(true);
+        let sender = OutboxEventSender::new(breaker, Some(outbox.clone()));
+
+        // One failure opens the breaker; the next events are refused without a request
+        endpoint.set_fail(true);
+        assert!(sender.send("message", &json!({"n": 1})).await.is_err());
+        endpoint.set_fail(false);
+        assert!(matches!(
+            sender.send("message", &json!({"n": 2})).await,
+            Err(GatehookError::CircuitOpen)
+        ));
+        assert!(endpoint.sent().is_empty());
+        assert_eq!(outbox.pending().unwrap().len(), 2);
+
+        // Probe after the cooldown, then the backlog drains oldest first
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert!(sender.send("message", &json!({"n": 3})).await.is_ok());
+        assert!(sender.has_backlog());
+        sender.replay_backlog().await;
+
+        assert_eq!(
+            endpoint.sent(),
+            vec![
+                ("message".to_string(), json!({"n": 3})),
+                ("message".to_string(), json!({"n": 1})),
+                ("message".to_string(), json!({"n": 2})),
+            ]
+        );
+        assert!(outbox.pending().unwrap().is_empty());
+        assert!(!sender.has_backlog());
+    }
+
+    #[tokio::test]
+    async fn test_replay_after_crash_between_enqueue_and_delivery() {
+        let path = TempPath::new("outbox-sender-crash.jsonl");
+
+        // Simulate a crash: the event was persisted, but the process died
+        // before delivery (nothing removes the record)
+        {
+            let outbox = open(&path);
+            outbox.enqueue("message", &json!({"n": 1})).unwrap();
+            outbox.enqueue("reaction_add", &json!({"n": 2})).unwrap();
+        }
+
+        // Restart
+        let outbox = open(&path);
+        let sender = OutboxEventSender::new(RecordingSender::default(), Some(outbox.clone()));
+        let delivered = sender.replay().await.unwrap();
+
+        assert_eq!(delivered, 2);
+        assert_eq!(
+            sender.inner.sent(),
+            vec![
+                ("message".to_string(), json!({"n": 1})),
+                ("reaction_add".to_string(), json!({"n": 2})),
+            ]
+        );
+        assert!(outbox.pending().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_replay_removes_delivered_records_in_batches() {
+        let path = TempPath::new("outbox-sender-batches.jsonl");
+        let outbox = open(&path);
+        for n in 0..REPLAY_BATCH * 2 + 10 {
+            outbox.enqueue("message", &json!({"n": n})).unwrap();
+        }
+
+        let sender = OutboxEventSender::new(RecordingSender::default(), Some(outbox.clone()));
+        let delivered = sender.replay().await.unwrap();
+
+        assert_eq!(delivered, REPLAY_BATCH * 2 + 10);
+        assert_eq!(sender.inner.sent()[REPLAY_BATCH].1, json!({"n": REPLAY_BATCH}));
+        assert!(outbox.is_empty());
+        assert!(outbox.pending().unwrap().is_empty());
+        assert!(!sender.has_backlog());
+    }
+
+    #[tokio::test]
+    async fn test_replay_stops_on_failure() {
+        let path = TempPath::new("outbox-sender-replay-fail.jsonl");
+        let outbox = open(&path);
+        outbox.enqueue("message", &json!({"n": 1})).unwrap();
+
+        let sender = OutboxEventSender::new(RecordingSender::default(), Some(outbox.clone()));
+        sender.inner.set_fail(true);
+
+        assert_eq!(sender.replay().await.unwrap(), 0);
+        assert_eq!(outbox.pending().unwrap().len(), 1);
+    }
+}
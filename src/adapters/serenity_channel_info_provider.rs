@@ -1,7 +1,16 @@
-use super::channel_info_provider::ChannelInfoProvider;
+use super::channel_index::ChannelIndex;
+use super::channel_info_provider::{ChannelInfoProvider, GuildInfo};
 use serenity::async_trait;
-use serenity::model::channel::{Channel, ChannelType};
+#[cfg(feature = "actions")]
+use serenity::model::channel::ChannelType;
+use serenity::model::channel::{Channel, GuildChannel};
+#[cfg(feature = "actions")]
+use serenity::model::guild::Guild;
 use serenity::model::id::{ChannelId, GuildId};
+#[cfg(feature = "actions")]
+use serenity::model::id::UserId;
+#[cfg(feature = "actions")]
+use serenity::model::permissions::Permissions;
 use std::sync::Arc;
 use tracing::debug;
 
@@ -9,89 +18,168 @@ use tracing::debug;
 ///
 /// Uses cache-first approach with API fallback for optimal performance.
 /// Holds references to cache and http that are maintained by Serenity's event loop.
+///
+/// When the guild cache is disabled (`CACHE_DISABLED=true`), every lookup
+/// misses and falls through to the API path.
+///
+/// Lookups without a `guild_id` scan every cached guild. With
+/// `max_guild_scan` set, the scan is skipped when the bot is in more guilds
+/// than that, going straight to the API instead (bounds lookup latency).
+///
+/// Channels found by a scan or fetched from the API are remembered in a
+/// bounded channel → guild index (`DEFAULT_CHANNEL_INDEX_MAX_ENTRIES` by
+/// default), so later guild-less lookups access their guild directly.
+/// Evicted or stale entries fall back to the scan/API path.
 pub struct SerenityChannelInfoProvider {
     cache: Arc<serenity::cache::Cache>,
     http: Arc<serenity::http::Http>,
+    max_guild_scan: Option<usize>,
+    index: ChannelIndex,
 }
 
+/// Default maximum number of channels in the channel → guild index
+pub const DEFAULT_CHANNEL_INDEX_MAX_ENTRIES: usize = 10_000;
+
 impl SerenityChannelInfoProvider {
     /// Create a new SerenityChannelInfoProvider with cache and http references
     pub fn new(cache: Arc<serenity::cache::Cache>, http: Arc<serenity::http::Http>) -> Self {
-        Self { cache, http }
+        Self {
+            cache,
+            http,
+            max_guild_scan: None,
+            index: ChannelIndex::new(DEFAULT_CHANNEL_INDEX_MAX_ENTRIES),
+        }
     }
-}
 
-#[async_trait]
-impl ChannelInfoProvider for SerenityChannelInfoProvider {
-    async fn is_thread(
-        &self,
-        guild_id: Option<GuildId>,
-        channel_id: ChannelId,
-    ) -> Result<bool, serenity::Error> {
-        // Try cache first (fast path)
-        let cached_result: Option<bool> = if let Some(gid) = guild_id {
-            // Direct guild access (O(1) - fast)
+    /// Cap the channel → guild index at `max_entries` channels (0 disables it)
+    pub fn with_channel_index_max_entries(mut self, max_entries: usize) -> Self {
+        self.index = ChannelIndex::new(max_entries);
+        self
+    }
+
+    /// Skip the all-guild cache scan when the bot is in more than `max_guilds` guilds
+    ///
+    /// `None` (the default) always scans.
+    pub fn with_max_guild_scan(mut self, max_guilds: Option<usize>) -> Self {
+        self.max_guild_scan = max_guilds;
+        self
+    }
+
+    /// Look up a channel (or thread) in the guild cache
+    ///
+    /// Returns `None` on cache miss, including when guild caching is disabled.
+    /// Cache references are dropped before returning, so the result is safe
+    /// to hold across await points.
+    fn cached_channel(&self, guild_id: Option<GuildId>, channel_id: ChannelId) -> Option<GuildChannel> {
+        // Check regular channels first, then threads
+        let find_in_guild = |gid: GuildId| {
             self.cache.guild(gid).and_then(|guild_ref| {
-                // Check regular channels first, then threads
-                guild_ref.channels
+                guild_ref
+                    .channels
                     .get(&channel_id)
                     .cloned()
                     .or_else(|| {
-                        guild_ref.threads.iter()
+                        guild_ref
+                            .threads
+                            .iter()
                             .find(|ch| ch.id == channel_id)
                             .cloned()
                     })
-                    .map(|channel| {
-                        let is_thread = matches!(
-                            channel.kind,
-                            ChannelType::PublicThread
-                                | ChannelType::PrivateThread
-                                | ChannelType::NewsThread
-                        );
-                        debug!(
-                            guild_id = %gid,
-                            channel_id = %channel_id,
-                            is_thread = is_thread,
-                            "Channel type resolved from cache (direct guild access)"
-                        );
-                        is_thread
-                    })
+            })
+        };
+
+        if let Some(gid) = guild_id {
+            // Direct guild access (O(1) - fast)
+            find_in_guild(gid).inspect(|channel| {
+                debug!(
+                    guild_id = %gid,
+                    channel_id = %channel_id,
+                    channel_name = %channel.name,
+                    "Channel retrieved from cache (direct guild access)"
+                );
             })
         } else {
-            // Search all guilds (O(n) - slower fallback)
-            self.cache.guilds().iter().find_map(|gid| {
-                self.cache.guild(*gid).and_then(|guild_ref| {
-                    // Check regular channels first, then threads
-                    guild_ref.channels
-                        .get(&channel_id)
-                        .cloned()
-                        .or_else(|| {
-                            guild_ref.threads.iter()
-                                .find(|ch| ch.id == channel_id)
-                                .cloned()
-                        })
-                        .map(|channel| {
-                            let is_thread = matches!(
-                                channel.kind,
-                                ChannelType::PublicThread
-                                    | ChannelType::PrivateThread
-                                    | ChannelType::NewsThread
-                            );
-                            debug!(
-                                guild_id = %gid,
-                                channel_id = %channel_id,
-                                is_thread = is_thread,
-                                "Channel type resolved from cache (guild search)"
-                            );
-                            is_thread
-                        })
+            // Indexed channel: direct guild access, dropping the entry if it is stale
+            if let Some(gid) = self.index.get(channel_id) {
+                if let Some(channel) = find_in_guild(gid) {
+                    debug!(
+                        guild_id = %gid,
+                        channel_id = %channel_id,
+                        channel_name = %channel.name,
+                        "Channel retrieved from cache (indexed guild)"
+                    );
+                    return Some(channel);
+                }
+                self.index.remove(channel_id);
+            }
+
+            // Search all guilds (O(n) - slower fallback), unless there are too many
+            let guilds = self.cache.guilds();
+            if let Some(max_guilds) = self.max_guild_scan
+                && guilds.len() > max_guilds
+            {
+                debug!(
+                    channel_id = %channel_id,
+                    guild_count = guilds.len(),
+                    max_guilds,
+                    "Too many guilds to scan, skipping cache lookup"
+                );
+                return None;
+            }
+
+            guilds.into_iter().find_map(|gid| {
+                find_in_guild(gid).inspect(|channel| {
+                    self.index.insert(channel_id, gid);
+                    debug!(
+                        guild_id = %gid,
+                        channel_id = %channel_id,
+                        channel_name = %channel.name,
+                        "Channel retrieved from cache (guild search)"
+                    );
                 })
             })
-        }; // Cache references are dropped here
+        }
+    }
+
+    /// Compute a member's permissions in a guild channel or thread
+    ///
+    /// Returns `None` if the member or channel is not part of `guild`.
+    /// Threads use the parent channel's overwrites, with `SEND_MESSAGES`
+    /// replaced by `SEND_MESSAGES_IN_THREADS`.
+    #[cfg(feature = "actions")]
+    fn member_permissions_in(guild: &Guild, user_id: UserId, channel_id: ChannelId) -> Option<Permissions> {
+        let member = guild.members.get(&user_id)?;
+
+        if let Some(channel) = guild.channels.get(&channel_id) {
+            return Some(guild.user_permissions_in(channel, member));
+        }
+
+        let thread = guild.threads.iter().find(|thread| thread.id == channel_id)?;
+        let parent = guild.channels.get(&thread.parent_id?)?;
+        let mut permissions = guild.user_permissions_in(parent, member);
+        permissions.set(Permissions::SEND_MESSAGES, permissions.send_messages_in_threads());
+        Some(permissions)
+    }
+}
+
+#[async_trait]
+impl ChannelInfoProvider for SerenityChannelInfoProvider {
+    #[cfg(feature = "actions")]
+    async fn is_thread(
+        &self,
+        guild_id: Option<GuildId>,
+        channel_id: ChannelId,
+    ) -> Result<bool, serenity::Error> {
+        let is_thread_kind = |kind: ChannelType| {
+            matches!(
+                kind,
+                ChannelType::PublicThread | ChannelType::PrivateThread | ChannelType::NewsThread
+            )
+        };
 
-        // Return cached result if available
-        if let Some(is_thread) = cached_result {
-            return Ok(is_thread);
+        // Try cache first (fast path)
+        if let Some(channel) = self.cached_channel(guild_id, channel_id) {
+            return Ok(is_thread_kind(channel.kind));
         }
 
         // Cache miss - fallback to API (slow path)
@@ -101,80 +189,21 @@ impl ChannelInfoProvider for SerenityChannelInfoProvider {
         );
 
         let channel = self.http.get_channel(channel_id).await?;
-        let is_thread = matches!(
-            channel,
-            Channel::Guild(ref c) if matches!(
-                c.kind,
-                ChannelType::PublicThread | ChannelType::PrivateThread | ChannelType::NewsThread
-            )
-        );
+        if let Channel::Guild(guild_channel) = &channel {
+            self.index.insert(channel_id, guild_channel.guild_id);
+        }
+        let is_thread = matches!(channel, Channel::Guild(ref c) if is_thread_kind(c.kind));
 
         Ok(is_thread)
     }
 
     async fn get_channel(
         &self,
-        guild_id: Option<serenity::model::id::GuildId>,
+        guild_id: Option<GuildId>,
         channel_id: ChannelId,
-    ) -> Result<Option<serenity::model::channel::GuildChannel>, serenity::Error> {
+    ) -> Result<Option<GuildChannel>, serenity::Error> {
         // Try cache first (fast path)
-        // Extract channel from cache without holding the lock across await points
-        let cached_result: Option<serenity::model::channel::GuildChannel> = if let Some(gid) =
-            guild_id
-        {
-            // Direct guild access (O(1) - fast)
-            self.cache.guild(gid).and_then(|guild_ref| {
-                // Check regular channels first, then threads
-                guild_ref
-                    .channels
-                    .get(&channel_id)
-                    .cloned()
-                    .or_else(|| {
-                        guild_ref
-                            .threads
-                            .iter()
-                            .find(|ch| ch.id == channel_id)
-                            .cloned()
-                    })
-                    .inspect(|channel| {
-                        debug!(
-                            guild_id = %gid,
-                            channel_id = %channel_id,
-                            channel_name = %channel.name,
-                            "Channel retrieved from cache (direct guild access)"
-                        );
-                    })
-            })
-        } else {
-            // Search all guilds (O(n) - slower fallback)
-            self.cache.guilds().iter().find_map(|guild_id| {
-                self.cache.guild(*guild_id).and_then(|guild_ref| {
-                    // Check regular channels first, then threads
-                    guild_ref
-                        .channels
-                        .get(&channel_id)
-                        .cloned()
-                        .or_else(|| {
-                            guild_ref
-                                .threads
-                                .iter()
-                                .find(|ch| ch.id == channel_id)
-                                .cloned()
-                        })
-                        .inspect(|channel| {
-                            debug!(
-                                guild_id = %guild_id,
-                                channel_id = %channel_id,
-                                channel_name = %channel.name,
-                                "Channel retrieved from cache (guild search)"
-                            );
-                        })
-                })
-            })
-        }; // Cache references are dropped here
-
-        // Return cached result if available
-        if let Some(channel) = cached_result {
+        if let Some(channel) = self.cached_channel(guild_id, channel_id) {
             return Ok(Some(channel));
         }
 
@@ -186,8 +215,297 @@ impl ChannelInfoProvider for SerenityChannelInfoProvider {
 
         let channel = self.http.get_channel(channel_id).await?;
         match channel {
-            Channel::Guild(guild_channel) => Ok(Some(guild_channel)),
+            Channel::Guild(guild_channel) => {
+                self.index.insert(channel_id, guild_channel.guild_id);
+                Ok(Some(guild_channel))
+            }
             _ => Ok(None), // DM channel
         }
     }
+
+    fn get_guild(&self, guild_id: GuildId) -> Option<GuildInfo> {
+        let guild = self.cache.guild(guild_id)?;
+
+        Some(GuildInfo {
+            system_channel_id: guild.system_channel_id,
+            rules_channel_id: guild.rules_channel_id,
+            role_names: guild.roles.iter().map(|(id, role)| (*id, role.name.clone())).collect(),
+        })
+    }
+
+    #[cfg(feature = "actions")]
+    fn bot_permissions(&self, guild_id: GuildId, channel_id: ChannelId) -> Option<Permissions> {
+        let bot_id = self.cache.current_user().id;
+        let guild = self.cache.guild(guild_id)?;
+
+        Self::member_permissions_in(&guild, bot_id, channel_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serenity::cache::{Cache, Settings as CacheSettings};
+    use serenity::http::{Http, HttpBuilder};
+    use serenity::model::event::{GuildCreateEvent, GuildDeleteEvent};
+    use serenity::model::guild::Guild;
+    use serenity::model::id::RoleId;
+
+    fn provider_with_cache(settings: CacheSettings) -> SerenityChannelInfoProvider {
+        SerenityChannelInfoProvider::new(
+            Arc::new(Cache::new_with_settings(settings)),
+            Arc::new(Http::new("")),
+        )
+    }
+
+    fn disabled_cache_settings() -> CacheSettings {
+        let mut settings = CacheSettings::default();
+        settings.cache_guilds = false;
+        settings.cache_channels = false;
+        settings.cache_users = false;
+        settings
+    }
+
+    #[test]
+    fn test_cached_channel_misses_with_guild_id_when_cache_disabled() {
+        let provider = provider_with_cache(disabled_cache_settings());
+
+        let result = provider.cached_channel(Some(GuildId::new(1)), ChannelId::new(2));
+
+        assert!(result.is_none(), "Disabled cache should always miss");
+    }
+
+    #[test]
+    fn test_cached_channel_misses_without_guild_id_when_cache_disabled() {
+        let provider = provider_with_cache(disabled_cache_settings());
+
+        let result = provider.cached_channel(None, ChannelId::new(2));
+
+        assert!(result.is_none(), "Disabled cache should always miss");
+    }
+
+    /// Cache one guild holding `channel_id`, plus `extra_guilds` unavailable guilds
+    fn cache_with_guilds(guild_id: GuildId, channel_id: ChannelId, extra_guilds: u64) -> Arc<Cache> {
+        let cache = Arc::new(Cache::new_with_settings(CacheSettings::default()));
+
+        let mut channel = GuildChannel::default();
+        channel.id = channel_id;
+        channel.guild_id = guild_id;
+        let mut guild = Guild::default();
+        guild.id = guild_id;
+        guild.channels.insert(channel_id, channel);
+        let mut create: GuildCreateEvent =
+            serde_json::from_value(serde_json::to_value(&guild).unwrap()).unwrap();
+        cache.update(&mut create);
+
+        for id in 0..extra_guilds {
+            let mut delete: GuildDeleteEvent = serde_json::from_value(serde_json::json!({
+                "id": (100_000 + id).to_string(),
+                "unavailable": true
+            }))
+            .unwrap();
+            cache.update(&mut delete);
+        }
+
+        cache
+    }
+
+    #[test]
+    fn test_cached_channel_guild_scan_within_cap() {
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(2));
+        let provider =
+            SerenityChannelInfoProvider::new(cache_with_guilds(guild_id, channel_id, 99), Arc::new(Http::new("")))
+                .with_max_guild_scan(Some(100));
+
+        let result = provider.cached_channel(None, channel_id);
+
+        assert_eq!(result.map(|channel| channel.id), Some(channel_id));
+    }
+
+    #[test]
+    fn test_cached_channel_guild_scan_skipped_beyond_cap() {
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(2));
+        let provider =
+            SerenityChannelInfoProvider::new(cache_with_guilds(guild_id, channel_id, 5_000), Arc::new(Http::new("")))
+                .with_max_guild_scan(Some(100));
+
+        // Scan skipped: cache miss even though the channel is cached
+        assert!(provider.cached_channel(None, channel_id).is_none());
+
+        // Direct guild access is unaffected by the cap
+        assert!(provider.cached_channel(Some(guild_id), channel_id).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_get_channel_beyond_cap_takes_api_path() {
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(2));
+        let cache = cache_with_guilds(guild_id, channel_id, 5_000);
+        // Unreachable API: a cache hit succeeds, the API path fails
+        let http = Arc::new(
+            HttpBuilder::new("")
+                .proxy("http://127.0.0.1:9")
+                .ratelimiter_disabled(true)
+                .build(),
+        );
+
+        let uncapped = SerenityChannelInfoProvider::new(cache.clone(), http.clone());
+        assert!(uncapped.get_channel(None, channel_id).await.unwrap().is_some());
+
+        let capped = SerenityChannelInfoProvider::new(cache, http).with_max_guild_scan(Some(100));
+        assert!(capped.get_channel(None, channel_id).await.is_err());
+    }
+
+    #[test]
+    fn test_cached_channel_scan_populates_index() {
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(2));
+        let provider = SerenityChannelInfoProvider::new(cache_with_guilds(guild_id, channel_id, 0), Arc::new(Http::new("")));
+
+        assert!(provider.cached_channel(None, channel_id).is_some());
+
+        assert_eq!(provider.index.get(channel_id), Some(guild_id));
+    }
+
+    #[test]
+    fn test_indexed_channel_bypasses_scan_cap_until_evicted() {
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(2));
+        let provider =
+            SerenityChannelInfoProvider::new(cache_with_guilds(guild_id, channel_id, 5_000), Arc::new(Http::new("")))
+                .with_max_guild_scan(Some(100))
+                .with_channel_index_max_entries(1);
+
+        // Indexed (e.g. from an earlier API fetch): found despite the scan cap
+        provider.index.insert(channel_id, guild_id);
+        assert!(provider.cached_channel(None, channel_id).is_some());
+
+        // Inserting beyond the cap evicts it: back to the (skipped) scan, i.e. the API path
+        provider.index.insert(ChannelId::new(3), GuildId::new(9));
+        assert_eq!(provider.index.get(channel_id), None);
+        assert!(provider.cached_channel(None, channel_id).is_none());
+    }
+
+    #[test]
+    fn test_evicted_channel_found_again_by_scan() {
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(2));
+        let provider = SerenityChannelInfoProvider::new(cache_with_guilds(guild_id, channel_id, 10), Arc::new(Http::new("")))
+            .with_channel_index_max_entries(1);
+        assert!(provider.cached_channel(None, channel_id).is_some());
+        provider.index.insert(ChannelId::new(3), GuildId::new(9));
+
+        let result = provider.cached_channel(None, channel_id);
+
+        assert_eq!(result.map(|channel| channel.id), Some(channel_id));
+        assert_eq!(provider.index.get(channel_id), Some(guild_id), "Re-indexed after the scan");
+    }
+
+    #[test]
+    fn test_stale_index_entry_falls_back_to_scan() {
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(2));
+        let provider = SerenityChannelInfoProvider::new(cache_with_guilds(guild_id, channel_id, 0), Arc::new(Http::new("")));
+        provider.index.insert(channel_id, GuildId::new(9));
+
+        let result = provider.cached_channel(None, channel_id);
+
+        assert_eq!(result.map(|channel| channel.id), Some(channel_id));
+        assert_eq!(provider.index.get(channel_id), Some(guild_id));
+    }
+
+    #[test]
+    fn test_cached_channel_misses_on_empty_cache() {
+        let provider = provider_with_cache(CacheSettings::default());
+
+        assert!(provider.cached_channel(Some(GuildId::new(1)), ChannelId::new(2)).is_none());
+        assert!(provider.cached_channel(None, ChannelId::new(2)).is_none());
+    }
+
+    #[test]
+    fn test_get_guild_from_cache() {
+        let (guild_id, channel_id) = (GuildId::new(1), ChannelId::new(2));
+        let cache = Arc::new(Cache::new_with_settings(CacheSettings::default()));
+        let mut guild = Guild::default();
+        guild.id = guild_id;
+        guild.system_channel_id = Some(channel_id);
+        guild.rules_channel_id = Some(ChannelId::new(3));
+        let mut role = serenity::model::guild::Role::default();
+        role.id = RoleId::new(4);
+        role.name = "moderator".to_string();
+        guild.roles.insert(role.id, role);
+        let mut create: GuildCreateEvent =
+            serde_json::from_value(serde_json::to_value(&guild).unwrap()).unwrap();
+        cache.update(&mut create);
+        let provider = SerenityChannelInfoProvider::new(cache, Arc::new(Http::new("")));
+
+        let info = provider.get_guild(guild_id).unwrap();
+
+        assert_eq!(info.system_channel_id, Some(channel_id));
+        assert_eq!(info.rules_channel_id, Some(ChannelId::new(3)));
+        assert_eq!(info.role_names.get(&RoleId::new(4)).map(String::as_str), Some("moderator"));
+        assert!(provider.get_guild(GuildId::new(9)).is_none());
+    }
+
+    /// Guild with an @everyone role granting `everyone`, one member, a text channel and a thread in it
+    #[cfg(feature = "actions")]
+    fn guild_with_member(everyone: Permissions) -> (Guild, UserId, ChannelId, ChannelId) {
+        use serenity::model::guild::{Member, Role};
+
+        let (guild_id, user_id) = (GuildId::new(1), UserId::new(10));
+        let (channel_id, thread_id) = (ChannelId::new(2), ChannelId::new(3));
+
+        let mut guild = Guild::default();
+        guild.id = guild_id;
+
+        let mut role = Role::default();
+        role.id = guild_id.get().into();
+        role.permissions = everyone;
+        guild.roles.insert(role.id, role);
+
+        let mut member = Member::default();
+        member.user.id = user_id;
+        guild.members.insert(user_id, member);
+
+        let mut channel = GuildChannel::default();
+        channel.id = channel_id;
+        guild.channels.insert(channel_id, channel);
+
+        let mut thread = GuildChannel::default();
+        thread.id = thread_id;
+        thread.kind = ChannelType::PublicThread;
+        thread.parent_id = Some(channel_id);
+        guild.threads.push(thread);
+
+        (guild, user_id, channel_id, thread_id)
+    }
+
+    #[cfg(feature = "actions")]
+    #[test]
+    fn test_member_permissions_in_channel() {
+        let (guild, user_id, channel_id, _) =
+            guild_with_member(Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS);
+
+        let permissions = SerenityChannelInfoProvider::member_permissions_in(&guild, user_id, channel_id).unwrap();
+
+        assert!(permissions.contains(Permissions::SEND_MESSAGES | Permissions::ADD_REACTIONS));
+        assert!(!permissions.contains(Permissions::CREATE_PUBLIC_THREADS));
+    }
+
+    #[cfg(feature = "actions")]
+    #[test]
+    fn test_member_permissions_in_thread_uses_thread_send_permission() {
+        let (guild, user_id, _, thread_id) = guild_with_member(Permissions::SEND_MESSAGES);
+
+        let permissions = SerenityChannelInfoProvider::member_permissions_in(&guild, user_id, thread_id).unwrap();
+
+        assert!(
+            !permissions.contains(Permissions::SEND_MESSAGES),
+            "SEND_MESSAGES alone does not allow posting in threads"
+        );
+    }
+
+    #[cfg(feature = "actions")]
+    #[test]
+    fn test_member_permissions_in_unknown_member_or_channel() {
+        let (guild, user_id, channel_id, _) = guild_with_member(Permissions::all());
+
+        assert!(SerenityChannelInfoProvider::member_permissions_in(&guild, UserId::new(99), channel_id).is_none());
+        assert!(SerenityChannelInfoProvider::member_permissions_in(&guild, user_id, ChannelId::new(99)).is_none());
+    }
 }
@@ -1,25 +1,110 @@
 use super::discord_service::DiscordService;
+#[cfg(feature = "actions")]
+use crate::adapters::EmbedParams;
 use serenity::async_trait;
-use serenity::model::channel::{AutoArchiveDuration, GuildChannel, Message};
+#[cfg(feature = "actions")]
+use serenity::builder::{CreateAllowedMentions, CreateChannel, CreateEmbed, CreateEmbedFooter, EditMessage};
+use serenity::model::channel::Message;
+#[cfg(feature = "actions")]
+use serenity::model::channel::{Attachment, AutoArchiveDuration, ChannelType, GuildChannel};
 use serenity::model::id::{ChannelId, MessageId};
+#[cfg(feature = "actions")]
+use serenity::model::id::{GuildId, InteractionId, RoleId, ShardId, UserId, WebhookId};
+#[cfg(feature = "actions")]
+use serenity::{cache::Cache, gateway::ShardManager};
+#[cfg(feature = "actions")]
+use tungstenite::Message as WsMessage;
+use std::str::FromStr;
 use std::sync::Arc;
 
+/// Mention types that ping in bot messages by default (`DEFAULT_ALLOWED_MENTIONS`)
+///
+/// `@everyone`/`@here` are not part of the default; they stay controlled by
+/// `SUPPRESS_MASS_MENTIONS` and the per-action `allow_mass_mentions`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultAllowedMentions {
+    /// User mentions ping
+    pub users: bool,
+    /// Role mentions ping
+    pub roles: bool,
+}
+
+/// Parse a comma-separated list of `users`/`roles` (`none` or empty: nothing pings)
+impl FromStr for DefaultAllowedMentions {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut mentions = Self::default();
+        for name in s.split(',').map(str::trim).filter(|name| !name.is_empty()) {
+            match name.to_lowercase().as_str() {
+                "users" => mentions.users = true,
+                "roles" => mentions.roles = true,
+                "none" => {}
+                other => {
+                    return Err(format!(
+                        "unknown mention type '{}' (expected users, roles or none)",
+                        other
+                    ));
+                }
+            }
+        }
+        Ok(mentions)
+    }
+}
+
 /// Implementation for Discord operations via Serenity
 ///
 /// Holds a reference to the HTTP client that is maintained by Serenity's event loop.
 pub struct SerenityDiscordService {
     http: Arc<serenity::http::Http>,
+    #[cfg_attr(not(feature = "actions"), allow(dead_code))]
+    default_allowed_mentions: Option<DefaultAllowedMentions>,
+    #[cfg(feature = "actions")]
+    voice_gateway: Option<VoiceGateway>,
+}
+
+/// Gateway access for voice state updates (`join_voice_channel`)
+///
+/// Voice joins go over the gateway connection of the guild's shard, not HTTP.
+#[cfg(feature = "actions")]
+struct VoiceGateway {
+    shard_manager: Arc<ShardManager>,
+    cache: Arc<Cache>,
 }
 
 impl SerenityDiscordService {
     /// Create a new SerenityDiscordService with an HTTP client reference
     pub fn new(http: Arc<serenity::http::Http>) -> Self {
-        Self { http }
+        Self {
+            http,
+            default_allowed_mentions: None,
+            #[cfg(feature = "actions")]
+            voice_gateway: None,
+        }
+    }
+
+    /// Enable `join_voice_channel` through the client's shard runners
+    ///
+    /// The cache provides the shard count to find the guild's shard.
+    #[cfg(feature = "actions")]
+    pub fn with_voice_gateway(mut self, shard_manager: Arc<ShardManager>, cache: Arc<Cache>) -> Self {
+        self.voice_gateway = Some(VoiceGateway { shard_manager, cache });
+        self
+    }
+
+    /// Mention types that ping in replies, messages and edits unless an action overrides them
+    ///
+    /// `None` (the default) lets users and roles ping in messages and nobody
+    /// in replies (except the replied-to user with `mention`).
+    pub fn with_default_allowed_mentions(mut self, mentions: Option<DefaultAllowedMentions>) -> Self {
+        self.default_allowed_mentions = mentions;
+        self
     }
 }
 
 #[async_trait]
 impl DiscordService for SerenityDiscordService {
+    #[cfg(feature = "actions")]
     async fn react_to_message(
         &self,
         channel_id: ChannelId,
@@ -48,67 +133,291 @@ impl DiscordService for SerenityDiscordService {
         Ok(())
     }
 
+    #[cfg(feature = "actions")]
     async fn create_thread_from_message(
         &self,
         channel_id: ChannelId,
         message_id: MessageId,
         name: &str,
         auto_archive_duration: u16,
+        rate_limit_per_user: Option<u16>,
     ) -> Result<GuildChannel, serenity::Error> {
         use serenity::builder::CreateThread;
-        use tracing::warn;
-
-        // Convert auto_archive_duration to enum
-        let auto_archive_duration = match auto_archive_duration {
-            60 => AutoArchiveDuration::OneHour,
-            1440 => AutoArchiveDuration::OneDay,
-            4320 => AutoArchiveDuration::ThreeDays,
-            10080 => AutoArchiveDuration::OneWeek,
-            invalid => {
-                warn!(
-                    invalid_value = invalid,
-                    "Invalid auto_archive_duration, using default (1440 = OneDay)"
-                );
-                AutoArchiveDuration::OneDay
-            }
-        };
 
-        let builder = CreateThread::new(name.to_string())
-            .auto_archive_duration(auto_archive_duration);
+        let mut builder = CreateThread::new(name.to_string())
+            .auto_archive_duration(to_auto_archive_duration(auto_archive_duration));
+        if let Some(seconds) = rate_limit_per_user {
+            builder = builder.rate_limit_per_user(seconds);
+        }
 
         channel_id
             .create_thread_from_message(&self.http, message_id, builder)
             .await
     }
 
+    #[cfg(feature = "actions")]
+    async fn create_private_thread(
+        &self,
+        channel_id: ChannelId,
+        name: &str,
+        auto_archive_duration: u16,
+        invitable: Option<bool>,
+        rate_limit_per_user: Option<u16>,
+    ) -> Result<GuildChannel, serenity::Error> {
+        use serenity::builder::CreateThread;
+        use serenity::model::channel::ChannelType;
+
+        let mut builder = CreateThread::new(name.to_string())
+            .kind(ChannelType::PrivateThread)
+            .auto_archive_duration(to_auto_archive_duration(auto_archive_duration));
+        if let Some(invitable) = invitable {
+            builder = builder.invitable(invitable);
+        }
+        if let Some(seconds) = rate_limit_per_user {
+            builder = builder.rate_limit_per_user(seconds);
+        }
+
+        channel_id.create_thread(&self.http, builder).await
+    }
+
+    #[cfg(feature = "actions")]
     async fn send_message_to_channel(
         &self,
         channel_id: ChannelId,
         content: &str,
+        allow_mass_mentions: bool,
     ) -> Result<Message, serenity::Error> {
         use serenity::builder::CreateMessage;
 
-        let builder = CreateMessage::new().content(content);
+        let builder = CreateMessage::new()
+            .content(content)
+            .allowed_mentions(message_allowed_mentions(self.default_allowed_mentions, allow_mass_mentions));
+        channel_id.send_message(&self.http, builder).await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn create_dm_channel(&self, user_id: UserId) -> Result<ChannelId, serenity::Error> {
+        Ok(user_id.create_dm_channel(&self.http).await?.id)
+    }
+
+    #[cfg(feature = "actions")]
+    async fn send_message_with_attachments(
+        &self,
+        channel_id: ChannelId,
+        content: &str,
+        attachments: &[Attachment],
+        allow_mass_mentions: bool,
+    ) -> Result<Message, serenity::Error> {
+        use serenity::builder::{CreateAttachment, CreateMessage};
+
+        let mut builder = CreateMessage::new()
+            .content(content)
+            .allowed_mentions(message_allowed_mentions(self.default_allowed_mentions, allow_mass_mentions));
+        for attachment in attachments {
+            let mut file = CreateAttachment::url(&self.http, &attachment.url).await?;
+            file.filename = attachment.filename.clone();
+            builder = builder.add_file(file);
+        }
         channel_id.send_message(&self.http, builder).await
     }
 
+    #[cfg(feature = "actions")]
     async fn reply_in_channel(
         &self,
         channel_id: ChannelId,
         message_id: MessageId,
         content: &str,
         mention: bool,
+        allow_mass_mentions: bool,
     ) -> Result<Message, serenity::Error> {
-        use serenity::builder::{CreateAllowedMentions, CreateMessage};
+        use serenity::builder::CreateMessage;
 
         let builder = CreateMessage::new()
             .content(content)
             .reference_message((channel_id, message_id))
+            .allowed_mentions(reply_allowed_mentions(
+                self.default_allowed_mentions,
+                mention,
+                allow_mass_mentions,
+            ));
+
+        channel_id.send_message(&self.http, builder).await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn reply_with_file_in_channel(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        filename: &str,
+        data: Vec<u8>,
+        mention: bool,
+    ) -> Result<Message, serenity::Error> {
+        use serenity::builder::{CreateAttachment, CreateMessage};
+
+        // No text content, so only the replied-to user can be pinged
+        let builder = CreateMessage::new()
+            .add_file(CreateAttachment::bytes(data, filename))
+            .reference_message((channel_id, message_id))
             .allowed_mentions(CreateAllowedMentions::new().replied_user(mention));
 
         channel_id.send_message(&self.http, builder).await
     }
 
+    #[cfg(feature = "actions")]
+    async fn delete_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), serenity::Error> {
+        channel_id.delete_message(&self.http, message_id).await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn edit_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        content: Option<&str>,
+        embed: Option<&EmbedParams>,
+        allow_mass_mentions: bool,
+    ) -> Result<Message, serenity::Error> {
+        let builder = message_edit(content, embed)
+            .allowed_mentions(message_allowed_mentions(self.default_allowed_mentions, allow_mass_mentions));
+        channel_id.edit_message(&self.http, message_id, builder).await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn execute_webhook(
+        &self,
+        webhook_id: WebhookId,
+        token: &str,
+        username: Option<&str>,
+        avatar_url: Option<&str>,
+        content: &str,
+        allow_mass_mentions: bool,
+    ) -> Result<(), serenity::Error> {
+        use serenity::builder::{Builder as _, ExecuteWebhook};
+
+        let mut builder = ExecuteWebhook::new()
+            .content(content)
+            .allowed_mentions(message_allowed_mentions(self.default_allowed_mentions, allow_mass_mentions));
+        if let Some(username) = username {
+            builder = builder.username(username);
+        }
+        if let Some(avatar_url) = avatar_url {
+            builder = builder.avatar_url(avatar_url);
+        }
+        builder.execute(&self.http, (webhook_id, token, false)).await?;
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn suppress_embeds(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), serenity::Error> {
+        channel_id.edit_message(&self.http, message_id, suppress_embeds_edit()).await?;
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn remove_all_reactions(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), serenity::Error> {
+        channel_id.delete_reactions(&self.http, message_id).await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn leave_guild(&self, guild_id: GuildId) -> Result<(), serenity::Error> {
+        guild_id.leave(&self.http).await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn join_voice_channel(&self, guild_id: GuildId, channel_id: ChannelId) -> Result<(), serenity::Error> {
+        let gateway = self
+            .voice_gateway
+            .as_ref()
+            .ok_or(serenity::Error::Other("Voice gateway not configured"))?;
+        let shard_id = ShardId(guild_id.shard_id(&gateway.cache));
+        let runners = gateway.shard_manager.runners.lock().await;
+        let runner = runners
+            .get(&shard_id)
+            .ok_or(serenity::Error::Other("No running shard for the voice channel's guild"))?;
+        runner
+            .runner_tx
+            .websocket_message(WsMessage::text(voice_state_update(guild_id, channel_id).to_string()));
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn create_channel(
+        &self,
+        guild_id: GuildId,
+        name: &str,
+        kind: ChannelType,
+        parent_id: Option<ChannelId>,
+        topic: Option<&str>,
+    ) -> Result<GuildChannel, serenity::Error> {
+        guild_id
+            .create_channel(&self.http, create_channel_builder(name, kind, parent_id, topic))
+            .await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn add_member_role(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId,
+    ) -> Result<(), serenity::Error> {
+        self.http.add_member_role(guild_id, user_id, role_id, None).await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn remove_member_role(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId,
+    ) -> Result<(), serenity::Error> {
+        self.http.remove_member_role(guild_id, user_id, role_id, None).await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn acknowledge_interaction(
+        &self,
+        interaction_id: InteractionId,
+        token: &str,
+    ) -> Result<(), serenity::Error> {
+        use serenity::builder::{Builder as _, CreateInteractionResponse};
+
+        CreateInteractionResponse::Acknowledge
+            .execute(&self.http, (interaction_id, token))
+            .await
+    }
+
+    #[cfg(feature = "actions")]
+    async fn update_interaction_message(
+        &self,
+        interaction_id: InteractionId,
+        token: &str,
+        content: &str,
+        allow_mass_mentions: bool,
+    ) -> Result<(), serenity::Error> {
+        use serenity::builder::{Builder as _, CreateInteractionResponse, CreateInteractionResponseMessage};
+
+        let builder = CreateInteractionResponseMessage::new()
+            .content(content)
+            .allowed_mentions(message_allowed_mentions(self.default_allowed_mentions, allow_mass_mentions));
+        CreateInteractionResponse::UpdateMessage(builder)
+            .execute(&self.http, (interaction_id, token))
+            .await
+    }
+
     async fn get_message(
         &self,
         channel_id: ChannelId,
@@ -117,3 +426,295 @@ impl DiscordService for SerenityDiscordService {
         self.http.get_message(channel_id, message_id).await
     }
 }
+
+/// Convert an auto-archive duration in minutes to the enum (invalid values → 1440)
+#[cfg(feature = "actions")]
+fn to_auto_archive_duration(minutes: u16) -> AutoArchiveDuration {
+    match minutes {
+        60 => AutoArchiveDuration::OneHour,
+        1440 => AutoArchiveDuration::OneDay,
+        4320 => AutoArchiveDuration::ThreeDays,
+        10080 => AutoArchiveDuration::OneWeek,
+        invalid => {
+            tracing::warn!(
+                invalid_value = invalid,
+                "Invalid auto_archive_duration, using default (1440 = OneDay)"
+            );
+            AutoArchiveDuration::OneDay
+        }
+    }
+}
+
+/// Gateway voice state update (opcode 4) joining `channel_id` in `guild_id`
+#[cfg(feature = "actions")]
+fn voice_state_update(guild_id: GuildId, channel_id: ChannelId) -> serde_json::Value {
+    serde_json::json!({
+        "op": 4,
+        "d": {
+            "guild_id": guild_id,
+            "channel_id": channel_id,
+            "self_mute": false,
+            "self_deaf": false,
+        }
+    })
+}
+
+/// Channel creation with the given type and optional category and topic
+#[cfg(feature = "actions")]
+fn create_channel_builder(
+    name: &str,
+    kind: ChannelType,
+    parent_id: Option<ChannelId>,
+    topic: Option<&str>,
+) -> CreateChannel<'static> {
+    let mut builder = CreateChannel::new(name).kind(kind);
+    if let Some(parent_id) = parent_id {
+        builder = builder.category(parent_id);
+    }
+    if let Some(topic) = topic {
+        builder = builder.topic(topic);
+    }
+    builder
+}
+
+/// Message edit setting only the `SUPPRESS_EMBEDS` flag (content is left unchanged)
+#[cfg(feature = "actions")]
+fn suppress_embeds_edit() -> EditMessage {
+    EditMessage::new().suppress_embeds(true)
+}
+
+/// Message edit setting the given content and/or embed (others are left unchanged)
+#[cfg(feature = "actions")]
+fn message_edit(content: Option<&str>, embed: Option<&EmbedParams>) -> EditMessage {
+    let mut edit = EditMessage::new();
+    if let Some(content) = content {
+        edit = edit.content(content);
+    }
+    if let Some(embed) = embed {
+        edit = edit.embed(create_embed(embed));
+    }
+    edit
+}
+
+/// Build a Discord embed from action parameters
+#[cfg(feature = "actions")]
+fn create_embed(params: &EmbedParams) -> CreateEmbed {
+    let mut embed = CreateEmbed::new();
+    if let Some(title) = &params.title {
+        embed = embed.title(title);
+    }
+    if let Some(description) = &params.description {
+        embed = embed.description(description);
+    }
+    if let Some(url) = &params.url {
+        embed = embed.url(url);
+    }
+    if let Some(color) = params.color {
+        embed = embed.color(color);
+    }
+    if let Some(footer) = &params.footer {
+        embed = embed.footer(CreateEmbedFooter::new(footer));
+    }
+    embed.fields(
+        params
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.value.as_str(), field.inline)),
+    )
+}
+
+/// Allowed mentions for plain messages: the default mention types (users and
+/// roles if unset), `@everyone`/`@here` only if allowed
+#[cfg(feature = "actions")]
+fn message_allowed_mentions(
+    default: Option<DefaultAllowedMentions>,
+    allow_mass_mentions: bool,
+) -> CreateAllowedMentions {
+    let default = default.unwrap_or(DefaultAllowedMentions { users: true, roles: true });
+    CreateAllowedMentions::new()
+        .all_users(default.users)
+        .all_roles(default.roles)
+        .everyone(allow_mass_mentions)
+}
+
+/// Allowed mentions for replies: the default mention types (none if unset),
+/// the replied-to user (if `mention`), plus `@everyone`/`@here` if allowed
+#[cfg(feature = "actions")]
+fn reply_allowed_mentions(
+    default: Option<DefaultAllowedMentions>,
+    mention: bool,
+    allow_mass_mentions: bool,
+) -> CreateAllowedMentions {
+    let default = default.unwrap_or_default();
+    CreateAllowedMentions::new()
+        .all_users(default.users)
+        .all_roles(default.roles)
+        .replied_user(mention)
+        .everyone(allow_mass_mentions)
+}
+
+#[cfg(all(test, feature = "actions"))]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    fn parse_list(mentions: CreateAllowedMentions) -> serde_json::Value {
+        serde_json::to_value(mentions).unwrap()["parse"].clone()
+    }
+
+    #[test]
+    fn test_voice_state_update_payload() {
+        let payload = voice_state_update(GuildId::new(333), ChannelId::new(555));
+
+        assert_eq!(
+            payload,
+            json!({
+                "op": 4,
+                "d": {"guild_id": "333", "channel_id": "555", "self_mute": false, "self_deaf": false}
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_join_voice_without_gateway_fails() {
+        let service = SerenityDiscordService::new(Arc::new(serenity::http::Http::new("token")));
+
+        let result = service.join_voice_channel(GuildId::new(333), ChannelId::new(555)).await;
+
+        assert!(matches!(result, Err(serenity::Error::Other(_))));
+    }
+
+    #[test]
+    fn test_create_channel_builder_sets_name_kind_and_parent() {
+        let builder = create_channel_builder("ticket-1", ChannelType::Text, Some(ChannelId::new(777)), Some("Help"));
+
+        let body = serde_json::to_value(builder).unwrap();
+
+        assert_eq!(body["name"], "ticket-1");
+        assert_eq!(body["type"], 0);
+        assert_eq!(body["parent_id"], "777");
+        assert_eq!(body["topic"], "Help");
+    }
+
+    #[test]
+    fn test_create_channel_builder_omits_unset_parent_and_topic() {
+        let builder = create_channel_builder("Support", ChannelType::Category, None, None);
+
+        let body = serde_json::to_value(builder).unwrap();
+
+        assert_eq!(body["type"], 4);
+        assert!(body.get("parent_id").is_none());
+        assert!(body.get("topic").is_none());
+    }
+
+    #[test]
+    fn test_suppress_embeds_edit_sets_only_flag() {
+        use serenity::model::channel::MessageFlags;
+
+        let edit = serde_json::to_value(suppress_embeds_edit()).unwrap();
+
+        assert_eq!(edit, json!({"flags": MessageFlags::SUPPRESS_EMBEDS.bits()}));
+    }
+
+    fn status_embed() -> EmbedParams {
+        EmbedParams {
+            title: Some("Status".to_string()),
+            color: Some(0x58B9FF),
+            fields: vec![crate::adapters::event_response::EmbedFieldParams {
+                name: "Build".to_string(),
+                value: "passed".to_string(),
+                inline: true,
+            }],
+            footer: Some("CI".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case::content_only(Some("Done"), None, json!({"content": "Done"}))]
+    #[case::embed_only(None, Some(status_embed()), json!({"embeds": [{
+        "type": "rich",
+        "title": "Status",
+        "color": 0x58B9FF,
+        "fields": [{"name": "Build", "value": "passed", "inline": true}],
+        "footer": {"text": "CI"}
+    }]}))]
+    #[case::content_and_embed(Some("Done"), Some(EmbedParams {
+        description: Some("All good".to_string()),
+        ..Default::default()
+    }), json!({"content": "Done", "embeds": [{"type": "rich", "description": "All good"}]}))]
+    fn test_message_edit_sets_only_given_parts(
+        #[case] content: Option<&str>,
+        #[case] embed: Option<EmbedParams>,
+        #[case] expected: serde_json::Value,
+    ) {
+        let edit = serde_json::to_value(message_edit(content, embed.as_ref())).unwrap();
+
+        assert_eq!(edit, expected);
+    }
+
+    #[test]
+    fn test_message_allowed_mentions_excludes_everyone_by_default() {
+        assert_eq!(parse_list(message_allowed_mentions(None, false)), json!(["users", "roles"]));
+    }
+
+    #[test]
+    fn test_message_allowed_mentions_includes_everyone_when_allowed() {
+        let parse = parse_list(message_allowed_mentions(None, true));
+
+        assert!(parse.as_array().unwrap().contains(&json!("everyone")));
+    }
+
+    #[test]
+    fn test_reply_allowed_mentions_excludes_everyone_by_default() {
+        let mentions = serde_json::to_value(reply_allowed_mentions(None, true, false)).unwrap();
+
+        assert_eq!(mentions["parse"], json!([]));
+        assert_eq!(mentions["replied_user"], true);
+    }
+
+    #[test]
+    fn test_reply_allowed_mentions_includes_everyone_when_allowed() {
+        assert_eq!(parse_list(reply_allowed_mentions(None, false, true)), json!(["everyone"]));
+    }
+
+    const USERS_ONLY: Option<DefaultAllowedMentions> = Some(DefaultAllowedMentions {
+        users: true,
+        roles: false,
+    });
+
+    #[test]
+    fn test_message_allowed_mentions_applies_default() {
+        assert_eq!(parse_list(message_allowed_mentions(USERS_ONLY, false)), json!(["users"]));
+    }
+
+    #[test]
+    fn test_reply_allowed_mentions_applies_default() {
+        assert_eq!(parse_list(reply_allowed_mentions(USERS_ONLY, false, false)), json!(["users"]));
+    }
+
+    #[test]
+    fn test_action_settings_override_default() {
+        let message = parse_list(message_allowed_mentions(Some(DefaultAllowedMentions::default()), true));
+        let reply = serde_json::to_value(reply_allowed_mentions(USERS_ONLY, true, true)).unwrap();
+
+        assert_eq!(message, json!(["everyone"]));
+        assert_eq!(reply["parse"], json!(["users", "everyone"]));
+        assert_eq!(reply["replied_user"], true);
+    }
+
+    #[rstest]
+    #[case::users("users", DefaultAllowedMentions { users: true, roles: false })]
+    #[case::both(" Users, roles ", DefaultAllowedMentions { users: true, roles: true })]
+    #[case::none("none", DefaultAllowedMentions::default())]
+    #[case::empty("", DefaultAllowedMentions::default())]
+    fn test_default_allowed_mentions_parse(#[case] input: &str, #[case] expected: DefaultAllowedMentions) {
+        assert_eq!(input.parse::<DefaultAllowedMentions>(), Ok(expected));
+    }
+
+    #[test]
+    fn test_default_allowed_mentions_rejects_everyone() {
+        assert!("users,everyone".parse::<DefaultAllowedMentions>().is_err());
+    }
+}
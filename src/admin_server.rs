@@ -0,0 +1,494 @@
+//! Admin HTTP endpoint for executing actions and toggling channel forwarding from a control plane
+//!
+//! The channel denylist routes are always served; `POST /actions` needs the
+//! `actions` feature (enabled by default) and is `404 Not Found` without it.
+
+use crate::adapters::{ChannelInfoProvider, DiscordService, EventSender};
+#[cfg(feature = "actions")]
+use crate::adapters::EventResponse;
+#[cfg(feature = "actions")]
+use crate::bridge::action_target::ActionTarget;
+use crate::bridge::event_bridge::EventBridge;
+use crate::bridge::task_tracker::Shutdown;
+use crate::error::GatehookError;
+use http_body_util::Full;
+#[cfg(feature = "actions")]
+use http_body_util::{BodyExt as _, Limited};
+use hyper::body::{Bytes, Incoming};
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::header::{AUTHORIZATION, CONTENT_TYPE, HeaderMap, HeaderValue};
+use hyper::{Method, Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use serde::Serialize;
+#[cfg(feature = "actions")]
+use serde::Deserialize;
+use serenity::model::id::ChannelId;
+#[cfg(feature = "actions")]
+use serenity::model::id::{GuildId, MessageId};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tracing::{debug, info, warn};
+
+/// Path accepting action requests
+#[cfg(feature = "actions")]
+const ACTIONS_PATH: &str = "/actions";
+
+/// Path of the channel denylist (`/channels/denylist/{channel_id}` for a single channel)
+const DENYLIST_PATH: &str = "/channels/denylist";
+
+/// Maximum accepted request body size in bytes
+#[cfg(feature = "actions")]
+const MAX_REQUEST_BODY_SIZE: usize = 64 * 1024;
+
+/// Request body for `POST /actions`
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "channel_id": "...",
+///   "message_id": "...",
+///   "guild_id": "...",              // optional (omit for DMs)
+///   "actions": [ /* same format as webhook responses */ ]
+/// }
+/// ```
+#[cfg(feature = "actions")]
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AdminActionRequest {
+    /// Channel containing the target message
+    pub channel_id: ChannelId,
+    /// Message the actions apply to
+    pub message_id: MessageId,
+    /// Guild containing the channel (`None` for DMs)
+    #[serde(default)]
+    pub guild_id: Option<GuildId>,
+    /// Actions to execute
+    #[serde(flatten)]
+    pub response: EventResponse,
+}
+
+/// Convert an admin request into an ActionTarget.
+#[cfg(feature = "actions")]
+impl From<&AdminActionRequest> for ActionTarget {
+    fn from(request: &AdminActionRequest) -> Self {
+        Self {
+            message_id: request.message_id,
+            channel_id: request.channel_id,
+            guild_id: request.guild_id,
+            trigger_emoji: None,
+            trigger_user_id: None,
+            reaction_change: None,
+            source: None,
+            interaction: None,
+        }
+    }
+}
+
+/// Response body for `GET /channels/denylist`
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "channel_ids": ["...", "..."]
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DenylistResponse {
+    /// Denied channels, in ascending ID order
+    pub channel_ids: Vec<ChannelId>,
+}
+
+/// HTTP server executing actions posted by a control plane
+///
+/// Accepts `POST /actions` with an [`AdminActionRequest`] and runs the actions
+/// through [`EventBridge::execute_actions`], so the usual limits
+/// (`MAX_ACTIONS`, `ACTION_CONCURRENCY`, reply cooldown) apply.
+///
+/// Also manages the bridge's channel denylist: `GET /channels/denylist`
+/// lists the denied channels, `PUT /channels/denylist/{channel_id}` stops
+/// forwarding a channel's events and `DELETE /channels/denylist/{channel_id}`
+/// resumes it. Both changes are idempotent. Without the `actions` feature
+/// only the denylist routes are served.
+///
+/// # Responses
+/// - `200 OK`: denylist listed ([`DenylistResponse`])
+/// - `204 No Content`: actions executed (individual action failures are logged), denylist changed
+/// - `400 Bad Request`: body is not a valid request, or channel ID is invalid
+/// - `401 Unauthorized`: a token is configured and the request lacks it
+/// - `404 Not Found` / `405 Method Not Allowed`: unknown path or method
+/// - `413 Payload Too Large`: body exceeds 64 KiB
+/// - `415 Unsupported Media Type`: `POST /actions` without `Content-Type: application/json`
+/// - `500 Internal Server Error`: actions failed, or denylist could not be persisted
+///
+/// # Security
+///
+/// With `with_token`, every request must carry `Authorization: Bearer <token>`.
+/// Without it the endpoint is unauthenticated; either way, bind it to a
+/// loopback or otherwise private address only. Requiring a JSON content type
+/// keeps browsers from posting actions cross-origin with a simple form.
+pub struct AdminServer {
+    listener: TcpListener,
+    token: Option<Arc<str>>,
+    shutdown: Option<Shutdown>,
+}
+
+impl AdminServer {
+    /// Bind the admin server to the given address
+    pub async fn bind(addr: SocketAddr) -> Result<Self, GatehookError> {
+        let listener = TcpListener::bind(addr).await?;
+        Ok(Self {
+            listener,
+            token: None,
+            shutdown: None,
+        })
+    }
+
+    /// Require `Authorization: Bearer <token>` on every request (`None` or empty disables)
+    pub fn with_token(mut self, token: Option<String>) -> Self {
+        self.token = token.filter(|token| !token.is_empty()).map(Arc::from);
+        self
+    }
+
+    /// Stop accepting connections when `shutdown` fires (default: serve until dropped)
+    ///
+    /// Open connections finish their request in flight and are then closed.
+    pub fn with_shutdown(mut self, shutdown: Shutdown) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// Address the server is listening on
+    pub fn local_addr(&self) -> Result<SocketAddr, GatehookError> {
+        Ok(self.listener.local_addr()?)
+    }
+
+    /// Accept connections and execute posted actions until shutdown (or until the task is dropped)
+    pub async fn serve<D, S, C>(self, bridge: Arc<EventBridge<D, S, C>>) -> Result<(), GatehookError>
+    where
+        D: DiscordService + 'static,
+        S: EventSender + 'static,
+        C: ChannelInfoProvider + 'static,
+    {
+        info!(addr = %self.local_addr()?, "Admin endpoint listening");
+        let mut shutdown = self.shutdown.clone();
+
+        loop {
+            let (stream, peer) = tokio::select! {
+                accepted = self.listener.accept() => accepted?,
+                () = wait_shutdown(&mut shutdown) => {
+                    info!("Admin endpoint stopped accepting connections");
+                    return Ok(());
+                }
+            };
+            let connection_bridge = bridge.clone();
+            let token = self.token.clone();
+            let mut connection_shutdown = self.shutdown.clone();
+
+            // Dropping the stream closes the connection when the task is rejected
+            bridge.spawn_task("admin_connection", async move {
+                let service = service_fn(move |request| {
+                    let bridge = connection_bridge.clone();
+                    let token = token.clone();
+                    async move { Ok::<_, Infallible>(handle_request(&bridge, token.as_deref(), request).await) }
+                });
+
+                let connection = http1::Builder::new().serve_connection(TokioIo::new(stream), service);
+                tokio::pin!(connection);
+                let result = tokio::select! {
+                    result = connection.as_mut() => result,
+                    () = wait_shutdown(&mut connection_shutdown) => {
+                        connection.as_mut().graceful_shutdown();
+                        connection.await
+                    }
+                };
+                if let Err(err) = result {
+                    debug!(?err, %peer, "Admin connection closed with error");
+                }
+            });
+        }
+    }
+}
+
+/// Wait for the shutdown signal (forever without one)
+async fn wait_shutdown(shutdown: &mut Option<Shutdown>) {
+    match shutdown {
+        Some(shutdown) => shutdown.wait().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// Route and handle a single admin request
+async fn handle_request<D, S, C>(
+    bridge: &EventBridge<D, S, C>,
+    token: Option<&str>,
+    request: Request<Incoming>,
+) -> Response<Full<Bytes>>
+where
+    D: DiscordService,
+    S: EventSender,
+    C: ChannelInfoProvider,
+{
+    if let Some(token) = token
+        && !is_authorized(request.headers(), token)
+    {
+        warn!(path = request.uri().path(), "Admin request without a valid token, rejecting");
+        return status_response(StatusCode::UNAUTHORIZED);
+    }
+
+    let path = request.uri().path();
+    if path == DENYLIST_PATH {
+        return match *request.method() {
+            Method::GET => list_denylist(bridge),
+            _ => status_response(StatusCode::METHOD_NOT_ALLOWED),
+        };
+    }
+    if let Some(channel_id) = path
+        .strip_prefix(DENYLIST_PATH)
+        .and_then(|rest| rest.strip_prefix('/'))
+    {
+        return update_denylist(bridge, request.method(), channel_id);
+    }
+
+    #[cfg(feature = "actions")]
+    if path == ACTIONS_PATH {
+        return post_actions(bridge, request).await;
+    }
+    status_response(StatusCode::NOT_FOUND)
+}
+
+/// Execute the actions of a `POST /actions` request
+#[cfg(feature = "actions")]
+async fn post_actions<D, S, C>(bridge: &EventBridge<D, S, C>, request: Request<Incoming>) -> Response<Full<Bytes>>
+where
+    D: DiscordService,
+    S: EventSender,
+    C: ChannelInfoProvider,
+{
+    if request.method() != Method::POST {
+        return status_response(StatusCode::METHOD_NOT_ALLOWED);
+    }
+    if !is_json(request.headers()) {
+        warn!("Admin action request without Content-Type: application/json, rejecting");
+        return status_response(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    let body = match Limited::new(request.into_body(), MAX_REQUEST_BODY_SIZE)
+        .collect()
+        .await
+    {
+        Ok(body) => body.to_bytes(),
+        Err(err) => {
+            warn!(error = %err, "Failed to read admin request body");
+            return status_response(StatusCode::PAYLOAD_TOO_LARGE);
+        }
+    };
+
+    let action_request: AdminActionRequest = match serde_json::from_slice(&body) {
+        Ok(action_request) => action_request,
+        Err(err) => {
+            warn!(error = %err, "Invalid admin action request");
+            return status_response(StatusCode::BAD_REQUEST);
+        }
+    };
+
+    info!(
+        channel_id = %action_request.channel_id,
+        message_id = %action_request.message_id,
+        action_count = action_request.response.actions.len(),
+        "Executing actions from admin endpoint"
+    );
+
+    match bridge
+        .execute_actions(&action_request, &action_request.response)
+        .await
+    {
+        Ok(()) => status_response(StatusCode::NO_CONTENT),
+        Err(err) => {
+            warn!(?err, "Failed to execute admin actions");
+            status_response(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// List the denied channels
+fn list_denylist<D, S, C>(bridge: &EventBridge<D, S, C>) -> Response<Full<Bytes>>
+where
+    D: DiscordService,
+    S: EventSender,
+    C: ChannelInfoProvider,
+{
+    let body = DenylistResponse {
+        channel_ids: bridge.channel_denylist().channels(),
+    };
+    match serde_json::to_vec(&body) {
+        Ok(body) => {
+            let mut response = Response::new(Full::new(Bytes::from(body)));
+            response
+                .headers_mut()
+                .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            response
+        }
+        Err(err) => {
+            warn!(?err, "Failed to serialize channel denylist");
+            status_response(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// Add (`PUT`) or remove (`DELETE`) a channel on the denylist
+fn update_denylist<D, S, C>(bridge: &EventBridge<D, S, C>, method: &Method, channel_id: &str) -> Response<Full<Bytes>>
+where
+    D: DiscordService,
+    S: EventSender,
+    C: ChannelInfoProvider,
+{
+    let Some(channel_id) = channel_id.parse::<u64>().ok().filter(|id| *id != 0).map(ChannelId::new) else {
+        warn!(channel_id, "Invalid channel ID for admin denylist request");
+        return status_response(StatusCode::BAD_REQUEST);
+    };
+
+    let denylist = bridge.channel_denylist();
+    let result = match *method {
+        Method::PUT => denylist.insert(channel_id),
+        Method::DELETE => denylist.remove(channel_id),
+        _ => return status_response(StatusCode::METHOD_NOT_ALLOWED),
+    };
+
+    match result {
+        Ok(changed) => {
+            info!(%channel_id, denied = *method == Method::PUT, changed, "Channel denylist updated from admin endpoint");
+            status_response(StatusCode::NO_CONTENT)
+        }
+        Err(err) => {
+            warn!(?err, %channel_id, "Failed to persist channel denylist");
+            status_response(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// Whether the request carries `Authorization: Bearer <token>`
+///
+/// Compares in constant time so the token cannot be guessed byte by byte.
+fn is_authorized(headers: &HeaderMap, token: &str) -> bool {
+    let Some(provided) = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+    else {
+        return false;
+    };
+    provided.len() == token.len()
+        && provided
+            .bytes()
+            .zip(token.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Whether the request's `Content-Type` is `application/json` (parameters such as `charset` allowed)
+#[cfg(feature = "actions")]
+fn is_json(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .is_some_and(|media_type| media_type.trim().eq_ignore_ascii_case("application/json"))
+}
+
+/// Build an empty response with the given status
+fn status_response(status: StatusCode) -> Response<Full<Bytes>> {
+    let mut response = Response::new(Full::new(Bytes::new()));
+    *response.status_mut() = status;
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "actions")]
+    use crate::adapters::{ReactParams, ResponseAction};
+    use rstest::rstest;
+    #[cfg(feature = "actions")]
+    use serde_json::json;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        pairs
+            .iter()
+            .map(|(name, value)| (name.parse().unwrap(), HeaderValue::from_static(value)))
+            .collect()
+    }
+
+    #[cfg(feature = "actions")]
+    #[test]
+    fn test_admin_action_request_parse() {
+        let request: AdminActionRequest = serde_json::from_value(json!({
+            "channel_id": "222",
+            "message_id": "111",
+            "guild_id": "333",
+            "actions": [{"type": "react", "emoji": "👍"}]
+        }))
+        .unwrap();
+
+        assert_eq!(request.channel_id, ChannelId::new(222));
+        assert_eq!(request.message_id, MessageId::new(111));
+        assert_eq!(request.guild_id, Some(GuildId::new(333)));
+        assert_eq!(
+            request.response.actions,
+            vec![ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            })]
+        );
+    }
+
+    #[cfg(feature = "actions")]
+    #[test]
+    fn test_admin_action_request_defaults() {
+        let request: AdminActionRequest = serde_json::from_value(json!({
+            "channel_id": "222",
+            "message_id": "111"
+        }))
+        .unwrap();
+
+        assert_eq!(request.guild_id, None);
+        assert!(request.response.actions.is_empty());
+    }
+
+    #[rstest]
+    #[case::valid(Some("Bearer s3cret"), true)]
+    #[case::wrong_token(Some("Bearer s3creT"), false)]
+    #[case::prefix_of_token(Some("Bearer s3cre"), false)]
+    #[case::not_bearer(Some("Basic s3cret"), false)]
+    #[case::missing(None, false)]
+    fn test_is_authorized(#[case] authorization: Option<&'static str>, #[case] expected: bool) {
+        let headers = headers(&authorization.map(|value| ("authorization", value)).into_iter().collect::<Vec<_>>());
+
+        assert_eq!(is_authorized(&headers, "s3cret"), expected);
+    }
+
+    #[cfg(feature = "actions")]
+    #[rstest]
+    #[case::json(Some("application/json"), true)]
+    #[case::json_with_charset(Some("Application/JSON; charset=utf-8"), true)]
+    #[case::form(Some("application/x-www-form-urlencoded"), false)]
+    #[case::text(Some("text/plain"), false)]
+    #[case::missing(None, false)]
+    fn test_is_json(#[case] content_type: Option<&'static str>, #[case] expected: bool) {
+        let headers = headers(&content_type.map(|value| ("content-type", value)).into_iter().collect::<Vec<_>>());
+
+        assert_eq!(is_json(&headers), expected);
+    }
+
+    #[cfg(feature = "actions")]
+    #[test]
+    fn test_admin_action_request_requires_target() {
+        let result: Result<AdminActionRequest, _> = serde_json::from_value(json!({
+            "actions": [{"type": "react", "emoji": "👍"}]
+        }));
+
+        assert!(result.is_err());
+    }
+}
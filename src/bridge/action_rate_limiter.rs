@@ -0,0 +1,121 @@
+use serde::Deserialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Global token bucket limiting executed actions per minute
+///
+/// Complements the per-response `max_actions`: a flurry of webhook responses
+/// cannot collectively exceed `max_actions_per_minute`. The bucket holds up
+/// to one minute's worth of tokens and refills continuously; a limit of 0
+/// disables it. A second instance caps thread creation (`max_threads_per_minute`).
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+pub struct ActionRateLimiter {
+    max_actions_per_minute: u32,
+    bucket: Mutex<Bucket>,
+}
+
+/// What a thread action does when `max_threads_per_minute` is reached
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreadLimitPolicy {
+    /// Skip the thread action entirely
+    #[default]
+    Skip,
+    /// Post the thread content to the parent channel instead
+    PostToParent,
+}
+
+/// Remaining tokens as of the last refill
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+impl ActionRateLimiter {
+    const WINDOW: Duration = Duration::from_secs(60);
+
+    /// Create a new ActionRateLimiter (0 disables it)
+    pub fn new(max_actions_per_minute: u32) -> Self {
+        Self {
+            max_actions_per_minute,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(max_actions_per_minute),
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Take a token for one action, returning `false` if the bucket is empty
+    pub fn try_acquire(&self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+
+    fn try_acquire_at(&self, now: Instant) -> bool {
+        if self.max_actions_per_minute == 0 {
+            return true;
+        }
+
+        let capacity = f64::from(self.max_actions_per_minute);
+        let mut bucket = self.bucket.lock().unwrap();
+        let elapsed = now.saturating_duration_since(bucket.refilled_at);
+        bucket.tokens =
+            (bucket.tokens + capacity * elapsed.as_secs_f64() / Self::WINDOW.as_secs_f64()).min(capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens < 1.0 {
+            return false;
+        }
+        bucket.tokens -= 1.0;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_actions_beyond_limit_are_rejected() {
+        let limiter = ActionRateLimiter::new(3);
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire_at(now));
+        assert!(limiter.try_acquire_at(now));
+        assert!(limiter.try_acquire_at(now));
+        assert!(!limiter.try_acquire_at(now));
+    }
+
+    #[test]
+    fn test_tokens_refill_over_time() {
+        let limiter = ActionRateLimiter::new(6);
+        let now = Instant::now();
+        for _ in 0..6 {
+            assert!(limiter.try_acquire_at(now));
+        }
+
+        // One token every 10 seconds
+        assert!(!limiter.try_acquire_at(now + Duration::from_secs(5)));
+        assert!(limiter.try_acquire_at(now + Duration::from_secs(10)));
+        assert!(!limiter.try_acquire_at(now + Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_refill_is_capped_at_limit() {
+        let limiter = ActionRateLimiter::new(2);
+        let later = Instant::now() + Duration::from_secs(600);
+
+        assert!(limiter.try_acquire_at(later));
+        assert!(limiter.try_acquire_at(later));
+        assert!(!limiter.try_acquire_at(later));
+    }
+
+    #[test]
+    fn test_zero_limit_disables_limiter() {
+        let limiter = ActionRateLimiter::new(0);
+
+        for _ in 0..1000 {
+            assert!(limiter.try_acquire());
+        }
+    }
+}
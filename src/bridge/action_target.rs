@@ -1,5 +1,7 @@
-use serenity::model::channel::{Message, Reaction};
-use serenity::model::id::{ChannelId, GuildId, MessageId};
+use serde::Deserialize;
+use serenity::model::application::ComponentInteraction;
+use serenity::model::channel::{Attachment, Message, Reaction, ReactionType};
+use serenity::model::id::{ChannelId, GuildId, InteractionId, MessageId, UserId};
 
 /// Target for webhook response actions.
 ///
@@ -11,11 +13,106 @@ use serenity::model::id::{ChannelId, GuildId, MessageId};
 /// - Performance optimization (O(1) cache lookups)
 /// - Future guild-specific actions (roles, permissions, etc.)
 /// - Clear DM vs Guild context distinction
-#[derive(Debug, Clone, Copy)]
+///
+/// The `trigger_emoji` field carries the emoji of the triggering reaction
+/// (reaction events only), in the same format accepted by `ReactParams.emoji`.
+///
+/// The `trigger_user_id` and `reaction_change` fields identify who reacted
+/// and whether the reaction was added or removed (used by `ToggleRole`).
+/// `reaction_change` is not known from the reaction itself and must be set
+/// by the caller via `with_reaction_change()`.
+///
+/// The `source` field carries the content and attachments of the triggering
+/// message (message events only), used by `Forward` to repost it.
+///
+/// The `interaction` field identifies the triggering component interaction
+/// (interaction events only), used by `InteractionResponse` to respond to it.
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
 pub struct ActionTarget {
     pub message_id: MessageId,
     pub channel_id: ChannelId,
     pub guild_id: Option<GuildId>,
+    pub trigger_emoji: Option<String>,
+    pub trigger_user_id: Option<UserId>,
+    pub reaction_change: Option<ReactionChange>,
+    pub source: Option<SourceMessage>,
+    pub interaction: Option<InteractionRef>,
+}
+
+/// Content of the triggering message
+#[derive(Debug, Clone)]
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+pub struct SourceMessage {
+    pub content: String,
+    pub attachments: Vec<Attachment>,
+}
+
+/// ID and token needed to respond to the triggering interaction
+#[derive(Clone)]
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+pub struct InteractionRef {
+    pub id: InteractionId,
+    pub token: String,
+}
+
+/// The token is a credential: only the interaction ID is logged
+impl std::fmt::Debug for InteractionRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InteractionRef").field("id", &self.id).finish_non_exhaustive()
+    }
+}
+
+/// Where a `Reply` action triggered by a reaction event is sent (`REACTION_REPLY_MODE`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReactionReplyMode {
+    /// Reply to the reacted-to message (message reference)
+    #[default]
+    Reference,
+    /// Post a plain message to the reaction's channel
+    Channel,
+    /// Send a direct message to the reacting user
+    Dm,
+}
+
+/// Whether the triggering reaction was added or removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+pub enum ReactionChange {
+    Added,
+    Removed,
+}
+
+impl ActionTarget {
+    /// Create an ActionTarget from bare IDs (events without a live message)
+    ///
+    /// Used for delete/update events, where only the IDs are known and the
+    /// message itself may no longer exist.
+    pub fn from_ids(message_id: MessageId, channel_id: ChannelId, guild_id: Option<GuildId>) -> Self {
+        Self {
+            message_id,
+            channel_id,
+            guild_id,
+            trigger_emoji: None,
+            trigger_user_id: None,
+            reaction_change: None,
+            source: None,
+            interaction: None,
+        }
+    }
+
+    /// Set whether the triggering reaction was added or removed
+    pub fn with_reaction_change(mut self, change: ReactionChange) -> Self {
+        self.reaction_change = Some(change);
+        self
+    }
+
+    /// The user who reacted, for targets built from a reaction event
+    #[cfg_attr(not(feature = "actions"), allow(dead_code))]
+    pub fn reacting_user_id(&self) -> Option<UserId> {
+        self.trigger_emoji.as_ref().and(self.trigger_user_id)
+    }
 }
 
 #[cfg(test)]
@@ -26,6 +123,11 @@ impl ActionTarget {
             message_id,
             channel_id,
             guild_id: None,
+            trigger_emoji: None,
+            trigger_user_id: None,
+            reaction_change: None,
+            source: None,
+            interaction: None,
         }
     }
 }
@@ -37,6 +139,14 @@ impl From<&Message> for ActionTarget {
             message_id: message.id,
             channel_id: message.channel_id,
             guild_id: message.guild_id,
+            trigger_emoji: None,
+            trigger_user_id: None,
+            reaction_change: None,
+            source: Some(SourceMessage {
+                content: message.content.clone(),
+                attachments: message.attachments.clone(),
+            }),
+            interaction: None,
         }
     }
 }
@@ -48,10 +158,47 @@ impl From<&Reaction> for ActionTarget {
             message_id: reaction.message_id,
             channel_id: reaction.channel_id,
             guild_id: reaction.guild_id,
+            trigger_emoji: Some(emoji_param(&reaction.emoji)),
+            trigger_user_id: reaction.user_id,
+            reaction_change: None,
+            source: None,
+            interaction: None,
         }
     }
 }
 
+/// Convert a component interaction into an ActionTarget (the message the component is attached to).
+impl From<&ComponentInteraction> for ActionTarget {
+    fn from(interaction: &ComponentInteraction) -> Self {
+        Self {
+            message_id: interaction.message.id,
+            channel_id: interaction.channel_id,
+            guild_id: interaction.guild_id,
+            trigger_emoji: None,
+            trigger_user_id: Some(interaction.user.id),
+            reaction_change: None,
+            source: None,
+            interaction: Some(InteractionRef {
+                id: interaction.id,
+                token: interaction.token.clone(),
+            }),
+        }
+    }
+}
+
+/// Format a reaction emoji as a `ReactParams.emoji` value
+/// (Unicode as-is, custom emoji as "name:id").
+pub(crate) fn emoji_param(emoji: &ReactionType) -> String {
+    match emoji {
+        ReactionType::Custom { id, name, .. } => {
+            format!("{}:{id}", name.as_deref().unwrap_or_default())
+        }
+        ReactionType::Unicode(unicode) => unicode.clone(),
+        // ReactionType is non-exhaustive; fall back to Discord's display format
+        other => other.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -66,5 +213,64 @@ mod tests {
 
         assert_eq!(target.message_id, message_id);
         assert_eq!(target.channel_id, channel_id);
+        assert_eq!(target.trigger_emoji, None);
+        assert_eq!(target.reaction_change, None);
+    }
+
+    #[test]
+    fn test_action_target_from_ids() {
+        let target = ActionTarget::from_ids(MessageId::new(1), ChannelId::new(2), Some(GuildId::new(3)));
+
+        assert_eq!(target.message_id, MessageId::new(1));
+        assert_eq!(target.channel_id, ChannelId::new(2));
+        assert_eq!(target.guild_id, Some(GuildId::new(3)));
+        assert_eq!(target.trigger_user_id, None);
+        assert!(target.source.is_none());
+    }
+
+    #[test]
+    fn test_action_target_from_message_keeps_source() {
+        let mut message = Message::default();
+        message.content = "Hello".to_string();
+
+        let target = ActionTarget::from(&message);
+
+        let source = target.source.unwrap();
+        assert_eq!(source.content, "Hello");
+        assert!(source.attachments.is_empty());
+    }
+
+    #[test]
+    fn test_with_reaction_change() {
+        let target = ActionTarget::new(MessageId::new(1), ChannelId::new(2))
+            .with_reaction_change(ReactionChange::Removed);
+
+        assert_eq!(target.reaction_change, Some(ReactionChange::Removed));
+    }
+
+    #[test]
+    fn test_reacting_user_id_only_for_reactions() {
+        let mut target = ActionTarget::new(MessageId::new(1), ChannelId::new(2));
+        target.trigger_user_id = Some(UserId::new(3));
+        assert_eq!(target.reacting_user_id(), None);
+
+        target.trigger_emoji = Some("👍".to_string());
+        assert_eq!(target.reacting_user_id(), Some(UserId::new(3)));
+    }
+
+    #[test]
+    fn test_emoji_param_unicode() {
+        let emoji = ReactionType::Unicode("👍".to_string());
+        assert_eq!(emoji_param(&emoji), "👍");
+    }
+
+    #[test]
+    fn test_emoji_param_custom() {
+        let emoji = ReactionType::Custom {
+            animated: false,
+            id: serenity::model::id::EmojiId::new(123456789),
+            name: Some("party".to_string()),
+        };
+        assert_eq!(emoji_param(&emoji), "party:123456789");
     }
 }
@@ -0,0 +1,76 @@
+use serde::Serialize;
+use serenity::model::guild::automod::ActionExecution;
+
+/// Payload for AUTO_MODERATION_ACTION_EXECUTION events
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "auto_moderation_action_execution": {
+///     "guild_id": "...",
+///     "rule_id": "...",
+///     "rule_trigger_type": 1,
+///     "action": { "type": 1, "metadata": { /* action-specific */ } },
+///     "user_id": "...",
+///     "channel_id": "...",               // null when not posted in a channel
+///     "message_id": "...",               // null when the message was blocked
+///     "alert_system_message_id": "...",  // null unless the action is an alert
+///     "content": "...",
+///     "matched_keyword": "...",
+///     "matched_content": "..."
+///   }
+/// }
+/// ```
+#[derive(Serialize)]
+pub struct AutoModerationActionPayload<'a> {
+    /// The executed AutoMod action
+    pub auto_moderation_action_execution: &'a ActionExecution,
+}
+
+impl<'a> AutoModerationActionPayload<'a> {
+    /// Create a new AutoModerationActionPayload
+    pub fn new(execution: &'a ActionExecution) -> Self {
+        Self {
+            auto_moderation_action_execution: execution,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_auto_moderation_action_payload_serialize() {
+        let execution: ActionExecution = serde_json::from_value(json!({
+            "guild_id": "777",
+            "action": {"type": 1, "metadata": {"custom_message": "Please keep it civil"}},
+            "rule_id": "999",
+            "rule_trigger_type": 1,
+            "user_id": "555",
+            "channel_id": "888",
+            "message_id": null,
+            "alert_system_message_id": null,
+            "content": "some bad word here",
+            "matched_keyword": "bad*",
+            "matched_content": "bad"
+        }))
+        .unwrap();
+
+        let payload = AutoModerationActionPayload::new(&execution);
+        let json = serde_json::to_value(&payload).unwrap();
+
+        let execution = &json["auto_moderation_action_execution"];
+        assert_eq!(execution["guild_id"], "777");
+        assert_eq!(execution["rule_id"], "999");
+        assert_eq!(execution["rule_trigger_type"], 1);
+        assert_eq!(execution["action"]["type"], 1);
+        assert_eq!(execution["action"]["metadata"]["custom_message"], "Please keep it civil");
+        assert_eq!(execution["user_id"], "555");
+        assert_eq!(execution["channel_id"], "888");
+        assert!(execution["message_id"].is_null());
+        assert_eq!(execution["matched_keyword"], "bad*");
+        assert_eq!(execution["matched_content"], "bad");
+    }
+}
@@ -0,0 +1,166 @@
+use crate::adapters::atomic_file::write_atomic;
+use crate::error::GatehookError;
+use serenity::model::id::ChannelId;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Channels whose events are not forwarded, changeable at runtime
+///
+/// Managed through the admin endpoint (`ADMIN_PORT`). With a backing file
+/// (`CHANNEL_DENYLIST_PATH`) the set is loaded on startup and every change
+/// is written back atomically (temporary file, then rename) before it takes
+/// effect, so a failed write leaves both the file and the set unchanged.
+///
+/// The file holds a JSON array of channel IDs, e.g. `["222", "333"]`.
+pub struct ChannelDenylist {
+    channels: RwLock<BTreeSet<ChannelId>>,
+    path: Option<PathBuf>,
+}
+
+impl ChannelDenylist {
+    /// Create an empty in-memory denylist
+    pub fn new() -> Self {
+        Self {
+            channels: RwLock::new(BTreeSet::new()),
+            path: None,
+        }
+    }
+
+    /// Open a denylist backed by a file (a missing file starts empty)
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, GatehookError> {
+        let path = path.into();
+        let channels = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(err) if err.kind() == ErrorKind::NotFound => BTreeSet::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Self {
+            channels: RwLock::new(channels),
+            path: Some(path),
+        })
+    }
+
+    /// Whether events of `channel_id` are dropped
+    pub fn contains(&self, channel_id: ChannelId) -> bool {
+        self.channels.read().unwrap().contains(&channel_id)
+    }
+
+    /// Denied channels, in ascending ID order
+    pub fn channels(&self) -> Vec<ChannelId> {
+        self.channels.read().unwrap().iter().copied().collect()
+    }
+
+    /// Deny a channel, returning whether it was newly added
+    pub fn insert(&self, channel_id: ChannelId) -> Result<bool, GatehookError> {
+        self.update(|channels| channels.insert(channel_id))
+    }
+
+    /// Allow a channel again, returning whether it was denied
+    pub fn remove(&self, channel_id: ChannelId) -> Result<bool, GatehookError> {
+        self.update(|channels| channels.remove(&channel_id))
+    }
+
+    /// Apply a change to a copy of the set, persist it, then make it current
+    fn update(&self, change: impl FnOnce(&mut BTreeSet<ChannelId>) -> bool) -> Result<bool, GatehookError> {
+        let mut channels = self.channels.write().unwrap();
+        let mut updated = channels.clone();
+        if !change(&mut updated) {
+            return Ok(false);
+        }
+        if let Some(path) = &self.path {
+            write_channels(path, &updated)?;
+        }
+        *channels = updated;
+        Ok(true)
+    }
+}
+
+impl Default for ChannelDenylist {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Atomically replace the denylist file contents
+fn write_channels(path: &Path, channels: &BTreeSet<ChannelId>) -> Result<(), GatehookError> {
+    write_atomic(path, &serde_json::to_vec(channels)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::TempPath;
+
+    #[test]
+    fn test_insert_and_remove() {
+        let denylist = ChannelDenylist::new();
+
+        assert!(denylist.insert(ChannelId::new(222)).unwrap());
+        assert!(!denylist.insert(ChannelId::new(222)).unwrap());
+        assert!(denylist.contains(ChannelId::new(222)));
+        assert!(!denylist.contains(ChannelId::new(333)));
+
+        assert!(denylist.remove(ChannelId::new(222)).unwrap());
+        assert!(!denylist.remove(ChannelId::new(222)).unwrap());
+        assert!(!denylist.contains(ChannelId::new(222)));
+    }
+
+    #[test]
+    fn test_channels_sorted() {
+        let denylist = ChannelDenylist::new();
+        denylist.insert(ChannelId::new(333)).unwrap();
+        denylist.insert(ChannelId::new(222)).unwrap();
+
+        assert_eq!(denylist.channels(), vec![ChannelId::new(222), ChannelId::new(333)]);
+    }
+
+    #[test]
+    fn test_open_missing_file_is_empty() {
+        let path = TempPath::new("denylist-missing.json");
+
+        let denylist = ChannelDenylist::open(&path.0).unwrap();
+
+        assert!(denylist.channels().is_empty());
+        assert!(!path.0.exists(), "Nothing is written until the set changes");
+    }
+
+    #[test]
+    fn test_changes_persist_across_open() {
+        let path = TempPath::new("denylist-persist.json");
+        let denylist = ChannelDenylist::open(&path.0).unwrap();
+        denylist.insert(ChannelId::new(222)).unwrap();
+        denylist.insert(ChannelId::new(333)).unwrap();
+        denylist.remove(ChannelId::new(333)).unwrap();
+
+        let reopened = ChannelDenylist::open(&path.0).unwrap();
+
+        assert_eq!(fs::read_to_string(&path.0).unwrap(), r#"["222"]"#);
+        assert_eq!(reopened.channels(), vec![ChannelId::new(222)]);
+    }
+
+    #[test]
+    fn test_open_malformed_file_fails() {
+        let path = TempPath::new("denylist-malformed.json");
+        fs::write(&path.0, "not json").unwrap();
+
+        assert!(matches!(
+            ChannelDenylist::open(&path.0),
+            Err(GatehookError::Serialize(_))
+        ));
+    }
+
+    #[test]
+    fn test_failed_write_leaves_set_unchanged() {
+        let path = TempPath::new("denylist-unwritable.json");
+        let denylist = ChannelDenylist::open(path.0.join("missing-dir").join("denylist.json")).unwrap();
+
+        assert!(denylist.insert(ChannelId::new(222)).is_err());
+        assert!(!denylist.contains(ChannelId::new(222)));
+    }
+}
@@ -0,0 +1,115 @@
+use serde::Serialize;
+use serenity::model::application::ComponentInteraction;
+use serenity::model::id::{ChannelId, GuildId, MessageId};
+use serenity::model::user::User;
+
+/// Payload for message component interactions (button clicks, select menus)
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "custom_id": "...",
+///   "message_id": "...",
+///   "channel_id": "...",
+///   "guild_id": "...",         // optional (omitted in DMs)
+///   "user": { /* User fields */ }
+/// }
+/// ```
+#[derive(Serialize)]
+pub struct ComponentInteractionPayload<'a> {
+    /// Developer-defined ID of the component that was used
+    pub custom_id: &'a str,
+
+    /// ID of the message the component is attached to
+    pub message_id: MessageId,
+
+    /// Channel of the message
+    pub channel_id: ChannelId,
+
+    /// Guild of the message, omitted for direct messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+
+    /// User who used the component
+    pub user: &'a User,
+}
+
+impl<'a> ComponentInteractionPayload<'a> {
+    /// Create a new ComponentInteractionPayload
+    ///
+    /// # Arguments
+    ///
+    /// * `interaction` - The component interaction received with interaction_create
+    pub fn new(interaction: &'a ComponentInteraction) -> Self {
+        Self {
+            custom_id: &interaction.data.custom_id,
+            message_id: interaction.message.id,
+            channel_id: interaction.channel_id,
+            guild_id: interaction.guild_id,
+            user: &interaction.user,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_interaction(guild_id: Option<&str>) -> ComponentInteraction {
+        serde_json::from_value(json!({
+            "id": "100",
+            "application_id": "200",
+            "type": 3,
+            "data": {"custom_id": "approve", "component_type": 2},
+            "guild_id": guild_id,
+            "channel_id": "222",
+            "user": {"id": "555", "username": "alice", "discriminator": "0000", "avatar": null},
+            "token": "interaction-token",
+            "version": 1,
+            "message": {
+                "id": "111",
+                "channel_id": "222",
+                "author": {"id": "999", "username": "bot", "discriminator": "0000", "avatar": null},
+                "content": "Approve?",
+                "timestamp": "2024-01-01T00:00:00Z",
+                "edited_timestamp": null,
+                "tts": false,
+                "mention_everyone": false,
+                "mentions": [],
+                "mention_roles": [],
+                "attachments": [],
+                "embeds": [],
+                "pinned": false,
+                "type": 0
+            },
+            "locale": "en-US",
+            "entitlements": [],
+            "authorizing_integration_owners": {}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_serialize_component_interaction_payload() {
+        let interaction = create_interaction(Some("777"));
+
+        let json = serde_json::to_value(ComponentInteractionPayload::new(&interaction)).unwrap();
+
+        assert_eq!(json["custom_id"], "approve");
+        assert_eq!(json["message_id"], "111");
+        assert_eq!(json["channel_id"], "222");
+        assert_eq!(json["guild_id"], "777");
+        assert_eq!(json["user"]["id"], "555");
+        assert!(json.get("token").is_none());
+    }
+
+    #[test]
+    fn test_direct_message_omits_guild_id() {
+        let interaction = create_interaction(None);
+
+        let json = serde_json::to_value(ComponentInteractionPayload::new(&interaction)).unwrap();
+
+        assert!(json.get("guild_id").is_none());
+    }
+}
@@ -9,18 +9,22 @@
 
 use tracing::warn;
 
+/// Discord's message content limit in characters
+pub const MAX_CONTENT_LEN: usize = 2000;
+
 /// Truncate content to Discord's 2000 character limit
 ///
-/// If content exceeds limit, truncates to 1997 chars and appends "..."
+/// If content exceeds limit, truncates it so that content plus `marker`
+/// is exactly 2000 chars (an empty marker cuts hard at 2000).
 /// Logs warning with original and truncated length.
-pub fn truncate_content(content: &str) -> String {
-    const MAX_LEN: usize = 2000;
-
+pub fn truncate_content(content: &str, marker: &str) -> String {
     let char_count = content.chars().count();
 
-    if char_count > MAX_LEN {
-        let truncated: String = content.chars().take(MAX_LEN - 3).collect();
-        let result = format!("{}...", truncated);
+    if char_count > MAX_CONTENT_LEN {
+        let marker: String = marker.chars().take(MAX_CONTENT_LEN).collect();
+        let budget = MAX_CONTENT_LEN - marker.chars().count();
+        let truncated: String = content.chars().take(budget).collect();
+        let result = format!("{}{}", truncated, marker);
 
         warn!(
             original_len = char_count,
@@ -61,7 +65,7 @@ mod tests {
     #[case("", "")]                           // Empty string
     #[case("Hello", "Hello")]                 // Short string
     fn test_truncate_content_no_truncation(#[case] input: &str, #[case] expected: &str) {
-        let result = truncate_content(input);
+        let result = truncate_content(input, "...");
         assert_eq!(result, expected);
         assert_eq!(result.chars().count(), expected.chars().count());
     }
@@ -69,7 +73,7 @@ mod tests {
     #[test]
     fn test_truncate_content_exactly_2000_chars() {
         let content = "a".repeat(2000);
-        let result = truncate_content(&content);
+        let result = truncate_content(&content, "...");
 
         assert_eq!(result, content);
         assert_eq!(result.chars().count(), 2000);
@@ -78,7 +82,7 @@ mod tests {
     #[test]
     fn test_truncate_content_truncates_long_content() {
         let long_content = "a".repeat(2100);
-        let result = truncate_content(&long_content);
+        let result = truncate_content(&long_content, "...");
 
         assert_eq!(result.chars().count(), 2000);
         assert!(result.ends_with("..."));
@@ -89,12 +93,32 @@ mod tests {
     fn test_truncate_content_handles_multibyte_chars() {
         // 2001 characters with emoji (multibyte)
         let content = format!("{}{}", "あ".repeat(1999), "🎉🎉");
-        let result = truncate_content(&content);
+        let result = truncate_content(&content, "...");
 
         assert_eq!(result.chars().count(), 2000);
         assert!(result.ends_with("..."));
     }
 
+    #[rstest]
+    #[case::custom(" [more]", 1993)]
+    #[case::multibyte("…", 1999)]
+    #[case::empty("", 2000)]
+    fn test_truncate_content_with_marker(#[case] marker: &str, #[case] kept_chars: usize) {
+        let long_content = "a".repeat(2100);
+        let result = truncate_content(&long_content, marker);
+
+        assert_eq!(result.chars().count(), 2000);
+        assert_eq!(result, format!("{}{}", "a".repeat(kept_chars), marker));
+    }
+
+    #[test]
+    fn test_truncate_content_marker_longer_than_limit() {
+        let long_content = "a".repeat(2100);
+        let result = truncate_content(&long_content, &"-".repeat(2500));
+
+        assert_eq!(result, "-".repeat(2000));
+    }
+
     // Tests for truncate_thread_name
 
     #[rstest]
@@ -0,0 +1,92 @@
+use crate::error::GatehookError;
+use serenity::model::id::ChannelId;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Rate-limited target for operator error notices (`ERROR_NOTIFY_CHANNEL`)
+///
+/// Action and webhook failures post a brief notice to the configured
+/// channel, at most one per `interval` so a failing endpoint cannot flood
+/// it. Notices never include error messages, URLs or content; see
+/// `webhook_failure_reason`.
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+pub struct ErrorNotifier {
+    channel_id: Option<ChannelId>,
+    interval: Duration,
+    last_sent: Mutex<Option<Instant>>,
+}
+
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+impl ErrorNotifier {
+    /// Create a new ErrorNotifier (`None` disables notices)
+    pub fn new(channel_id: Option<ChannelId>, interval: Duration) -> Self {
+        Self {
+            channel_id,
+            interval,
+            last_sent: Mutex::new(None),
+        }
+    }
+
+    /// Channel to post a notice to now, or `None` if disabled or still rate limited
+    pub fn try_acquire(&self) -> Option<ChannelId> {
+        self.try_acquire_at(Instant::now())
+    }
+
+    fn try_acquire_at(&self, now: Instant) -> Option<ChannelId> {
+        let channel_id = self.channel_id?;
+        let mut last_sent = self.last_sent.lock().unwrap();
+        if let Some(sent_at) = *last_sent
+            && now.saturating_duration_since(sent_at) < self.interval
+        {
+            return None;
+        }
+        *last_sent = Some(now);
+        Some(channel_id)
+    }
+}
+
+/// Redacted description of a webhook failure (no URLs, bodies or paths)
+///
+/// Only covers delivery errors; rejected responses (5xx/429) are described
+/// by their status.
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+pub fn webhook_failure_reason(err: &GatehookError) -> String {
+    match err {
+        GatehookError::Http(err) if err.is_timeout() => "timeout".to_string(),
+        GatehookError::Http(_) => "request error".to_string(),
+        GatehookError::CircuitOpen => "circuit breaker open".to_string(),
+        GatehookError::Serialize(_) => "serialization error".to_string(),
+        GatehookError::Io(_) => "I/O error".to_string(),
+        GatehookError::Config(_) | GatehookError::Discord(_) => "error".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notices_are_rate_limited() {
+        let notifier = ErrorNotifier::new(Some(ChannelId::new(1)), Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert_eq!(notifier.try_acquire_at(now), Some(ChannelId::new(1)));
+        assert_eq!(notifier.try_acquire_at(now + Duration::from_secs(59)), None);
+        assert_eq!(notifier.try_acquire_at(now + Duration::from_secs(60)), Some(ChannelId::new(1)));
+    }
+
+    #[test]
+    fn test_disabled_without_channel() {
+        let notifier = ErrorNotifier::new(None, Duration::ZERO);
+
+        assert_eq!(notifier.try_acquire(), None);
+    }
+
+    #[test]
+    fn test_webhook_failure_reason_is_redacted() {
+        let err = GatehookError::Io(std::io::Error::other("/secret/path/outbox.jsonl"));
+
+        assert_eq!(webhook_failure_reason(&err), "I/O error");
+        assert_eq!(webhook_failure_reason(&GatehookError::CircuitOpen), "circuit breaker open");
+    }
+}
@@ -1,23 +1,119 @@
-use crate::adapters::{
-    ChannelInfoProvider, DiscordService, EventResponse, EventSender, ReactParams, ReplyParams,
-    ResponseAction, ThreadParams,
-};
+use crate::adapters::event_response::{ReactParams, ReplyParams, ResponseAction};
+use crate::adapters::{ChannelInfoProvider, Delivery, DiscordService, EventResponse, EventSender, PAYLOAD_SCHEMA_VERSION};
 use crate::bridge::action_target::ActionTarget;
-use crate::bridge::discord_text::{truncate_content, truncate_thread_name};
+use crate::bridge::auto_moderation_action_payload::AutoModerationActionPayload;
+use crate::bridge::channel_denylist::ChannelDenylist;
+use crate::bridge::event_sampler::EventSampler;
+use crate::bridge::field_redactor::redact_fields;
+use crate::bridge::component_interaction_payload::ComponentInteractionPayload;
+use crate::bridge::guild_create_payload::GuildCreatePayload;
+use crate::bridge::guild_member_update_payload::GuildMemberUpdatePayload;
+use crate::bridge::heartbeat::HeartbeatStats;
 use crate::bridge::message_delete_bulk_payload::MessageDeleteBulkPayload;
 use crate::bridge::message_delete_payload::MessageDeletePayload;
 use crate::bridge::message_payload::MessagePayload;
 use crate::bridge::message_update_payload::MessageUpdatePayload;
+use crate::bridge::raw_event_payload::RawEventPayload;
 use crate::bridge::reaction_payload::ReactionPayload;
+use crate::bridge::reaction_remove_batch_payload::ReactionRemoveBatchPayload;
+use crate::bridge::reaction_remove_coalescer::ReactionRemoveCoalescer;
+use crate::bridge::reaction_rollup::ReactionRollup;
+use crate::bridge::reaction_rollup_payload::ReactionRollupPayload;
 use crate::bridge::ready_payload::ReadyPayload;
+use crate::bridge::reconnect_tracker::{ReconnectTracker, ReconnectingPayload, StageOutcome};
 use crate::bridge::resumed_payload::ResumedPayload;
-use anyhow::Context as _;
-use serenity::model::channel::{Message, Reaction};
-use serenity::model::event::{MessageUpdateEvent, ResumedEvent};
+use crate::bridge::scheduled_event_payload::ScheduledEventPayload;
+use crate::bridge::event_limiter::{EventLimiter, EventPermit};
+use crate::bridge::stale_event_filter::StaleEventFilter;
+use crate::bridge::task_tracker::{Shutdown, TaskTracker};
+use crate::bridge::thread_create_payload::ThreadCreatePayload;
+use crate::bridge::thread_delete_payload::ThreadDeletePayload;
+use crate::error::GatehookError;
+use serde::Serialize;
+use serenity::model::application::ComponentInteraction;
+use serenity::model::channel::{GuildChannel, Message, MessageReferenceKind, Reaction};
+use serenity::model::event::{GuildMemberUpdateEvent, MessageUpdateEvent, ResumedEvent};
+use serenity::gateway::ConnectionStage;
 use serenity::model::gateway::Ready;
-use serenity::model::id::{ChannelId, GuildId, MessageId};
+use serenity::model::guild::automod::ActionExecution;
+use serenity::model::guild::{Guild, Member, ScheduledEvent};
+use serenity::model::id::{ChannelId, GuildId, MessageId, ShardId, UserId};
+use serenity::model::Timestamp;
+use std::collections::HashMap;
+use std::future::Future;
 use std::sync::Arc;
-use tracing::{debug, error, info};
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+
+#[cfg(feature = "actions")]
+mod actions;
+
+/// Whether `payload` serializes to more than `max_bytes` of JSON
+///
+/// Serialization stops as soon as the limit is passed, so oversized payloads
+/// are never fully materialized.
+fn exceeds_size<T: Serialize + ?Sized>(payload: &T, max_bytes: usize) -> bool {
+    /// Counts written bytes, failing once past the limit
+    struct LimitWriter {
+        written: usize,
+        max_bytes: usize,
+    }
+
+    impl std::io::Write for LimitWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.written += buf.len();
+            if self.written > self.max_bytes {
+                return Err(std::io::Error::other("payload size limit exceeded"));
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut writer = LimitWriter { written: 0, max_bytes };
+    serde_json::to_writer(&mut writer, payload).is_err() && writer.written > max_bytes
+}
+
+/// Maximum number of replied-to messages fetched per message event
+pub const MAX_REPLY_CHAIN_DEPTH: u8 = 10;
+
+tokio::task_local! {
+    /// Shard that received the event being handled (set by `EventBridge::in_shard`)
+    static EVENT_SHARD: ShardId;
+    /// When gatehook received the event being handled (set by `EventBridge::in_shard`)
+    static EVENT_RECEIVED_AT: Timestamp;
+}
+
+/// Payload with the `schema_version` field and the optional `_shard`
+/// (`INCLUDE_SHARD`) and `received_at` (`INCLUDE_RECEIVED_AT`) fields added
+#[derive(Serialize)]
+struct TaggedPayload<'a, T> {
+    #[serde(flatten)]
+    payload: &'a T,
+    schema_version: u32,
+    #[serde(rename = "_shard", skip_serializing_if = "Option::is_none")]
+    shard: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    received_at: Option<Timestamp>,
+}
+
+/// Default number of retries for a rate-limited Discord call of an action
+pub const DEFAULT_ACTION_MAX_RETRIES: u32 = 2;
+
+/// Upper bound for `action_max_retries` (the backoff would reach hours beyond it)
+pub const MAX_ACTION_MAX_RETRIES: u32 = 10;
+
+/// Default maximum number of background tasks running at once
+pub const DEFAULT_MAX_PENDING_TASKS: usize = 256;
+
+/// Default maximum lifetime of a background task in seconds
+pub const DEFAULT_MAX_TASK_LIFETIME_SECS: u64 = 600;
+
+/// Handler name of heartbeat events (not counted in heartbeat event counts)
+pub const HEARTBEAT_HANDLER: &str = "heartbeat";
 
 /// Bridge Discord Gateway events to external endpoints
 pub struct EventBridge<D, S, C>
@@ -29,7 +125,36 @@ where
     discord_service: Arc<D>,
     event_sender: Arc<S>,
     channel_info: Arc<C>,
-    max_actions: usize,
+    #[cfg(feature = "actions")]
+    actions: actions::ActionState,
+    include_mentions: bool,
+    include_attachments: bool,
+    include_role_names: bool,
+    content_max_forward_chars: Option<usize>,
+    message_content_intent: bool,
+    bot_user_id: Option<UserId>,
+    reaction_include_message: bool,
+    reaction_own_messages_only: Option<UserId>,
+    reaction_remove_coalescer: ReactionRemoveCoalescer,
+    reaction_rollup: ReactionRollup,
+    message_fields: Vec<String>,
+    reply_chain_depth: u8,
+    maintenance_message: Option<String>,
+    auto_react_emoji: Option<String>,
+    truncation_marker: String,
+    include_shard: bool,
+    include_received_at: bool,
+    max_payload_size: Option<usize>,
+    webhook_deadlines: HashMap<String, Duration>,
+    redacted_fields: HashMap<String, Vec<String>>,
+    contextless_actions: bool,
+    sampler: EventSampler,
+    stale_event_filter: StaleEventFilter,
+    event_limiter: EventLimiter,
+    channel_denylist: Arc<ChannelDenylist>,
+    heartbeat: HeartbeatStats,
+    reconnects: ReconnectTracker,
+    tasks: TaskTracker,
 }
 
 impl<D, S, C> EventBridge<D, S, C>
@@ -46,18 +171,467 @@ where
     /// * `event_sender` - The event sender for forwarding events
     /// * `channel_info` - The channel info provider for retrieving channel information
     /// * `max_actions` - Maximum number of actions to execute per event (for DoS protection)
+    #[cfg_attr(not(feature = "actions"), allow(unused_variables))]
     pub fn new(discord_service: Arc<D>, event_sender: Arc<S>, channel_info: Arc<C>, max_actions: usize) -> Self {
         Self {
             discord_service,
             event_sender,
             channel_info,
-            max_actions,
+            #[cfg(feature = "actions")]
+            actions: actions::ActionState::new(max_actions),
+            include_mentions: false,
+            include_attachments: false,
+            include_role_names: false,
+            content_max_forward_chars: None,
+            message_content_intent: true,
+            bot_user_id: None,
+            reaction_include_message: false,
+            reaction_own_messages_only: None,
+            reaction_remove_coalescer: ReactionRemoveCoalescer::new(Duration::ZERO),
+            reaction_rollup: ReactionRollup::new(Duration::ZERO),
+            message_fields: Vec::new(),
+            reply_chain_depth: 0,
+            maintenance_message: None,
+            auto_react_emoji: None,
+            truncation_marker: "...".to_string(),
+            include_shard: false,
+            include_received_at: false,
+            max_payload_size: None,
+            webhook_deadlines: HashMap::new(),
+            redacted_fields: HashMap::new(),
+            contextless_actions: false,
+            sampler: EventSampler::new(),
+            stale_event_filter: StaleEventFilter::new(Duration::ZERO),
+            event_limiter: EventLimiter::new(0),
+            channel_denylist: Arc::new(ChannelDenylist::new()),
+            heartbeat: HeartbeatStats::new(),
+            reconnects: ReconnectTracker::new(0),
+            tasks: TaskTracker::new(DEFAULT_MAX_PENDING_TASKS)
+                .with_max_lifetime(Duration::from_secs(DEFAULT_MAX_TASK_LIFETIME_SECS)),
+        }
+    }
+
+    /// Refuse new background tasks while `max_pending_tasks` are running (0 = unlimited)
+    ///
+    /// Defaults to `DEFAULT_MAX_PENDING_TASKS`.
+    pub fn with_max_pending_tasks(mut self, max_pending_tasks: usize) -> Self {
+        self.tasks = TaskTracker::new(max_pending_tasks).with_max_lifetime(self.tasks.max_lifetime());
+        self
+    }
+
+    /// Cancel background tasks still running after `max_lifetime` (`Duration::ZERO` = unlimited)
+    ///
+    /// Defaults to `DEFAULT_MAX_TASK_LIFETIME_SECS`. Replays are cancel-safe:
+    /// undelivered events stay in the outbox or breaker buffer.
+    pub fn with_max_task_lifetime(mut self, max_lifetime: Duration) -> Self {
+        self.tasks = self.tasks.with_max_lifetime(max_lifetime);
+        self
+    }
+
+    /// Include flattened mention lists (users, roles, channels) in message payloads
+    pub fn with_include_mentions(mut self, include_mentions: bool) -> Self {
+        self.include_mentions = include_mentions;
+        self
+    }
+
+    /// Include attachment summaries (alt text, spoiler flag) in message payloads
+    pub fn with_include_attachments(mut self, include_attachments: bool) -> Self {
+        self.include_attachments = include_attachments;
+        self
+    }
+
+    /// Include the author's role names (`member_role_names`) in guild message payloads
+    ///
+    /// Names come from the guild cache; payloads of uncached guilds omit them.
+    pub fn with_include_role_names(mut self, include_role_names: bool) -> Self {
+        self.include_role_names = include_role_names;
+        self
+    }
+
+    /// Cut forwarded message content to `max_chars` characters (`None` forwards it in full)
+    ///
+    /// The cut content ends in the truncation marker (see `with_truncation_marker`)
+    /// and the payload gets `content_truncated: true`. Independent of action
+    /// content truncation.
+    pub fn with_content_max_forward_chars(mut self, max_chars: Option<usize>) -> Self {
+        self.content_max_forward_chars = max_chars;
+        self
+    }
+
+    /// Record whether the MESSAGE_CONTENT intent is requested (default: true)
+    ///
+    /// Without it, message payloads whose content Discord likely withheld get
+    /// `content_available: false`; `bot_user_id` exempts the bot's own and
+    /// mentioning messages, which keep their content.
+    pub fn with_message_content_intent(mut self, active: bool, bot_user_id: UserId) -> Self {
+        self.message_content_intent = active;
+        self.bot_user_id = Some(bot_user_id);
+        self
+    }
+
+    /// Forward and act on guild reaction adds only for messages authored by `bot_user_id`
+    /// (`None` disables)
+    ///
+    /// Uses the reaction's `message_author_id`, which Discord only sends with
+    /// reaction adds; reaction removes and DM reactions are unaffected.
+    pub fn with_reaction_own_messages_only(mut self, bot_user_id: Option<UserId>) -> Self {
+        self.reaction_own_messages_only = bot_user_id;
+        self
+    }
+
+    /// Fetch and include the reacted-to message in reaction payloads
+    pub fn with_reaction_include_message(mut self, reaction_include_message: bool) -> Self {
+        self.reaction_include_message = reaction_include_message;
+        self
+    }
+
+    /// Group reaction removals for the same message within `window` (`Duration::ZERO` disables)
+    ///
+    /// Groups of two or more are forwarded as one `reaction_remove_batch` event.
+    pub fn with_reaction_remove_coalesce(mut self, window: Duration) -> Self {
+        self.reaction_remove_coalescer = ReactionRemoveCoalescer::new(window);
+        self
+    }
+
+    /// Roll up same-emoji reaction adds on the same message within `window` (`Duration::ZERO` disables)
+    ///
+    /// Groups of two or more are forwarded as one `reaction_rollup` event.
+    pub fn with_reaction_rollup(mut self, window: Duration) -> Self {
+        self.reaction_rollup = ReactionRollup::new(window);
+        self
+    }
+
+    /// Keep only these top-level `message` fields in message payloads (empty keeps all)
+    pub fn with_message_fields(mut self, message_fields: Vec<String>) -> Self {
+        self.message_fields = message_fields;
+        self
+    }
+
+    /// Fetch up to `depth` replied-to ancestors into message payloads (0 disables)
+    ///
+    /// Capped at `MAX_REPLY_CHAIN_DEPTH`; each ancestor costs one API call.
+    pub fn with_reply_chain_depth(mut self, depth: u8) -> Self {
+        self.reply_chain_depth = depth.min(MAX_REPLY_CHAIN_DEPTH);
+        self
+    }
+
+    /// Suffix appended to action content truncated to 2000 chars and to message
+    /// content cut by `with_content_max_forward_chars` (`None` keeps `...`)
+    ///
+    /// The marker counts toward the limit; an empty marker cuts hard.
+    pub fn with_truncation_marker(mut self, marker: Option<String>) -> Self {
+        if let Some(marker) = marker {
+            self.truncation_marker = marker;
+        }
+        self
+    }
+
+    /// Add a `_shard` field with the receiving shard ID to every payload (default: false)
+    ///
+    /// Only events handled inside [`EventBridge::in_shard`] carry the field.
+    pub fn with_include_shard(mut self, include_shard: bool) -> Self {
+        self.include_shard = include_shard;
+        self
+    }
+
+    /// Add a `received_at` RFC 3339 timestamp to every event payload (default: false)
+    ///
+    /// Marks when gatehook received the event: the time [`EventBridge::in_shard`]
+    /// was entered, or the send time for events handled outside it. Heartbeats
+    /// are not received events and do not carry the field.
+    pub fn with_include_received_at(mut self, include_received_at: bool) -> Self {
+        self.include_received_at = include_received_at;
+        self
+    }
+
+    /// Execute contextless actions returned for delete/update events (default: false)
+    ///
+    /// When disabled, actions returned for these events are logged and ignored.
+    /// See [`EventBridge::execute_contextless_actions`].
+    pub fn with_contextless_actions(mut self, contextless_actions: bool) -> Self {
+        self.contextless_actions = contextless_actions;
+        self
+    }
+
+    /// Skip events whose serialized payload exceeds `max_bytes` (`None` disables)
+    ///
+    /// Oversized events are logged and dropped (`Ok(None)`) instead of sent.
+    pub fn with_max_payload_size(mut self, max_bytes: Option<usize>) -> Self {
+        self.max_payload_size = max_bytes;
+        self
+    }
+
+    /// Run an event handler call, attributing its events to `shard_id`
+    ///
+    /// Payloads sent while `future` runs get `_shard` when `with_include_shard`
+    /// is enabled, and the current time as `received_at` when
+    /// `with_include_received_at` is enabled.
+    pub async fn in_shard<F: Future>(&self, shard_id: ShardId, future: F) -> F::Output {
+        let received_at = Timestamp::now();
+        EVENT_SHARD
+            .scope(shard_id, EVENT_RECEIVED_AT.scope(received_at, future))
+            .await
+    }
+
+    /// Enable maintenance mode with a canned reply (`None` or empty disables)
+    ///
+    /// While enabled, no events are forwarded to the webhook: message events
+    /// are answered with a reply containing `message`, all other events are
+    /// dropped.
+    pub fn with_maintenance_message(mut self, message: Option<String>) -> Self {
+        self.maintenance_message = message.filter(|message| !message.is_empty());
+        self
+    }
+
+    /// React to every forwarded message with `emoji` (`None` or empty disables)
+    ///
+    /// Uses the `react` emoji format (Unicode or `name:id`). The reaction is
+    /// prepended to the actions returned by `handle_message` whatever the
+    /// webhook responds, and does not count toward `max_actions`.
+    pub fn with_auto_react_emoji(mut self, emoji: Option<String>) -> Self {
+        self.auto_react_emoji = emoji.filter(|emoji| !emoji.is_empty());
+        self
+    }
+
+    /// Forward only a random fraction (`0.0..=1.0`) of `handler` events
+    ///
+    /// Supported for `message`, `message_update`, `reaction_add` and
+    /// `reaction_remove`. Sampled-out events return `Ok(None)`.
+    pub fn with_sample_rate(mut self, handler: &str, rate: f64) -> Self {
+        self.sampler.set_rate(handler, rate);
+        self
+    }
+
+    /// Abort waiting for the webhook response of `handler` events after `deadline`
+    ///
+    /// A late response is dropped (logged, no actions run). The deadline only
+    /// shortens the wait: the HTTP client's own timeout (`HTTP_TIMEOUT`) still
+    /// applies to every handler.
+    pub fn with_webhook_deadline(mut self, handler: &str, deadline: Duration) -> Self {
+        self.webhook_deadlines.insert(handler.to_string(), deadline);
+        self
+    }
+
+    /// Null out `paths` (dotted, from the payload root) in `handler` payloads before sending
+    ///
+    /// See `field_redactor::redact_fields` for the path syntax. Redaction
+    /// runs before the `MAX_PAYLOAD_SIZE` check.
+    pub fn with_redacted_fields(mut self, handler: &str, paths: Vec<String>) -> Self {
+        self.redacted_fields.insert(handler.to_string(), paths);
+        self
+    }
+
+    /// Forward a `reconnecting` event once `threshold` consecutive gateway
+    /// reconnect attempts have failed (0 disables, attempts are still logged)
+    pub fn with_reconnect_alert_threshold(mut self, threshold: u32) -> Self {
+        self.reconnects = ReconnectTracker::new(threshold);
+        self
+    }
+
+    /// Seed the sampling RNG (reproducible sampling, mainly for tests)
+    pub fn with_sample_seed(mut self, seed: u64) -> Self {
+        self.sampler.set_seed(seed);
+        self
+    }
+
+    /// Drop events whose Discord timestamp is older than `max_age` (`Duration::ZERO` disables)
+    ///
+    /// Applies to `message` (creation time) and `message_update` (edit
+    /// time) events; other events carry no timestamp of their own. Dropped
+    /// events return `Ok(None)`.
+    pub fn with_max_event_age(mut self, max_age: Duration) -> Self {
+        self.stale_event_filter = StaleEventFilter::new(max_age);
+        self
+    }
+
+    /// Process at most `max_events` events at once (0 = unlimited, the default)
+    ///
+    /// Event handlers take a permit with `try_begin_event` before
+    /// processing; events arriving while all permits are held are shed.
+    pub fn with_max_concurrent_events(mut self, max_events: usize) -> Self {
+        self.event_limiter = EventLimiter::new(max_events);
+        self
+    }
+
+    /// Drop events of the channels in `denylist` (`Ok(None)`)
+    ///
+    /// The denylist is consulted on every event, so channels added or
+    /// removed at runtime (admin endpoint) take effect immediately. Applies
+    /// to message, reaction, delete, update, thread and component
+    /// interaction events; thread events match the thread or its parent.
+    pub fn with_channel_denylist(mut self, denylist: Arc<ChannelDenylist>) -> Self {
+        self.channel_denylist = denylist;
+        self
+    }
+
+    /// Channels whose events are currently dropped
+    pub fn channel_denylist(&self) -> &ChannelDenylist {
+        &self.channel_denylist
+    }
+
+    /// Send an event to the webhook endpoint
+    ///
+    /// In maintenance mode the event is dropped (`Ok(None)`) without calling the endpoint.
+    async fn send_event<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        Ok(self.forward_event(handler, payload).await?.and_then(|delivery| delivery.response))
+    }
+
+    /// Send an event to the webhook endpoint, returning `None` if it was not forwarded
+    ///
+    /// Not forwarded means no answer from the endpoint: maintenance mode,
+    /// `MAX_PAYLOAD_SIZE` rejects and handler deadline timeouts.
+    async fn forward_event<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Option<Delivery>, GatehookError> {
+        if self.maintenance_message.is_some() {
+            debug!(handler, "Maintenance mode, event not forwarded");
+            return Ok(None);
+        }
+        let shard = if self.include_shard {
+            EVENT_SHARD.try_with(|shard_id| shard_id.0).ok()
+        } else {
+            None
+        };
+        let received_at = (self.include_received_at && handler != HEARTBEAT_HANDLER)
+            .then(|| EVENT_RECEIVED_AT.try_with(|received_at| *received_at).unwrap_or_else(|_| Timestamp::now()));
+        let tagged = TaggedPayload {
+            payload,
+            schema_version: PAYLOAD_SCHEMA_VERSION,
+            shard,
+            received_at,
+        };
+        match self.redacted_fields.get(handler) {
+            Some(paths) => {
+                let mut value = serde_json::to_value(&tagged)?;
+                redact_fields(&mut value, paths);
+                self.deliver(handler, &value).await
+            }
+            None => self.deliver(handler, &tagged).await,
+        }
+    }
+
+    /// Send a payload, applying `MAX_PAYLOAD_SIZE` and the handler deadline
+    ///
+    /// Once the endpoint accepts an event, any backlog (outbox records,
+    /// circuit breaker buffer) is replayed in a background task so the live
+    /// response is returned without waiting for it.
+    async fn deliver<P: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &P,
+    ) -> Result<Option<Delivery>, GatehookError> {
+        if let Some(max_bytes) = self.max_payload_size
+            && exceeds_size(payload, max_bytes)
+        {
+            warn!(handler, max_bytes, "Payload exceeds MAX_PAYLOAD_SIZE, event not forwarded");
+            return Ok(None);
+        }
+        let send = self.event_sender.deliver(handler, payload);
+        let result = match self.webhook_deadlines.get(handler) {
+            Some(&deadline) => match tokio::time::timeout(deadline, send).await {
+                Ok(result) => result,
+                Err(_) => {
+                    warn!(
+                        handler,
+                        deadline_ms = deadline.as_millis() as u64,
+                        "Webhook response exceeded the handler deadline, no actions executed"
+                    );
+                    return Ok(None);
+                }
+            },
+            None => send.await,
+        };
+        let delivery = match result {
+            Ok(delivery) => delivery,
+            Err(err) => {
+                #[cfg(feature = "actions")]
+                self.notify_error(&format!(
+                    "Webhook delivery failed for `{handler}` event ({})",
+                    crate::bridge::error_notifier::webhook_failure_reason(&err)
+                ))
+                .await;
+                return Err(err);
+            }
+        };
+        #[cfg(feature = "actions")]
+        if !delivery.accepted()
+            && let Some(status) = delivery.status
+        {
+            self.notify_error(&format!("Webhook delivery failed for `{handler}` event (HTTP {status})"))
+                .await;
+        }
+        if handler != HEARTBEAT_HANDLER {
+            self.heartbeat.record(handler);
+        }
+        if delivery.accepted() && self.event_sender.has_backlog() {
+            let event_sender = self.event_sender.clone();
+            self.spawn_task("backlog_replay", async move { event_sender.replay_backlog().await });
+        }
+        Ok(Some(delivery))
+    }
+
+    /// Whether a `handler` event with Discord `timestamp` is too old to forward (logs when dropped)
+    fn stale(&self, handler: &str, timestamp: Timestamp) -> bool {
+        let stale = self.stale_event_filter.is_stale(timestamp);
+        if stale {
+            warn!(
+                handler,
+                %timestamp,
+                dropped_total = self.stale_event_filter.dropped(),
+                "Event older than MAX_EVENT_AGE_SECS, not forwarded"
+            );
+        }
+        stale
+    }
+
+    /// Take a processing permit for a `handler` event (logs when shed)
+    ///
+    /// Returns `None` when `max_concurrent_events` events are already being
+    /// processed: the event must then be dropped. Hold the permit until the
+    /// event (including its actions) is done.
+    pub fn try_begin_event(&self, handler: &str) -> Option<EventPermit> {
+        let permit = self.event_limiter.try_acquire();
+        if permit.is_none() {
+            warn!(
+                handler,
+                shed_total = self.event_limiter.shed(),
+                "Too many events in progress (MAX_CONCURRENT_EVENTS), event shed"
+            );
         }
+        permit
+    }
+
+    /// Whether a `handler` event of `channel_id` is on the channel denylist (logs when dropped)
+    fn channel_denied(&self, handler: &str, channel_id: ChannelId) -> bool {
+        let denied = self.channel_denylist.contains(channel_id);
+        if denied {
+            debug!(handler, %channel_id, "Channel is denylisted, event not forwarded");
+        }
+        denied
+    }
+
+    /// Whether a `handler` event passes sampling (logs when dropped)
+    fn sampled(&self, handler: &str) -> bool {
+        let forward = self.sampler.should_forward(handler);
+        if !forward {
+            debug!(handler, "Event dropped by sampling");
+        }
+        forward
     }
 
     /// Handle a message event
     ///
-    /// Sends event to webhook and returns the response.
+    /// Sends event to webhook and returns the response. In maintenance mode
+    /// the webhook is skipped and a reply with the maintenance message is
+    /// returned instead. With `with_auto_react_emoji`, a `react` action is
+    /// added in front of the webhook's actions once the endpoint answered
+    /// (not when the payload was too large or the deadline passed).
     ///
     /// # Arguments
     ///
@@ -69,7 +643,7 @@ where
     pub async fn handle_message(
         &self,
         message: &Message,
-    ) -> anyhow::Result<Option<EventResponse>> {
+    ) -> Result<Option<EventResponse>, GatehookError> {
         debug!(
             message_id = %message.id,
             author = %message.author.name,
@@ -77,14 +651,56 @@ where
             "Processing message event"
         );
 
+        if self.stale("message", message.timestamp) || self.channel_denied("message", message.channel_id) {
+            return Ok(None);
+        }
+
+        if let Some(content) = &self.maintenance_message {
+            return Ok(Some(EventResponse::from(vec![ResponseAction::Reply(ReplyParams {
+                content: content.clone(),
+                mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
+            })])));
+        }
+
+        if !self.sampled("message") {
+            return Ok(None);
+        }
+
         // Build payload with channel information (cache-first with API fallback)
         let payload = self.build_message_payload(message).await;
 
         // Forward event to webhook endpoint and return response
-        self.event_sender
-            .send("message", &payload)
-            .await
-            .context("Failed to send message event to HTTP endpoint")
+        let delivery = if self.message_fields.is_empty() {
+            self.forward_event("message", &payload).await?
+        } else {
+            let payload = payload.to_filtered_value(&self.message_fields)?;
+            self.forward_event("message", &payload).await?
+        };
+
+        Ok(delivery.and_then(|delivery| self.add_auto_reaction(delivery.response)))
+    }
+
+    /// Prepend the `auto_react_emoji` reaction to a message response
+    ///
+    /// The reaction is marked exempt so it does not count toward `max_actions`.
+    fn add_auto_reaction(&self, response: Option<EventResponse>) -> Option<EventResponse> {
+        let Some(emoji) = &self.auto_react_emoji else {
+            return response;
+        };
+
+        let mut auto_reacted = EventResponse::from(vec![ResponseAction::React(ReactParams {
+            emoji: emoji.clone(),
+            message_id: None,
+            channel_id: None,
+        })]);
+        auto_reacted.exempt_actions = 1;
+        if let Some(response) = response {
+            auto_reacted.append(response);
+        }
+        Some(auto_reacted)
     }
 
     /// Build MessagePayload with channel information
@@ -118,7 +734,7 @@ where
             }
         };
 
-        match channel {
+        let payload = match channel {
             Some(ch) => {
                 debug!(
                     channel_id = %message.channel_id,
@@ -129,7 +745,78 @@ where
                 MessagePayload::with_channel(message, ch)
             }
             None => MessagePayload::new(message),
+        };
+        let payload = payload.with_content_visibility(self.message_content_intent, self.bot_user_id);
+
+        let payload = match message.guild_id.and_then(|guild_id| self.channel_info.get_guild(guild_id)) {
+            Some(guild) if self.include_role_names => payload.with_guild(&guild).with_role_names(&guild),
+            Some(guild) => payload.with_guild(&guild),
+            None => payload,
+        };
+
+        let payload = if self.include_mentions {
+            payload.with_mentions()
+        } else {
+            payload
+        };
+
+        let payload = if self.include_attachments {
+            payload.with_attachments()
+        } else {
+            payload
+        };
+
+        let payload = match self.content_max_forward_chars {
+            Some(max_chars) => payload.with_content_limit(max_chars, &self.truncation_marker),
+            None => payload,
+        };
+
+        if self.reply_chain_depth > 0 {
+            payload.with_reply_chain(self.fetch_reply_chain(message).await)
+        } else {
+            payload
+        }
+    }
+
+    /// Fetch the messages `message` replies to, newest first
+    ///
+    /// Follows reply references up to `reply_chain_depth` messages. A fetch
+    /// failure ends the chain at the messages fetched so far.
+    async fn fetch_reply_chain(&self, message: &Message) -> Vec<Message> {
+        let mut chain = Vec::new();
+        let mut reference = Self::reply_reference(message);
+
+        for _ in 0..self.reply_chain_depth {
+            let Some((channel_id, message_id)) = reference else {
+                break;
+            };
+            match self.discord_service.get_message(channel_id, message_id).await {
+                Ok(parent) => {
+                    reference = Self::reply_reference(&parent);
+                    chain.push(parent);
+                }
+                Err(err) => {
+                    debug!(
+                        %message_id,
+                        ?err,
+                        depth = chain.len(),
+                        "Failed to fetch replied-to message, truncating reply chain"
+                    );
+                    break;
+                }
+            }
+        }
+
+        chain
+    }
+
+    /// Channel and message a message replies to (forwards are not replies)
+    fn reply_reference(message: &Message) -> Option<(ChannelId, MessageId)> {
+        let reference = message.message_reference.as_ref()?;
+        if reference.kind != MessageReferenceKind::Default {
+            return None;
         }
+        Some((reference.channel_id, reference.message_id?))
     }
 
     /// Handle a ready event
@@ -141,7 +828,10 @@ where
     /// # Returns
     ///
     /// Response from webhook (may contain actions)
-    pub async fn handle_ready(&self, ready: &Ready) -> anyhow::Result<Option<EventResponse>> {
+    pub async fn handle_ready(
+        &self,
+        ready: &Ready,
+    ) -> Result<Option<EventResponse>, GatehookError> {
         debug!(
             user = %ready.user.display_name(),
             "Processing ready event"
@@ -151,10 +841,118 @@ where
         let payload = ReadyPayload::new(ready);
 
         // Forward event to webhook endpoint and return response
-        self.event_sender
-            .send("ready", &payload)
-            .await
-            .context("Failed to send ready event to HTTP endpoint")
+        self.send_event("ready", &payload).await
+    }
+
+    /// Record the gateway connection state of a shard, reported in heartbeats
+    pub fn set_connected(&self, shard_id: ShardId, connected: bool) {
+        self.heartbeat.set_connected(shard_id.0, connected);
+    }
+
+    /// Track a stage change of gateway shard `shard_id`
+    ///
+    /// Updates the shard's connection state reported in heartbeats and logs
+    /// every reconnect attempt with its number and downtime. Once consecutive
+    /// failures of the shard reach the alert threshold, a `reconnecting`
+    /// event is sent (once per outage).
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook for a `reconnecting` event (actions are not supported)
+    pub async fn handle_stage_update(
+        &self,
+        shard_id: ShardId,
+        stage: ConnectionStage,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        self.set_connected(shard_id, stage == ConnectionStage::Connected);
+
+        match self.reconnects.on_stage(shard_id.0, stage) {
+            StageOutcome::Attempt { attempt, downtime, alert } => {
+                warn!(shard_id = shard_id.0, attempt, downtime_secs = downtime.as_secs(), "Gateway reconnect attempt");
+                if alert {
+                    return self
+                        .send_event("reconnecting", &ReconnectingPayload::new(attempt, downtime))
+                        .await;
+                }
+            }
+            StageOutcome::Reconnected { attempts, downtime } => {
+                info!(shard_id = shard_id.0, attempts, downtime_secs = downtime.as_secs(), "Gateway reconnected");
+            }
+            StageOutcome::None => {}
+        }
+        Ok(None)
+    }
+
+    /// Send a heartbeat event with uptime, connection state and event counts
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for heartbeats)
+    pub async fn handle_heartbeat(&self) -> Result<Option<EventResponse>, GatehookError> {
+        let payload = self.heartbeat.snapshot();
+        debug!(uptime_secs = payload.heartbeat.uptime_secs, "Sending heartbeat");
+
+        self.send_event(HEARTBEAT_HANDLER, &payload).await
+    }
+
+    /// Send a heartbeat every `interval` until `shutdown` (run with `spawn_service()`)
+    ///
+    /// The first heartbeat is sent one `interval` after the call. Failures
+    /// are logged and do not stop the loop. A heartbeat in flight when
+    /// `shutdown` fires is completed.
+    pub async fn run_heartbeat(&self, interval: Duration, mut shutdown: Shutdown) {
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {}
+                () = shutdown.wait() => return,
+            }
+            match self.handle_heartbeat().await {
+                Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                    warn!(
+                        action_count = event_response.actions.len(),
+                        "Heartbeat received actions from webhook, but action execution is not supported for heartbeats"
+                    );
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    error!(?err, "Failed to send heartbeat");
+                }
+            }
+        }
+    }
+
+    /// Spawn a one-shot background task, registered for `drain_tasks()`
+    ///
+    /// Returns `false` (without spawning) when `max_pending_tasks` tasks are
+    /// already running.
+    pub fn spawn_task<F>(&self, name: &'static str, task: F) -> bool
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.tasks.spawn(name, task)
+    }
+
+    /// Spawn a long-running service (heartbeat, admin endpoint), registered for `drain_tasks()`
+    ///
+    /// The service gets a `Shutdown` signal that fires when `drain_tasks()`
+    /// starts. Returns `false` (without spawning) when `max_pending_tasks`
+    /// tasks are already running.
+    pub fn spawn_service<F, Fut>(&self, name: &'static str, service: F) -> bool
+    where
+        F: FnOnce(Shutdown) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.tasks.spawn_service(name, service)
+    }
+
+    /// Stop services and wait up to `timeout` for background tasks to finish (on shutdown)
+    ///
+    /// Returns `true` when no task is left running.
+    pub async fn drain_tasks(&self, timeout: Duration) -> bool {
+        self.tasks.drain(timeout).await
     }
 
     /// Handle a resumed event
@@ -171,21 +969,23 @@ where
     pub async fn handle_resumed(
         &self,
         resumed: &ResumedEvent,
-    ) -> anyhow::Result<Option<EventResponse>> {
+    ) -> Result<Option<EventResponse>, GatehookError> {
         debug!("Processing resumed event");
 
         // Build payload with resumed event
         let payload = ResumedPayload::new(resumed);
 
         // Forward event to webhook endpoint and return response
-        self.event_sender
-            .send("resumed", &payload)
-            .await
-            .context("Failed to send resumed event to HTTP endpoint")
+        self.send_event("resumed", &payload).await
     }
 
     /// Handle a reaction add event
     ///
+    /// With reaction rollup enabled, forwarded adds of the same emoji on the
+    /// same message are held for the window: a lone add is forwarded as
+    /// `reaction_add`, two or more as a single `reaction_rollup`. Calls whose
+    /// add joined an open window forward nothing.
+    ///
     /// # Arguments
     ///
     /// * `reaction` - The reaction event from Discord
@@ -196,7 +996,7 @@ where
     pub async fn handle_reaction_add(
         &self,
         reaction: &Reaction,
-    ) -> anyhow::Result<Option<EventResponse>> {
+    ) -> Result<Option<EventResponse>, GatehookError> {
         debug!(
             user_id = ?reaction.user_id,
             message_id = %reaction.message_id,
@@ -204,20 +1004,70 @@ where
             "Processing reaction add event"
         );
 
+        if self.channel_denied("reaction_add", reaction.channel_id) {
+            return Ok(None);
+        }
+
+        if let Some(bot_user_id) = self.reaction_own_messages_only
+            && reaction.guild_id.is_some()
+            && reaction.message_author_id != Some(bot_user_id)
+        {
+            debug!(
+                message_id = %reaction.message_id,
+                message_author_id = ?reaction.message_author_id,
+                "Reaction not on the bot's own message, not forwarded"
+            );
+            return Ok(None);
+        }
+
+        // Local fast path: mapped emojis answer without a webhook round-trip
+        #[cfg(feature = "actions")]
+        if let Some(response) = self.reaction_command(reaction).await? {
+            return Ok(Some(self.skip_repeated_reaction_actions(reaction, response)));
+        }
+
+        if !self.sampled("reaction_add") {
+            return Ok(None);
+        }
+        let response = self.forward_reaction_add(reaction).await?;
+        #[cfg(feature = "actions")]
+        let response = response.map(|response| self.skip_repeated_reaction_actions(reaction, response));
+        Ok(response)
+    }
+
+    /// Forward a reaction_add event to the webhook endpoint and return the response
+    ///
+    /// Rolled up into a `reaction_rollup` event when reaction rollup is enabled.
+    async fn forward_reaction_add(&self, reaction: &Reaction) -> Result<Option<EventResponse>, GatehookError> {
+        if self.reaction_rollup.is_enabled() {
+            let Some(group) = self.reaction_rollup.accumulate(reaction).await else {
+                debug!(
+                    message_id = %reaction.message_id,
+                    "Reaction add joined pending rollup"
+                );
+                return Ok(None);
+            };
+
+            if let Some(payload) = ReactionRollupPayload::new(&group)
+                && group.len() > 1
+            {
+                return self.send_event("reaction_rollup", &payload).await;
+            }
+        }
+
         // Build payload with optional channel metadata
         let payload = self.build_reaction_payload(reaction).await;
 
-        // Forward event to webhook endpoint and return response
-        self.event_sender
-            .send("reaction_add", &payload)
-            .await
-            .context("Failed to send reaction add event to HTTP endpoint")
+        self.send_event("reaction_add", &payload).await
     }
 
     /// Build reaction payload with optional channel info from cache
+    ///
+    /// When `reaction_include_message` is enabled, also fetches the reacted-to message
+    /// (one API call per reaction). Fetch failures omit the message field.
     async fn build_reaction_payload<'a>(&self, reaction: &'a Reaction) -> ReactionPayload<'a> {
         // Try to get channel info from cache if this is a guild reaction
-        match reaction.guild_id {
+        let payload = match reaction.guild_id {
             Some(guild_id) => {
                 match self
                     .channel_info
@@ -229,11 +1079,41 @@ where
                 }
             }
             None => ReactionPayload::new(reaction),
+        };
+
+        let payload = match reaction.guild_id.and_then(|guild_id| self.channel_info.get_guild(guild_id)) {
+            Some(guild) => payload.with_guild(&guild),
+            None => payload,
+        };
+
+        if !self.reaction_include_message {
+            return payload;
+        }
+
+        match self
+            .discord_service
+            .get_message(reaction.channel_id, reaction.message_id)
+            .await
+        {
+            Ok(message) => payload.with_message(message),
+            Err(err) => {
+                debug!(
+                    message_id = %reaction.message_id,
+                    ?err,
+                    "Failed to fetch reacted message, omitting from payload"
+                );
+                payload
+            }
         }
     }
 
     /// Handle a reaction remove event
     ///
+    /// With reaction remove coalescing enabled, removals for the same message
+    /// are held for the window: a lone removal is forwarded as `reaction_remove`,
+    /// two or more as a single `reaction_remove_batch`. Calls whose removal joined
+    /// an open window return `Ok(None)` without forwarding.
+    ///
     /// # Arguments
     ///
     /// * `reaction` - The reaction event from Discord
@@ -244,7 +1124,7 @@ where
     pub async fn handle_reaction_remove(
         &self,
         reaction: &Reaction,
-    ) -> anyhow::Result<Option<EventResponse>> {
+    ) -> Result<Option<EventResponse>, GatehookError> {
         debug!(
             user_id = ?reaction.user_id,
             message_id = %reaction.message_id,
@@ -252,269 +1132,104 @@ where
             "Processing reaction remove event"
         );
 
+        if self.channel_denied("reaction_remove", reaction.channel_id) || !self.sampled("reaction_remove") {
+            return Ok(None);
+        }
+
+        if self.reaction_remove_coalescer.is_enabled() {
+            let Some(group) = self.reaction_remove_coalescer.coalesce(reaction).await else {
+                debug!(
+                    message_id = %reaction.message_id,
+                    "Reaction removal joined pending batch"
+                );
+                return Ok(None);
+            };
+
+            if let Some(payload) = ReactionRemoveBatchPayload::new(&group)
+                && group.len() > 1
+            {
+                return self.send_event("reaction_remove_batch", &payload).await;
+            }
+        }
+
         // Build payload with optional channel metadata
         let payload = self.build_reaction_payload(reaction).await;
 
         // Forward event to webhook endpoint and return response
-        self.event_sender
-            .send("reaction_remove", &payload)
-            .await
-            .context("Failed to send reaction remove event to HTTP endpoint")
+        self.send_event("reaction_remove", &payload).await
     }
 
-    /// Execute actions from webhook response
-    ///
-    /// # Arguments
-    ///
-    /// * `target` - The action target (message, reaction, etc.)
-    /// * `event_response` - The response from webhook containing actions
+    /// Execute actions returned for an event without a live target message
     ///
-    /// # Security
-    ///
-    /// Limits the number of actions to `max_actions` to prevent DoS attacks.
-    /// Logs action type only (not content) to prevent sensitive information exposure.
-    pub async fn execute_actions(
+    /// Delete/update events only carry IDs (the message may be gone and no
+    /// author is known), so only actions that do not need the target message
+    /// run (see `ResponseAction::supports_contextless`: `send_message`,
+    /// `webhook_message`, `edit`); others are skipped with a warning. Everything is ignored
+    /// unless enabled via `with_contextless_actions`.
+    pub async fn execute_contextless_actions(
         &self,
-        target: impl Into<ActionTarget>,
+        handler: &str,
+        target: ActionTarget,
         event_response: &EventResponse,
-    ) -> anyhow::Result<()> {
-        let target = target.into();
-
-        let total_actions = event_response.actions.len();
-
-        // Limit actions for DoS protection
-        let actions_to_execute = if total_actions > self.max_actions {
-            tracing::warn!(
-                total_actions,
-                max_actions = self.max_actions,
-                "Too many actions in webhook response, truncating to max_actions"
+    ) -> Result<(), GatehookError> {
+        if !self.contextless_actions {
+            warn!(
+                %handler,
+                action_count = event_response.actions.len(),
+                "Event received actions from webhook, but CONTEXTLESS_ACTIONS is disabled, ignoring actions"
             );
-            &event_response.actions[..self.max_actions]
-        } else {
-            &event_response.actions[..]
-        };
+            return Ok(());
+        }
 
-        for action in actions_to_execute {
-            // Execute action (log error and continue with next)
-            // Note: Only log action type, not content, to prevent sensitive information exposure
-            if let Err(err) = self.execute_action(&target, action).await {
-                error!(
-                    ?err,
-                    action_type = ?std::mem::discriminant(action),
-                    "Failed to execute action, continuing with next"
-                );
+        let mut contextless = EventResponse::default();
+        let mut skipped = Vec::new();
+        for (index, action) in event_response.actions.iter().enumerate() {
+            if action.supports_contextless() {
+                contextless.actions.push(action.clone());
+                contextless
+                    .idempotency_keys
+                    .push(event_response.idempotency_key(index).map(str::to_string));
+            } else {
+                skipped.push(action);
             }
         }
-        Ok(())
-    }
-
-    /// Execute a single action
-    async fn execute_action(
-        &self,
-        target: &ActionTarget,
-        action: &ResponseAction,
-    ) -> anyhow::Result<()> {
-        match action {
-            ResponseAction::Reply(params) => self.execute_reply(target, params).await,
-            ResponseAction::React(params) => self.execute_react(target, params).await,
-            ResponseAction::Thread(params) => self.execute_thread(target, params).await,
+        for action in skipped {
+            warn!(
+                %handler,
+                action_type = action.name(),
+                "Action requires message context, skipping action for contextless event"
+            );
+        }
+        if contextless.actions.is_empty() {
+            return Ok(());
         }
-    }
-
-    /// Execute Reply action
-    ///
-    /// # Content Handling
-    /// - Content exceeding 2000 characters is truncated with warning log
-    ///
-    /// # Mention
-    /// - `params.mention = true`: Reply with ping (user receives notification)
-    /// - `params.mention = false`: Reply without ping (default)
-    async fn execute_reply(
-        &self,
-        target: &ActionTarget,
-        params: &ReplyParams,
-    ) -> anyhow::Result<()> {
-        let content = truncate_content(&params.content);
-
-        self.discord_service
-            .reply_in_channel(target.channel_id, target.message_id, &content, params.mention)
-            .await
-            .context("Failed to send reply to Discord")?;
-
-        info!(
-            message_id = %target.message_id,
-            mention = params.mention,
-            content_len = content.chars().count(),
-            "Successfully executed reply action"
-        );
-
-        Ok(())
-    }
-
-    /// Execute React action
-    ///
-    /// # Emoji Format
-    /// - Unicode emoji: "👍", "🎉", etc.
-    /// - Custom emoji: "name:id" format (e.g., "customemoji:123456789")
-    async fn execute_react(
-        &self,
-        target: &ActionTarget,
-        params: &ReactParams,
-    ) -> anyhow::Result<()> {
-        self.discord_service
-            .react_to_message(target.channel_id, target.message_id, &params.emoji)
-            .await
-            .context("Failed to add reaction to Discord")?;
-
-        info!(
-            message_id = %target.message_id,
-            emoji = %params.emoji,
-            "Successfully executed react action"
-        );
 
-        Ok(())
+        self.execute_actions(target, &contextless).await
     }
 
-    /// Execute Thread action
-    ///
-    /// # Thread Name
-    /// - `params.name = Some(...)`: Use specified name
-    /// - `params.name = None`: Defaults to "Thread"
-    /// - Name is ignored if already in a thread
-    ///
-    /// # Content Handling
-    /// - Content exceeding 2000 characters is truncated with warning log
-    ///
-    /// # Auto-archive Duration
-    /// - Valid values: 60, 1440, 4320, 10080 (minutes)
-    /// - Invalid values fall back to 1440 (OneDay) with warning log
+    /// Execute actions from webhook response (forward-only build)
     ///
-    /// # Error Handling
-    /// - If thread creation fails with error code 160004 (thread already exists),
-    ///   retrieves the existing thread and posts to it
-    async fn execute_thread(
+    /// Action execution is compiled out without the `actions` feature.
+    /// Actions returned by the webhook are logged and ignored.
+    #[cfg(not(feature = "actions"))]
+    pub async fn execute_actions(
         &self,
-        target: &ActionTarget,
-        params: &ThreadParams,
-    ) -> anyhow::Result<()> {
-        // Check if already in thread (cache-first with API fallback)
-        // Note: This will fail for DM channels (threads not supported)
-        let is_in_thread = self.channel_info
-            .is_thread(target.guild_id, target.channel_id)
-            .await
-            .context("Failed to check if channel is thread (threads not supported in DM)")?;
-
-        // Determine target channel ID
-        let target_channel_id = if is_in_thread {
-            // Already in thread → use as-is
-            info!("Message is already in thread, skipping thread creation");
-            target.channel_id
-        } else {
-            // Normal channel → create new thread (with fallback to existing thread)
-            self.create_or_get_thread(target, params).await?
-        };
-
-        // Truncate content
-        let content = truncate_content(&params.content);
-
-        // Post message to thread
-        self.discord_service
-            .send_message_to_channel(target_channel_id, &content)
-            .await
-            .context("Failed to send message to thread")?;
-
-        info!(
-            channel_id = %target_channel_id,
-            is_in_thread = is_in_thread,
-            "Successfully executed thread action"
+        _target: impl Into<ActionTarget>,
+        event_response: &EventResponse,
+    ) -> Result<(), GatehookError> {
+        tracing::warn!(
+            action_count = event_response.actions.len(),
+            "Action execution is disabled (built without `actions` feature), ignoring webhook actions"
         );
-
         Ok(())
     }
 
-    /// Create a new thread or retrieve existing thread if error 160004 occurs
-    async fn create_or_get_thread(
-        &self,
-        target: &ActionTarget,
-        params: &ThreadParams,
-    ) -> anyhow::Result<serenity::model::id::ChannelId> {
-        let thread_name = match &params.name {
-            Some(name) => truncate_thread_name(name),
-            None => "Thread".to_string(),
-        };
-
-        // Try to create thread
-        match self
-            .discord_service
-            .create_thread_from_message(
-                target.channel_id,
-                target.message_id,
-                &thread_name,
-                params.auto_archive_duration,
-            )
-            .await
-        {
-            Ok(thread) => {
-                info!(
-                    thread_id = %thread.id,
-                    thread_name = %thread_name,
-                    "Created new thread"
-                );
-                Ok(thread.id)
-            }
-            Err(serenity::Error::Http(ref http_error))
-                if Self::is_thread_already_exists_error(http_error) =>
-            {
-                // Thread already exists (error 160004) - retrieve it
-                info!("Thread already exists for this message, retrieving existing thread");
-                self.get_existing_thread_id(target).await
-            }
-            Err(e) => Err(e).context("Failed to create thread"),
-        }
-    }
-
-    /// Retrieve existing thread ID from message
-    async fn get_existing_thread_id(
-        &self,
-        target: &ActionTarget,
-    ) -> anyhow::Result<serenity::model::id::ChannelId> {
-        let message = self
-            .discord_service
-            .get_message(target.channel_id, target.message_id)
-            .await
-            .context("Failed to get message to find existing thread")?;
-
-        let thread_id = message
-            .thread
-            .as_ref()
-            .map(|t| t.id)
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Thread creation failed with 160004 but message has no thread field"
-                )
-            })?;
-
-        info!(
-            thread_id = %thread_id,
-            "Found existing thread from message"
-        );
-        Ok(thread_id)
-    }
-
-    /// Check if HTTP error is "thread already exists" (error code 160004)
-    fn is_thread_already_exists_error(http_error: &serenity::http::HttpError) -> bool {
-        use serenity::http::HttpError;
-
-        matches!(
-            http_error,
-            HttpError::UnsuccessfulRequest(error_response) if error_response.error.code == 160004
-        )
-    }
-
     /// Handle a message_delete event
     ///
     /// Sends event to webhook and returns the response.
-    /// Note: Actions are not supported for delete events.
+    /// Note: Only contextless actions (`send_message`, `webhook_message`,
+    /// `edit`) run for delete events, via `execute_contextless_actions` with
+    /// `CONTEXTLESS_ACTIONS` enabled.
     ///
     /// # Arguments
     ///
@@ -524,13 +1239,13 @@ where
     ///
     /// # Returns
     ///
-    /// Response from webhook (actions are not supported for delete events)
+    /// Response from webhook (may contain contextless actions)
     pub async fn handle_message_delete(
         &self,
         channel_id: ChannelId,
         message_id: MessageId,
         guild_id: Option<GuildId>,
-    ) -> anyhow::Result<Option<EventResponse>> {
+    ) -> Result<Option<EventResponse>, GatehookError> {
         debug!(
             message_id = %message_id,
             channel_id = %channel_id,
@@ -538,18 +1253,21 @@ where
             "Processing message_delete event"
         );
 
+        if self.channel_denied("message_delete", channel_id) {
+            return Ok(None);
+        }
+
         let payload = MessageDeletePayload::new(channel_id, message_id, guild_id);
 
-        self.event_sender
-            .send("message_delete", &payload)
-            .await
-            .context("Failed to send message_delete event to HTTP endpoint")
+        self.send_event("message_delete", &payload).await
     }
 
     /// Handle a message_delete_bulk event
     ///
     /// Sends event to webhook and returns the response.
-    /// Note: Actions are not supported for delete events.
+    /// Note: Only contextless actions (`send_message`, `webhook_message`,
+    /// `edit`) run for delete events, via `execute_contextless_actions` with
+    /// `CONTEXTLESS_ACTIONS` enabled.
     ///
     /// # Arguments
     ///
@@ -559,13 +1277,13 @@ where
     ///
     /// # Returns
     ///
-    /// Response from webhook (actions are not supported for delete events)
+    /// Response from webhook (may contain contextless actions)
     pub async fn handle_message_delete_bulk(
         &self,
         channel_id: ChannelId,
         message_ids: Vec<MessageId>,
         guild_id: Option<GuildId>,
-    ) -> anyhow::Result<Option<EventResponse>> {
+    ) -> Result<Option<EventResponse>, GatehookError> {
         debug!(
             message_count = message_ids.len(),
             channel_id = %channel_id,
@@ -573,19 +1291,22 @@ where
             "Processing message_delete_bulk event"
         );
 
+        if self.channel_denied("message_delete_bulk", channel_id) {
+            return Ok(None);
+        }
+
         let payload = MessageDeleteBulkPayload::new(channel_id, message_ids, guild_id);
 
-        self.event_sender
-            .send("message_delete_bulk", &payload)
-            .await
-            .context("Failed to send message_delete_bulk event to HTTP endpoint")
+        self.send_event("message_delete_bulk", &payload).await
     }
 
     /// Handle a message_update event
     ///
     /// Sends event to webhook and returns the response.
     /// Note: Discord only provides changed fields in MessageUpdateEvent.
-    /// Note: Actions are not supported for update events.
+    /// Note: Only contextless actions (`send_message`, `webhook_message`,
+    /// `edit`) run for update events, via `execute_contextless_actions` with
+    /// `CONTEXTLESS_ACTIONS` enabled.
     ///
     /// # Arguments
     ///
@@ -593,11 +1314,11 @@ where
     ///
     /// # Returns
     ///
-    /// Response from webhook (actions are not supported for update events)
+    /// Response from webhook (may contain contextless actions)
     pub async fn handle_message_update(
         &self,
         event: MessageUpdateEvent,
-    ) -> anyhow::Result<Option<EventResponse>> {
+    ) -> Result<Option<EventResponse>, GatehookError> {
         debug!(
             message_id = %event.id,
             channel_id = %event.channel_id,
@@ -605,11 +1326,304 @@ where
             "Processing message_update event"
         );
 
+        if self.channel_denied("message_update", event.channel_id) {
+            return Ok(None);
+        }
+
+        if let Some(edited_at) = event.edited_timestamp
+            && self.stale("message_update", edited_at)
+        {
+            return Ok(None);
+        }
+
+        if !self.sampled("message_update") {
+            return Ok(None);
+        }
+
         let payload = MessageUpdatePayload::new(event);
 
-        self.event_sender
-            .send("message_update", &payload)
+        self.send_event("message_update", &payload).await
+    }
+
+    /// Handle a thread_create event
+    ///
+    /// Sends event to webhook and returns the response.
+    /// Note: Actions are not supported for thread events.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread` - The created thread
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for thread events)
+    pub async fn handle_thread_create(
+        &self,
+        thread: &GuildChannel,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        debug!(
+            thread_id = %thread.id,
+            guild_id = %thread.guild_id,
+            "Processing thread_create event"
+        );
+
+        let parent_denied = thread
+            .parent_id
+            .is_some_and(|parent_id| self.channel_denied("thread_create", parent_id));
+        if parent_denied || self.channel_denied("thread_create", thread.id) {
+            return Ok(None);
+        }
+
+        let payload = ThreadCreatePayload::new(thread);
+
+        self.send_event("thread_create", &payload).await
+    }
+
+    /// Handle a thread_delete event
+    ///
+    /// Sends event to webhook and returns the response.
+    /// Note: Actions are not supported for thread events.
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_id` - The ID of the deleted thread
+    /// * `guild_id` - The guild the thread belonged to
+    /// * `parent_id` - The thread's parent channel
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for thread events)
+    pub async fn handle_thread_delete(
+        &self,
+        thread_id: ChannelId,
+        guild_id: GuildId,
+        parent_id: ChannelId,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        debug!(
+            thread_id = %thread_id,
+            guild_id = %guild_id,
+            "Processing thread_delete event"
+        );
+
+        if self.channel_denied("thread_delete", parent_id) || self.channel_denied("thread_delete", thread_id) {
+            return Ok(None);
+        }
+
+        let payload = ThreadDeletePayload::new(thread_id, guild_id, parent_id);
+
+        self.send_event("thread_delete", &payload).await
+    }
+
+    /// Handle a guild_scheduled_event_create event
+    ///
+    /// Sends event to webhook and returns the response.
+    /// Note: Actions are not supported for scheduled events.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The created scheduled event
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for scheduled events)
+    pub async fn handle_guild_scheduled_event_create(
+        &self,
+        event: &ScheduledEvent,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        self.forward_scheduled_event("guild_scheduled_event_create", event)
+            .await
+    }
+
+    /// Handle a guild_scheduled_event_update event
+    ///
+    /// Sends event to webhook and returns the response.
+    /// Note: Actions are not supported for scheduled events.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The updated scheduled event
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for scheduled events)
+    pub async fn handle_guild_scheduled_event_update(
+        &self,
+        event: &ScheduledEvent,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        self.forward_scheduled_event("guild_scheduled_event_update", event)
             .await
-            .context("Failed to send message_update event to HTTP endpoint")
+    }
+
+    /// Handle a guild_scheduled_event_delete event
+    ///
+    /// Sends event to webhook and returns the response.
+    /// Note: Actions are not supported for scheduled events.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The deleted scheduled event
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for scheduled events)
+    pub async fn handle_guild_scheduled_event_delete(
+        &self,
+        event: &ScheduledEvent,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        self.forward_scheduled_event("guild_scheduled_event_delete", event)
+            .await
+    }
+
+    /// Handle a message component interaction (interaction_create)
+    ///
+    /// Sends the interaction to the webhook and returns the response; the
+    /// caller executes its actions against the component's message, with
+    /// `interaction_response` available to acknowledge or update it.
+    ///
+    /// # Arguments
+    ///
+    /// * `interaction` - The component interaction from Discord
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (may contain actions)
+    pub async fn handle_component_interaction(
+        &self,
+        interaction: &ComponentInteraction,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        debug!(
+            interaction_id = %interaction.id,
+            message_id = %interaction.message.id,
+            user_id = %interaction.user.id,
+            "Processing component interaction"
+        );
+
+        if self.channel_denied("interaction_create", interaction.channel_id) {
+            return Ok(None);
+        }
+
+        let payload = ComponentInteractionPayload::new(interaction);
+
+        self.send_event("interaction_create", &payload).await
+    }
+
+    /// Handle a guild_create event
+    ///
+    /// Sends a summary of the guild to the webhook and returns the response.
+    /// Note: Actions are not supported for guild events.
+    ///
+    /// # Arguments
+    ///
+    /// * `guild` - The guild received with the event
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for guild events)
+    pub async fn handle_guild_create(
+        &self,
+        guild: &Guild,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        debug!(guild_id = %guild.id, "Processing guild_create event");
+
+        let payload = GuildCreatePayload::new(guild);
+
+        self.send_event("guild_create", &payload).await
+    }
+
+    /// Handle a guild_member_update event
+    ///
+    /// Sends event to webhook and returns the response.
+    /// Note: Actions are not supported for member events.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_if_available` - The member before the update (cache hit only)
+    /// * `event` - The member update event
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for member events)
+    pub async fn handle_guild_member_update(
+        &self,
+        old_if_available: Option<&Member>,
+        event: &GuildMemberUpdateEvent,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        debug!(
+            user_id = %event.user.id,
+            guild_id = %event.guild_id,
+            has_before = old_if_available.is_some(),
+            "Processing guild_member_update event"
+        );
+
+        let payload = GuildMemberUpdatePayload::new(event, old_if_available);
+
+        self.send_event("guild_member_update", &payload).await
+    }
+
+    /// Handle an auto_moderation_action_execution event
+    ///
+    /// Sends event to webhook and returns the response.
+    /// Note: Actions are not supported for AutoMod events.
+    ///
+    /// # Arguments
+    ///
+    /// * `execution` - The executed AutoMod action
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for AutoMod events)
+    pub async fn handle_auto_moderation_action_execution(
+        &self,
+        execution: &ActionExecution,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        debug!(
+            rule_id = %execution.rule_id,
+            guild_id = %execution.guild_id,
+            user_id = %execution.user_id,
+            "Processing auto_moderation_action_execution event"
+        );
+
+        let payload = AutoModerationActionPayload::new(execution);
+
+        self.send_event("auto_moderation_action_execution", &payload).await
+    }
+
+    /// Handle a raw gateway event (RAW_EVENTS passthrough)
+    ///
+    /// Sends event to webhook and returns the response.
+    /// Note: Actions are not supported for raw events.
+    ///
+    /// # Arguments
+    ///
+    /// * `payload` - The raw gateway event
+    ///
+    /// # Returns
+    ///
+    /// Response from webhook (actions are not supported for raw events)
+    pub async fn handle_raw_event(
+        &self,
+        payload: &RawEventPayload,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        debug!(event_type = %payload.kind, "Processing raw event");
+
+        self.send_event("raw", payload).await
+    }
+
+    /// Forward a scheduled event to the webhook under the given handler name
+    async fn forward_scheduled_event(
+        &self,
+        handler: &str,
+        event: &ScheduledEvent,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        debug!(
+            handler = handler,
+            event_id = %event.id,
+            guild_id = %event.guild_id,
+            "Processing scheduled event"
+        );
+
+        let payload = ScheduledEventPayload::new(event);
+
+        self.send_event(handler, &payload).await
     }
 }
@@ -0,0 +1,1790 @@
+//! Action execution for webhook responses
+//!
+//! Compiled only with the `actions` feature (enabled by default).
+
+use super::{DEFAULT_ACTION_MAX_RETRIES, EventBridge, MAX_ACTION_MAX_RETRIES};
+use crate::adapters::{
+    ChannelInfoProvider, CreateChannelParams, DiscordService, EditParams, EventResponse, EventSender, ForwardParams,
+    InteractionResponseKind, InteractionResponseParams, JoinVoiceParams, LeaveGuildParams, ReactParams,
+    ReplyParams, ResponseAction, SendMessageParams, ThreadParams, ToggleRoleParams, WebhookMessageParams,
+};
+use crate::bridge::action_rate_limiter::{ActionRateLimiter, ThreadLimitPolicy};
+use crate::bridge::action_target::{ActionTarget, ReactionChange, ReactionReplyMode, emoji_param};
+use crate::bridge::discord_text::{MAX_CONTENT_LEN, truncate_content, truncate_thread_name};
+use crate::bridge::error_notifier::ErrorNotifier;
+use crate::bridge::idempotency_cache::IdempotencyCache;
+use crate::bridge::reaction_action_cooldown::ReactionActionCooldown;
+use crate::bridge::reply_cooldown::{ReplyCooldown, ReplyDestination};
+use crate::error::GatehookError;
+use anyhow::Context as _;
+use futures::stream::{self, StreamExt as _};
+use icu_normalizer::ComposingNormalizerBorrowed;
+use serenity::model::channel::{ChannelType, Message, Reaction};
+use serenity::model::id::{ChannelId, GuildId, MessageId, RoleId};
+use serenity::model::permissions::Permissions;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+
+/// `ReactParams.emoji` value that echoes the triggering reaction's emoji
+const TRIGGER_EMOJI: &str = "$trigger";
+
+/// `EditParams.message_id` value that refers to the last message sent in this response
+const LAST_MESSAGE: &str = "$last";
+
+/// File name of the attachment uploaded by `ReplyParams.as_file`
+const REPLY_FILE_NAME: &str = "reply.txt";
+
+/// Delay before the first retry of a rate-limited Discord call (doubled per retry)
+///
+/// Serenity's error does not carry Discord's `retry_after`, so a fixed backoff is used.
+const RATE_LIMIT_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Longest wait between retries of a rate-limited Discord call
+const MAX_RATE_LIMIT_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Discord's maximum slowmode (`rate_limit_per_user`) in seconds
+const MAX_RATE_LIMIT_PER_USER: u16 = 21600;
+
+/// Action index, channel and ID of the last message sent while executing one response
+type LastSent = Mutex<Option<(usize, ChannelId, MessageId)>>;
+
+/// Bridge state only used to execute actions
+pub(super) struct ActionState {
+    max_actions: usize,
+    action_rate_limiter: ActionRateLimiter,
+    thread_rate_limiter: ActionRateLimiter,
+    thread_limit_policy: ThreadLimitPolicy,
+    action_concurrency: usize,
+    action_max_retries: u32,
+    reply_cooldown: ReplyCooldown,
+    suppress_mass_mentions: bool,
+    precheck_permissions: bool,
+    normalize_emoji: bool,
+    reaction_reply_mode: ReactionReplyMode,
+    reaction_commands: HashMap<String, Vec<ResponseAction>>,
+    reaction_commands_forward: bool,
+    reaction_action_cooldown: ReactionActionCooldown,
+    allowed_actions: Option<Vec<String>>,
+    error_notifier: ErrorNotifier,
+    idempotency_cache: IdempotencyCache,
+}
+
+impl ActionState {
+    /// Create the action state with every limit and cooldown disabled
+    pub(super) fn new(max_actions: usize) -> Self {
+        Self {
+            max_actions,
+            action_rate_limiter: ActionRateLimiter::new(0),
+            thread_rate_limiter: ActionRateLimiter::new(0),
+            thread_limit_policy: ThreadLimitPolicy::Skip,
+            action_concurrency: 1,
+            action_max_retries: DEFAULT_ACTION_MAX_RETRIES,
+            reply_cooldown: ReplyCooldown::new(Duration::ZERO),
+            suppress_mass_mentions: true,
+            precheck_permissions: false,
+            normalize_emoji: false,
+            reaction_reply_mode: ReactionReplyMode::Reference,
+            reaction_commands: HashMap::new(),
+            reaction_commands_forward: false,
+            reaction_action_cooldown: ReactionActionCooldown::new(Duration::ZERO),
+            allowed_actions: None,
+            error_notifier: ErrorNotifier::new(None, Duration::ZERO),
+            idempotency_cache: IdempotencyCache::new(Duration::ZERO),
+        }
+    }
+}
+
+impl<D, S, C> EventBridge<D, S, C>
+where
+    D: DiscordService,
+    S: EventSender,
+    C: ChannelInfoProvider,
+{
+    /// Execute at most `max_actions_per_minute` actions across all responses (0 = unlimited)
+    ///
+    /// Enforced with a token bucket; actions beyond the rate are skipped with a warning.
+    pub fn with_max_actions_per_minute(mut self, max_actions_per_minute: u32) -> Self {
+        self.actions.action_rate_limiter = ActionRateLimiter::new(max_actions_per_minute);
+        self
+    }
+
+    /// Create at most `max_threads_per_minute` threads across all responses (0 = unlimited)
+    ///
+    /// Enforced with a token bucket in `create_or_get_thread`; throttled thread
+    /// actions follow `policy` (skip, or post the content to the parent channel).
+    pub fn with_max_threads_per_minute(mut self, max_threads_per_minute: u32, policy: ThreadLimitPolicy) -> Self {
+        self.actions.thread_rate_limiter = ActionRateLimiter::new(max_threads_per_minute);
+        self.actions.thread_limit_policy = policy;
+        self
+    }
+
+    /// Run up to `action_concurrency` independent actions concurrently
+    ///
+    /// Defaults to 1 (sequential). Values below 1 are treated as 1.
+    pub fn with_action_concurrency(mut self, action_concurrency: usize) -> Self {
+        self.actions.action_concurrency = action_concurrency.max(1);
+        self
+    }
+
+    /// Retry a rate-limited (HTTP 429) Discord call of an action up to `action_max_retries` times
+    ///
+    /// Defaults to `DEFAULT_ACTION_MAX_RETRIES`; 0 disables retries. Values
+    /// above `MAX_ACTION_MAX_RETRIES` are capped. Other errors are never retried.
+    pub fn with_action_max_retries(mut self, action_max_retries: u32) -> Self {
+        self.actions.action_max_retries = action_max_retries.min(MAX_ACTION_MAX_RETRIES);
+        self
+    }
+
+    /// Skip identical replies to the same channel within `cooldown` (`Duration::ZERO` disables)
+    pub fn with_reply_cooldown(mut self, cooldown: Duration) -> Self {
+        self.actions.reply_cooldown = ReplyCooldown::new(cooldown);
+        self
+    }
+
+    /// Skip actions of a reaction repeated by the same user on the same message
+    /// with the same emoji within `cooldown` (`Duration::ZERO` disables)
+    ///
+    /// The reaction is still forwarded; only its actions are dropped.
+    pub fn with_reaction_action_cooldown(mut self, cooldown: Duration) -> Self {
+        self.actions.reaction_action_cooldown = ReactionActionCooldown::new(cooldown);
+        self
+    }
+
+    /// Skip actions whose `idempotency_key` was already executed within `window`
+    /// (`Duration::ZERO` disables)
+    ///
+    /// Actions without a key are always executed.
+    pub fn with_action_idempotency_window(mut self, window: Duration) -> Self {
+        self.actions.idempotency_cache = IdempotencyCache::new(window);
+        self
+    }
+
+    /// Prevent `@everyone`/`@here` from pinging in bot output (default: true)
+    ///
+    /// Actions can still opt in individually via `allow_mass_mentions`.
+    pub fn with_suppress_mass_mentions(mut self, suppress_mass_mentions: bool) -> Self {
+        self.actions.suppress_mass_mentions = suppress_mass_mentions;
+        self
+    }
+
+    /// Only execute actions whose type is listed (e.g. `["reply", "react"]`)
+    ///
+    /// `None` (the default) allows every action except `leave_guild` and
+    /// `join_voice`, which must always be listed explicitly.
+    pub fn with_allowed_actions(mut self, allowed_actions: Option<Vec<String>>) -> Self {
+        self.actions.allowed_actions = allowed_actions;
+        self
+    }
+
+    /// Skip actions the bot lacks permissions for, based on cached guild data
+    ///
+    /// Actions whose permissions are unknown (DMs, cache misses) still execute.
+    pub fn with_precheck_permissions(mut self, precheck_permissions: bool) -> Self {
+        self.actions.precheck_permissions = precheck_permissions;
+        self
+    }
+
+    /// Normalize `react` emoji to Unicode NFC before sending
+    ///
+    /// Fixes decomposed emoji in webhook JSON that Discord rejects as invalid.
+    pub fn with_normalize_emoji(mut self, normalize_emoji: bool) -> Self {
+        self.actions.normalize_emoji = normalize_emoji;
+        self
+    }
+
+    /// Choose where replies to reaction events go (default: a reply to the reacted-to message)
+    pub fn with_reaction_reply_mode(mut self, mode: ReactionReplyMode) -> Self {
+        self.actions.reaction_reply_mode = mode;
+        self
+    }
+
+    /// Execute local actions for reactions with these emojis, bypassing the webhook
+    ///
+    /// Keys use the `ReactParams.emoji` format (Unicode as-is, custom emoji as
+    /// `"name:id"`). Only `reaction_add` events are matched.
+    pub fn with_reaction_commands(mut self, reaction_commands: HashMap<String, Vec<ResponseAction>>) -> Self {
+        self.actions.reaction_commands = reaction_commands;
+        self
+    }
+
+    /// Still forward reactions matched by `with_reaction_commands` (default: false)
+    ///
+    /// Actions from the webhook response run after the local ones.
+    pub fn with_reaction_commands_forward(mut self, forward: bool) -> Self {
+        self.actions.reaction_commands_forward = forward;
+        self
+    }
+
+    /// Post a brief notice to `channel_id` when an action or webhook send fails
+    ///
+    /// At most one notice per `interval`; further failures within it are only
+    /// logged. Notices name the action or handler and a redacted reason, never
+    /// error messages or content.
+    pub fn with_error_notify_channel(mut self, channel_id: Option<ChannelId>, interval: Duration) -> Self {
+        self.actions.error_notifier = ErrorNotifier::new(channel_id, interval);
+        self
+    }
+}
+
+impl<D, S, C> EventBridge<D, S, C>
+where
+    D: DiscordService,
+    S: EventSender,
+    C: ChannelInfoProvider,
+{
+    /// Execute actions from webhook response
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The action target (message, reaction, etc.)
+    /// * `event_response` - The response from webhook containing actions
+    ///
+    /// # Concurrency
+    ///
+    /// Actions execute sequentially by default. With `action_concurrency > 1`,
+    /// consecutive independent actions run concurrently (up to the limit).
+    /// Ordering-sensitive actions (see `requires_serial_execution`) act as
+    /// barriers: everything before them completes first, and they run alone.
+    ///
+    /// # Message References
+    ///
+    /// Messages sent by `Reply`/`Thread`/`SendMessage`/`Forward` are tracked for the duration of the
+    /// call, so a later `Edit` can target the last one with `"$last"`. "Last" follows
+    /// the response order, also when concurrent sends finish in another order.
+    ///
+    /// # Security
+    ///
+    /// Limits the number of actions to `max_actions` to prevent DoS attacks,
+    /// and across responses to `max_actions_per_minute` (see `execute_action`).
+    /// Logs action type only (not content) to prevent sensitive information exposure.
+    ///
+    /// # Idempotency
+    ///
+    /// Actions carrying an `idempotency_key` already executed within the
+    /// idempotency window (e.g. returned again by a retried delivery) are skipped.
+    /// A key only counts as executed once its action succeeds: keys of actions
+    /// that were skipped (allowlist, precheck, rate limit) or failed are
+    /// released so a retry can run them.
+    pub async fn execute_actions(
+        &self,
+        target: impl Into<ActionTarget>,
+        event_response: &EventResponse,
+    ) -> Result<(), GatehookError> {
+        let target = target.into();
+
+        let total_actions = event_response.actions.len();
+
+        // Limit actions for DoS protection (gatehook's own leading actions are exempt)
+        let limit = self.actions.max_actions.saturating_add(event_response.exempt_actions);
+        let actions_to_execute = if total_actions > limit {
+            tracing::warn!(
+                total_actions,
+                max_actions = self.actions.max_actions,
+                "Too many actions in webhook response, truncating to max_actions"
+            );
+            &event_response.actions[..limit]
+        } else {
+            &event_response.actions[..]
+        };
+
+        let last_sent = LastSent::default();
+        let mut independent: Vec<(usize, &ResponseAction, Option<&str>)> = Vec::new();
+        for (index, action) in actions_to_execute.iter().enumerate() {
+            let key = event_response.idempotency_key(index);
+            if let Some(key) = key
+                && !self.actions.idempotency_cache.try_acquire(key)
+            {
+                info!(
+                    action_type = action.name(),
+                    "Action with an already executed idempotency key, skipping action"
+                );
+                continue;
+            }
+
+            if self.actions.action_concurrency > 1 && !Self::requires_serial_execution(action) {
+                independent.push((index, action, key));
+                continue;
+            }
+
+            // Barrier: flush pending independent actions, then run this one alone
+            self.execute_concurrently(&target, &last_sent, independent.drain(..)).await;
+            self.execute_and_log(&target, &last_sent, index, action, key).await;
+        }
+        self.execute_concurrently(&target, &last_sent, independent.drain(..)).await;
+
+        Ok(())
+    }
+
+    /// Whether an action depends on (or affects) the outcome of other actions
+    ///
+    /// - `Thread`: creating a thread races with other thread actions on the same message
+    /// - `Reply` with `delete_original`: later actions would target a deleted message
+    /// - `Edit`: `"$last"` must see every message sent before it
+    /// - `LeaveGuild`: later actions in the guild would fail once the bot has left
+    fn requires_serial_execution(action: &ResponseAction) -> bool {
+        match action {
+            ResponseAction::Thread(_) | ResponseAction::Edit(_) | ResponseAction::LeaveGuild(_) => {
+                true
+            }
+            ResponseAction::Reply(params) => params.delete_original,
+            ResponseAction::React(_)
+            | ResponseAction::ToggleRole(_)
+            | ResponseAction::SuppressEmbeds
+            | ResponseAction::RemoveAllReactions
+            | ResponseAction::SendMessage(_)
+            | ResponseAction::WebhookMessage(_)
+            | ResponseAction::Forward(_)
+            | ResponseAction::JoinVoice(_)
+            | ResponseAction::CreateChannel(_)
+            | ResponseAction::InteractionResponse(_) => false,
+        }
+    }
+
+    /// Execute independent actions with up to `action_concurrency` in flight
+    async fn execute_concurrently(
+        &self,
+        target: &ActionTarget,
+        last_sent: &LastSent,
+        actions: impl Iterator<Item = (usize, &ResponseAction, Option<&str>)>,
+    ) {
+        stream::iter(actions)
+            .for_each_concurrent(self.actions.action_concurrency, |(index, action, key)| {
+                self.execute_and_log(target, last_sent, index, action, key)
+            })
+            .await;
+    }
+
+    /// Execute a single action, logging (not propagating) failures
+    ///
+    /// `index` is the action's position in the response. The action's
+    /// idempotency `key` is released unless the action executed.
+    async fn execute_and_log(
+        &self,
+        target: &ActionTarget,
+        last_sent: &LastSent,
+        index: usize,
+        action: &ResponseAction,
+        key: Option<&str>,
+    ) {
+        // Note: Only log action type, not content, to prevent sensitive information exposure
+        let result = self.execute_action(target, last_sent, index, action).await;
+        if let Some(key) = key
+            && !matches!(result, Ok(true))
+        {
+            self.actions.idempotency_cache.release(key);
+        }
+        if let Err(err) = result {
+            error!(
+                ?err,
+                action_type = action.name(),
+                "Failed to execute action, continuing with next"
+            );
+            self.notify_error(&format!("Action `{}` failed", action.name())).await;
+        }
+    }
+
+    /// Answer a reaction_add whose emoji is mapped by `with_reaction_commands`
+    ///
+    /// Returns `None` for unmapped emojis. With `with_reaction_commands_forward`
+    /// the reaction is still forwarded and the webhook's actions follow the local ones.
+    pub(super) async fn reaction_command(&self, reaction: &Reaction) -> Result<Option<EventResponse>, GatehookError> {
+        let Some(actions) = self.actions.reaction_commands.get(&emoji_param(&reaction.emoji)) else {
+            return Ok(None);
+        };
+        debug!(message_id = %reaction.message_id, "Reaction matches a local reaction command");
+        let mut local = EventResponse::from(actions.clone());
+        if self.actions.reaction_commands_forward
+            && self.sampled("reaction_add")
+            && let Some(response) = self.forward_reaction_add(reaction).await?
+        {
+            local.append(response);
+        }
+        Ok(Some(local))
+    }
+
+    /// Drop the actions of a reaction repeated within the reaction action cooldown
+    pub(super) fn skip_repeated_reaction_actions(&self, reaction: &Reaction, response: EventResponse) -> EventResponse {
+        let repeated = !response.actions.is_empty()
+            && reaction.user_id.is_some_and(|user_id| {
+                !self.actions.reaction_action_cooldown.try_acquire(
+                    reaction.message_id,
+                    user_id,
+                    &emoji_param(&reaction.emoji),
+                )
+            });
+        if repeated {
+            debug!(
+                user_id = ?reaction.user_id,
+                message_id = %reaction.message_id,
+                action_count = response.actions.len(),
+                "Reaction repeated within cooldown, skipping actions"
+            );
+            return EventResponse::default();
+        }
+        response
+    }
+
+    /// Post `notice` to the error notify channel, unless disabled or rate limited
+    ///
+    /// Failures to post are logged and otherwise ignored.
+    pub(super) async fn notify_error(&self, notice: &str) {
+        let Some(channel_id) = self.actions.error_notifier.try_acquire() else {
+            return;
+        };
+        let content = format!("⚠️ gatehook: {notice} (see logs for details)");
+        if let Err(err) = self.discord_service.send_message_to_channel(channel_id, &content, false).await {
+            warn!(?err, channel_id = %channel_id, "Failed to post error notice");
+        }
+    }
+
+    /// Execute a single action
+    ///
+    /// Actions not permitted by `allowed_actions` are skipped with a warning.
+    /// With `precheck_permissions`, actions the bot lacks (cached) permissions
+    /// for are skipped with a warning instead of failing at the API.
+    /// Actions beyond the global `max_actions_per_minute` rate are skipped
+    /// with a warning.
+    /// Messages sent by the action are recorded in `last_sent` under `index`,
+    /// unless an action later in the response already sent one.
+    ///
+    /// Returns `false` if the action was skipped by one of these checks.
+    async fn execute_action(
+        &self,
+        target: &ActionTarget,
+        last_sent: &LastSent,
+        index: usize,
+        action: &ResponseAction,
+    ) -> anyhow::Result<bool> {
+        if !self.is_action_allowed(action) {
+            warn!(
+                action_type = action.name(),
+                "Action not permitted by ALLOWED_ACTIONS, skipping action"
+            );
+            return Ok(false);
+        }
+
+        if self.actions.precheck_permissions
+            && let Some(missing) = self.missing_permissions(target, action)
+        {
+            warn!(
+                message_id = %target.message_id,
+                channel_id = %target.channel_id,
+                action_type = action.name(),
+                missing = ?missing,
+                "Bot lacks required permissions, skipping action"
+            );
+            return Ok(false);
+        }
+
+        if !self.actions.action_rate_limiter.try_acquire() {
+            warn!(
+                message_id = %target.message_id,
+                action_type = action.name(),
+                "Global action rate limit (MAX_ACTIONS_PER_MINUTE) reached, skipping action"
+            );
+            return Ok(false);
+        }
+
+        let sent = match action {
+            ResponseAction::Reply(params) => self.execute_reply(target, params).await?,
+            ResponseAction::React(params) => {
+                self.execute_react(target, params).await?;
+                None
+            }
+            ResponseAction::Thread(params) => self.execute_thread(target, params).await?,
+            ResponseAction::SendMessage(params) => self.execute_send_message(target, params).await?,
+            ResponseAction::WebhookMessage(params) => {
+                self.execute_webhook_message(params).await?;
+                None
+            }
+            ResponseAction::Forward(params) => self.execute_forward(target, params).await?,
+            ResponseAction::Edit(params) => {
+                let last = last_sent.lock().unwrap().map(|(_, channel_id, message_id)| (channel_id, message_id));
+                self.execute_edit(target, params, last).await?;
+                None
+            }
+            ResponseAction::ToggleRole(params) => {
+                self.execute_toggle_role(target, params).await?;
+                None
+            }
+            ResponseAction::SuppressEmbeds => {
+                self.execute_suppress_embeds(target).await?;
+                None
+            }
+            ResponseAction::RemoveAllReactions => {
+                self.execute_remove_all_reactions(target).await?;
+                None
+            }
+            ResponseAction::JoinVoice(params) => {
+                self.execute_join_voice(target, params).await?;
+                None
+            }
+            ResponseAction::LeaveGuild(params) => {
+                self.execute_leave_guild(target, params).await?;
+                None
+            }
+            ResponseAction::CreateChannel(params) => {
+                self.execute_create_channel(target, params).await?;
+                None
+            }
+            ResponseAction::InteractionResponse(params) => {
+                self.execute_interaction_response(target, params).await?;
+                None
+            }
+        };
+
+        if let Some(message) = sent {
+            let mut last = last_sent.lock().unwrap();
+            if last.is_none_or(|(last_index, ..)| last_index < index) {
+                *last = Some((index, message.channel_id, message.id));
+            }
+        }
+        Ok(true)
+    }
+
+    /// Whether an action may be executed
+    ///
+    /// Without an explicit allowlist every action except `LeaveGuild` and
+    /// `JoinVoice` is allowed; those must always be listed explicitly
+    /// (`join_voice` also enables the voice states intent).
+    fn is_action_allowed(&self, action: &ResponseAction) -> bool {
+        match &self.actions.allowed_actions {
+            Some(allowed) => allowed.iter().any(|name| name == action.name()),
+            None => !matches!(action, ResponseAction::LeaveGuild(_) | ResponseAction::JoinVoice(_)),
+        }
+    }
+
+    /// Permissions required to execute an action
+    ///
+    /// - `Reply`: Send Messages + Read Message History (replies reference the
+    ///   target), plus Attach Files with `as_file`; replies to reactions
+    ///   depend on `reaction_reply_mode` (see `missing_permissions`)
+    /// - `React`: Add Reactions + Read Message History
+    /// - `Thread`: Create Public Threads (Create Private Threads with
+    ///   `private`) + Send Messages in Threads
+    /// - `SendMessage`: Send Messages
+    /// - `Forward`: Send Messages (+ Attach Files with `include_attachments`)
+    /// - `ToggleRole`: Manage Roles
+    /// - `SuppressEmbeds`: Manage Messages (not needed for the bot's own
+    ///   messages, but the author is unknown here)
+    /// - `RemoveAllReactions`: Manage Messages
+    /// - `Edit`: none (the bot can always edit its own messages)
+    /// - `WebhookMessage`: none (authorized by the webhook token)
+    /// - `LeaveGuild`: none
+    /// - `JoinVoice`: Connect (checked on the voice channel)
+    /// - `CreateChannel`: Manage Channels
+    /// - `InteractionResponse`: none (answered via the interaction token)
+    fn required_permissions(action: &ResponseAction) -> Permissions {
+        match action {
+            ResponseAction::Reply(params) => {
+                let required = Permissions::SEND_MESSAGES | Permissions::READ_MESSAGE_HISTORY;
+                if params.as_file {
+                    required | Permissions::ATTACH_FILES
+                } else {
+                    required
+                }
+            }
+            ResponseAction::React(_) => Permissions::ADD_REACTIONS | Permissions::READ_MESSAGE_HISTORY,
+            ResponseAction::Thread(params) => {
+                let create = if params.private {
+                    Permissions::CREATE_PRIVATE_THREADS
+                } else {
+                    Permissions::CREATE_PUBLIC_THREADS
+                };
+                create | Permissions::SEND_MESSAGES_IN_THREADS
+            }
+            ResponseAction::SendMessage(_) => Permissions::SEND_MESSAGES,
+            ResponseAction::Forward(params) => {
+                if params.include_attachments {
+                    Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES
+                } else {
+                    Permissions::SEND_MESSAGES
+                }
+            }
+            ResponseAction::ToggleRole(_) => Permissions::MANAGE_ROLES,
+            ResponseAction::SuppressEmbeds | ResponseAction::RemoveAllReactions => Permissions::MANAGE_MESSAGES,
+            ResponseAction::JoinVoice(_) => Permissions::CONNECT,
+            ResponseAction::CreateChannel(_) => Permissions::MANAGE_CHANNELS,
+            ResponseAction::Edit(_)
+            | ResponseAction::WebhookMessage(_)
+            | ResponseAction::LeaveGuild(_)
+            | ResponseAction::InteractionResponse(_) => Permissions::empty(),
+        }
+    }
+
+    /// Required permissions the bot is known to lack for an action
+    ///
+    /// Returns `None` when nothing is missing or the bot's permissions are
+    /// unknown (DMs, cache misses); the action then executes as usual.
+    ///
+    /// Replies to reactions follow `reaction_reply_mode`: `Dm` replies are
+    /// not checked (they go to the reacting user's DMs), `Channel` replies
+    /// are plain messages and only need Send Messages.
+    fn missing_permissions(&self, target: &ActionTarget, action: &ResponseAction) -> Option<Permissions> {
+        let guild_id = target.guild_id?;
+        let required = match (action, self.actions.reaction_reply_mode, target.reacting_user_id()) {
+            (ResponseAction::Reply(_), ReactionReplyMode::Dm, Some(_)) => return None,
+            (ResponseAction::Reply(_), ReactionReplyMode::Channel, Some(_)) => Permissions::SEND_MESSAGES,
+            _ => Self::required_permissions(action),
+        };
+        // Thread, react, send_message and forward actions may target another channel
+        let channel_id = match action {
+            ResponseAction::Thread(ThreadParams {
+                message_id: Some(_),
+                channel_id: Some(channel_id),
+                ..
+            })
+            | ResponseAction::React(ReactParams {
+                message_id: Some(_),
+                channel_id: Some(channel_id),
+                ..
+            })
+            | ResponseAction::SendMessage(SendMessageParams {
+                channel_id: Some(channel_id),
+                ..
+            })
+            | ResponseAction::Forward(ForwardParams {
+                to_channel_id: channel_id,
+                ..
+            })
+            | ResponseAction::JoinVoice(JoinVoiceParams { channel_id }) => channel_id.parse::<ChannelId>().ok()?,
+            _ => target.channel_id,
+        };
+
+        let granted = self.channel_info.bot_permissions(guild_id, channel_id)?;
+        let missing = required - granted;
+        (!missing.is_empty()).then_some(missing)
+    }
+
+    /// Execute Reply action
+    ///
+    /// # Content Handling
+    /// - Content exceeding 2000 characters is truncated with warning log
+    /// - With `params.as_file = true`, such content is instead uploaded in full
+    ///   as a `reply.txt` attachment (shorter content is sent as a normal reply)
+    ///
+    /// # Mention
+    /// - `params.mention = true`: Reply with ping (user receives notification)
+    /// - `params.mention = false`: Reply without ping (default)
+    ///
+    /// # Delete Original
+    /// - `params.delete_original = true`: Delete the target message after a successful reply
+    /// - Deletion failure is logged but does not fail the action (the reply was already sent)
+    ///
+    /// # Reaction Events
+    /// - `reaction_reply_mode` decides where replies to reactions go:
+    ///   `Reference` replies to the reacted-to message, `Channel` posts a plain
+    ///   message to the channel (mentioning the reacting user with
+    ///   `params.mention`), `Dm` messages the reacting user directly
+    /// - `Channel` and `Dm` send content above 2000 characters truncated (no `as_file`)
+    ///
+    /// # Cooldown
+    /// - An identical reply to the same channel (or, in `Dm` mode, the same
+    ///   reacting user) within `REPLY_COOLDOWN_SECS` is skipped with a warning
+    ///   (guards against webhook loops)
+    /// - A reply that fails to send does not start the cooldown
+    ///
+    /// Returns the sent reply, or `None` if the action was skipped.
+    async fn execute_reply(
+        &self,
+        target: &ActionTarget,
+        params: &ReplyParams,
+    ) -> anyhow::Result<Option<Message>> {
+        // Reaction replies may go to the channel or the reacting user's DMs instead
+        let (destination, redirect) = match (self.actions.reaction_reply_mode, target.reacting_user_id()) {
+            (ReactionReplyMode::Channel, Some(user_id)) => {
+                let mention = if params.mention { format!("<@{user_id}> ") } else { String::new() };
+                (ReplyDestination::Channel(target.channel_id), Some(mention + &params.content))
+            }
+            (ReactionReplyMode::Dm, Some(user_id)) => (ReplyDestination::User(user_id), Some(params.content.clone())),
+            _ => (ReplyDestination::Channel(target.channel_id), None),
+        };
+
+        let as_file = redirect.is_none() && params.as_file && params.content.chars().count() > MAX_CONTENT_LEN;
+        let content = match &redirect {
+            Some(content) => truncate_content(content, &self.truncation_marker),
+            None if as_file => params.content.clone(),
+            None => truncate_content(&params.content, &self.truncation_marker),
+        };
+
+        // Checked before opening a DM channel, so a looping webhook makes no API call
+        if !self.actions.reply_cooldown.try_acquire(destination, &content) {
+            warn!(
+                message_id = %target.message_id,
+                destination = ?destination,
+                "Identical reply sent to this destination recently, skipping reply action"
+            );
+            return Ok(None);
+        }
+
+        let channel_id = match destination {
+            ReplyDestination::Channel(channel_id) => Ok(channel_id),
+            ReplyDestination::User(user_id) => self
+                .retry_rate_limited(|| self.discord_service.create_dm_channel(user_id))
+                .await
+                .context("Failed to open DM channel with reacting user"),
+        };
+        let channel_id = match channel_id {
+            Ok(channel_id) => channel_id,
+            Err(err) => {
+                self.actions.reply_cooldown.release(destination, &content);
+                return Err(err);
+            }
+        };
+
+        let sent = if redirect.is_some() {
+            self.retry_rate_limited(|| {
+                self.discord_service.send_message_to_channel(
+                    channel_id,
+                    &content,
+                    self.allow_mass_mentions(params.allow_mass_mentions),
+                )
+            })
+            .await
+            .context("Failed to send reaction reply to Discord")
+        } else if as_file {
+            self.retry_rate_limited(|| {
+                self.discord_service.reply_with_file_in_channel(
+                    target.channel_id,
+                    target.message_id,
+                    REPLY_FILE_NAME,
+                    content.clone().into_bytes(),
+                    params.mention,
+                )
+            })
+            .await
+            .context("Failed to send file reply to Discord")
+        } else {
+            self.retry_rate_limited(|| {
+                self.discord_service.reply_in_channel(
+                    target.channel_id,
+                    target.message_id,
+                    &content,
+                    params.mention,
+                    self.allow_mass_mentions(params.allow_mass_mentions),
+                )
+            })
+            .await
+            .context("Failed to send reply to Discord")
+        };
+        let reply = match sent {
+            Ok(reply) => reply,
+            Err(err) => {
+                // Nothing was posted, so an identical retry must not be suppressed
+                self.actions.reply_cooldown.release(destination, &content);
+                return Err(err);
+            }
+        };
+
+        info!(
+            message_id = %target.message_id,
+            channel_id = %channel_id,
+            mention = params.mention,
+            as_file,
+            content_len = content.chars().count(),
+            "Successfully executed reply action"
+        );
+
+        if params.delete_original {
+            match self.retry_rate_limited(|| {
+                self.discord_service.delete_message(target.channel_id, target.message_id)
+            })
+            .await
+            {
+                Ok(()) => {
+                    info!(
+                        message_id = %target.message_id,
+                        "Deleted original message after reply"
+                    );
+                }
+                Err(err) => {
+                    warn!(
+                        ?err,
+                        message_id = %target.message_id,
+                        "Reply succeeded but failed to delete original message"
+                    );
+                }
+            }
+        }
+
+        Ok(Some(reply))
+    }
+
+    /// Execute React action
+    ///
+    /// # Emoji Format
+    /// - Unicode emoji: "👍", "🎉", etc.
+    /// - Custom emoji: "name:id" format (e.g., "customemoji:123456789")
+    /// - `"$trigger"`: Echo the triggering reaction's emoji; skipped with a warning
+    ///   for non-reaction targets
+    ///
+    /// # Target Override
+    /// - `params.message_id`/`params.channel_id` react to another message, so one
+    ///   response can react to several messages
+    /// - Invalid IDs (or `channel_id` without `message_id`) skip the action with a warning
+    async fn execute_react(
+        &self,
+        target: &ActionTarget,
+        params: &ReactParams,
+    ) -> anyhow::Result<()> {
+        let Some(target) = Self::override_target(target, &params.message_id, &params.channel_id, "react") else {
+            return Ok(());
+        };
+        let target = &target;
+
+        let emoji = if params.emoji == TRIGGER_EMOJI {
+            let Some(trigger_emoji) = &target.trigger_emoji else {
+                warn!(
+                    message_id = %target.message_id,
+                    "$trigger emoji is only available for reaction events, skipping react action"
+                );
+                return Ok(());
+            };
+            trigger_emoji
+        } else {
+            &params.emoji
+        };
+        let emoji = if self.actions.normalize_emoji {
+            ComposingNormalizerBorrowed::new_nfc().normalize(emoji)
+        } else {
+            Cow::Borrowed(emoji.as_str())
+        };
+
+        self.retry_rate_limited(|| {
+            self.discord_service.react_to_message(target.channel_id, target.message_id, &emoji)
+        })
+        .await
+        .context("Failed to add reaction to Discord")?;
+
+        info!(
+            message_id = %target.message_id,
+            emoji = %emoji,
+            "Successfully executed react action"
+        );
+
+        Ok(())
+    }
+
+    /// Execute Thread action
+    ///
+    /// # Thread Name
+    /// - `params.name = Some(...)`: Use specified name
+    /// - `params.name = None`: Defaults to "Thread"
+    /// - Name is ignored if already in a thread
+    ///
+    /// # Content Handling
+    /// - Content exceeding 2000 characters is truncated with warning log
+    ///
+    /// # Auto-archive Duration
+    /// - Valid values: 60, 1440, 4320, 10080 (minutes)
+    /// - Invalid values fall back to 1440 (OneDay) with warning log
+    ///
+    /// # DM Context
+    /// - Threads are not supported in DMs; the action is skipped with a warning
+    ///   before any Discord API call is made
+    ///
+    /// # Target Override
+    /// - `params.message_id`/`params.channel_id` start the thread on another message
+    /// - Invalid IDs (or `channel_id` without `message_id`) skip the action with a warning
+    ///
+    /// # Private Threads
+    /// - `params.private = true`: Creates a private thread in the target channel
+    /// - Discord only starts public threads from messages, so private threads are
+    ///   not attached to the target message
+    /// - Announcement channels fall back to a public thread with a warning
+    /// - Beyond `max_threads_per_minute` no thread is created: the action is
+    ///   skipped, or with `ThreadLimitPolicy::PostToParent` the content is
+    ///   posted to the target channel instead
+    ///
+    /// # Error Handling
+    /// - If thread creation fails with error code 160004 (thread already exists),
+    ///   retrieves the existing thread and posts to it
+    ///
+    /// Returns the message posted to the thread, or `None` if the action was skipped.
+    async fn execute_thread(
+        &self,
+        target: &ActionTarget,
+        params: &ThreadParams,
+    ) -> anyhow::Result<Option<Message>> {
+        // Threads only exist in guild channels
+        if target.guild_id.is_none() {
+            warn!(
+                message_id = %target.message_id,
+                channel_id = %target.channel_id,
+                "Threads are not supported in DMs, skipping thread action"
+            );
+            return Ok(None);
+        }
+
+        let Some(target) = Self::override_target(target, &params.message_id, &params.channel_id, "thread") else {
+            return Ok(None);
+        };
+        let target = &target;
+
+        // Check if already in thread (cache-first with API fallback)
+        let is_in_thread = self.channel_info
+            .is_thread(target.guild_id, target.channel_id)
+            .await
+            .context("Failed to check if channel is thread")?;
+
+        // Determine target channel ID
+        let target_channel_id = if is_in_thread {
+            // Already in thread → use as-is
+            info!("Message is already in thread, skipping thread creation");
+            target.channel_id
+        } else {
+            // Normal channel → create new thread (with fallback to existing thread)
+            match self.create_or_get_thread(target, params).await? {
+                Some(thread_id) => thread_id,
+                None if self.actions.thread_limit_policy == ThreadLimitPolicy::PostToParent => {
+                    warn!(
+                        message_id = %target.message_id,
+                        channel_id = %target.channel_id,
+                        outcome = "posted_to_parent",
+                        "Thread rate limit (MAX_THREADS_PER_MINUTE) reached, posting to parent channel"
+                    );
+                    target.channel_id
+                }
+                None => {
+                    warn!(
+                        message_id = %target.message_id,
+                        channel_id = %target.channel_id,
+                        outcome = "skipped",
+                        "Thread rate limit (MAX_THREADS_PER_MINUTE) reached, skipping thread action"
+                    );
+                    return Ok(None);
+                }
+            }
+        };
+
+        // Truncate content
+        let content = truncate_content(&params.content, &self.truncation_marker);
+
+        // Post message to thread
+        let message = self.retry_rate_limited(|| {
+            self.discord_service.send_message_to_channel(
+                target_channel_id,
+                &content,
+                self.allow_mass_mentions(params.allow_mass_mentions),
+            )
+        })
+        .await
+        .context("Failed to send message to thread")?;
+
+        info!(
+            channel_id = %target_channel_id,
+            is_in_thread = is_in_thread,
+            "Successfully executed thread action"
+        );
+
+        Ok(Some(message))
+    }
+
+    /// Execute Edit action
+    ///
+    /// # Message Resolution
+    /// - `params.message_id = "$last"`: The last message sent by an earlier
+    ///   `Reply`/`Thread`/`SendMessage`/`Forward` action in the same response (`params.channel_id` is ignored)
+    /// - Otherwise: The given message in `params.channel_id` (defaults to the target channel)
+    /// - Skipped with a warning when nothing was sent yet or the IDs are invalid
+    ///
+    /// # Content Handling
+    /// - Only the given parts change: `content` replaces the content, `embed`
+    ///   replaces the embeds; skipped with a warning when neither is given
+    /// - Content exceeding 2000 characters is truncated with warning log
+    async fn execute_edit(
+        &self,
+        target: &ActionTarget,
+        params: &EditParams,
+        last_sent: Option<(ChannelId, MessageId)>,
+    ) -> anyhow::Result<()> {
+        if params.content.is_none() && params.embed.is_none() {
+            warn!(
+                message_id = %params.message_id,
+                "Edit action has neither content nor embed, skipping edit action"
+            );
+            return Ok(());
+        }
+        let Some((channel_id, message_id)) = Self::edit_target(target, params, last_sent) else {
+            return Ok(());
+        };
+
+        let content = params
+            .content
+            .as_deref()
+            .map(|content| truncate_content(content, &self.truncation_marker));
+
+        self.retry_rate_limited(|| {
+            self.discord_service.edit_message(
+                channel_id,
+                message_id,
+                content.as_deref(),
+                params.embed.as_ref(),
+                self.allow_mass_mentions(params.allow_mass_mentions),
+            )
+        })
+        .await
+        .context("Failed to edit message on Discord")?;
+
+        info!(
+            message_id = %message_id,
+            channel_id = %channel_id,
+            content_len = content.as_deref().map(|content| content.chars().count()),
+            embed = params.embed.is_some(),
+            "Successfully executed edit action"
+        );
+
+        Ok(())
+    }
+
+    /// Execute SendMessage action
+    ///
+    /// # Channel Resolution
+    /// - `params.channel_id`: Post to the given channel
+    /// - Otherwise: Post to the target channel
+    /// - Skipped with a warning for an invalid `channel_id`
+    ///
+    /// # Content Handling
+    /// - Content exceeding 2000 characters is truncated with warning log
+    ///
+    /// Returns the sent message, or `None` if the action was skipped.
+    async fn execute_send_message(
+        &self,
+        target: &ActionTarget,
+        params: &SendMessageParams,
+    ) -> anyhow::Result<Option<Message>> {
+        let channel_id = match &params.channel_id {
+            Some(channel_id) => match channel_id.parse::<ChannelId>() {
+                Ok(channel_id) => channel_id,
+                Err(_) => {
+                    warn!(channel_id = %channel_id, "Invalid send_message channel_id, skipping send_message action");
+                    return Ok(None);
+                }
+            },
+            None => target.channel_id,
+        };
+
+        let content = truncate_content(&params.content, &self.truncation_marker);
+
+        let message = self.retry_rate_limited(|| {
+            self.discord_service.send_message_to_channel(
+                channel_id,
+                &content,
+                self.allow_mass_mentions(params.allow_mass_mentions),
+            )
+        })
+        .await
+        .context("Failed to send message to Discord")?;
+
+        info!(
+            channel_id = %channel_id,
+            content_len = content.chars().count(),
+            "Successfully executed send_message action"
+        );
+
+        Ok(Some(message))
+    }
+
+    /// Execute WebhookMessage action
+    ///
+    /// # Webhook
+    /// - Posts through the Discord channel webhook at `params.webhook_url`,
+    ///   as `params.username`/`params.avatar_url` when given
+    /// - Skipped with a warning unless the URL is a Discord webhook URL
+    ///   (`https://discord.com/api/webhooks/{id}/{token}`)
+    /// - The URL's token is never logged
+    ///
+    /// # Content Handling
+    /// - Content exceeding 2000 characters is truncated with warning log
+    ///
+    /// The message is not recorded for `"$last"` (the bot cannot edit webhook messages).
+    async fn execute_webhook_message(&self, params: &WebhookMessageParams) -> anyhow::Result<()> {
+        let Some((webhook_id, token)) = url::Url::parse(&params.webhook_url)
+            .ok()
+            .and_then(|url| {
+                serenity::utils::parse_webhook(&url).map(|(webhook_id, token)| (webhook_id, token.to_string()))
+            })
+        else {
+            warn!("Invalid webhook_message webhook_url, skipping webhook_message action");
+            return Ok(());
+        };
+
+        let content = truncate_content(&params.content, &self.truncation_marker);
+
+        self.retry_rate_limited(|| {
+            self.discord_service.execute_webhook(
+                webhook_id,
+                &token,
+                params.username.as_deref(),
+                params.avatar_url.as_deref(),
+                &content,
+                self.allow_mass_mentions(params.allow_mass_mentions),
+            )
+        })
+        .await
+        .context("Failed to execute Discord webhook")?;
+
+        info!(
+            webhook_id = %webhook_id,
+            content_len = content.chars().count(),
+            "Successfully executed webhook_message action"
+        );
+
+        Ok(())
+    }
+
+    /// Execute Forward action
+    ///
+    /// # Content
+    /// - Reposts the triggering message's content to `params.to_channel_id`
+    ///   (truncated to 2000 characters)
+    /// - `params.include_attachments = true`: Downloads and re-uploads its attachments
+    /// - Skipped with a warning for non-message events (no content), messages
+    ///   with nothing to forward, or an invalid `to_channel_id`
+    ///
+    /// Returns the sent message, or `None` if the action was skipped.
+    async fn execute_forward(
+        &self,
+        target: &ActionTarget,
+        params: &ForwardParams,
+    ) -> anyhow::Result<Option<Message>> {
+        let Some(source) = &target.source else {
+            warn!(
+                message_id = %target.message_id,
+                "Forward is only available for message events, skipping forward action"
+            );
+            return Ok(None);
+        };
+
+        let Ok(channel_id) = params.to_channel_id.parse::<ChannelId>() else {
+            warn!(
+                to_channel_id = %params.to_channel_id,
+                "Invalid forward to_channel_id, skipping forward action"
+            );
+            return Ok(None);
+        };
+
+        let attachments: &[_] = if params.include_attachments {
+            &source.attachments
+        } else {
+            &[]
+        };
+        if source.content.is_empty() && attachments.is_empty() {
+            warn!(
+                message_id = %target.message_id,
+                "Message has nothing to forward, skipping forward action"
+            );
+            return Ok(None);
+        }
+
+        let content = truncate_content(&source.content, &self.truncation_marker);
+
+        let message = if attachments.is_empty() {
+            self.retry_rate_limited(|| {
+                self.discord_service
+                    .send_message_to_channel(channel_id, &content, self.allow_mass_mentions(false))
+            })
+            .await
+        } else {
+            self.retry_rate_limited(|| {
+                self.discord_service.send_message_with_attachments(
+                    channel_id,
+                    &content,
+                    attachments,
+                    self.allow_mass_mentions(false),
+                )
+            })
+            .await
+        }
+        .context("Failed to forward message to Discord")?;
+
+        info!(
+            message_id = %target.message_id,
+            channel_id = %channel_id,
+            content_len = content.chars().count(),
+            attachments = attachments.len(),
+            "Successfully executed forward action"
+        );
+
+        Ok(Some(message))
+    }
+
+    /// Resolve the message an edit action applies to
+    ///
+    /// Returns `None` (after logging) when there is no message to edit.
+    fn edit_target(
+        target: &ActionTarget,
+        params: &EditParams,
+        last_sent: Option<(ChannelId, MessageId)>,
+    ) -> Option<(ChannelId, MessageId)> {
+        if params.message_id == LAST_MESSAGE {
+            if last_sent.is_none() {
+                warn!(
+                    message_id = %target.message_id,
+                    "No message sent earlier in this response, skipping edit action"
+                );
+            }
+            return last_sent;
+        }
+
+        let Ok(message_id) = params.message_id.parse::<MessageId>() else {
+            warn!(message_id = %params.message_id, "Invalid edit message_id, skipping edit action");
+            return None;
+        };
+        let channel_id = match &params.channel_id {
+            Some(channel_id) => match channel_id.parse::<ChannelId>() {
+                Ok(channel_id) => channel_id,
+                Err(_) => {
+                    warn!(channel_id = %channel_id, "Invalid edit channel_id, skipping edit action");
+                    return None;
+                }
+            },
+            None => target.channel_id,
+        };
+
+        Some((channel_id, message_id))
+    }
+
+    /// Whether `@everyone`/`@here` may ping for an action
+    ///
+    /// Always allowed when suppression is disabled; otherwise only if the action opts in.
+    fn allow_mass_mentions(&self, action_allows: bool) -> bool {
+        !self.actions.suppress_mass_mentions || action_allows
+    }
+
+    /// Execute ToggleRole action
+    ///
+    /// # Direction
+    /// - Reaction added: Adds `params.role_id` to the reacting user
+    /// - Reaction removed: Removes `params.role_id` from the reacting user
+    ///
+    /// Skipped with a warning outside guild reaction events (no direction or
+    /// user) and for an invalid `role_id`.
+    async fn execute_toggle_role(
+        &self,
+        target: &ActionTarget,
+        params: &ToggleRoleParams,
+    ) -> anyhow::Result<()> {
+        let (Some(guild_id), Some(user_id), Some(change)) =
+            (target.guild_id, target.trigger_user_id, target.reaction_change)
+        else {
+            warn!(
+                message_id = %target.message_id,
+                "toggle_role is only available for guild reaction events, skipping toggle_role action"
+            );
+            return Ok(());
+        };
+        let Ok(role_id) = params.role_id.parse::<RoleId>() else {
+            warn!(role_id = %params.role_id, "Invalid toggle_role role_id, skipping toggle_role action");
+            return Ok(());
+        };
+
+        match change {
+            ReactionChange::Added => self.retry_rate_limited(|| {
+                self.discord_service.add_member_role(guild_id, user_id, role_id)
+            })
+            .await
+            .context("Failed to add role to member")?,
+            ReactionChange::Removed => self.retry_rate_limited(|| {
+                self.discord_service.remove_member_role(guild_id, user_id, role_id)
+            })
+            .await
+            .context("Failed to remove role from member")?,
+        }
+
+        info!(
+            user_id = %user_id,
+            role_id = %role_id,
+            change = ?change,
+            "Successfully executed toggle_role action"
+        );
+
+        Ok(())
+    }
+
+    /// Execute SuppressEmbeds action
+    ///
+    /// Hides the link embeds of the target message. Discord rejects the edit
+    /// unless the message is the bot's own or the bot has Manage Messages.
+    async fn execute_suppress_embeds(&self, target: &ActionTarget) -> anyhow::Result<()> {
+        self.retry_rate_limited(|| {
+            self.discord_service.suppress_embeds(target.channel_id, target.message_id)
+        })
+        .await
+        .context("Failed to suppress message embeds")?;
+
+        info!(
+            message_id = %target.message_id,
+            "Successfully executed suppress_embeds action"
+        );
+
+        Ok(())
+    }
+
+    /// Execute RemoveAllReactions action
+    ///
+    /// Clears every reaction from the target message. Requires Manage
+    /// Messages, which does not exist in DMs, so DM targets are skipped.
+    async fn execute_remove_all_reactions(&self, target: &ActionTarget) -> anyhow::Result<()> {
+        if target.guild_id.is_none() {
+            warn!(
+                message_id = %target.message_id,
+                channel_id = %target.channel_id,
+                "Reactions cannot be cleared in DMs, skipping remove_all_reactions action"
+            );
+            return Ok(());
+        }
+
+        self.retry_rate_limited(|| {
+            self.discord_service.remove_all_reactions(target.channel_id, target.message_id)
+        })
+        .await
+        .context("Failed to remove all reactions")?;
+
+        info!(
+            message_id = %target.message_id,
+            "Successfully executed remove_all_reactions action"
+        );
+
+        Ok(())
+    }
+
+    /// Execute LeaveGuild action
+    ///
+    /// # Guild Resolution
+    /// - `params.guild_id`: Leave the given guild
+    /// - Otherwise: Leave the event's guild
+    /// - Skipped with a warning in DMs without `guild_id`, or for an invalid `guild_id`
+    ///
+    /// # Allowlist
+    /// - Only executed when `leave_guild` is listed in `ALLOWED_ACTIONS`
+    ///   (checked in `execute_action`)
+    async fn execute_leave_guild(
+        &self,
+        target: &ActionTarget,
+        params: &LeaveGuildParams,
+    ) -> anyhow::Result<()> {
+        let guild_id = match &params.guild_id {
+            Some(guild_id) => match guild_id.parse::<GuildId>() {
+                Ok(guild_id) => guild_id,
+                Err(_) => {
+                    warn!(guild_id = %guild_id, "Invalid leave_guild guild_id, skipping leave_guild action");
+                    return Ok(());
+                }
+            },
+            None => match target.guild_id {
+                Some(guild_id) => guild_id,
+                None => {
+                    warn!(
+                        message_id = %target.message_id,
+                        channel_id = %target.channel_id,
+                        "No guild context (DM) and no guild_id given, skipping leave_guild action"
+                    );
+                    return Ok(());
+                }
+            },
+        };
+
+        self.retry_rate_limited(|| self.discord_service.leave_guild(guild_id))
+            .await
+            .context("Failed to leave guild")?;
+
+        warn!(guild_id = %guild_id, "Left guild as requested by webhook response");
+
+        Ok(())
+    }
+
+    /// Execute JoinVoice action
+    ///
+    /// # Connection
+    /// - Sends a gateway voice state update joining `params.channel_id` in the
+    ///   event's guild; audio playback is left to a separate voice integration
+    /// - Skipped with a warning in DMs, or for an invalid `channel_id`
+    ///
+    /// # Allowlist
+    /// - Only executed when `join_voice` is listed in `ALLOWED_ACTIONS`
+    ///   (checked in `execute_action`)
+    async fn execute_join_voice(
+        &self,
+        target: &ActionTarget,
+        params: &JoinVoiceParams,
+    ) -> anyhow::Result<()> {
+        let Some(guild_id) = target.guild_id else {
+            warn!(
+                message_id = %target.message_id,
+                channel_id = %target.channel_id,
+                "Voice channels are not available in DMs, skipping join_voice action"
+            );
+            return Ok(());
+        };
+        let Ok(channel_id) = params.channel_id.parse::<ChannelId>() else {
+            warn!(channel_id = %params.channel_id, "Invalid join_voice channel_id, skipping join_voice action");
+            return Ok(());
+        };
+
+        self.discord_service
+            .join_voice_channel(guild_id, channel_id)
+            .await
+            .context("Failed to join voice channel")?;
+
+        info!(
+            guild_id = %guild_id,
+            channel_id = %channel_id,
+            "Successfully executed join_voice action"
+        );
+
+        Ok(())
+    }
+
+    /// Execute CreateChannel action
+    ///
+    /// # Channel
+    /// - Creates a `params.kind` channel named `params.name` in the event's
+    ///   guild, inside the `params.parent_id` category if given
+    /// - Skipped with a warning in DMs, or for an invalid `parent_id`
+    async fn execute_create_channel(
+        &self,
+        target: &ActionTarget,
+        params: &CreateChannelParams,
+    ) -> anyhow::Result<()> {
+        let Some(guild_id) = target.guild_id else {
+            warn!(
+                message_id = %target.message_id,
+                channel_id = %target.channel_id,
+                "Channels cannot be created in DMs, skipping create_channel action"
+            );
+            return Ok(());
+        };
+        let parent_id = match &params.parent_id {
+            Some(parent_id) => match parent_id.parse::<ChannelId>() {
+                Ok(parent_id) => Some(parent_id),
+                Err(_) => {
+                    warn!(parent_id = %parent_id, "Invalid create_channel parent_id, skipping create_channel action");
+                    return Ok(());
+                }
+            },
+            None => None,
+        };
+
+        let channel = self
+            .retry_rate_limited(|| {
+                self.discord_service.create_channel(
+                    guild_id,
+                    &params.name,
+                    params.kind.channel_type(),
+                    parent_id,
+                    params.topic.as_deref(),
+                )
+            })
+            .await
+            .context("Failed to create channel")?;
+
+        info!(
+            guild_id = %guild_id,
+            channel_id = %channel.id,
+            kind = ?params.kind,
+            "Successfully executed create_channel action"
+        );
+
+        Ok(())
+    }
+
+    /// Execute InteractionResponse action
+    ///
+    /// # Response
+    /// - `kind = deferred_update`: Acknowledges the interaction, leaving the message as is
+    /// - `kind = update`: Replaces the content of the component's message with
+    ///   `params.content` (truncated to 2000 characters)
+    /// - Skipped with a warning for non-interaction events, or `update` without content
+    ///
+    /// Discord only accepts one response per interaction, within 3 seconds of the click.
+    async fn execute_interaction_response(
+        &self,
+        target: &ActionTarget,
+        params: &InteractionResponseParams,
+    ) -> anyhow::Result<()> {
+        let Some(interaction) = &target.interaction else {
+            warn!(
+                message_id = %target.message_id,
+                "InteractionResponse is only available for interaction events, skipping interaction_response action"
+            );
+            return Ok(());
+        };
+
+        match params.kind {
+            InteractionResponseKind::DeferredUpdate => {
+                self.retry_rate_limited(|| {
+                    self.discord_service
+                        .acknowledge_interaction(interaction.id, &interaction.token)
+                })
+                .await
+                .context("Failed to acknowledge interaction")?;
+            }
+            InteractionResponseKind::Update => {
+                let Some(content) = &params.content else {
+                    warn!(
+                        interaction_id = %interaction.id,
+                        "Interaction update without content, skipping interaction_response action"
+                    );
+                    return Ok(());
+                };
+                let content = truncate_content(content, &self.truncation_marker);
+
+                self.retry_rate_limited(|| {
+                    self.discord_service.update_interaction_message(
+                        interaction.id,
+                        &interaction.token,
+                        &content,
+                        self.allow_mass_mentions(params.allow_mass_mentions),
+                    )
+                })
+                .await
+                .context("Failed to update interaction message")?;
+            }
+        }
+
+        info!(
+            interaction_id = %interaction.id,
+            message_id = %target.message_id,
+            kind = ?params.kind,
+            "Successfully executed interaction_response action"
+        );
+
+        Ok(())
+    }
+
+    /// Resolve the message a `thread`/`react` action applies to
+    ///
+    /// `message_id`/`channel_id` override the event target (`channel_id`
+    /// defaults to the target's channel). Returns `None` (after logging) when
+    /// the override IDs are invalid.
+    fn override_target(
+        target: &ActionTarget,
+        message_id: &Option<String>,
+        channel_id: &Option<String>,
+        action_type: &'static str,
+    ) -> Option<ActionTarget> {
+        let (message_id, channel_id) = match (message_id, channel_id) {
+            (None, None) => return Some(target.clone()),
+            (None, Some(_)) => {
+                warn!(action_type, "channel_id override requires message_id, skipping action");
+                return None;
+            }
+            (Some(message_id), channel_id) => (message_id, channel_id),
+        };
+
+        let Ok(message_id) = message_id.parse::<MessageId>() else {
+            warn!(action_type, message_id = %message_id, "Invalid message_id override, skipping action");
+            return None;
+        };
+        let channel_id = match channel_id {
+            Some(channel_id) => match channel_id.parse::<ChannelId>() {
+                Ok(channel_id) => channel_id,
+                Err(_) => {
+                    warn!(action_type, channel_id = %channel_id, "Invalid channel_id override, skipping action");
+                    return None;
+                }
+            },
+            None => target.channel_id,
+        };
+
+        Some(ActionTarget {
+            message_id,
+            channel_id,
+            ..target.clone()
+        })
+    }
+
+    /// Create a new thread or retrieve existing thread if error 160004 occurs
+    ///
+    /// Returns `None` without calling Discord when `max_threads_per_minute` is reached.
+    async fn create_or_get_thread(
+        &self,
+        target: &ActionTarget,
+        params: &ThreadParams,
+    ) -> anyhow::Result<Option<serenity::model::id::ChannelId>> {
+        if !self.actions.thread_rate_limiter.try_acquire() {
+            return Ok(None);
+        }
+
+        let thread_name = match &params.name {
+            Some(name) => truncate_thread_name(name),
+            None => "Thread".to_string(),
+        };
+
+        let rate_limit_per_user = params.rate_limit_per_user.map(|seconds| {
+            if seconds > MAX_RATE_LIMIT_PER_USER {
+                warn!(
+                    rate_limit_per_user = seconds,
+                    max = MAX_RATE_LIMIT_PER_USER,
+                    "Thread slowmode exceeds Discord's maximum, clamping"
+                );
+            }
+            seconds.min(MAX_RATE_LIMIT_PER_USER)
+        });
+
+        if params.private && self.supports_private_threads(target).await {
+            let thread = self.retry_rate_limited(|| {
+                self.discord_service.create_private_thread(
+                    target.channel_id,
+                    &thread_name,
+                    params.auto_archive_duration,
+                    params.invitable,
+                    rate_limit_per_user,
+                )
+            })
+            .await
+            .context("Failed to create private thread")?;
+            info!(
+                thread_id = %thread.id,
+                thread_name = %thread_name,
+                "Created new private thread"
+            );
+            return Ok(Some(thread.id));
+        }
+
+        // Try to create thread
+        match self.retry_rate_limited(|| {
+            self.discord_service.create_thread_from_message(
+                target.channel_id,
+                target.message_id,
+                &thread_name,
+                params.auto_archive_duration,
+                rate_limit_per_user,
+            )
+        })
+        .await
+        {
+            Ok(thread) => {
+                info!(
+                    thread_id = %thread.id,
+                    thread_name = %thread_name,
+                    "Created new thread"
+                );
+                Ok(Some(thread.id))
+            }
+            Err(serenity::Error::Http(ref http_error))
+                if Self::is_thread_already_exists_error(http_error) =>
+            {
+                // Thread already exists (error 160004) - retrieve it
+                info!("Thread already exists for this message, retrieving existing thread");
+                self.get_existing_thread_id(target).await.map(Some)
+            }
+            Err(e) => Err(e).context("Failed to create thread"),
+        }
+    }
+
+    /// Whether private threads can be created in the target channel
+    ///
+    /// Announcement channels only support public threads; falls back to a
+    /// public thread for them (with a warning). Unknown channels are assumed
+    /// to support private threads.
+    async fn supports_private_threads(&self, target: &ActionTarget) -> bool {
+        match self.channel_info.get_channel(target.guild_id, target.channel_id).await {
+            Ok(Some(channel)) if channel.kind == ChannelType::News => {
+                warn!(
+                    channel_id = %target.channel_id,
+                    "Private threads are not supported in announcement channels, creating public thread"
+                );
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// Retrieve existing thread ID from message
+    async fn get_existing_thread_id(
+        &self,
+        target: &ActionTarget,
+    ) -> anyhow::Result<serenity::model::id::ChannelId> {
+        let message = self
+            .discord_service
+            .get_message(target.channel_id, target.message_id)
+            .await
+            .context("Failed to get message to find existing thread")?;
+
+        let thread_id = message
+            .thread
+            .as_ref()
+            .map(|t| t.id)
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Thread creation failed with 160004 but message has no thread field"
+                )
+            })?;
+
+        info!(
+            thread_id = %thread_id,
+            "Found existing thread from message"
+        );
+        Ok(thread_id)
+    }
+
+    /// Check if HTTP error is "thread already exists" (error code 160004)
+    fn is_thread_already_exists_error(http_error: &serenity::http::HttpError) -> bool {
+        use serenity::http::HttpError;
+
+        matches!(
+            http_error,
+            HttpError::UnsuccessfulRequest(error_response) if error_response.error.code == 160004
+        )
+    }
+
+    /// Run a Discord call, retrying it up to `action_max_retries` times while rate limited
+    ///
+    /// Waits `RATE_LIMIT_RETRY_DELAY` before the first retry, doubling it for each
+    /// further one up to `MAX_RATE_LIMIT_RETRY_DELAY`. Any other error is
+    /// returned immediately.
+    ///
+    /// Discord's `retry_after` is not available here: serenity's ratelimiter
+    /// already sleeps for the `retry-after` header and retries itself, and its
+    /// `ErrorResponse` keeps only `code`, `message` and `errors` from the 429
+    /// body. A 429 reaching this loop therefore carries no usable delay, and the
+    /// backoff is the only wait.
+    async fn retry_rate_limited<T, F, Fut>(&self, mut call: F) -> Result<T, serenity::Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, serenity::Error>>,
+    {
+        let mut retries = 0;
+        loop {
+            match call().await {
+                Err(serenity::Error::Http(ref http_error))
+                    if retries < self.actions.action_max_retries && Self::is_rate_limited_error(http_error) =>
+                {
+                    let delay = Self::rate_limit_retry_delay(retries);
+                    retries += 1;
+                    warn!(
+                        retry = retries,
+                        max_retries = self.actions.action_max_retries,
+                        delay_ms = delay.as_millis() as u64,
+                        "Discord call rate limited, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Backoff before retry number `retries + 1`, doubling per retry and capped
+    fn rate_limit_retry_delay(retries: u32) -> Duration {
+        2u32.checked_pow(retries)
+            .and_then(|factor| RATE_LIMIT_RETRY_DELAY.checked_mul(factor))
+            .map_or(MAX_RATE_LIMIT_RETRY_DELAY, |delay| delay.min(MAX_RATE_LIMIT_RETRY_DELAY))
+    }
+
+    /// Check if HTTP error is a rate limit (status 429)
+    fn is_rate_limited_error(http_error: &serenity::http::HttpError) -> bool {
+        use serenity::http::{HttpError, StatusCode};
+
+        matches!(
+            http_error,
+            HttpError::UnsuccessfulRequest(error_response)
+                if error_response.status_code == StatusCode::TOO_MANY_REQUESTS
+        )
+    }
+}
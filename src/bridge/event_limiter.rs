@@ -0,0 +1,90 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Caps the number of events processed at the same time
+///
+/// Serenity runs every gateway event in its own task, so a reaction storm
+/// means unbounded concurrent handlers (and webhook calls). Each event takes
+/// a permit before processing; when all `max_concurrent_events` permits are
+/// held, new events are shed instead of queued. A maximum of 0 disables the
+/// limit. Shed events are counted over the process lifetime.
+pub struct EventLimiter {
+    permits: Option<Arc<Semaphore>>,
+    shed: AtomicU64,
+}
+
+/// Permit for one event in progress, released when dropped
+pub struct EventPermit {
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl EventLimiter {
+    /// Create a new EventLimiter allowing `max_concurrent_events` events at once (0 = unlimited)
+    pub fn new(max_concurrent_events: usize) -> Self {
+        Self {
+            permits: (max_concurrent_events > 0).then(|| Arc::new(Semaphore::new(max_concurrent_events))),
+            shed: AtomicU64::new(0),
+        }
+    }
+
+    /// Take a permit, returning `None` (counted as shed) when all are in use
+    pub fn try_acquire(&self) -> Option<EventPermit> {
+        let Some(permits) = &self.permits else {
+            return Some(EventPermit { _permit: None });
+        };
+
+        match permits.clone().try_acquire_owned() {
+            Ok(permit) => Some(EventPermit { _permit: Some(permit) }),
+            Err(_) => {
+                self.shed.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Number of events shed so far
+    pub fn shed(&self) -> u64 {
+        self.shed.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sheds_when_saturated() {
+        let limiter = EventLimiter::new(2);
+
+        let first = limiter.try_acquire();
+        let second = limiter.try_acquire();
+
+        assert!(first.is_some() && second.is_some());
+        assert!(limiter.try_acquire().is_none());
+        assert!(limiter.try_acquire().is_none());
+        assert_eq!(limiter.shed(), 2);
+    }
+
+    #[test]
+    fn test_dropped_permit_frees_slot() {
+        let limiter = EventLimiter::new(1);
+
+        let permit = limiter.try_acquire();
+        assert!(limiter.try_acquire().is_none());
+        drop(permit);
+
+        assert!(limiter.try_acquire().is_some());
+        assert_eq!(limiter.shed(), 1);
+    }
+
+    #[test]
+    fn test_zero_is_unlimited() {
+        let limiter = EventLimiter::new(0);
+
+        let permits: Vec<_> = (0..1_000).map(|_| limiter.try_acquire()).collect();
+
+        assert!(permits.iter().all(Option::is_some));
+        assert_eq!(limiter.shed(), 0);
+    }
+}
@@ -0,0 +1,89 @@
+use rand::rngs::StdRng;
+use rand::{Rng as _, SeedableRng as _};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Forwards only a random fraction of events per handler
+///
+/// Handlers without a configured rate are always forwarded. Rates are
+/// clamped to `0.0..=1.0`; `0.0` drops every event and `1.0` keeps every event.
+pub struct EventSampler {
+    rates: HashMap<String, f64>,
+    rng: Mutex<StdRng>,
+}
+
+impl EventSampler {
+    /// Create a new EventSampler seeded from the OS
+    pub fn new() -> Self {
+        Self {
+            rates: HashMap::new(),
+            rng: Mutex::new(StdRng::from_os_rng()),
+        }
+    }
+
+    /// Set the sampling rate for a handler (e.g. `"message"`)
+    pub fn set_rate(&mut self, handler: &str, rate: f64) {
+        self.rates.insert(handler.to_string(), rate.clamp(0.0, 1.0));
+    }
+
+    /// Reseed the RNG (reproducible sampling for tests)
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+    }
+
+    /// Decide whether an event for `handler` should be forwarded
+    pub fn should_forward(&self, handler: &str) -> bool {
+        match self.rates.get(handler) {
+            None => true,
+            Some(&rate) if rate >= 1.0 => true,
+            Some(&rate) if rate <= 0.0 => false,
+            Some(&rate) => self.rng.lock().unwrap().random_bool(rate),
+        }
+    }
+}
+
+impl Default for EventSampler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sampler(rate: f64, seed: u64) -> EventSampler {
+        let mut sampler = EventSampler::new();
+        sampler.set_rate("message", rate);
+        sampler.set_seed(seed);
+        sampler
+    }
+
+    fn forwarded(sampler: &EventSampler, events: usize) -> usize {
+        (0..events).filter(|_| sampler.should_forward("message")).count()
+    }
+
+    #[test]
+    fn test_unconfigured_handler_always_forwarded() {
+        let sampler = sampler(0.0, 1);
+
+        assert_eq!((0..100).filter(|_| sampler.should_forward("reaction_add")).count(), 100);
+    }
+
+    #[test]
+    fn test_rate_bounds() {
+        assert_eq!(forwarded(&sampler(0.0, 1), 100), 0);
+        assert_eq!(forwarded(&sampler(1.0, 1), 100), 100);
+        assert_eq!(forwarded(&sampler(-1.0, 1), 100), 0, "Negative rate clamps to 0");
+        assert_eq!(forwarded(&sampler(2.0, 1), 100), 100, "Rate above 1 clamps to 1");
+    }
+
+    #[test]
+    fn test_fractional_rate_is_reproducible_with_seed() {
+        let first = forwarded(&sampler(0.25, 42), 1000);
+        let second = forwarded(&sampler(0.25, 42), 1000);
+
+        assert_eq!(first, second);
+        assert!((150..350).contains(&first), "Expected roughly 25%, got {first}");
+    }
+}
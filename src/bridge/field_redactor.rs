@@ -0,0 +1,90 @@
+use serde_json::Value;
+
+/// Null out fields of a serialized payload by dotted path
+///
+/// Paths start at the payload root (e.g. `message.author.username`). An
+/// array along the path applies the rest of the path to every element, so
+/// `mentions.name` redacts each mentioned user's name. Missing fields are
+/// left alone rather than added as `null`.
+pub fn redact_fields(value: &mut Value, paths: &[String]) {
+    for path in paths {
+        let segments: Vec<&str> = path.split('.').collect();
+        redact_path(value, &segments);
+    }
+}
+
+fn redact_path(value: &mut Value, segments: &[&str]) {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                redact_path(item, segments);
+            }
+        }
+        Value::Object(fields) => {
+            let Some((first, rest)) = segments.split_first() else {
+                return;
+            };
+            let Some(field) = fields.get_mut(*first) else {
+                return;
+            };
+            if rest.is_empty() {
+                *field = Value::Null;
+            } else {
+                redact_path(field, rest);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn paths(paths: &[&str]) -> Vec<String> {
+        paths.iter().map(|path| path.to_string()).collect()
+    }
+
+    #[test]
+    fn test_redacts_nested_field() {
+        let mut value = json!({"message": {"author": {"id": "1", "username": "alice"}, "content": "hi"}});
+
+        redact_fields(&mut value, &paths(&["message.author.username"]));
+
+        assert_eq!(
+            value,
+            json!({"message": {"author": {"id": "1", "username": null}, "content": "hi"}})
+        );
+    }
+
+    #[test]
+    fn test_redacts_every_array_element() {
+        let mut value = json!({"mentions": [{"id": "1", "name": "a"}, {"id": "2", "name": "b"}]});
+
+        redact_fields(&mut value, &paths(&["mentions.name"]));
+
+        assert_eq!(
+            value,
+            json!({"mentions": [{"id": "1", "name": null}, {"id": "2", "name": null}]})
+        );
+    }
+
+    #[test]
+    fn test_missing_path_is_ignored() {
+        let mut value = json!({"message": {"content": "hi"}});
+
+        redact_fields(&mut value, &paths(&["message.author.username", "message.content.len", "url"]));
+
+        assert_eq!(value, json!({"message": {"content": "hi"}}));
+    }
+
+    #[test]
+    fn test_redacts_whole_object() {
+        let mut value = json!({"message": {"content": "hi"}, "channel": {"name": "general"}});
+
+        redact_fields(&mut value, &paths(&["channel"]));
+
+        assert_eq!(value, json!({"message": {"content": "hi"}, "channel": null}));
+    }
+}
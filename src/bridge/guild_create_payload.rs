@@ -0,0 +1,100 @@
+use serde::Serialize;
+use serenity::model::guild::Guild;
+use serenity::model::id::GuildId;
+
+/// Payload for GUILD_CREATE events
+///
+/// A summary of the guild suitable for server directories, sent when the bot
+/// joins a guild or a guild becomes available on startup.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "id": "...",
+///   "name": "...",
+///   "icon_url": "https://cdn.discordapp.com/icons/...",  // optional
+///   "approximate_member_count": 42
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct GuildCreatePayload {
+    /// ID of the guild
+    pub id: GuildId,
+
+    /// Name of the guild
+    pub name: String,
+
+    /// CDN URL of the guild icon, omitted when the guild has no icon
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+
+    /// Approximate number of members in the guild
+    ///
+    /// Discord's `approximate_member_count` when present, otherwise the
+    /// `member_count` sent with GUILD_CREATE.
+    pub approximate_member_count: u64,
+}
+
+impl GuildCreatePayload {
+    /// Create a new GuildCreatePayload
+    ///
+    /// # Arguments
+    ///
+    /// * `guild` - The guild received with the guild_create event
+    pub fn new(guild: &Guild) -> Self {
+        Self {
+            id: guild.id,
+            name: guild.name.clone(),
+            icon_url: guild.icon_url(),
+            approximate_member_count: guild.approximate_member_count.unwrap_or(guild.member_count),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_guild(icon: Option<&str>) -> Guild {
+        let mut guild = Guild::default();
+        guild.id = GuildId::new(777);
+        guild.name = "Test Guild".to_string();
+        guild.icon = icon.map(|hash| hash.parse().unwrap());
+        guild.member_count = 42;
+        guild
+    }
+
+    #[test]
+    fn test_serialize_guild_create_payload() {
+        let guild = create_guild(Some("0123456789abcdef0123456789abcdef"));
+
+        let json = serde_json::to_value(GuildCreatePayload::new(&guild)).unwrap();
+
+        assert_eq!(json["id"], "777");
+        assert_eq!(json["name"], "Test Guild");
+        assert_eq!(
+            json["icon_url"],
+            "https://cdn.discordapp.com/icons/777/0123456789abcdef0123456789abcdef.webp"
+        );
+        assert_eq!(json["approximate_member_count"], 42);
+    }
+
+    #[test]
+    fn test_without_icon_omits_icon_url() {
+        let guild = create_guild(None);
+
+        let json = serde_json::to_value(GuildCreatePayload::new(&guild)).unwrap();
+
+        assert!(json.get("icon_url").is_none());
+    }
+
+    #[test]
+    fn test_prefers_approximate_member_count() {
+        let mut guild = create_guild(None);
+        guild.approximate_member_count = Some(100);
+
+        let payload = GuildCreatePayload::new(&guild);
+
+        assert_eq!(payload.approximate_member_count, 100);
+    }
+}
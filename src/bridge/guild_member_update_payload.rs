@@ -0,0 +1,191 @@
+use serde::Serialize;
+use serenity::model::event::GuildMemberUpdateEvent;
+use serenity::model::guild::Member;
+use serenity::model::id::{GuildId, RoleId};
+use serenity::model::user::User;
+
+/// Payload for GUILD_MEMBER_UPDATE events
+///
+/// Carries the member's state after the update and, when the previous state
+/// was cached, the state before it along with the role diff.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "guild_id": "...",
+///   "user": { /* User fields */ },
+///   "before": { "roles": ["..."], "nick": "..." },  // optional (cache hit only)
+///   "after": { "roles": ["..."], "nick": "..." },
+///   "roles_added": ["..."],                         // optional
+///   "roles_removed": ["..."]                        // optional
+/// }
+/// ```
+#[derive(Serialize)]
+pub struct GuildMemberUpdatePayload<'a> {
+    /// Guild the member belongs to
+    pub guild_id: GuildId,
+
+    /// The updated member's user
+    pub user: &'a User,
+
+    /// Member state before the update
+    ///
+    /// `None` when the member was not in the cache before the update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<MemberState>,
+
+    /// Member state after the update
+    pub after: MemberState,
+
+    /// Roles present after but not before the update, omitted when empty
+    ///
+    /// Only computed when `before` is available.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub roles_added: Vec<RoleId>,
+
+    /// Roles present before but not after the update, omitted when empty
+    ///
+    /// Only computed when `before` is available.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub roles_removed: Vec<RoleId>,
+}
+
+/// Role and nickname snapshot of a guild member
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MemberState {
+    /// Role IDs held by the member
+    pub roles: Vec<RoleId>,
+    /// Guild nickname (`null` when unset)
+    pub nick: Option<String>,
+}
+
+impl<'a> GuildMemberUpdatePayload<'a> {
+    /// Create a new GuildMemberUpdatePayload
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The member update event (state after the update)
+    /// * `old` - The cached member before the update, if available
+    pub fn new(event: &'a GuildMemberUpdateEvent, old: Option<&Member>) -> Self {
+        let after = MemberState {
+            roles: event.roles.clone(),
+            nick: event.nick.clone(),
+        };
+        let before = old.map(|member| MemberState {
+            roles: member.roles.clone(),
+            nick: member.nick.clone(),
+        });
+
+        let (roles_added, roles_removed) = match &before {
+            Some(before) => (
+                Self::roles_missing_from(&after.roles, &before.roles),
+                Self::roles_missing_from(&before.roles, &after.roles),
+            ),
+            None => (Vec::new(), Vec::new()),
+        };
+
+        Self {
+            guild_id: event.guild_id,
+            user: &event.user,
+            before,
+            after,
+            roles_added,
+            roles_removed,
+        }
+    }
+
+    /// Roles in `roles` that are not in `other` (order preserved)
+    fn roles_missing_from(roles: &[RoleId], other: &[RoleId]) -> Vec<RoleId> {
+        roles
+            .iter()
+            .filter(|role| !other.contains(role))
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_event(roles: &[&str], nick: Option<&str>) -> GuildMemberUpdateEvent {
+        serde_json::from_value(json!({
+            "guild_id": "777",
+            "nick": nick,
+            "joined_at": "2024-01-01T00:00:00Z",
+            "roles": roles,
+            "user": {"id": "555", "username": "alice", "discriminator": "0000", "avatar": null},
+            "premium_since": null,
+            "avatar": null,
+            "communication_disabled_until": null,
+            "unusual_dm_activity_until": null
+        }))
+        .unwrap()
+    }
+
+    fn create_member(roles: &[&str], nick: Option<&str>) -> Member {
+        serde_json::from_value(json!({
+            "guild_id": "777",
+            "nick": nick,
+            "joined_at": "2024-01-01T00:00:00Z",
+            "roles": roles,
+            "user": {"id": "555", "username": "alice", "discriminator": "0000", "avatar": null},
+            "deaf": false,
+            "mute": false,
+            "flags": 0
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_roles_added_and_removed() {
+        let event = create_event(&["1", "3"], Some("new"));
+        let old = create_member(&["1", "2"], Some("old"));
+
+        let payload = GuildMemberUpdatePayload::new(&event, Some(&old));
+
+        assert_eq!(payload.roles_added, vec![RoleId::new(3)]);
+        assert_eq!(payload.roles_removed, vec![RoleId::new(2)]);
+    }
+
+    #[test]
+    fn test_serialize_with_before() {
+        let event = create_event(&["1", "3"], Some("new"));
+        let old = create_member(&["1", "2"], Some("old"));
+
+        let json = serde_json::to_value(GuildMemberUpdatePayload::new(&event, Some(&old))).unwrap();
+
+        assert_eq!(json["guild_id"], "777");
+        assert_eq!(json["user"]["id"], "555");
+        assert_eq!(json["before"], json!({"roles": ["1", "2"], "nick": "old"}));
+        assert_eq!(json["after"], json!({"roles": ["1", "3"], "nick": "new"}));
+        assert_eq!(json["roles_added"], json!(["3"]));
+        assert_eq!(json["roles_removed"], json!(["2"]));
+    }
+
+    #[test]
+    fn test_nick_change_only_omits_role_diff() {
+        let event = create_event(&["1"], None);
+        let old = create_member(&["1"], Some("old"));
+
+        let json = serde_json::to_value(GuildMemberUpdatePayload::new(&event, Some(&old))).unwrap();
+
+        assert_eq!(json["before"]["nick"], "old");
+        assert!(json["after"]["nick"].is_null());
+        assert!(json.get("roles_added").is_none());
+        assert!(json.get("roles_removed").is_none());
+    }
+
+    #[test]
+    fn test_without_before_omits_before_and_diff() {
+        let event = create_event(&["1", "3"], Some("new"));
+
+        let json = serde_json::to_value(GuildMemberUpdatePayload::new(&event, None)).unwrap();
+
+        assert!(json.get("before").is_none());
+        assert_eq!(json["after"]["roles"], json!(["1", "3"]));
+        assert!(json.get("roles_added").is_none());
+        assert!(json.get("roles_removed").is_none());
+    }
+}
@@ -0,0 +1,147 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use tokio::time::Instant;
+
+/// Liveness state reported by heartbeat events
+///
+/// Tracks uptime, the gateway connection state of each shard and the
+/// number of events forwarded per handler since startup.
+pub struct HeartbeatStats {
+    started_at: Instant,
+    shards: Mutex<BTreeMap<u32, bool>>,
+    event_counts: Mutex<BTreeMap<String, u64>>,
+}
+
+impl HeartbeatStats {
+    /// Create a new HeartbeatStats, starting the uptime clock now
+    pub fn new() -> Self {
+        Self {
+            started_at: Instant::now(),
+            shards: Mutex::new(BTreeMap::new()),
+            event_counts: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Record the gateway connection state of shard `shard_id`
+    pub fn set_connected(&self, shard_id: u32, connected: bool) {
+        self.shards.lock().unwrap().insert(shard_id, connected);
+    }
+
+    /// Whether a shard has connected and none is currently disconnected
+    fn connected(&self) -> bool {
+        let shards = self.shards.lock().unwrap();
+        !shards.is_empty() && shards.values().all(|connected| *connected)
+    }
+
+    /// Count an event forwarded by `handler`
+    pub fn record(&self, handler: &str) {
+        *self
+            .event_counts
+            .lock()
+            .unwrap()
+            .entry(handler.to_string())
+            .or_default() += 1;
+    }
+
+    /// Current state as a heartbeat payload
+    pub fn snapshot(&self) -> HeartbeatPayload {
+        HeartbeatPayload {
+            heartbeat: Heartbeat {
+                uptime_secs: self.started_at.elapsed().as_secs(),
+                connected: self.connected(),
+                event_counts: self.event_counts.lock().unwrap().clone(),
+            },
+        }
+    }
+}
+
+impl Default for HeartbeatStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Payload for heartbeat events sent to webhook
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "heartbeat": {
+///     "uptime_secs": 3600,
+///     "connected": true,
+///     "event_counts": { "message": 42, "reaction_add": 7 }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct HeartbeatPayload {
+    /// Liveness state
+    pub heartbeat: Heartbeat,
+}
+
+/// Liveness state at the time of a heartbeat
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Heartbeat {
+    /// Seconds since the bridge was created
+    pub uptime_secs: u64,
+    /// Whether the gateway connection is up on every shard
+    pub connected: bool,
+    /// Events forwarded per handler since startup (heartbeats excluded)
+    pub event_counts: BTreeMap<String, u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_snapshot() {
+        let stats = HeartbeatStats::new();
+        stats.set_connected(0, true);
+        stats.record("message");
+        stats.record("message");
+        stats.record("reaction_add");
+
+        tokio::time::advance(Duration::from_secs(90)).await;
+
+        let json = serde_json::to_value(stats.snapshot()).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "heartbeat": {
+                    "uptime_secs": 90,
+                    "connected": true,
+                    "event_counts": {"message": 2, "reaction_add": 1}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_initial_state() {
+        let stats = HeartbeatStats::new();
+
+        let heartbeat = stats.snapshot().heartbeat;
+
+        assert!(!heartbeat.connected);
+        assert!(heartbeat.event_counts.is_empty());
+    }
+
+    #[test]
+    fn test_connected_requires_every_shard() {
+        let stats = HeartbeatStats::new();
+        stats.set_connected(0, true);
+        stats.set_connected(1, true);
+
+        // Shard 1 dropping is not hidden by shard 0 staying up
+        stats.set_connected(1, false);
+        assert!(!stats.snapshot().heartbeat.connected);
+
+        stats.set_connected(1, true);
+        assert!(stats.snapshot().heartbeat.connected);
+    }
+}
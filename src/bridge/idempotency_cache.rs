@@ -0,0 +1,151 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Maximum number of idempotency keys remembered at once
+///
+/// The oldest keys are evicted first once full, so a flood of unique keys
+/// cannot grow memory without bound.
+pub const MAX_IDEMPOTENCY_KEYS: usize = 4096;
+
+/// Skips actions whose `idempotency_key` was already executed within a window
+///
+/// Protects against double-acting when a retried webhook delivery returns
+/// the same actions again. Unlike event dedup this works per action, keyed
+/// by the webhook-chosen key; a zero window disables it.
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+pub struct IdempotencyCache {
+    window: Duration,
+    capacity: usize,
+    seen: Mutex<SeenKeys>,
+}
+
+/// Recently executed keys, oldest first
+#[derive(Default)]
+struct SeenKeys {
+    order: VecDeque<(String, Instant)>,
+    keys: HashSet<String>,
+}
+
+impl SeenKeys {
+    fn evict_oldest(&mut self) {
+        if let Some((oldest, _)) = self.order.pop_front() {
+            self.keys.remove(&oldest);
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+impl IdempotencyCache {
+    /// Create a new IdempotencyCache (`Duration::ZERO` disables it)
+    pub fn new(window: Duration) -> Self {
+        Self::with_capacity(window, MAX_IDEMPOTENCY_KEYS)
+    }
+
+    fn with_capacity(window: Duration, capacity: usize) -> Self {
+        Self {
+            window,
+            capacity,
+            seen: Mutex::new(SeenKeys::default()),
+        }
+    }
+
+    /// Record an action's key, returning `false` if it was already executed within the window
+    ///
+    /// The key is claimed before the action runs so concurrent duplicates are
+    /// skipped; `release()` it if the action ends up not executing.
+    pub fn try_acquire(&self, key: &str) -> bool {
+        self.try_acquire_at(key, Instant::now())
+    }
+
+    fn try_acquire_at(&self, key: &str, now: Instant) -> bool {
+        if self.window.is_zero() {
+            return true;
+        }
+
+        let mut seen = self.seen.lock().unwrap();
+        // Drop expired keys, oldest first
+        while seen
+            .order
+            .front()
+            .is_some_and(|(_, executed_at)| now.saturating_duration_since(*executed_at) >= self.window)
+        {
+            seen.evict_oldest();
+        }
+
+        if seen.keys.contains(key) {
+            return false;
+        }
+        while seen.order.len() >= self.capacity {
+            seen.evict_oldest();
+        }
+        seen.keys.insert(key.to_string());
+        seen.order.push_back((key.to_string(), now));
+        true
+    }
+
+    /// Forget a key whose action was skipped or failed, so a retried delivery executes it
+    pub fn release(&self, key: &str) {
+        let mut seen = self.seen.lock().unwrap();
+        if seen.keys.remove(key) {
+            seen.order.retain(|(seen_key, _)| seen_key != key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_key_within_window_is_rejected() {
+        let cache = IdempotencyCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(cache.try_acquire_at("order-42", now));
+        assert!(!cache.try_acquire_at("order-42", now + Duration::from_secs(59)));
+        assert!(cache.try_acquire_at("order-43", now + Duration::from_secs(59)));
+    }
+
+    #[test]
+    fn test_key_expires_after_window() {
+        let cache = IdempotencyCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(cache.try_acquire_at("order-42", now));
+        assert!(cache.try_acquire_at("order-42", now + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_oldest_key_is_evicted_when_full() {
+        let cache = IdempotencyCache::with_capacity(Duration::from_secs(60), 2);
+        let now = Instant::now();
+
+        assert!(cache.try_acquire_at("a", now));
+        assert!(cache.try_acquire_at("b", now));
+        assert!(cache.try_acquire_at("c", now));
+
+        assert!(cache.try_acquire_at("a", now));
+        assert!(!cache.try_acquire_at("c", now));
+    }
+
+    #[test]
+    fn test_released_key_can_be_acquired_again() {
+        let cache = IdempotencyCache::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        assert!(cache.try_acquire_at("order-42", now));
+        cache.release("order-42");
+
+        assert!(cache.try_acquire_at("order-42", now));
+        assert!(!cache.try_acquire_at("order-42", now));
+    }
+
+    #[test]
+    fn test_zero_window_disables_cache() {
+        let cache = IdempotencyCache::new(Duration::ZERO);
+
+        assert!(cache.try_acquire("order-42"));
+        assert!(cache.try_acquire("order-42"));
+    }
+}
@@ -0,0 +1,240 @@
+use serde::Serialize;
+use serenity::model::application::{
+    ActionRow, ActionRowComponent, Button, ButtonKind, ButtonStyle, ComponentType, SelectMenu,
+};
+
+/// Summary of one component of a message's action row
+///
+/// A flattened, readable view of `message.components`, so consumers can
+/// react to buttons and select menus posted by other bots without parsing
+/// Discord's raw component objects.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "kind": "button",            // button, string_select, user_select, role_select,
+///                                // mentionable_select, channel_select, input_text
+///   "custom_id": "approve",      // optional (absent on link buttons)
+///   "label": "Approve",          // optional
+///   "style": "success",          // optional (buttons only)
+///   "url": "https://...",        // optional (link buttons only)
+///   "placeholder": "Pick one",   // optional (select menus only)
+///   "options": [{ "label": "...", "value": "..." }], // optional (string selects only)
+///   "disabled": true             // optional (only when true)
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ComponentSummary {
+    /// Readable component type
+    pub kind: &'static str,
+
+    /// Developer-defined ID sent back when the component is used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+
+    /// Text shown on the component
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Button style (`primary`, `secondary`, `success`, `danger`, `link`, `premium`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<&'static str>,
+
+    /// Target of a link button
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// Placeholder of a select menu
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+
+    /// Choices of a string select menu, omitted when empty
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<SelectOptionSummary>,
+
+    /// Whether the component is disabled, omitted unless true
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+}
+
+impl ComponentSummary {
+    /// Summary carrying only the component kind
+    fn bare(kind: &'static str) -> Self {
+        Self {
+            kind,
+            custom_id: None,
+            label: None,
+            style: None,
+            url: None,
+            placeholder: None,
+            options: Vec::new(),
+            disabled: false,
+        }
+    }
+}
+
+/// Choice of a string select menu
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct SelectOptionSummary {
+    /// Text shown for the choice
+    pub label: String,
+    /// Value sent back when the choice is selected
+    pub value: String,
+}
+
+/// Summarize action rows, one inner list per row
+///
+/// Rows without components are skipped.
+pub fn summarize_components(rows: &[ActionRow]) -> Vec<Vec<ComponentSummary>> {
+    rows.iter()
+        .map(|row| row.components.iter().map(summarize_component).collect::<Vec<_>>())
+        .filter(|row| !row.is_empty())
+        .collect()
+}
+
+fn summarize_component(component: &ActionRowComponent) -> ComponentSummary {
+    match component {
+        ActionRowComponent::Button(button) => summarize_button(button),
+        ActionRowComponent::SelectMenu(menu) => summarize_select_menu(menu),
+        ActionRowComponent::InputText(input) => ComponentSummary {
+            custom_id: Some(input.custom_id.clone()),
+            label: input.label.clone(),
+            placeholder: input.placeholder.clone(),
+            ..ComponentSummary::bare(component_kind(input.kind))
+        },
+        _ => ComponentSummary::bare("unknown"),
+    }
+}
+
+fn summarize_button(button: &Button) -> ComponentSummary {
+    let (style, custom_id, url) = match &button.data {
+        ButtonKind::Link { url } => ("link", None, Some(url.clone())),
+        ButtonKind::Premium { .. } => ("premium", None, None),
+        ButtonKind::NonLink { custom_id, style } => (button_style(*style), Some(custom_id.clone()), None),
+    };
+    ComponentSummary {
+        custom_id,
+        label: button.label.clone(),
+        style: Some(style),
+        url,
+        disabled: button.disabled,
+        ..ComponentSummary::bare(component_kind(ComponentType::Button))
+    }
+}
+
+fn summarize_select_menu(menu: &SelectMenu) -> ComponentSummary {
+    ComponentSummary {
+        custom_id: menu.custom_id.clone(),
+        placeholder: menu.placeholder.clone(),
+        options: menu
+            .options
+            .iter()
+            .map(|option| SelectOptionSummary {
+                label: option.label.clone(),
+                value: option.value.clone(),
+            })
+            .collect(),
+        disabled: menu.disabled,
+        ..ComponentSummary::bare(component_kind(menu.kind))
+    }
+}
+
+/// Readable name of a component type
+fn component_kind(kind: ComponentType) -> &'static str {
+    match kind {
+        ComponentType::ActionRow => "action_row",
+        ComponentType::Button => "button",
+        ComponentType::StringSelect => "string_select",
+        ComponentType::InputText => "input_text",
+        ComponentType::UserSelect => "user_select",
+        ComponentType::RoleSelect => "role_select",
+        ComponentType::MentionableSelect => "mentionable_select",
+        ComponentType::ChannelSelect => "channel_select",
+        _ => "unknown",
+    }
+}
+
+/// Readable name of a (non-link) button style
+fn button_style(style: ButtonStyle) -> &'static str {
+    match style {
+        ButtonStyle::Primary => "primary",
+        ButtonStyle::Secondary => "secondary",
+        ButtonStyle::Success => "success",
+        ButtonStyle::Danger => "danger",
+        _ => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rows(value: serde_json::Value) -> Vec<ActionRow> {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_summarize_button_row() {
+        let rows = rows(json!([{
+            "type": 1,
+            "components": [
+                {"type": 2, "style": 3, "label": "Approve", "custom_id": "approve"},
+                {"type": 2, "style": 4, "label": "Reject", "custom_id": "reject", "disabled": true},
+                {"type": 2, "style": 5, "label": "Docs", "url": "https://example.com/docs"}
+            ]
+        }]));
+
+        let json = serde_json::to_value(summarize_components(&rows)).unwrap();
+
+        assert_eq!(
+            json,
+            json!([[
+                {"kind": "button", "custom_id": "approve", "label": "Approve", "style": "success"},
+                {"kind": "button", "custom_id": "reject", "label": "Reject", "style": "danger", "disabled": true},
+                {"kind": "button", "label": "Docs", "style": "link", "url": "https://example.com/docs"}
+            ]])
+        );
+    }
+
+    #[test]
+    fn test_summarize_select_menus() {
+        let rows = rows(json!([
+            {
+                "type": 1,
+                "components": [{
+                    "type": 3,
+                    "custom_id": "color",
+                    "placeholder": "Pick a color",
+                    "options": [
+                        {"label": "Red", "value": "red"},
+                        {"label": "Blue", "value": "blue", "description": "Calm"}
+                    ]
+                }]
+            },
+            {"type": 1, "components": [{"type": 6, "custom_id": "roles"}]}
+        ]));
+
+        let json = serde_json::to_value(summarize_components(&rows)).unwrap();
+
+        assert_eq!(
+            json,
+            json!([
+                [{
+                    "kind": "string_select",
+                    "custom_id": "color",
+                    "placeholder": "Pick a color",
+                    "options": [{"label": "Red", "value": "red"}, {"label": "Blue", "value": "blue"}]
+                }],
+                [{"kind": "role_select", "custom_id": "roles"}]
+            ])
+        );
+    }
+
+    #[test]
+    fn test_summarize_skips_empty_rows() {
+        let rows = rows(json!([{"type": 1, "components": []}]));
+
+        assert!(summarize_components(&rows).is_empty());
+    }
+}
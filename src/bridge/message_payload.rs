@@ -1,5 +1,22 @@
+use crate::adapters::GuildInfo;
+use crate::bridge::message_components::{ComponentSummary, summarize_components};
 use serde::Serialize;
-use serenity::model::channel::{GuildChannel, Message};
+use serde_json::Value;
+use serenity::model::application::{InteractionType, MessageInteraction};
+use serenity::model::channel::{Attachment, GuildChannel, Message, MessageType};
+use serenity::model::id::{AttachmentId, ChannelId, InteractionId, RoleId, UserId, WebhookId};
+use std::borrow::Cow;
+use tracing::warn;
+
+/// Maximum number of `message.embeds` entries kept in the payload
+///
+/// Matches Discord's per-message limit, so only malformed messages are cut.
+pub const MAX_PAYLOAD_EMBEDS: usize = 10;
+
+/// Maximum number of `message.attachments` entries kept in the payload
+///
+/// Matches Discord's per-message limit, so only malformed messages are cut.
+pub const MAX_PAYLOAD_ATTACHMENTS: usize = 10;
 
 /// Payload for message events sent to webhook
 ///
@@ -10,13 +27,67 @@ use serenity::model::channel::{GuildChannel, Message};
 /// ```json
 /// {
 ///   "message": { /* Discord Message fields */ },
-///   "channel": { /* GuildChannel fields (optional) */ }
+///   "url": "https://discord.com/channels/.../.../...",
+///   "author_avatar_url": "https://cdn.discordapp.com/...",
+///   "pinned": false,
+///   "content_available": true,
+///   "tts": true,                                    // optional (only when true)
+///   "content_truncated": true,                      // optional (only when true)
+///   "channel": { /* GuildChannel fields (optional) */ },
+///   "channel_kind": "text",                         // optional
+///   "guild_system_channel_id": "...",               // optional (cached guilds)
+///   "guild_rules_channel_id": "...",                // optional (cached guilds)
+///   "webhook": { "id": "...", "name": "..." },      // optional (webhook messages)
+///   "dm_recipient": { "id": "...", "name": "..." }, // optional (direct messages)
+///   "dm_channel": { "id": "...", "kind": "dm" },    // optional (direct messages)
+///   "mentions": [{ "id": "...", "name": "..." }],   // optional
+///   "mention_roles": ["..."],                       // optional
+///   "member_role_names": ["..."],                   // optional (cached guilds)
+///   "mention_channels": ["..."],                    // optional
+///   "reply_chain": [{ /* Message */ }],             // optional (newest first)
+///   "components": [[{ "kind": "button", ... }]],    // optional (one list per row)
+///   "attachments": [{ "id": "...", "is_spoiler": false, ... }], // optional
+///   "interaction": { "name": "ping", "user": { ... }, ... }     // optional (command replies)
 /// }
 /// ```
 #[derive(Serialize)]
 pub struct MessagePayload<'a> {
     /// The original Discord message
-    pub message: &'a Message,
+    ///
+    /// `embeds` and `attachments` are cut to `MAX_PAYLOAD_EMBEDS` /
+    /// `MAX_PAYLOAD_ATTACHMENTS` entries, and `content` to the limit given to
+    /// `with_content_limit()` (copied only when cut).
+    pub message: Cow<'a, Message>,
+
+    /// Permalink to the message
+    ///
+    /// `https://discord.com/channels/{guild_id}/{channel_id}/{message_id}`,
+    /// with `@me` in place of the guild ID for direct messages.
+    pub url: String,
+
+    /// Avatar URL of the message author
+    ///
+    /// The custom avatar if set, otherwise Discord's default avatar for the user.
+    pub author_avatar_url: String,
+
+    /// Whether the message is pinned (copied from `message.pinned`)
+    pub pinned: bool,
+
+    /// Whether `message.content` can be trusted (default: true)
+    ///
+    /// False when the content is empty, the message type normally carries
+    /// text, and the bot runs without the MESSAGE_CONTENT intent, so the
+    /// content was likely withheld by Discord rather than empty.
+    /// See `with_content_visibility()`.
+    pub content_available: bool,
+
+    /// Whether the message is text-to-speech, omitted unless true
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub tts: bool,
+
+    /// Whether `message.content` was cut by `with_content_limit()`, omitted unless true
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub content_truncated: bool,
 
     /// Guild channel information (if available from cache)
     ///
@@ -35,22 +106,959 @@ pub struct MessagePayload<'a> {
     /// - Cache misses (channel not yet cached)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub channel: Option<GuildChannel>,
+
+    /// Readable channel type (e.g. `"text"`, `"public_thread"`, `"forum"`)
+    ///
+    /// Derived from `channel.type`, omitted when channel information is absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_kind: Option<String>,
+
+    /// System messages channel of the guild
+    ///
+    /// Only populated via `with_guild()`, omitted for DMs, guild cache
+    /// misses, and guilds without one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_system_channel_id: Option<ChannelId>,
+
+    /// Rules channel of the guild (community guilds)
+    ///
+    /// Only populated via `with_guild()`, omitted like `guild_system_channel_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_rules_channel_id: Option<ChannelId>,
+
+    /// Webhook that authored the message, omitted for non-webhook messages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub webhook: Option<WebhookAuthor>,
+
+    /// The other participant of a direct message, omitted for guild messages
+    ///
+    /// DMs carry no `GuildChannel` with recipient data, so this is taken from
+    /// the message author (the other participant for incoming DMs).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dm_recipient: Option<DmRecipient>,
+
+    /// The direct message channel, omitted for guild messages
+    ///
+    /// `channel` is never available for DMs; this is built from the message's
+    /// channel ID without an API call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dm_channel: Option<DmChannel>,
+
+    /// Users mentioned in the message (flattened to id + name)
+    ///
+    /// Only populated via `with_mentions()`, omitted when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mentions: Vec<MentionedUser>,
+
+    /// Roles mentioned in the message, omitted when empty
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mention_roles: Vec<RoleId>,
+
+    /// Names of the author's roles, in the order of `message.member.roles`
+    ///
+    /// Only populated via `with_role_names()`, omitted when empty (DMs,
+    /// guild cache misses, authors without roles). Roles missing from the
+    /// cache fall back to their ID.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub member_role_names: Vec<String>,
+
+    /// Channels mentioned in the message, omitted when empty
+    ///
+    /// Note: Discord only populates channel mentions for crossposted messages.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mention_channels: Vec<ChannelId>,
+
+    /// Messages this message replies to, newest first (direct parent first)
+    ///
+    /// Only populated via `with_reply_chain()`, omitted when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reply_chain: Vec<Message>,
+
+    /// Readable summary of `message.components` (buttons, select menus), one list per action row
+    ///
+    /// Omitted when the message has no components.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Vec<ComponentSummary>>,
+
+    /// Summary of `message.attachments` with alt text and spoiler flag
+    ///
+    /// Only populated via `with_attachments()`, omitted when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<AttachmentSummary>,
+
+    /// Interaction (e.g. slash command) this message responds to
+    ///
+    /// Taken from `message.interaction`, omitted for other messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interaction: Option<InteractionSummary>,
+}
+
+/// Webhook that authored a message
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct WebhookAuthor {
+    /// ID of the webhook
+    pub id: WebhookId,
+    /// Display name the message was posted under
+    ///
+    /// Taken from the message author, which Discord sets to the webhook's
+    /// name (or the per-message username override). Omitted when empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Other participant of a direct message
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DmRecipient {
+    /// ID of the user
+    pub id: UserId,
+    /// Username of the user
+    pub name: String,
+    /// Display name of the user, omitted when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub global_name: Option<String>,
+}
+
+/// Direct message channel of a DM event
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DmChannel {
+    /// ID of the DM channel
+    pub id: ChannelId,
+    /// Channel kind, always `"dm"`
+    pub kind: &'static str,
+}
+
+impl DmChannel {
+    /// Create the DM channel block for `id`
+    pub fn new(id: ChannelId) -> Self {
+        Self { id, kind: "dm" }
+    }
+}
+
+/// Attachment metadata for accessibility-focused consumers
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct AttachmentSummary {
+    /// ID of the attachment
+    pub id: AttachmentId,
+    /// Filename as uploaded
+    pub filename: String,
+    /// CDN URL of the file
+    pub url: String,
+    /// MIME type, omitted when unknown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// Size in bytes
+    pub size: u32,
+    /// Alt text set by the uploader, omitted when not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the attachment is marked as a spoiler
+    ///
+    /// Discord marks spoilers with the `SPOILER_` filename prefix.
+    pub is_spoiler: bool,
+}
+
+impl From<&Attachment> for AttachmentSummary {
+    fn from(attachment: &Attachment) -> Self {
+        Self {
+            id: attachment.id,
+            filename: attachment.filename.clone(),
+            url: attachment.url.clone(),
+            content_type: attachment.content_type.clone(),
+            size: attachment.size,
+            description: attachment.description.clone().filter(|description| !description.is_empty()),
+            is_spoiler: attachment.filename.starts_with(SPOILER_PREFIX),
+        }
+    }
+}
+
+/// Filename prefix Discord uses to mark an attachment as a spoiler
+const SPOILER_PREFIX: &str = "SPOILER_";
+
+/// Interaction a message responds to, flattened for correlation with commands
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct InteractionSummary {
+    /// ID of the interaction
+    pub id: InteractionId,
+    /// Readable interaction type (e.g. `"application_command"`)
+    pub kind: &'static str,
+    /// Name of the invoked command
+    pub name: String,
+    /// User who invoked the interaction
+    pub user: MentionedUser,
+}
+
+impl From<&MessageInteraction> for InteractionSummary {
+    fn from(interaction: &MessageInteraction) -> Self {
+        Self {
+            id: interaction.id,
+            kind: match interaction.kind {
+                InteractionType::Ping => "ping",
+                InteractionType::Command => "application_command",
+                InteractionType::Component => "message_component",
+                InteractionType::Autocomplete => "autocomplete",
+                InteractionType::Modal => "modal_submit",
+                _ => "unknown",
+            },
+            name: interaction.name.clone(),
+            user: MentionedUser {
+                id: interaction.user.id,
+                name: interaction.user.name.clone(),
+            },
+        }
+    }
+}
+
+/// Flattened user mention
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct MentionedUser {
+    /// ID of the mentioned user
+    pub id: UserId,
+    /// Username of the mentioned user
+    pub name: String,
 }
 
 impl<'a> MessagePayload<'a> {
     /// Create a new MessagePayload without channel information
     pub fn new(message: &'a Message) -> Self {
         Self {
-            message,
+            message: bound_arrays(message),
+            url: message.link(),
+            author_avatar_url: message.author.face(),
+            pinned: message.pinned,
+            content_available: true,
+            tts: message.tts,
+            content_truncated: false,
             channel: None,
+            channel_kind: None,
+            guild_system_channel_id: None,
+            guild_rules_channel_id: None,
+            webhook: message.webhook_id.map(|id| WebhookAuthor {
+                id,
+                name: Some(message.author.name.clone()).filter(|name| !name.is_empty()),
+            }),
+            dm_recipient: message.guild_id.is_none().then(|| DmRecipient {
+                id: message.author.id,
+                name: message.author.name.clone(),
+                global_name: message.author.global_name.clone(),
+            }),
+            dm_channel: message.guild_id.is_none().then(|| DmChannel::new(message.channel_id)),
+            mentions: Vec::new(),
+            mention_roles: Vec::new(),
+            member_role_names: Vec::new(),
+            mention_channels: Vec::new(),
+            reply_chain: Vec::new(),
+            components: summarize_components(&message.components),
+            attachments: Vec::new(),
+            // `interaction` is deprecated upstream in favor of `interaction_metadata`,
+            // which lacks the command name
+            #[allow(deprecated)]
+            interaction: message.interaction.as_deref().map(InteractionSummary::from),
         }
     }
 
     /// Create a new MessagePayload with channel information from cache
     pub fn with_channel(message: &'a Message, channel: GuildChannel) -> Self {
         Self {
-            message,
+            channel_kind: Some(channel.kind.name().to_string()),
             channel: Some(channel),
+            ..Self::new(message)
+        }
+    }
+
+    /// Add the system and rules channel IDs of the message's guild
+    pub fn with_guild(mut self, guild: &GuildInfo) -> Self {
+        self.guild_system_channel_id = guild.system_channel_id;
+        self.guild_rules_channel_id = guild.rules_channel_id;
+        self
+    }
+
+    /// Resolve the author's role IDs (`message.member.roles`) to names from `guild`
+    pub fn with_role_names(mut self, guild: &GuildInfo) -> Self {
+        self.member_role_names = self
+            .message
+            .member
+            .iter()
+            .flat_map(|member| &member.roles)
+            .map(|role_id| {
+                guild
+                    .role_names
+                    .get(role_id)
+                    .cloned()
+                    .unwrap_or_else(|| role_id.to_string())
+            })
+            .collect();
+        self
+    }
+
+    /// Populate flattened mention lists from the wrapped message
+    pub fn with_mentions(mut self) -> Self {
+        self.mentions = self
+            .message
+            .mentions
+            .iter()
+            .map(|user| MentionedUser {
+                id: user.id,
+                name: user.name.clone(),
+            })
+            .collect();
+        self.mention_roles = self.message.mention_roles.clone();
+        self.mention_channels = self
+            .message
+            .mention_channels
+            .iter()
+            .map(|channel| channel.id)
+            .collect();
+        self
+    }
+
+    /// Populate the attachment summaries from the wrapped message
+    pub fn with_attachments(mut self) -> Self {
+        self.attachments = self.message.attachments.iter().map(AttachmentSummary::from).collect();
+        self
+    }
+
+    /// Flag content withheld for lack of the MESSAGE_CONTENT intent
+    ///
+    /// Without the intent Discord still delivers content of DMs, messages
+    /// mentioning the bot and the bot's own messages, so those (and messages
+    /// with content, or of types without text) stay `content_available`.
+    pub fn with_content_visibility(mut self, content_intent: bool, bot_user_id: Option<UserId>) -> Self {
+        let message = &self.message;
+        let is_bot = |user_id: UserId| bot_user_id == Some(user_id);
+        self.content_available = content_intent
+            || !message.content.is_empty()
+            || !matches!(message.kind, MessageType::Regular | MessageType::InlineReply)
+            || message.guild_id.is_none()
+            || is_bot(message.author.id)
+            || message.mentions.iter().any(|user| is_bot(user.id));
+        self
+    }
+
+    /// Cut `message.content` to `max_chars` characters, ending in `marker`
+    ///
+    /// The marker counts toward the limit (an empty marker cuts hard).
+    /// Content within the limit is left as is.
+    pub fn with_content_limit(mut self, max_chars: usize, marker: &str) -> Self {
+        if self.message.content.chars().count() <= max_chars {
+            return self;
+        }
+
+        let marker: String = marker.chars().take(max_chars).collect();
+        let budget = max_chars - marker.chars().count();
+        let content = self.message.content.chars().take(budget).chain(marker.chars()).collect();
+        self.message.to_mut().content = content;
+        self.content_truncated = true;
+        self
+    }
+
+    /// Attach the reply chain (ancestors newest first)
+    pub fn with_reply_chain(mut self, reply_chain: Vec<Message>) -> Self {
+        self.reply_chain = reply_chain;
+        self
+    }
+
+    /// Serialize to JSON keeping only the given top-level fields of `message`
+    ///
+    /// Other payload fields (`channel`, `mentions`, ...) are unaffected.
+    /// Unknown field names are ignored.
+    pub fn to_filtered_value(&self, message_fields: &[String]) -> Result<Value, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        if let Some(Value::Object(message)) = value.get_mut("message") {
+            message.retain(|key, _| message_fields.iter().any(|field| field == key));
+        }
+        Ok(value)
+    }
+}
+
+/// Cut oversized `embeds`/`attachments` arrays, borrowing the message when within bounds
+fn bound_arrays(message: &Message) -> Cow<'_, Message> {
+    if message.embeds.len() <= MAX_PAYLOAD_EMBEDS && message.attachments.len() <= MAX_PAYLOAD_ATTACHMENTS {
+        return Cow::Borrowed(message);
+    }
+
+    warn!(
+        message_id = %message.id,
+        embeds = message.embeds.len(),
+        attachments = message.attachments.len(),
+        "Message has abnormally many embeds/attachments, truncating payload arrays"
+    );
+    let mut message = message.clone();
+    message.embeds.truncate(MAX_PAYLOAD_EMBEDS);
+    message.attachments.truncate(MAX_PAYLOAD_ATTACHMENTS);
+    Cow::Owned(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serenity::model::channel::ChannelType;
+    use serenity::model::id::{GuildId, MessageId};
+    use serenity::model::user::User;
+
+    fn create_message_with_mentions() -> Message {
+        let mut user = User::default();
+        user.id = UserId::new(111);
+        user.name = "alice".to_string();
+
+        let mut message = Message::default();
+        message.mentions = vec![user];
+        message.mention_roles = vec![RoleId::new(222)];
+        message.mention_channels = vec![
+            serde_json::from_value(serde_json::json!({
+                "id": "333",
+                "guild_id": "444",
+                "type": 0,
+                "name": "general"
+            }))
+            .unwrap(),
+        ];
+        message
+    }
+
+    #[test]
+    fn test_with_mentions_serialize() {
+        let message = create_message_with_mentions();
+        let payload = MessagePayload::new(&message).with_mentions();
+
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(json["mentions"][0]["id"], "111");
+        assert_eq!(json["mentions"][0]["name"], "alice");
+        assert_eq!(json["mention_roles"][0], "222");
+        assert_eq!(json["mention_channels"][0], "333");
+    }
+
+    #[test]
+    fn test_without_mentions_omits_fields() {
+        let message = create_message_with_mentions();
+        let payload = MessagePayload::new(&message);
+
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert!(json.get("mentions").is_none());
+        assert!(json.get("mention_roles").is_none());
+        assert!(json.get("mention_channels").is_none());
+    }
+
+    fn create_attachment(filename: &str, description: Option<&str>) -> Attachment {
+        serde_json::from_value(serde_json::json!({
+            "id": "555",
+            "filename": filename,
+            "description": description,
+            "size": 1024,
+            "url": "https://cdn.discordapp.com/attachments/1/555/file.png",
+            "proxy_url": "https://media.discordapp.net/attachments/1/555/file.png",
+            "content_type": "image/png"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_with_attachments_marks_spoiler() {
+        let mut message = Message::default();
+        message.attachments = vec![create_attachment("SPOILER_ending.png", None)];
+
+        let json = serde_json::to_value(MessagePayload::new(&message).with_attachments()).unwrap();
+
+        assert_eq!(
+            json["attachments"],
+            serde_json::json!([{
+                "id": "555",
+                "filename": "SPOILER_ending.png",
+                "url": "https://cdn.discordapp.com/attachments/1/555/file.png",
+                "content_type": "image/png",
+                "size": 1024,
+                "is_spoiler": true
+            }])
+        );
+    }
+
+    #[test]
+    fn test_with_attachments_includes_description() {
+        let mut message = Message::default();
+        message.attachments = vec![create_attachment("cat.png", Some("A cat asleep on a keyboard"))];
+
+        let payload = MessagePayload::new(&message).with_attachments();
+
+        assert_eq!(payload.attachments.len(), 1);
+        assert_eq!(payload.attachments[0].description.as_deref(), Some("A cat asleep on a keyboard"));
+        assert!(!payload.attachments[0].is_spoiler);
+    }
+
+    #[test]
+    fn test_without_attachments_omits_field() {
+        let mut message = Message::default();
+        message.attachments = vec![create_attachment("cat.png", None)];
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert!(json.get("attachments").is_none());
+    }
+
+    #[rstest]
+    #[case::under_limit("Hello", 10, "Hello", false)]
+    #[case::at_limit("0123456789", 10, "0123456789", false)]
+    #[case::over_limit("0123456789abc", 10, "0123456...", true)]
+    #[case::multibyte("こんにちは世界", 5, "こん...", true)]
+    fn test_with_content_limit(
+        #[case] content: &str,
+        #[case] max_chars: usize,
+        #[case] expected: &str,
+        #[case] truncated: bool,
+    ) {
+        let mut message = Message::default();
+        message.content = content.to_string();
+
+        let payload = MessagePayload::new(&message).with_content_limit(max_chars, "...");
+
+        assert_eq!(payload.message.content, expected);
+        assert_eq!(payload.content_truncated, truncated);
+        assert_eq!(matches!(payload.message, Cow::Owned(_)), truncated);
+    }
+
+    #[test]
+    fn test_with_content_limit_serialize() {
+        let mut message = Message::default();
+        message.content = "a".repeat(100);
+
+        let untouched = serde_json::to_value(MessagePayload::new(&message).with_content_limit(100, "")).unwrap();
+        let truncated = serde_json::to_value(MessagePayload::new(&message).with_content_limit(10, "")).unwrap();
+
+        assert!(untouched.get("content_truncated").is_none());
+        assert_eq!(truncated["message"]["content"], "a".repeat(10));
+        assert_eq!(truncated["content_truncated"], true);
+    }
+
+    #[test]
+    fn test_command_reply_includes_interaction() {
+        let mut message = Message::default();
+        #[allow(deprecated)]
+        {
+            message.interaction = Some(Box::new(
+                serde_json::from_value(serde_json::json!({
+                    "id": "777",
+                    "type": 2,
+                    "name": "ping",
+                    "user": {"id": "111", "username": "alice", "discriminator": "0000", "avatar": null}
+                }))
+                .unwrap(),
+            ));
         }
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(
+            json["interaction"],
+            serde_json::json!({
+                "id": "777",
+                "kind": "application_command",
+                "name": "ping",
+                "user": {"id": "111", "name": "alice"}
+            })
+        );
+    }
+
+    fn guild_message(content: &str) -> Message {
+        let mut message = Message::default();
+        message.guild_id = Some(GuildId::new(333));
+        message.author.id = UserId::new(111);
+        message.content = content.to_string();
+        message
+    }
+
+    #[rstest]
+    #[case::intent_active(guild_message(""), true, true)]
+    #[case::withheld(guild_message(""), false, false)]
+    #[case::has_content(guild_message("Hello"), false, true)]
+    fn test_content_available(#[case] message: Message, #[case] content_intent: bool, #[case] expected: bool) {
+        let payload = MessagePayload::new(&message).with_content_visibility(content_intent, Some(UserId::new(999)));
+
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(json["content_available"], expected);
+    }
+
+    #[rstest]
+    #[case::dm(|message: &mut Message| message.guild_id = None)]
+    #[case::own_message(|message: &mut Message| message.author.id = UserId::new(999))]
+    #[case::mentions_bot(|message: &mut Message| message.mentions.push({
+        let mut bot = User::default();
+        bot.id = UserId::new(999);
+        bot
+    }))]
+    #[case::system_message(|message: &mut Message| message.kind = MessageType::MemberJoin)]
+    fn test_content_available_without_intent_exemptions(#[case] adjust: fn(&mut Message)) {
+        let mut message = guild_message("");
+        adjust(&mut message);
+
+        let payload = MessagePayload::new(&message).with_content_visibility(false, Some(UserId::new(999)));
+
+        assert!(payload.content_available);
+    }
+
+    #[test]
+    fn test_normal_message_omits_interaction() {
+        let message = Message::default();
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert!(json.get("interaction").is_none());
+    }
+
+    #[test]
+    fn test_with_mentions_omits_empty_lists() {
+        let message = Message::default();
+        let payload = MessagePayload::new(&message).with_mentions();
+
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert!(json.get("mentions").is_none());
+        assert!(json.get("mention_roles").is_none());
+        assert!(json.get("mention_channels").is_none());
+    }
+
+    #[rstest]
+    #[case(ChannelType::Text, "text")]
+    #[case(ChannelType::Voice, "voice")]
+    #[case(ChannelType::News, "news")]
+    #[case(ChannelType::PublicThread, "public_thread")]
+    #[case(ChannelType::PrivateThread, "private_thread")]
+    #[case(ChannelType::Forum, "forum")]
+    fn test_with_channel_sets_channel_kind(#[case] kind: ChannelType, #[case] expected: &str) {
+        let message = Message::default();
+        let mut channel = GuildChannel::default();
+        channel.kind = kind;
+
+        let json = serde_json::to_value(MessagePayload::with_channel(&message, channel)).unwrap();
+
+        assert_eq!(json["channel_kind"], expected);
+    }
+
+    #[test]
+    fn test_without_channel_omits_channel_kind() {
+        let message = Message::default();
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert!(json.get("channel").is_none());
+        assert!(json.get("channel_kind").is_none());
+    }
+
+    #[test]
+    fn test_webhook_message_includes_webhook() {
+        let mut message = Message::default();
+        message.webhook_id = Some(WebhookId::new(999));
+        message.author.name = "Deploy Bot".to_string();
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(json["webhook"], serde_json::json!({"id": "999", "name": "Deploy Bot"}));
+    }
+
+    #[test]
+    fn test_webhook_without_author_name_omits_name() {
+        let mut message = Message::default();
+        message.webhook_id = Some(WebhookId::new(999));
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(json["webhook"], serde_json::json!({"id": "999"}));
+    }
+
+    #[test]
+    fn test_normal_message_omits_webhook() {
+        let message = Message::default();
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert!(json.get("webhook").is_none());
+    }
+
+    #[test]
+    fn test_guild_message_url() {
+        let mut message = Message::default();
+        message.id = MessageId::new(111);
+        message.channel_id = ChannelId::new(222);
+        message.guild_id = Some(GuildId::new(333));
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(json["url"], "https://discord.com/channels/333/222/111");
+    }
+
+    #[test]
+    fn test_direct_message_url() {
+        let mut message = Message::default();
+        message.id = MessageId::new(111);
+        message.channel_id = ChannelId::new(222);
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(json["url"], "https://discord.com/channels/@me/222/111");
+    }
+
+    #[test]
+    fn test_direct_message_includes_dm_recipient() {
+        let mut message = Message::default();
+        message.author.id = UserId::new(111);
+        message.author.name = "alice".to_string();
+        message.author.global_name = Some("Alice".to_string());
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(
+            json["dm_recipient"],
+            serde_json::json!({"id": "111", "name": "alice", "global_name": "Alice"})
+        );
+    }
+
+    #[test]
+    fn test_guild_message_omits_dm_recipient() {
+        let mut message = Message::default();
+        message.author.id = UserId::new(111);
+        message.guild_id = Some(GuildId::new(333));
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert!(json.get("dm_recipient").is_none());
+    }
+
+    #[test]
+    fn test_direct_message_includes_dm_channel() {
+        let mut message = Message::default();
+        message.channel_id = ChannelId::new(222);
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(json["dm_channel"], serde_json::json!({"id": "222", "kind": "dm"}));
+    }
+
+    #[test]
+    fn test_guild_message_omits_dm_channel() {
+        let mut message = Message::default();
+        message.channel_id = ChannelId::new(222);
+        message.guild_id = Some(GuildId::new(333));
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert!(json.get("dm_channel").is_none());
+    }
+
+    #[test]
+    fn test_with_guild_sets_system_and_rules_channels() {
+        let mut message = Message::default();
+        message.guild_id = Some(GuildId::new(333));
+        let guild = GuildInfo {
+            system_channel_id: Some(ChannelId::new(10)),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(MessagePayload::new(&message).with_guild(&guild)).unwrap();
+
+        assert_eq!(json["guild_system_channel_id"], "10");
+        assert!(json.get("guild_rules_channel_id").is_none());
+    }
+
+    #[test]
+    fn test_with_role_names_falls_back_to_id() {
+        let mut message = Message::default();
+        message.guild_id = Some(GuildId::new(333));
+        message.member = Some(Box::new(
+            serde_json::from_value(serde_json::json!({"roles": ["1", "2"], "deaf": false, "mute": false})).unwrap(),
+        ));
+        let guild = GuildInfo {
+            role_names: [(RoleId::new(1), "moderator".to_string())].into(),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(MessagePayload::new(&message).with_role_names(&guild)).unwrap();
+
+        assert_eq!(json["member_role_names"], serde_json::json!(["moderator", "2"]));
+    }
+
+    #[test]
+    fn test_without_member_omits_role_names() {
+        let message = Message::default();
+
+        let json = serde_json::to_value(MessagePayload::new(&message).with_role_names(&GuildInfo::default())).unwrap();
+
+        assert!(json.get("member_role_names").is_none());
+    }
+
+    #[test]
+    fn test_large_embed_array_is_bounded() {
+        let mut message = Message::default();
+        message.embeds = vec![Default::default(); 500];
+
+        let payload = MessagePayload::new(&message);
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert!(matches!(payload.message, Cow::Owned(_)));
+        assert_eq!(json["message"]["embeds"].as_array().unwrap().len(), MAX_PAYLOAD_EMBEDS);
+        assert_eq!(message.embeds.len(), 500, "original message is left untouched");
+    }
+
+    #[test]
+    fn test_normal_message_is_borrowed() {
+        let mut message = Message::default();
+        message.embeds = vec![Default::default(); MAX_PAYLOAD_EMBEDS];
+
+        let payload = MessagePayload::new(&message);
+
+        assert!(matches!(payload.message, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_author_custom_avatar_url() {
+        let mut message = Message::default();
+        message.author.id = UserId::new(111);
+        message.author.avatar = Some("a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4".parse().unwrap());
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(
+            json["author_avatar_url"],
+            "https://cdn.discordapp.com/avatars/111/a1b2c3d4e5f6a1b2c3d4e5f6a1b2c3d4.webp?size=1024"
+        );
+    }
+
+    #[test]
+    fn test_author_default_avatar_url() {
+        let mut message = Message::default();
+        message.author.id = UserId::new(111);
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(json["author_avatar_url"], message.author.default_avatar_url());
+        assert!(
+            json["author_avatar_url"]
+                .as_str()
+                .unwrap()
+                .starts_with("https://cdn.discordapp.com/embed/avatars/")
+        );
+    }
+
+    #[test]
+    fn test_pinned_tts_message_includes_flags() {
+        let mut message = Message::default();
+        message.pinned = true;
+        message.tts = true;
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(json["pinned"], true);
+        assert_eq!(json["tts"], true);
+    }
+
+    #[test]
+    fn test_normal_message_includes_pinned_and_omits_tts() {
+        let message = Message::default();
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(json["pinned"], false);
+        assert!(json.get("tts").is_none());
+    }
+
+    #[test]
+    fn test_to_filtered_value_keeps_url() {
+        let mut message = Message::default();
+        message.guild_id = Some(GuildId::new(333));
+        let payload = MessagePayload::new(&message);
+
+        let filtered = payload.to_filtered_value(&[]).unwrap();
+
+        assert_eq!(filtered["url"], payload.url);
+    }
+
+    #[test]
+    fn test_reply_chain_serialize() {
+        let message = Message::default();
+        let mut parent = Message::default();
+        parent.id = MessageId::new(2);
+        let mut grandparent = Message::default();
+        grandparent.id = MessageId::new(1);
+
+        let json = serde_json::to_value(
+            MessagePayload::new(&message).with_reply_chain(vec![parent, grandparent]),
+        )
+        .unwrap();
+
+        assert_eq!(json["reply_chain"][0]["id"], "2");
+        assert_eq!(json["reply_chain"][1]["id"], "1");
+    }
+
+    #[test]
+    fn test_without_reply_chain_omits_field() {
+        let message = Message::default();
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert!(json.get("reply_chain").is_none());
+    }
+
+    #[test]
+    fn test_message_with_button_row_includes_components() {
+        let mut message = Message::default();
+        message.components = serde_json::from_value(serde_json::json!([{
+            "type": 1,
+            "components": [{"type": 2, "style": 1, "label": "Vote", "custom_id": "vote"}]
+        }]))
+        .unwrap();
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert_eq!(
+            json["components"],
+            serde_json::json!([[{"kind": "button", "custom_id": "vote", "label": "Vote", "style": "primary"}]])
+        );
+    }
+
+    #[test]
+    fn test_message_without_components_omits_field() {
+        let message = Message::default();
+
+        let json = serde_json::to_value(MessagePayload::new(&message)).unwrap();
+
+        assert!(json.get("components").is_none());
+    }
+
+    fn fields(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_to_filtered_value_keeps_only_listed_message_fields() {
+        let mut message = Message::default();
+        message.content = "Hello".to_string();
+        let payload = MessagePayload::new(&message);
+
+        let full = serde_json::to_value(&payload).unwrap();
+        let filtered = payload
+            .to_filtered_value(&fields(&["id", "content", "author", "channel_id"]))
+            .unwrap();
+
+        let mut keys: Vec<&String> = filtered["message"].as_object().unwrap().keys().collect();
+        keys.sort();
+        assert_eq!(keys, ["author", "channel_id", "content", "id"]);
+        for key in keys {
+            assert_eq!(filtered["message"][key], full["message"][key]);
+        }
+        assert!(full["message"].as_object().unwrap().len() > 4);
+    }
+
+    #[test]
+    fn test_to_filtered_value_keeps_other_payload_fields() {
+        let message = Message::default();
+        let mut channel = GuildChannel::default();
+        channel.kind = ChannelType::Text;
+        let payload = MessagePayload::with_channel(&message, channel);
+
+        let filtered = payload.to_filtered_value(&fields(&["id", "unknown"])).unwrap();
+
+        assert_eq!(filtered["message"].as_object().unwrap().len(), 1);
+        assert!(filtered.get("channel").is_some());
+        assert_eq!(filtered["channel_kind"], "text");
     }
 }
@@ -1,11 +1,39 @@
+pub mod action_rate_limiter;
 pub mod action_target;
+pub mod auto_moderation_action_payload;
+pub mod channel_denylist;
+pub mod component_interaction_payload;
+#[cfg(feature = "actions")]
 pub mod discord_text;
+pub mod error_notifier;
 pub mod event_bridge;
+pub mod event_limiter;
+pub mod event_sampler;
+pub mod field_redactor;
+pub mod guild_create_payload;
+pub mod guild_member_update_payload;
+pub mod heartbeat;
+pub mod idempotency_cache;
 pub mod message_delete_bulk_payload;
+pub mod message_components;
 pub mod message_delete_payload;
 pub mod message_payload;
 pub mod message_update_payload;
+pub mod raw_event_payload;
+#[cfg(feature = "actions")]
+pub mod reaction_action_cooldown;
 pub mod reaction_payload;
+pub mod reaction_remove_batch_payload;
+pub mod reaction_remove_coalescer;
+pub mod reaction_rollup;
+pub mod reaction_rollup_payload;
 pub mod ready_payload;
+pub mod reconnect_tracker;
+pub mod reply_cooldown;
 pub mod resumed_payload;
+pub mod scheduled_event_payload;
 pub mod sender_filter;
+pub mod stale_event_filter;
+pub mod task_tracker;
+pub mod thread_create_payload;
+pub mod thread_delete_payload;
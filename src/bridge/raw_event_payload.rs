@@ -0,0 +1,89 @@
+use serde::Serialize;
+use serde_json::Value;
+use serenity::model::event::Event;
+
+/// Payload for raw gateway events forwarded via `RAW_EVENTS`
+///
+/// Passes the gateway event data through unmodified, for event types
+/// gatehook does not model yet.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "_type": "TYPING_START",
+///   "raw": { /* gateway event data (`d` field) */ }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RawEventPayload {
+    /// Gateway event name (e.g. `TYPING_START`)
+    #[serde(rename = "_type")]
+    pub kind: String,
+
+    /// Gateway event data
+    pub raw: Value,
+}
+
+impl RawEventPayload {
+    /// Create a RawEventPayload from a gateway event
+    ///
+    /// Returns `None` if the event cannot be represented as `{ "t", "d" }` JSON.
+    pub fn from_event(event: &Event) -> Option<Self> {
+        let Value::Object(mut map) = serde_json::to_value(event).ok()? else {
+            return None;
+        };
+        let Some(Value::String(kind)) = map.remove("t") else {
+            return None;
+        };
+        let raw = map.remove("d").unwrap_or(Value::Null);
+
+        Some(Self { kind, raw })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_from_unknown_event() {
+        let event: Event = serde_json::from_value(json!({
+            "t": "SOME_FUTURE_EVENT",
+            "d": {"guild_id": "777", "value": 42}
+        }))
+        .unwrap();
+
+        let payload = RawEventPayload::from_event(&event).unwrap();
+
+        assert_eq!(payload.kind, "SOME_FUTURE_EVENT");
+        assert_eq!(payload.raw, json!({"guild_id": "777", "value": 42}));
+    }
+
+    #[test]
+    fn test_from_modeled_event() {
+        let event: Event = serde_json::from_value(json!({
+            "t": "TYPING_START",
+            "d": {"channel_id": "888", "user_id": "555", "timestamp": 1700000000}
+        }))
+        .unwrap();
+
+        let payload = RawEventPayload::from_event(&event).unwrap();
+
+        assert_eq!(payload.kind, "TYPING_START");
+        assert_eq!(payload.raw["channel_id"], "888");
+        assert_eq!(payload.raw["user_id"], "555");
+    }
+
+    #[test]
+    fn test_serialize() {
+        let payload = RawEventPayload {
+            kind: "TYPING_START".to_string(),
+            raw: json!({"channel_id": "888"}),
+        };
+
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(json, json!({"_type": "TYPING_START", "raw": {"channel_id": "888"}}));
+    }
+}
@@ -0,0 +1,75 @@
+use serenity::model::id::{MessageId, UserId};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Skips actions of repeated reactions by the same user within a window
+///
+/// Protects against a user toggling a reaction to trigger the same actions
+/// over and over. Reactions are keyed by (message, user, emoji); a zero
+/// window disables the cooldown.
+pub struct ReactionActionCooldown {
+    window: Duration,
+    triggered: Mutex<HashMap<(MessageId, UserId, String), Instant>>,
+}
+
+impl ReactionActionCooldown {
+    /// Create a new ReactionActionCooldown (`Duration::ZERO` disables it)
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            triggered: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record a triggering reaction, returning `false` if the same one is still cooling down
+    pub fn try_acquire(&self, message_id: MessageId, user_id: UserId, emoji: &str) -> bool {
+        if self.window.is_zero() {
+            return true;
+        }
+
+        let key = (message_id, user_id, emoji.to_string());
+
+        let now = Instant::now();
+        let mut triggered = self.triggered.lock().unwrap();
+        // Drop expired entries so the map stays bounded by recent traffic
+        triggered.retain(|_, triggered_at| now.duration_since(*triggered_at) < self.window);
+
+        if triggered.contains_key(&key) {
+            return false;
+        }
+        triggered.insert(key, now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_reaction_within_window_is_rejected() {
+        let cooldown = ReactionActionCooldown::new(Duration::from_secs(60));
+
+        assert!(cooldown.try_acquire(MessageId::new(1), UserId::new(2), "👍"));
+        assert!(!cooldown.try_acquire(MessageId::new(1), UserId::new(2), "👍"));
+    }
+
+    #[test]
+    fn test_different_message_user_or_emoji_is_allowed() {
+        let cooldown = ReactionActionCooldown::new(Duration::from_secs(60));
+
+        assert!(cooldown.try_acquire(MessageId::new(1), UserId::new(2), "👍"));
+        assert!(cooldown.try_acquire(MessageId::new(3), UserId::new(2), "👍"));
+        assert!(cooldown.try_acquire(MessageId::new(1), UserId::new(4), "👍"));
+        assert!(cooldown.try_acquire(MessageId::new(1), UserId::new(2), "👎"));
+    }
+
+    #[test]
+    fn test_zero_window_disables_cooldown() {
+        let cooldown = ReactionActionCooldown::new(Duration::ZERO);
+
+        assert!(cooldown.try_acquire(MessageId::new(1), UserId::new(2), "👍"));
+        assert!(cooldown.try_acquire(MessageId::new(1), UserId::new(2), "👍"));
+    }
+}
@@ -1,23 +1,124 @@
+use crate::adapters::GuildInfo;
+use crate::bridge::message_payload::DmChannel;
 use serde::Serialize;
-use serenity::model::channel::{GuildChannel, Reaction};
+use serenity::model::channel::{GuildChannel, Message, MessageReaction, Reaction, ReactionType};
+use serenity::model::id::{ChannelId, EmojiId};
 
 /// Wrapper for reaction event payload sent to webhook
 ///
 /// Wraps serenity's Reaction with optional channel metadata for richer context.
 ///
+/// Gateway reaction events carry no counts, so `reaction_counts` is derived
+/// from the reacted-to message and is only present alongside `message`.
+///
 /// # JSON Structure
 ///
 /// ```json
 /// {
 ///   "reaction": { ... },        // Discord Reaction object
-///   "channel": { ... }          // Optional GuildChannel (omitted for DMs)
+///   "emoji": { ... },           // Normalized emoji (with emoji_url for custom emojis)
+///   "channel": { ... },         // Optional GuildChannel (omitted for DMs)
+///   "channel_kind": "text",     // Optional readable channel type (omitted with channel)
+///   "guild_system_channel_id": "...", // Optional (cached guilds with a system channel)
+///   "guild_rules_channel_id": "...",  // Optional (cached guilds with a rules channel)
+///   "dm_channel": { ... },      // Optional { "id", "kind": "dm" } (DMs only)
+///   "message": { ... },         // Optional reacted-to Message (REACTION_INCLUDE_MESSAGE)
+///   "reaction_counts": { ... }  // Optional counts for this emoji (present with message)
 /// }
 /// ```
 #[derive(Serialize)]
 pub struct ReactionPayload<'a> {
     reaction: &'a Reaction,
+    emoji: ReactionEmoji,
     #[serde(skip_serializing_if = "Option::is_none")]
     channel: Option<GuildChannel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    channel_kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guild_system_channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    guild_rules_channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dm_channel: Option<DmChannel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reaction_counts: Option<ReactionCounts>,
+}
+
+/// Current counts for the reacted emoji on the message
+///
+/// All zero when the emoji is no longer on the message (e.g. the last
+/// reaction was removed).
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct ReactionCounts {
+    /// Total reactions with this emoji, including super reactions
+    pub count: u64,
+    /// Regular reactions with this emoji
+    pub normal_count: u64,
+    /// Super (burst) reactions with this emoji
+    pub burst_count: u64,
+    /// Whether the bot has reacted with this emoji
+    pub me: bool,
+    /// Whether the bot has super-reacted with this emoji
+    pub me_burst: bool,
+}
+
+/// Normalized view of the reacted emoji
+///
+/// `emoji_url` points at the CDN image of a custom emoji (`.gif` when
+/// animated); Unicode emojis have no image and omit it.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReactionEmoji {
+    /// Custom emoji ID (`None` for Unicode emojis)
+    pub id: Option<EmojiId>,
+    /// Emoji name, or the Unicode character itself
+    pub name: Option<String>,
+    /// Whether the custom emoji is animated
+    pub animated: bool,
+    /// CDN image URL of a custom emoji
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji_url: Option<String>,
+}
+
+impl From<&ReactionType> for ReactionEmoji {
+    fn from(emoji: &ReactionType) -> Self {
+        match emoji {
+            ReactionType::Custom { animated, id, name } => Self {
+                id: Some(*id),
+                name: name.clone(),
+                animated: *animated,
+                emoji_url: Some(format!(
+                    "https://cdn.discordapp.com/emojis/{id}.{}",
+                    if *animated { "gif" } else { "png" }
+                )),
+            },
+            ReactionType::Unicode(name) => Self {
+                id: None,
+                name: Some(name.clone()),
+                animated: false,
+                emoji_url: None,
+            },
+            _ => Self {
+                id: None,
+                name: None,
+                animated: false,
+                emoji_url: None,
+            },
+        }
+    }
+}
+
+impl From<&MessageReaction> for ReactionCounts {
+    fn from(reaction: &MessageReaction) -> Self {
+        Self {
+            count: reaction.count,
+            normal_count: reaction.count_details.normal,
+            burst_count: reaction.count_details.burst,
+            me: reaction.me,
+            me_burst: reaction.me_burst,
+        }
+    }
 }
 
 impl<'a> ReactionPayload<'a> {
@@ -25,7 +126,14 @@ impl<'a> ReactionPayload<'a> {
     pub fn new(reaction: &'a Reaction) -> Self {
         Self {
             reaction,
+            emoji: ReactionEmoji::from(&reaction.emoji),
             channel: None,
+            channel_kind: None,
+            guild_system_channel_id: None,
+            guild_rules_channel_id: None,
+            dm_channel: reaction.guild_id.is_none().then(|| DmChannel::new(reaction.channel_id)),
+            message: None,
+            reaction_counts: None,
         }
     }
 
@@ -33,7 +141,225 @@ impl<'a> ReactionPayload<'a> {
     pub fn with_channel(reaction: &'a Reaction, channel: GuildChannel) -> Self {
         Self {
             reaction,
+            emoji: ReactionEmoji::from(&reaction.emoji),
+            channel_kind: Some(channel.kind.name().to_string()),
             channel: Some(channel),
+            guild_system_channel_id: None,
+            guild_rules_channel_id: None,
+            dm_channel: None,
+            message: None,
+            reaction_counts: None,
         }
     }
+
+    /// Add the system and rules channel IDs of the reaction's guild
+    pub fn with_guild(mut self, guild: &GuildInfo) -> Self {
+        self.guild_system_channel_id = guild.system_channel_id;
+        self.guild_rules_channel_id = guild.rules_channel_id;
+        self
+    }
+
+    /// Attach the message that was reacted to, along with the emoji's counts
+    pub fn with_message(mut self, message: Message) -> Self {
+        self.reaction_counts = Some(
+            message
+                .reactions
+                .iter()
+                .find(|reaction| same_emoji(&reaction.reaction_type, &self.reaction.emoji))
+                .map(ReactionCounts::from)
+                .unwrap_or_default(),
+        );
+        self.message = Some(message);
+        self
+    }
+}
+
+/// Whether two emojis are the same (custom emojis compare by ID only)
+fn same_emoji(a: &ReactionType, b: &ReactionType) -> bool {
+    match (a, b) {
+        (ReactionType::Custom { id: a, .. }, ReactionType::Custom { id: b, .. }) => a == b,
+        _ => a == b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serenity::model::channel::ChannelType;
+
+    fn create_reaction() -> Reaction {
+        serde_json::from_value(serde_json::json!({
+            "user_id": "1",
+            "channel_id": "2",
+            "message_id": "3",
+            "emoji": {"id": null, "name": "👍"},
+            "burst": false,
+            "type": 0
+        }))
+        .unwrap()
+    }
+
+    #[rstest]
+    #[case(ChannelType::Text, "text")]
+    #[case(ChannelType::NewsThread, "news_thread")]
+    #[case(ChannelType::PublicThread, "public_thread")]
+    #[case(ChannelType::Forum, "forum")]
+    fn test_with_channel_sets_channel_kind(#[case] kind: ChannelType, #[case] expected: &str) {
+        let reaction = create_reaction();
+        let mut channel = GuildChannel::default();
+        channel.kind = kind;
+
+        let json = serde_json::to_value(ReactionPayload::with_channel(&reaction, channel)).unwrap();
+
+        assert_eq!(json["channel_kind"], expected);
+    }
+
+    fn create_message_with_reactions() -> Message {
+        serde_json::from_value(serde_json::json!({
+            "id": "3",
+            "channel_id": "2",
+            "author": {"id": "9", "username": "author", "discriminator": "0000", "avatar": null},
+            "content": "hello",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+            "reactions": [
+                {
+                    "count": 1,
+                    "count_details": {"normal": 1, "burst": 0},
+                    "me": false,
+                    "me_burst": false,
+                    "emoji": {"id": null, "name": "🎉"},
+                    "burst_colors": []
+                },
+                {
+                    "count": 5,
+                    "count_details": {"normal": 3, "burst": 2},
+                    "me": true,
+                    "me_burst": false,
+                    "emoji": {"id": null, "name": "👍"},
+                    "burst_colors": []
+                }
+            ]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_with_message_sets_reaction_counts() {
+        let reaction = create_reaction();
+
+        let payload = ReactionPayload::new(&reaction).with_message(create_message_with_reactions());
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(
+            json["reaction_counts"],
+            serde_json::json!({
+                "count": 5,
+                "normal_count": 3,
+                "burst_count": 2,
+                "me": true,
+                "me_burst": false
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_message_without_emoji_zero_counts() {
+        let reaction = create_reaction();
+        let mut message = create_message_with_reactions();
+        message.reactions.retain(|r| r.reaction_type != reaction.emoji);
+
+        let payload = ReactionPayload::new(&reaction).with_message(message);
+
+        assert_eq!(payload.reaction_counts, Some(ReactionCounts::default()));
+    }
+
+    #[test]
+    fn test_without_message_omits_reaction_counts() {
+        let reaction = create_reaction();
+
+        let json = serde_json::to_value(ReactionPayload::new(&reaction)).unwrap();
+
+        assert!(json.get("reaction_counts").is_none());
+    }
+
+    #[test]
+    fn test_same_emoji_custom_compares_by_id() {
+        let a: ReactionType = serde_json::from_value(serde_json::json!({"id": "42", "name": "party", "animated": false})).unwrap();
+        let renamed: ReactionType = serde_json::from_value(serde_json::json!({"id": "42", "name": "renamed", "animated": true})).unwrap();
+        let other: ReactionType = serde_json::from_value(serde_json::json!({"id": "43", "name": "party", "animated": false})).unwrap();
+
+        assert!(same_emoji(&a, &renamed));
+        assert!(!same_emoji(&a, &other));
+        assert!(!same_emoji(&a, &ReactionType::Unicode("party".to_string())));
+    }
+
+    #[rstest]
+    #[case(serde_json::json!({"id": "42", "name": "party", "animated": false}), Some("https://cdn.discordapp.com/emojis/42.png"))]
+    #[case(serde_json::json!({"id": "43", "name": "dance", "animated": true}), Some("https://cdn.discordapp.com/emojis/43.gif"))]
+    #[case(serde_json::json!({"id": null, "name": "👍"}), None)]
+    fn test_emoji_url(#[case] emoji: serde_json::Value, #[case] expected: Option<&str>) {
+        let mut reaction = create_reaction();
+        reaction.emoji = serde_json::from_value(emoji).unwrap();
+
+        let json = serde_json::to_value(ReactionPayload::new(&reaction)).unwrap();
+
+        assert_eq!(json["emoji"].get("emoji_url").and_then(|url| url.as_str()), expected);
+    }
+
+    #[test]
+    fn test_emoji_block_for_custom_emoji() {
+        let mut reaction = create_reaction();
+        reaction.emoji = serde_json::from_value(serde_json::json!({"id": "43", "name": "dance", "animated": true})).unwrap();
+
+        let json = serde_json::to_value(ReactionPayload::new(&reaction)).unwrap();
+
+        assert_eq!(
+            json["emoji"],
+            serde_json::json!({
+                "id": "43",
+                "name": "dance",
+                "animated": true,
+                "emoji_url": "https://cdn.discordapp.com/emojis/43.gif"
+            })
+        );
+    }
+
+    #[test]
+    fn test_without_channel_omits_channel_kind() {
+        let reaction = create_reaction();
+
+        let json = serde_json::to_value(ReactionPayload::new(&reaction)).unwrap();
+
+        assert!(json.get("channel").is_none());
+        assert!(json.get("channel_kind").is_none());
+    }
+
+    #[test]
+    fn test_direct_reaction_includes_dm_channel() {
+        let reaction = create_reaction();
+
+        let json = serde_json::to_value(ReactionPayload::new(&reaction)).unwrap();
+
+        assert_eq!(json["dm_channel"], serde_json::json!({"id": "2", "kind": "dm"}));
+    }
+
+    #[test]
+    fn test_guild_reaction_omits_dm_channel() {
+        let mut reaction = create_reaction();
+        reaction.guild_id = Some(serenity::model::id::GuildId::new(4));
+
+        let json = serde_json::to_value(ReactionPayload::new(&reaction)).unwrap();
+
+        assert!(json.get("dm_channel").is_none());
+    }
 }
@@ -0,0 +1,113 @@
+use serde::Serialize;
+use serenity::model::channel::{Reaction, ReactionType};
+use serenity::model::id::{ChannelId, GuildId, MessageId, UserId};
+
+/// Payload for coalesced REACTION_REMOVE events (`REACTION_REMOVE_COALESCE_MS`)
+///
+/// Lists every reaction removed from one message within the coalescing window.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "reaction_remove_batch": {
+///     "message_id": "...",
+///     "channel_id": "...",
+///     "guild_id": "...",                                // optional
+///     "reactions": [{ "user_id": "...", "emoji": { /* ReactionType */ } }]
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactionRemoveBatchPayload {
+    pub reaction_remove_batch: ReactionRemoveBatch,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactionRemoveBatch {
+    /// ID of the message the reactions were removed from
+    pub message_id: MessageId,
+    /// ID of the channel containing the message
+    pub channel_id: ChannelId,
+    /// ID of the guild (None for DMs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// Removed reactions in arrival order
+    pub reactions: Vec<RemovedReaction>,
+}
+
+/// A single removed reaction
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct RemovedReaction {
+    /// User whose reaction was removed (if known)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+    /// The removed emoji
+    pub emoji: ReactionType,
+}
+
+impl ReactionRemoveBatchPayload {
+    /// Create a new ReactionRemoveBatchPayload
+    ///
+    /// Message, channel and guild are taken from the first reaction; all
+    /// reactions are expected to target the same message.
+    ///
+    /// Returns `None` if `reactions` is empty.
+    pub fn new(reactions: &[Reaction]) -> Option<Self> {
+        let first = reactions.first()?;
+
+        Some(Self {
+            reaction_remove_batch: ReactionRemoveBatch {
+                message_id: first.message_id,
+                channel_id: first.channel_id,
+                guild_id: first.guild_id,
+                reactions: reactions
+                    .iter()
+                    .map(|reaction| RemovedReaction {
+                        user_id: reaction.user_id,
+                        emoji: reaction.emoji.clone(),
+                    })
+                    .collect(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_reaction(user_id: &str, emoji: &str) -> Reaction {
+        serde_json::from_value(json!({
+            "type": 0,
+            "burst": false,
+            "channel_id": "222",
+            "message_id": "111",
+            "guild_id": "333",
+            "user_id": user_id,
+            "emoji": {"name": emoji, "id": null}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_serialize() {
+        let reactions = vec![create_reaction("1", "👍"), create_reaction("2", "🎉")];
+
+        let json = serde_json::to_value(ReactionRemoveBatchPayload::new(&reactions).unwrap()).unwrap();
+        let batch = &json["reaction_remove_batch"];
+
+        assert_eq!(batch["message_id"], "111");
+        assert_eq!(batch["channel_id"], "222");
+        assert_eq!(batch["guild_id"], "333");
+        assert_eq!(batch["reactions"][0]["user_id"], "1");
+        assert_eq!(batch["reactions"][0]["emoji"]["name"], "👍");
+        assert_eq!(batch["reactions"][1]["user_id"], "2");
+        assert_eq!(batch["reactions"][1]["emoji"]["name"], "🎉");
+    }
+
+    #[test]
+    fn test_empty_reactions() {
+        assert!(ReactionRemoveBatchPayload::new(&[]).is_none());
+    }
+}
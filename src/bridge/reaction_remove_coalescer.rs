@@ -0,0 +1,105 @@
+use serenity::model::channel::Reaction;
+use serenity::model::id::MessageId;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Groups reaction removals for the same message within a window
+///
+/// The first removal for a message opens a window: its caller waits for the
+/// window to elapse and then takes the whole group. Removals arriving while
+/// the window is open are only recorded. A zero window disables coalescing.
+pub struct ReactionRemoveCoalescer {
+    window: Duration,
+    pending: Mutex<HashMap<MessageId, Vec<Reaction>>>,
+}
+
+impl ReactionRemoveCoalescer {
+    /// Create a new ReactionRemoveCoalescer (`Duration::ZERO` disables it)
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether removals are being coalesced
+    pub fn is_enabled(&self) -> bool {
+        !self.window.is_zero()
+    }
+
+    /// Record a removal, returning the group if this call owns the window
+    ///
+    /// Returns `None` when the removal joined a window opened by an earlier
+    /// call (that call forwards it). Otherwise waits for the window and
+    /// returns every removal recorded for the message, in arrival order.
+    pub async fn coalesce(&self, reaction: &Reaction) -> Option<Vec<Reaction>> {
+        let message_id = reaction.message_id;
+
+        {
+            let mut pending = self.pending.lock().unwrap();
+            if let Some(group) = pending.get_mut(&message_id) {
+                group.push(reaction.clone());
+                return None;
+            }
+            pending.insert(message_id, vec![reaction.clone()]);
+        }
+
+        tokio::time::sleep(self.window).await;
+
+        self.pending.lock().unwrap().remove(&message_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_reaction(message_id: &str, emoji: &str) -> Reaction {
+        serde_json::from_value(json!({
+            "type": 0,
+            "burst": false,
+            "channel_id": "222",
+            "message_id": message_id,
+            "user_id": "1",
+            "emoji": {"name": emoji, "id": null}
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_removals_for_same_message_are_grouped() {
+        let coalescer = ReactionRemoveCoalescer::new(Duration::from_millis(50));
+        let first = create_reaction("111", "👍");
+        let second = create_reaction("111", "🎉");
+
+        let (owner, joined) = tokio::join!(coalescer.coalesce(&first), async {
+            tokio::task::yield_now().await;
+            coalescer.coalesce(&second).await
+        });
+
+        assert!(joined.is_none());
+        let group = owner.unwrap();
+        assert_eq!(group.len(), 2);
+        assert_eq!(group[1].emoji, second.emoji);
+    }
+
+    #[tokio::test]
+    async fn test_removals_for_different_messages_are_separate() {
+        let coalescer = ReactionRemoveCoalescer::new(Duration::from_millis(50));
+        let first = create_reaction("111", "👍");
+        let second = create_reaction("112", "👍");
+
+        let (a, b) = tokio::join!(coalescer.coalesce(&first), coalescer.coalesce(&second));
+
+        assert_eq!(a.unwrap().len(), 1);
+        assert_eq!(b.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_zero_window_is_disabled() {
+        assert!(!ReactionRemoveCoalescer::new(Duration::ZERO).is_enabled());
+        assert!(ReactionRemoveCoalescer::new(Duration::from_millis(1)).is_enabled());
+    }
+}
@@ -0,0 +1,111 @@
+use crate::bridge::action_target::emoji_param;
+use serenity::model::channel::Reaction;
+use serenity::model::id::MessageId;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Accumulates same-emoji reaction adds on the same message within a window
+///
+/// Adds are keyed by message and emoji. The first add for a key opens a
+/// window: its caller waits for the window to elapse and then takes the
+/// whole group. Adds arriving while the window is open are only recorded.
+/// A zero window disables the rollup.
+pub struct ReactionRollup {
+    window: Duration,
+    pending: Mutex<HashMap<(MessageId, String), Vec<Reaction>>>,
+}
+
+impl ReactionRollup {
+    /// Create a new ReactionRollup (`Duration::ZERO` disables it)
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether reaction adds are being rolled up
+    pub fn is_enabled(&self) -> bool {
+        !self.window.is_zero()
+    }
+
+    /// Record an add, returning the group if this call owns the window
+    ///
+    /// Returns `None` when the add joined a window opened by an earlier call
+    /// (that call forwards it). Otherwise waits for the window and returns
+    /// every add recorded for the message and emoji, in arrival order.
+    pub async fn accumulate(&self, reaction: &Reaction) -> Option<Vec<Reaction>> {
+        let key = (reaction.message_id, emoji_param(&reaction.emoji));
+
+        {
+            let mut pending = self.pending.lock().unwrap();
+            if let Some(group) = pending.get_mut(&key) {
+                group.push(reaction.clone());
+                return None;
+            }
+            pending.insert(key.clone(), vec![reaction.clone()]);
+        }
+
+        tokio::time::sleep(self.window).await;
+
+        self.pending.lock().unwrap().remove(&key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+    use serde_json::json;
+
+    fn create_reaction(message_id: &str, user_id: &str, emoji: &str) -> Reaction {
+        serde_json::from_value(json!({
+            "type": 0,
+            "burst": false,
+            "channel_id": "222",
+            "message_id": message_id,
+            "user_id": user_id,
+            "emoji": {"name": emoji, "id": null}
+        }))
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_same_emoji_on_same_message_is_grouped() {
+        let rollup = ReactionRollup::new(Duration::from_millis(50));
+        let first = create_reaction("111", "1", "👍");
+        let second = create_reaction("111", "2", "👍");
+
+        let (owner, joined) = tokio::join!(rollup.accumulate(&first), async {
+            tokio::task::yield_now().await;
+            rollup.accumulate(&second).await
+        });
+
+        assert!(joined.is_none());
+        let group = owner.unwrap();
+        assert_eq!(group.len(), 2);
+        assert_eq!(group[1].user_id, second.user_id);
+    }
+
+    #[rstest]
+    #[case::different_emoji("111", "🎉")]
+    #[case::different_message("112", "👍")]
+    #[tokio::test]
+    async fn test_different_keys_are_separate(#[case] message_id: &str, #[case] emoji: &str) {
+        let rollup = ReactionRollup::new(Duration::from_millis(50));
+        let first = create_reaction("111", "1", "👍");
+        let second = create_reaction(message_id, "2", emoji);
+
+        let (a, b) = tokio::join!(rollup.accumulate(&first), rollup.accumulate(&second));
+
+        assert_eq!(a.unwrap().len(), 1);
+        assert_eq!(b.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_zero_window_is_disabled() {
+        assert!(!ReactionRollup::new(Duration::ZERO).is_enabled());
+        assert!(ReactionRollup::new(Duration::from_millis(1)).is_enabled());
+    }
+}
@@ -0,0 +1,104 @@
+use serde::Serialize;
+use serenity::model::channel::{Reaction, ReactionType};
+use serenity::model::id::{ChannelId, GuildId, MessageId, UserId};
+
+/// Payload for rolled-up REACTION_ADD events (`REACTION_ROLLUP_WINDOW_MS`)
+///
+/// Counts the adds of one emoji on one message within the rollup window.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "reaction_rollup": {
+///     "message_id": "...",
+///     "channel_id": "...",
+///     "guild_id": "...",                                // optional
+///     "emoji": { /* ReactionType */ },
+///     "count": 3,
+///     "user_ids": ["...", "...", "..."]
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactionRollupPayload {
+    pub reaction_rollup: ReactionRollup,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ReactionRollup {
+    /// ID of the reacted-to message
+    pub message_id: MessageId,
+    /// ID of the channel containing the message
+    pub channel_id: ChannelId,
+    /// ID of the guild (None for DMs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    /// The added emoji
+    pub emoji: ReactionType,
+    /// Number of adds rolled up
+    pub count: usize,
+    /// Users who added the reaction, in arrival order (unknown users omitted)
+    pub user_ids: Vec<UserId>,
+}
+
+impl ReactionRollupPayload {
+    /// Create a new ReactionRollupPayload
+    ///
+    /// Message, channel, guild and emoji are taken from the first reaction;
+    /// all reactions are expected to share the message and emoji.
+    ///
+    /// Returns `None` if `reactions` is empty.
+    pub fn new(reactions: &[Reaction]) -> Option<Self> {
+        let first = reactions.first()?;
+
+        Some(Self {
+            reaction_rollup: ReactionRollup {
+                message_id: first.message_id,
+                channel_id: first.channel_id,
+                guild_id: first.guild_id,
+                emoji: first.emoji.clone(),
+                count: reactions.len(),
+                user_ids: reactions.iter().filter_map(|reaction| reaction.user_id).collect(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_reaction(user_id: Option<&str>) -> Reaction {
+        serde_json::from_value(json!({
+            "type": 0,
+            "burst": false,
+            "channel_id": "222",
+            "message_id": "111",
+            "guild_id": "333",
+            "user_id": user_id,
+            "emoji": {"name": "👍", "id": null}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_serialize() {
+        let reactions = vec![create_reaction(Some("1")), create_reaction(Some("2")), create_reaction(None)];
+
+        let json = serde_json::to_value(ReactionRollupPayload::new(&reactions).unwrap()).unwrap();
+        let rollup = &json["reaction_rollup"];
+
+        assert_eq!(rollup["message_id"], "111");
+        assert_eq!(rollup["channel_id"], "222");
+        assert_eq!(rollup["guild_id"], "333");
+        assert_eq!(rollup["emoji"]["name"], "👍");
+        assert_eq!(rollup["count"], 3);
+        assert_eq!(rollup["user_ids"], json!(["1", "2"]));
+    }
+
+    #[test]
+    fn test_empty_reactions() {
+        assert!(ReactionRollupPayload::new(&[]).is_none());
+    }
+}
@@ -0,0 +1,258 @@
+use serde::Serialize;
+use serenity::gateway::ConnectionStage;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Counts gateway reconnect attempts between connections
+///
+/// Serenity reconnects on its own; this only observes shard stage updates.
+/// Every transition into `Connecting` after a lost connection is one
+/// attempt, so consecutive failures are the attempts beyond the first.
+/// Downtime runs from the first stage change away from `Connected`.
+///
+/// Each shard reconnects on its own, so the state is kept per shard id.
+pub struct ReconnectTracker {
+    alert_threshold: u32,
+    shards: Mutex<HashMap<u32, ReconnectState>>,
+}
+
+#[derive(Default)]
+struct ReconnectState {
+    attempts: u32,
+    disconnected_at: Option<Instant>,
+    alerted: bool,
+}
+
+/// Outcome of a shard stage update
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StageOutcome {
+    /// A reconnect attempt started
+    Attempt {
+        /// Attempt number since the connection was lost (1-based)
+        attempt: u32,
+        /// Time since the connection was lost
+        downtime: Duration,
+        /// Whether consecutive failures just reached the alert threshold
+        alert: bool,
+    },
+    /// The connection is back after `attempts` attempts
+    Reconnected { attempts: u32, downtime: Duration },
+    /// Nothing to report
+    None,
+}
+
+impl ReconnectTracker {
+    /// Create a new ReconnectTracker (`alert_threshold` 0 never alerts)
+    pub fn new(alert_threshold: u32) -> Self {
+        Self {
+            alert_threshold,
+            shards: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record a stage update of shard `shard_id`
+    pub fn on_stage(&self, shard_id: u32, stage: ConnectionStage) -> StageOutcome {
+        self.on_stage_at(shard_id, stage, Instant::now())
+    }
+
+    fn on_stage_at(&self, shard_id: u32, stage: ConnectionStage, now: Instant) -> StageOutcome {
+        let mut shards = self.shards.lock().unwrap();
+        let state = shards.entry(shard_id).or_default();
+
+        if stage == ConnectionStage::Connected {
+            let Some(disconnected_at) = state.disconnected_at.take() else {
+                return StageOutcome::None;
+            };
+            let attempts = state.attempts;
+            *state = ReconnectState::default();
+            return StageOutcome::Reconnected {
+                attempts,
+                downtime: now.saturating_duration_since(disconnected_at),
+            };
+        }
+
+        let disconnected_at = *state.disconnected_at.get_or_insert(now);
+        if stage != ConnectionStage::Connecting {
+            return StageOutcome::None;
+        }
+
+        state.attempts += 1;
+        let failures = state.attempts - 1;
+        let alert = self.alert_threshold > 0 && failures >= self.alert_threshold && !state.alerted;
+        state.alerted |= alert;
+        StageOutcome::Attempt {
+            attempt: state.attempts,
+            downtime: now.saturating_duration_since(disconnected_at),
+            alert,
+        }
+    }
+}
+
+/// Payload for reconnecting events sent to webhook
+///
+/// Sent once per outage, when consecutive reconnect failures reach
+/// `RECONNECT_ALERT_THRESHOLD`.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "reconnecting": {
+///     "attempt": 4,
+///     "consecutive_failures": 3,
+///     "downtime_secs": 95
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReconnectingPayload {
+    /// Reconnect state
+    pub reconnecting: Reconnecting,
+}
+
+/// Reconnect state at the time of the alert
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct Reconnecting {
+    /// Attempt number since the connection was lost (1-based)
+    pub attempt: u32,
+    /// Attempts that did not reconnect
+    pub consecutive_failures: u32,
+    /// Seconds since the connection was lost
+    pub downtime_secs: u64,
+}
+
+impl ReconnectingPayload {
+    /// Create a new ReconnectingPayload for an attempt
+    pub fn new(attempt: u32, downtime: Duration) -> Self {
+        Self {
+            reconnecting: Reconnecting {
+                attempt,
+                consecutive_failures: attempt.saturating_sub(1),
+                downtime_secs: downtime.as_secs(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feed one lost connection with `attempts` reconnect attempts 10s apart
+    fn outage(tracker: &ReconnectTracker, shard_id: u32, start: Instant, attempts: u32) -> Vec<StageOutcome> {
+        let mut outcomes = vec![tracker.on_stage_at(shard_id, ConnectionStage::Disconnected, start)];
+        for attempt in 1..=attempts {
+            let now = start + Duration::from_secs(10 * u64::from(attempt));
+            outcomes.push(tracker.on_stage_at(shard_id, ConnectionStage::Connecting, now));
+            outcomes.push(tracker.on_stage_at(shard_id, ConnectionStage::Handshake, now));
+        }
+        outcomes
+    }
+
+    #[test]
+    fn test_counts_attempts_and_downtime() {
+        let tracker = ReconnectTracker::new(0);
+        let start = Instant::now();
+
+        let outcomes = outage(&tracker, 0, start, 2);
+
+        assert_eq!(outcomes[0], StageOutcome::None);
+        assert_eq!(
+            outcomes[1],
+            StageOutcome::Attempt { attempt: 1, downtime: Duration::from_secs(10), alert: false }
+        );
+        assert_eq!(
+            outcomes[3],
+            StageOutcome::Attempt { attempt: 2, downtime: Duration::from_secs(20), alert: false }
+        );
+        assert_eq!(
+            tracker.on_stage_at(0, ConnectionStage::Connected, start + Duration::from_secs(25)),
+            StageOutcome::Reconnected { attempts: 2, downtime: Duration::from_secs(25) }
+        );
+    }
+
+    #[test]
+    fn test_alerts_once_when_failures_reach_threshold() {
+        let tracker = ReconnectTracker::new(2);
+
+        let alerts: Vec<u32> = outage(&tracker, 0, Instant::now(), 5)
+            .into_iter()
+            .filter_map(|outcome| match outcome {
+                StageOutcome::Attempt { attempt, alert: true, .. } => Some(attempt),
+                _ => None,
+            })
+            .collect();
+
+        // Attempt 3 follows two failed attempts
+        assert_eq!(alerts, vec![3]);
+    }
+
+    #[test]
+    fn test_reconnect_resets_counter_and_alert() {
+        let tracker = ReconnectTracker::new(1);
+        let start = Instant::now();
+
+        outage(&tracker, 0, start, 2);
+        tracker.on_stage_at(0, ConnectionStage::Connected, start + Duration::from_secs(30));
+        let outcomes = outage(&tracker, 0, start + Duration::from_secs(60), 2);
+
+        assert_eq!(
+            outcomes[1],
+            StageOutcome::Attempt { attempt: 1, downtime: Duration::from_secs(10), alert: false }
+        );
+        assert_eq!(
+            outcomes[3],
+            StageOutcome::Attempt { attempt: 2, downtime: Duration::from_secs(20), alert: true }
+        );
+    }
+
+    #[test]
+    fn test_connected_without_outage_reports_nothing() {
+        let tracker = ReconnectTracker::new(1);
+
+        assert_eq!(tracker.on_stage(0, ConnectionStage::Connected), StageOutcome::None);
+    }
+
+    #[test]
+    fn test_shards_are_tracked_separately() {
+        let tracker = ReconnectTracker::new(2);
+        let start = Instant::now();
+
+        // Shard 1 fails twice while shard 0 reconnects at once
+        let shard_1 = outage(&tracker, 1, start, 2);
+        let shard_0 = outage(&tracker, 0, start, 1);
+        let reconnected = tracker.on_stage_at(0, ConnectionStage::Connected, start + Duration::from_secs(15));
+        let next = tracker.on_stage_at(1, ConnectionStage::Connecting, start + Duration::from_secs(30));
+
+        assert_eq!(
+            shard_1[3],
+            StageOutcome::Attempt { attempt: 2, downtime: Duration::from_secs(20), alert: false }
+        );
+        assert_eq!(
+            shard_0[1],
+            StageOutcome::Attempt { attempt: 1, downtime: Duration::from_secs(10), alert: false }
+        );
+        assert_eq!(
+            reconnected,
+            StageOutcome::Reconnected { attempts: 1, downtime: Duration::from_secs(15) }
+        );
+        // Shard 0 reconnecting neither resets nor advances shard 1
+        assert_eq!(
+            next,
+            StageOutcome::Attempt { attempt: 3, downtime: Duration::from_secs(30), alert: true }
+        );
+    }
+
+    #[test]
+    fn test_reconnecting_payload_serialize() {
+        let payload = ReconnectingPayload::new(4, Duration::from_millis(95_500));
+
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({
+                "reconnecting": {"attempt": 4, "consecutive_failures": 3, "downtime_secs": 95}
+            })
+        );
+    }
+}
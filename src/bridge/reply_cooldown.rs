@@ -0,0 +1,123 @@
+use serenity::model::id::{ChannelId, UserId};
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Suppresses identical replies sent to the same channel within a window
+///
+/// Protects channels from a looping webhook that keeps returning the same
+/// reply. Replies are keyed by (destination, content hash); a zero window
+/// disables the cooldown.
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+pub struct ReplyCooldown {
+    window: Duration,
+    sent: Mutex<HashMap<(ReplyDestination, u64), Instant>>,
+}
+
+/// Where a reply goes, as known before any API call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+pub enum ReplyDestination {
+    /// A channel (replies and channel posts)
+    Channel(ChannelId),
+    /// A user's DMs (the DM channel is opened after the cooldown check)
+    User(UserId),
+}
+
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
+impl ReplyCooldown {
+    /// Create a new ReplyCooldown (`Duration::ZERO` disables it)
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Record a reply, returning `false` if an identical one is still cooling down
+    ///
+    /// Check and record happen atomically, so concurrent duplicates are
+    /// also suppressed.
+    pub fn try_acquire(&self, destination: ReplyDestination, content: &str) -> bool {
+        if self.window.is_zero() {
+            return true;
+        }
+
+        let key = Self::key(destination, content);
+        let now = Instant::now();
+        let mut sent = self.sent.lock().unwrap();
+        // Drop expired entries so the map stays bounded by recent traffic
+        sent.retain(|_, sent_at| now.duration_since(*sent_at) < self.window);
+
+        if sent.contains_key(&key) {
+            return false;
+        }
+        sent.insert(key, now);
+        true
+    }
+
+    /// Forget a reply that failed to send, so an identical retry is not suppressed
+    pub fn release(&self, destination: ReplyDestination, content: &str) {
+        self.sent.lock().unwrap().remove(&Self::key(destination, content));
+    }
+
+    fn key(destination: ReplyDestination, content: &str) -> (ReplyDestination, u64) {
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        (destination, hasher.finish())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channel(id: u64) -> ReplyDestination {
+        ReplyDestination::Channel(ChannelId::new(id))
+    }
+
+    #[test]
+    fn test_identical_reply_within_window_is_rejected() {
+        let cooldown = ReplyCooldown::new(Duration::from_secs(60));
+
+        assert!(cooldown.try_acquire(channel(1), "hello"));
+        assert!(!cooldown.try_acquire(channel(1), "hello"));
+    }
+
+    #[test]
+    fn test_different_content_or_channel_is_allowed() {
+        let cooldown = ReplyCooldown::new(Duration::from_secs(60));
+
+        assert!(cooldown.try_acquire(channel(1), "hello"));
+        assert!(cooldown.try_acquire(channel(1), "world"));
+        assert!(cooldown.try_acquire(channel(2), "hello"));
+    }
+
+    #[test]
+    fn test_user_and_channel_with_same_id_are_distinct() {
+        let cooldown = ReplyCooldown::new(Duration::from_secs(60));
+
+        assert!(cooldown.try_acquire(channel(1), "hello"));
+        assert!(cooldown.try_acquire(ReplyDestination::User(UserId::new(1)), "hello"));
+        assert!(!cooldown.try_acquire(ReplyDestination::User(UserId::new(1)), "hello"));
+    }
+
+    #[test]
+    fn test_released_reply_is_allowed_again() {
+        let cooldown = ReplyCooldown::new(Duration::from_secs(60));
+
+        assert!(cooldown.try_acquire(channel(1), "hello"));
+        cooldown.release(channel(1), "hello");
+
+        assert!(cooldown.try_acquire(channel(1), "hello"));
+    }
+
+    #[test]
+    fn test_zero_window_disables_cooldown() {
+        let cooldown = ReplyCooldown::new(Duration::ZERO);
+
+        assert!(cooldown.try_acquire(channel(1), "hello"));
+        assert!(cooldown.try_acquire(channel(1), "hello"));
+    }
+}
@@ -0,0 +1,68 @@
+use serde::Serialize;
+use serenity::model::guild::ScheduledEvent;
+
+/// Payload for GUILD_SCHEDULED_EVENT_CREATE/UPDATE/DELETE events
+///
+/// All three events carry the full scheduled event object; the `handler`
+/// query parameter distinguishes create, update and delete.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "scheduled_event": { /* ScheduledEvent fields */ }
+/// }
+/// ```
+#[derive(Serialize)]
+pub struct ScheduledEventPayload<'a> {
+    /// The scheduled event
+    pub scheduled_event: &'a ScheduledEvent,
+}
+
+impl<'a> ScheduledEventPayload<'a> {
+    /// Create a new ScheduledEventPayload
+    pub fn new(event: &'a ScheduledEvent) -> Self {
+        Self {
+            scheduled_event: event,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_scheduled_event_payload_serialize() {
+        let event: ScheduledEvent = serde_json::from_value(json!({
+            "id": "999",
+            "guild_id": "777",
+            "channel_id": "888",
+            "creator_id": "555",
+            "name": "Community call",
+            "description": "Monthly sync",
+            "scheduled_start_time": "2026-01-01T12:00:00Z",
+            "scheduled_end_time": null,
+            "privacy_level": 2,
+            "status": 1,
+            "entity_type": 2,
+            "entity_id": null,
+            "entity_metadata": null,
+            "creator": null,
+            "user_count": null,
+            "image": null
+        }))
+        .unwrap();
+
+        let payload = ScheduledEventPayload::new(&event);
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(json["scheduled_event"]["id"], "999");
+        assert_eq!(json["scheduled_event"]["guild_id"], "777");
+        assert_eq!(json["scheduled_event"]["channel_id"], "888");
+        assert_eq!(json["scheduled_event"]["name"], "Community call");
+        assert_eq!(json["scheduled_event"]["scheduled_start_time"], "2026-01-01T12:00:00Z");
+        assert_eq!(json["scheduled_event"]["status"], 1);
+        assert_eq!(json["scheduled_event"]["entity_type"], 2);
+    }
+}
@@ -0,0 +1,88 @@
+use serenity::model::Timestamp;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Drops events whose Discord timestamp is older than a maximum age
+///
+/// Protects the webhook from acting on stale context when a backlog of
+/// events is delivered after a reconnect. A zero maximum age disables the
+/// filter. Dropped events are counted over the process lifetime.
+pub struct StaleEventFilter {
+    max_age: Duration,
+    dropped: AtomicU64,
+}
+
+impl StaleEventFilter {
+    /// Create a new StaleEventFilter (`Duration::ZERO` disables it)
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Whether an event with `timestamp` is too old to forward (counts it as dropped)
+    pub fn is_stale(&self, timestamp: Timestamp) -> bool {
+        self.is_stale_at(timestamp, Timestamp::now())
+    }
+
+    /// `is_stale` relative to a given current time
+    pub fn is_stale_at(&self, timestamp: Timestamp, now: Timestamp) -> bool {
+        if self.max_age.is_zero() {
+            return false;
+        }
+
+        let age_secs = now.unix_timestamp().saturating_sub(timestamp.unix_timestamp());
+        let stale = u64::try_from(age_secs).is_ok_and(|age_secs| age_secs > self.max_age.as_secs());
+        if stale {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+        }
+        stale
+    }
+
+    /// Number of events dropped so far
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn at(secs: i64) -> Timestamp {
+        Timestamp::from_unix_timestamp(secs).unwrap()
+    }
+
+    #[rstest]
+    #[case::fresh(1_000, false)]
+    #[case::at_threshold(940, false)]
+    #[case::older(939, true)]
+    #[case::from_the_future(1_010, false)]
+    fn test_is_stale(#[case] timestamp: i64, #[case] expected: bool) {
+        let filter = StaleEventFilter::new(Duration::from_secs(60));
+
+        assert_eq!(filter.is_stale_at(at(timestamp), at(1_000)), expected);
+        assert_eq!(filter.dropped(), u64::from(expected));
+    }
+
+    #[test]
+    fn test_zero_max_age_is_disabled() {
+        let filter = StaleEventFilter::new(Duration::ZERO);
+
+        assert!(!filter.is_stale_at(at(0), at(1_000_000)));
+        assert_eq!(filter.dropped(), 0);
+    }
+
+    #[test]
+    fn test_dropped_counts_accumulate() {
+        let filter = StaleEventFilter::new(Duration::from_secs(1));
+
+        for _ in 0..3 {
+            assert!(filter.is_stale_at(at(0), at(100)));
+        }
+
+        assert_eq!(filter.dropped(), 3);
+    }
+}
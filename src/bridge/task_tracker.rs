@@ -0,0 +1,280 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::{Notify, watch};
+use tracing::warn;
+
+/// Bounded registry of spawned background tasks
+///
+/// Every one-shot background task (outbox replay, admin connections, ...)
+/// is spawned through `spawn()`, and every long-running loop (heartbeat,
+/// admin listener) through `spawn_service()`; both refuse new tasks once
+/// `max_pending_tasks` are running. `drain()` signals `Shutdown` to the
+/// services and waits for all running tasks on shutdown. A
+/// `max_pending_tasks` of 0 disables the cap.
+///
+/// With `with_max_lifetime()`, a one-shot task still running after the
+/// lifetime is cancelled with a warning, so a stuck task cannot hold its
+/// slot forever. Services run until shutdown and have no lifetime.
+pub struct TaskTracker {
+    max_pending_tasks: usize,
+    max_lifetime: Duration,
+    pending: Arc<AtomicUsize>,
+    idle: Arc<Notify>,
+    shutdown: watch::Sender<bool>,
+}
+
+/// Shutdown signal handed to services spawned with `TaskTracker::spawn_service()`
+#[derive(Clone)]
+pub struct Shutdown(watch::Receiver<bool>);
+
+impl Shutdown {
+    /// Wait until the tracker starts draining
+    pub async fn wait(&mut self) {
+        // A dropped tracker cannot drain anymore: treat it as shutdown too
+        let _ = self.0.wait_for(|shutdown| *shutdown).await;
+    }
+}
+
+/// Decrements the pending count when a task finishes (or is dropped)
+struct PendingGuard {
+    pending: Arc<AtomicUsize>,
+    idle: Arc<Notify>,
+}
+
+impl Drop for PendingGuard {
+    fn drop(&mut self) {
+        if self.pending.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.idle.notify_waiters();
+        }
+    }
+}
+
+impl TaskTracker {
+    /// Create a new TaskTracker allowing `max_pending_tasks` running tasks (0 = unlimited)
+    pub fn new(max_pending_tasks: usize) -> Self {
+        Self {
+            max_pending_tasks,
+            max_lifetime: Duration::ZERO,
+            pending: Arc::new(AtomicUsize::new(0)),
+            idle: Arc::new(Notify::new()),
+            shutdown: watch::Sender::new(false),
+        }
+    }
+
+    /// Cancel tasks still running after `max_lifetime` (`Duration::ZERO` = unlimited)
+    pub fn with_max_lifetime(mut self, max_lifetime: Duration) -> Self {
+        self.max_lifetime = max_lifetime;
+        self
+    }
+
+    /// Lifetime after which running tasks are cancelled (`Duration::ZERO` = unlimited)
+    pub fn max_lifetime(&self) -> Duration {
+        self.max_lifetime
+    }
+
+    /// Number of registered tasks still running
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::SeqCst)
+    }
+
+    /// Spawn a task, returning `false` (without spawning) when the cap is reached
+    pub fn spawn<F>(&self, name: &'static str, task: F) -> bool
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let Some(guard) = self.reserve(name) else {
+            return false;
+        };
+        let max_lifetime = self.max_lifetime;
+        tokio::spawn(async move {
+            let _guard = guard;
+            if max_lifetime.is_zero() {
+                task.await;
+            } else if tokio::time::timeout(max_lifetime, task).await.is_err() {
+                warn!(task = name, max_lifetime = ?max_lifetime, "Background task exceeded its maximum lifetime, cancelled");
+            }
+        });
+        true
+    }
+
+    /// Spawn a long-running service, returning `false` (without spawning) when the cap is reached
+    ///
+    /// The service gets a `Shutdown` signal that fires when `drain()` starts
+    /// and must return soon after it; it is not subject to the maximum lifetime.
+    pub fn spawn_service<F, Fut>(&self, name: &'static str, service: F) -> bool
+    where
+        F: FnOnce(Shutdown) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let Some(guard) = self.reserve(name) else {
+            return false;
+        };
+        let service = service(self.shutdown_signal());
+        tokio::spawn(async move {
+            let _guard = guard;
+            service.await;
+        });
+        true
+    }
+
+    /// Signal that fires when `drain()` starts
+    pub fn shutdown_signal(&self) -> Shutdown {
+        Shutdown(self.shutdown.subscribe())
+    }
+
+    /// Take a pending slot, or `None` (with a warning) when the cap is reached
+    fn reserve(&self, name: &'static str) -> Option<PendingGuard> {
+        let reserved = self
+            .pending
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |pending| {
+                (self.max_pending_tasks == 0 || pending < self.max_pending_tasks).then_some(pending + 1)
+            })
+            .is_ok();
+        if !reserved {
+            warn!(
+                task = name,
+                max_pending_tasks = self.max_pending_tasks,
+                "Too many pending background tasks, rejecting task"
+            );
+            return None;
+        }
+
+        Some(PendingGuard {
+            pending: self.pending.clone(),
+            idle: self.idle.clone(),
+        })
+    }
+
+    /// Signal `Shutdown` to services, then wait up to `timeout` for all registered tasks to finish
+    ///
+    /// Returns `true` when no task is left running.
+    pub async fn drain(&self, timeout: Duration) -> bool {
+        self.shutdown.send_replace(true);
+
+        let wait_idle = async {
+            loop {
+                let notified = self.idle.notified();
+                tokio::pin!(notified);
+                // Register before checking, so a task finishing in between is not missed
+                notified.as_mut().enable();
+                if self.pending() == 0 {
+                    return;
+                }
+                notified.await;
+            }
+        };
+
+        tokio::time::timeout(timeout, wait_idle).await.is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_spawn_beyond_cap_is_rejected() {
+        let tracker = TaskTracker::new(1);
+
+        assert!(tracker.spawn("first", std::future::pending()));
+        assert!(!tracker.spawn("second", async {}));
+        assert_eq!(tracker.pending(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_zero_cap_is_unlimited() {
+        let tracker = TaskTracker::new(0);
+
+        for _ in 0..100 {
+            assert!(tracker.spawn("task", std::future::pending()));
+        }
+        assert_eq!(tracker.pending(), 100);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_drain_waits_for_registered_tasks() {
+        let tracker = TaskTracker::new(10);
+        let finished = Arc::new(AtomicUsize::new(0));
+        for delay in [1, 5] {
+            let finished = finished.clone();
+            tracker.spawn("task", async move {
+                tokio::time::sleep(Duration::from_secs(delay)).await;
+                finished.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        assert!(tracker.drain(Duration::from_secs(30)).await);
+        assert_eq!(finished.load(Ordering::SeqCst), 2);
+        assert_eq!(tracker.pending(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_drain_times_out() {
+        let tracker = TaskTracker::new(10);
+        tracker.spawn("stuck", std::future::pending());
+
+        assert!(!tracker.drain(Duration::from_secs(5)).await);
+        assert_eq!(tracker.pending(), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_task_exceeding_max_lifetime_is_cancelled() {
+        let tracker = TaskTracker::new(10).with_max_lifetime(Duration::from_secs(60));
+        let finished = Arc::new(AtomicUsize::new(0));
+        for delay in [30, 90] {
+            let finished = finished.clone();
+            tracker.spawn("task", async move {
+                tokio::time::sleep(Duration::from_secs(delay)).await;
+                finished.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        tracker.spawn("stuck", std::future::pending());
+
+        // Tasks past their lifetime are cancelled, so the drain completes
+        assert!(tracker.drain(Duration::from_secs(120)).await);
+        assert_eq!(finished.load(Ordering::SeqCst), 1);
+        assert_eq!(tracker.pending(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_drain_stops_services() {
+        let tracker = TaskTracker::new(10).with_max_lifetime(Duration::from_secs(60));
+        let stopped = Arc::new(AtomicUsize::new(0));
+        let spawned = tracker.spawn_service("service", {
+            let stopped = stopped.clone();
+            |mut shutdown| async move {
+                shutdown.wait().await;
+                stopped.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+        assert!(spawned);
+
+        // Services outlive the maximum lifetime until shutdown
+        tokio::time::sleep(Duration::from_secs(120)).await;
+        assert_eq!(tracker.pending(), 1);
+
+        assert!(tracker.drain(Duration::from_secs(5)).await);
+        assert_eq!(stopped.load(Ordering::SeqCst), 1);
+        assert_eq!(tracker.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_service_beyond_cap_is_rejected() {
+        let tracker = TaskTracker::new(1);
+        tracker.spawn("first", std::future::pending());
+
+        assert!(!tracker.spawn_service("service", |_shutdown| async {}));
+        assert_eq!(tracker.pending(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_finished_task_frees_slot() {
+        let tracker = TaskTracker::new(1);
+        tracker.spawn("first", async {});
+
+        assert!(tracker.drain(Duration::from_secs(5)).await);
+        assert!(tracker.spawn("second", async {}));
+    }
+}
@@ -0,0 +1,54 @@
+use serde::Serialize;
+use serenity::model::channel::GuildChannel;
+
+/// Payload for THREAD_CREATE event
+///
+/// Contains the created thread wrapped in a `thread_create` key.
+/// Discord also dispatches this event when the bot is added to a private thread.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "thread_create": { /* GuildChannel fields */ }
+/// }
+/// ```
+#[derive(Serialize)]
+pub struct ThreadCreatePayload<'a> {
+    /// The created thread
+    pub thread_create: &'a GuildChannel,
+}
+
+impl<'a> ThreadCreatePayload<'a> {
+    /// Create a new ThreadCreatePayload
+    pub fn new(thread: &'a GuildChannel) -> Self {
+        Self {
+            thread_create: thread,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serenity::model::channel::ChannelType;
+    use serenity::model::id::{ChannelId, GuildId};
+
+    #[test]
+    fn test_thread_create_payload_serialize() {
+        let mut thread = GuildChannel::default();
+        thread.id = ChannelId::new(999);
+        thread.guild_id = GuildId::new(777);
+        thread.parent_id = Some(ChannelId::new(888));
+        thread.name = "support".to_string();
+        thread.kind = ChannelType::PublicThread;
+
+        let payload = ThreadCreatePayload::new(&thread);
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(json["thread_create"]["id"], "999");
+        assert_eq!(json["thread_create"]["guild_id"], "777");
+        assert_eq!(json["thread_create"]["parent_id"], "888");
+        assert_eq!(json["thread_create"]["name"], "support");
+        assert_eq!(json["thread_create"]["type"], 11);
+    }
+}
@@ -0,0 +1,68 @@
+use serde::Serialize;
+use serenity::model::id::{ChannelId, GuildId};
+
+/// Payload for THREAD_DELETE event
+///
+/// This payload is sent to the webhook endpoint when a thread is deleted.
+/// Note that the Discord API only provides IDs, not the thread's full data.
+///
+/// JSON structure:
+/// ```json
+/// {
+///   "thread_delete": {
+///     "id": "123...",
+///     "guild_id": "456...",
+///     "parent_id": "789..."
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreadDeletePayload {
+    pub thread_delete: ThreadDelete,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThreadDelete {
+    /// ID of the deleted thread
+    pub id: ChannelId,
+    /// ID of the guild the thread belonged to
+    pub guild_id: GuildId,
+    /// ID of the thread's parent channel
+    pub parent_id: ChannelId,
+}
+
+impl ThreadDeletePayload {
+    /// Create a new ThreadDeletePayload
+    ///
+    /// # Arguments
+    ///
+    /// * `thread_id` - The ID of the deleted thread
+    /// * `guild_id` - The guild the thread belonged to
+    /// * `parent_id` - The thread's parent channel
+    pub fn new(thread_id: ChannelId, guild_id: GuildId, parent_id: ChannelId) -> Self {
+        Self {
+            thread_delete: ThreadDelete {
+                id: thread_id,
+                guild_id,
+                parent_id,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thread_delete_payload_serialize() {
+        let payload =
+            ThreadDeletePayload::new(ChannelId::new(999), GuildId::new(777), ChannelId::new(888));
+
+        let json = serde_json::to_value(&payload).unwrap();
+
+        assert_eq!(json["thread_delete"]["id"], "999");
+        assert_eq!(json["thread_delete"]["guild_id"], "777");
+        assert_eq!(json["thread_delete"]["parent_id"], "888");
+    }
+}
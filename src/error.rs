@@ -0,0 +1,117 @@
+//! Error types for the gatehook library surface
+//!
+//! Public APIs (`EventBridge`, `EventSender`, `HttpEventSender`, `Outbox`, `Params`) return
+//! `GatehookError` so that embedders can match on failure kinds. The binary keeps
+//! using `anyhow`, which converts from `GatehookError` via `?`.
+
+use thiserror::Error;
+
+/// Errors returned at the gatehook library boundary
+#[derive(Debug, Error)]
+pub enum GatehookError {
+    /// Configuration could not be loaded or is invalid
+    #[error("Failed to load configuration: {0}")]
+    Config(#[from] envy::Error),
+
+    /// HTTP client construction or webhook request failed
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// Payload could not be serialized to JSON
+    #[error("Failed to serialize payload: {0}")]
+    Serialize(#[from] serde_json::Error),
+
+    /// Discord API or gateway operation failed
+    ///
+    /// Boxed because `serenity::Error` is large compared to the other variants.
+    #[error("Discord operation failed: {0}")]
+    Discord(#[source] Box<serenity::Error>),
+
+    /// Local file operation failed (e.g. outbox persistence)
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Event dropped because the webhook circuit breaker is open and its buffer is full
+    #[error("Webhook circuit breaker is open, event dropped")]
+    CircuitOpen,
+}
+
+impl From<serenity::Error> for GatehookError {
+    fn from(err: serenity::Error) -> Self {
+        Self::Discord(Box::new(err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_error_from_envy() {
+        let env: Vec<(String, String)> = Vec::new();
+
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Required {
+            discord_token: String,
+        }
+
+        let err: GatehookError = envy::from_iter::<_, Required>(env).unwrap_err().into();
+
+        assert!(matches!(err, GatehookError::Config(_)));
+        assert!(err.to_string().starts_with("Failed to load configuration"));
+    }
+
+    #[test]
+    fn test_http_error_from_reqwest() {
+        // Invalid URL scheme fails at request build time without network access
+        let reqwest_err = reqwest::Client::new()
+            .post("not a url")
+            .build()
+            .unwrap_err();
+        let err: GatehookError = reqwest_err.into();
+
+        assert!(matches!(err, GatehookError::Http(_)));
+    }
+
+    #[test]
+    fn test_serialize_error_from_serde_json() {
+        let serde_err = serde_json::from_str::<serde_json::Value>("{invalid").unwrap_err();
+        let err: GatehookError = serde_err.into();
+
+        assert!(matches!(err, GatehookError::Serialize(_)));
+    }
+
+    #[test]
+    fn test_discord_error_from_serenity() {
+        let err: GatehookError = serenity::Error::Other("Mock error").into();
+
+        assert!(matches!(err, GatehookError::Discord(_)));
+        assert_eq!(err.to_string(), "Discord operation failed: Mock error");
+    }
+
+    #[test]
+    fn test_io_error_from_std() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        let err: GatehookError = io_err.into();
+
+        assert!(matches!(err, GatehookError::Io(_)));
+        assert_eq!(err.to_string(), "I/O error: missing");
+    }
+
+    #[test]
+    fn test_circuit_open_message() {
+        assert_eq!(
+            GatehookError::CircuitOpen.to_string(),
+            "Webhook circuit breaker is open, event dropped"
+        );
+    }
+
+    #[test]
+    fn test_converts_into_anyhow() {
+        let err: GatehookError = serenity::Error::Other("Mock error").into();
+        let anyhow_err: anyhow::Error = err.into();
+
+        assert!(anyhow_err.downcast_ref::<GatehookError>().is_some());
+    }
+}
@@ -2,4 +2,9 @@
 // This allows modules to be used in tests and potentially as a library
 
 pub mod adapters;
+pub mod admin_server;
 pub mod bridge;
+pub mod error;
+#[cfg(test)]
+mod test_support;
+pub mod validate_response;
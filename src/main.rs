@@ -1,24 +1,50 @@
 mod adapters;
+mod admin_server;
 mod bridge;
+mod error;
 mod params;
+#[cfg(test)]
+mod test_support;
+mod validate_response;
 
 use anyhow::Context as _;
-use adapters::{HttpEventSender, SerenityChannelInfoProvider, SerenityDiscordService};
+use adapters::{
+    CircuitBreakerEventSender, DebugDump, DebugDumpEventSender, FanoutEventSender, HttpEventSender, Outbox,
+    OutboxEventSender, SerenityChannelInfoProvider, SerenityDiscordService,
+};
+use bridge::action_target::{ActionTarget, ReactionChange};
+use bridge::channel_denylist::ChannelDenylist;
 use bridge::event_bridge::EventBridge;
+use bridge::raw_event_payload::RawEventPayload;
 use bridge::sender_filter::{MessageFilter, ReactionFilter};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{error, info};
 
 use serenity::async_trait;
-use serenity::model::channel::{Message, Reaction};
-use serenity::model::event::{MessageUpdateEvent, ResumedEvent};
+use serenity::cache::Settings as CacheSettings;
+use serenity::client::ClientBuilder;
+use serenity::model::application::Interaction;
+use serenity::model::channel::{GuildChannel, Message, PartialGuildChannel, Reaction};
+use serenity::model::event::{Event, GuildMemberUpdateEvent, MessageUpdateEvent, ResumedEvent};
+use serenity::gateway::{ActivityData, ShardStageUpdateEvent};
 use serenity::model::gateway::Ready;
+use serenity::model::guild::automod::ActionExecution;
+use serenity::model::guild::{Guild, Member, ScheduledEvent};
 use serenity::model::id::{ChannelId, GuildId, MessageId};
 use serenity::prelude::*;
 
 struct Handler {
-    bridge: std::sync::OnceLock<EventBridge<SerenityDiscordService, HttpEventSender, SerenityChannelInfoProvider>>,
+    bridge: std::sync::OnceLock<Arc<SerenityEventBridge>>,
     params: Arc<params::Params>,
+    // Disk outbox for at-least-once delivery (OUTBOX_PATH)
+    outbox: Option<Arc<Outbox>>,
+    // Gzipped copies of outgoing payloads (DEBUG_DUMP_DIR)
+    debug_dump: Option<Arc<DebugDump>>,
+    // Channels whose events are dropped, shared across reconnects (CHANNEL_DENYLIST_PATH)
+    channel_denylist: Arc<ChannelDenylist>,
+    // Client shard runners, for voice state updates (join_voice action)
+    shard_manager: std::sync::OnceLock<Arc<serenity::gateway::ShardManager>>,
     // Active filters initialized in ready event
     message_direct_filter: std::sync::OnceLock<MessageFilter>,
     message_guild_filter: std::sync::OnceLock<MessageFilter>,
@@ -30,9 +56,37 @@ struct Handler {
 
 impl Handler {
     fn new(params: &params::Params) -> anyhow::Result<Handler> {
+        let outbox = params
+            .outbox_path
+            .as_ref()
+            .map(|path| {
+                Outbox::open(path)
+                    .with_context(|| format!("Opening outbox at {}", path.display()))
+            })
+            .transpose()?
+            .map(Arc::new);
+        let debug_dump = params
+            .debug_dump_dir
+            .as_ref()
+            .map(|dir| {
+                DebugDump::open(dir, params.debug_dump_max_files, params.debug_dump_max_bytes)
+                    .with_context(|| format!("Opening debug dump directory {}", dir.display()))
+            })
+            .transpose()?
+            .map(Arc::new);
+        let channel_denylist = match &params.channel_denylist_path {
+            Some(path) => ChannelDenylist::open(path)
+                .with_context(|| format!("Opening channel denylist at {}", path.display()))?,
+            None => ChannelDenylist::new(),
+        };
+
         Ok(Handler {
             bridge: std::sync::OnceLock::new(),
             params: Arc::new(params.clone()),
+            outbox,
+            debug_dump,
+            channel_denylist: Arc::new(channel_denylist),
+            shard_manager: std::sync::OnceLock::new(),
             message_direct_filter: std::sync::OnceLock::new(),
             message_guild_filter: std::sync::OnceLock::new(),
             reaction_add_direct_filter: std::sync::OnceLock::new(),
@@ -50,15 +104,23 @@ impl EventHandler for Handler {
 
         // Initialize EventBridge with cache and http from Context
         // Both are kept alive and maintained by Serenity's event loop
-        let discord_service = Arc::new(SerenityDiscordService::new(ctx.http.clone()));
-        let channel_info = Arc::new(SerenityChannelInfoProvider::new(
-            ctx.cache.clone(),
-            ctx.http.clone()
-        ));
+        let discord_service = SerenityDiscordService::new(ctx.http.clone())
+            .with_default_allowed_mentions(self.params.default_allowed_mentions);
+        #[cfg(feature = "actions")]
+        let discord_service = match self.shard_manager.get() {
+            Some(shard_manager) => discord_service.with_voice_gateway(shard_manager.clone(), ctx.cache.clone()),
+            None => discord_service,
+        };
+        let discord_service = Arc::new(discord_service);
+        let channel_info = Arc::new(
+            SerenityChannelInfoProvider::new(ctx.cache.clone(), ctx.http.clone())
+                .with_max_guild_scan(self.params.channel_lookup_max_guilds)
+                .with_channel_index_max_entries(self.params.channel_index_max_entries),
+        );
 
         let endpoint = url::Url::parse(&self.params.http_endpoint)
             .expect("HTTP_ENDPOINT already validated");
-        let event_sender = Arc::new(
+        let http_event_sender = |endpoint| {
             HttpEventSender::new(
                 endpoint,
                 self.params.insecure_mode,
@@ -67,10 +129,131 @@ impl EventHandler for Handler {
                 self.params.max_response_body_size,
             )
             .expect("HttpEventSender already validated")
-        );
+            .with_actions_on_status(self.params.actions_on_status)
+            .with_retry_after(
+                Duration::from_millis(self.params.retry_after_min_ms),
+                Duration::from_secs(self.params.retry_after_max_secs),
+            )
+            .with_headers(
+                self.params.webhook_headers.clone(),
+                self.params.handler_webhook_headers.clone(),
+            )
+        };
+        // Mirrors only receive copies: their responses are ignored, so no parse error reports or guild routing
+        let fanout_event_sender = FanoutEventSender::new(
+            http_event_sender(endpoint)
+                .with_report_parse_errors(self.params.report_parse_errors)
+                .with_guild_endpoints(self.params.guild_http_endpoints.clone()),
+            self.params.fanout_endpoints.iter().cloned().map(http_event_sender).collect(),
+        )
+        .with_mode(self.params.fanout_mode)
+        .with_failure(self.params.fanout_failure, self.params.fanout_suppress_actions);
+        // The outbox wraps the breaker, so events refused while it is open are persisted in order
+        let event_sender = Arc::new(OutboxEventSender::new(
+            CircuitBreakerEventSender::new(
+                DebugDumpEventSender::new(fanout_event_sender, self.debug_dump.clone()),
+                self.params.breaker_failure_threshold,
+                Duration::from_secs(self.params.breaker_cooldown_secs),
+                self.params.breaker_buffer_size,
+            )
+            .with_outbox_backed(self.outbox.is_some()),
+            self.outbox.clone(),
+        ));
 
-        let bridge = EventBridge::new(discord_service, event_sender, channel_info, self.params.max_actions);
-        let _ = self.bridge.set(bridge);
+        let mut bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, self.params.max_actions)
+            .with_max_pending_tasks(self.params.max_pending_tasks)
+            .with_max_task_lifetime(Duration::from_secs(self.params.max_task_lifetime_secs))
+            .with_contextless_actions(self.params.contextless_actions)
+            .with_include_mentions(self.params.message_include_mentions)
+            .with_include_attachments(self.params.message_include_attachments)
+            .with_include_role_names(self.params.message_include_role_names)
+            .with_content_max_forward_chars(self.params.content_max_forward_chars)
+            .with_message_content_intent(
+                build_gateway_intents(&self.params).contains(GatewayIntents::MESSAGE_CONTENT),
+                current_user_id,
+            )
+            .with_reaction_include_message(self.params.reaction_include_message)
+            .with_reaction_own_messages_only(
+                self.params.reaction_guild_on_own_messages_only.then_some(current_user_id),
+            )
+            .with_reaction_remove_coalesce(Duration::from_millis(self.params.reaction_remove_coalesce_ms))
+            .with_reaction_rollup(Duration::from_millis(self.params.reaction_rollup_window_ms))
+            .with_message_fields(self.params.message_fields.clone())
+            .with_reply_chain_depth(self.params.reply_chain_depth)
+            .with_include_shard(self.params.include_shard)
+            .with_include_received_at(self.params.include_received_at)
+            .with_max_payload_size(self.params.max_payload_size)
+            .with_maintenance_message(self.params.maintenance_message.clone())
+            .with_auto_react_emoji(self.params.auto_react_emoji.clone())
+            .with_max_event_age(Duration::from_secs(self.params.max_event_age_secs))
+            .with_max_concurrent_events(self.params.max_concurrent_events)
+            .with_reconnect_alert_threshold(self.params.reconnect_alert_threshold)
+            .with_channel_denylist(self.channel_denylist.clone())
+            .with_truncation_marker(self.params.truncation_marker.clone());
+        #[cfg(feature = "actions")]
+        {
+            bridge = bridge
+                .with_max_actions_per_minute(self.params.max_actions_per_minute)
+                .with_max_threads_per_minute(self.params.max_threads_per_minute, self.params.thread_limit_policy)
+                .with_action_concurrency(self.params.action_concurrency)
+                .with_action_max_retries(self.params.action_max_retries)
+                .with_reply_cooldown(Duration::from_secs(self.params.reply_cooldown_secs))
+                .with_reaction_action_cooldown(Duration::from_secs(self.params.reaction_action_cooldown_secs))
+                .with_action_idempotency_window(Duration::from_secs(self.params.action_idempotency_window_secs))
+                .with_suppress_mass_mentions(self.params.suppress_mass_mentions)
+                .with_precheck_permissions(self.params.precheck_permissions)
+                .with_normalize_emoji(self.params.normalize_emoji)
+                .with_reaction_commands(self.params.reaction_commands.clone())
+                .with_reaction_commands_forward(self.params.reaction_commands_forward)
+                .with_allowed_actions(self.params.allowed_actions.clone())
+                .with_reaction_reply_mode(self.params.reaction_reply_mode)
+                .with_error_notify_channel(
+                    self.params.error_notify_channel,
+                    Duration::from_secs(self.params.error_notify_interval_secs),
+                );
+        }
+        for (handler, rate) in self.params.sample_rates() {
+            bridge = bridge.with_sample_rate(handler, rate);
+        }
+        for (handler, &deadline) in &self.params.handler_webhook_deadlines {
+            bridge = bridge.with_webhook_deadline(handler, deadline);
+        }
+        for (handler, paths) in &self.params.handler_redacted_fields {
+            bridge = bridge.with_redacted_fields(handler, paths.clone());
+        }
+        if let Some(seed) = self.params.sample_seed {
+            bridge = bridge.with_sample_seed(seed);
+        }
+
+        let bridge = Arc::new(bridge);
+        if self.bridge.set(bridge.clone()).is_ok() {
+            // Start the heartbeat once the bridge exists (first READY only)
+            if self.params.heartbeat_interval_secs > 0 {
+                let heartbeat_bridge = bridge.clone();
+                let interval = Duration::from_secs(self.params.heartbeat_interval_secs);
+                bridge.spawn_service("heartbeat", move |shutdown| async move {
+                    heartbeat_bridge.run_heartbeat(interval, shutdown).await;
+                });
+            }
+
+            // Replay events left in the outbox by a previous run (first READY only)
+            if self.outbox.is_some() {
+                bridge.spawn_task("outbox_replay", async move {
+                    if let Err(err) = event_sender.replay().await {
+                        error!(?err, "Failed to replay events from outbox");
+                    }
+                });
+            }
+
+            // Start the admin endpoint once the bridge exists (first READY only)
+            if let Some(port) = self.params.admin_port {
+                spawn_admin_server(port, self.params.admin_token.clone(), bridge);
+            }
+        }
+
+        if let Some(bridge) = self.bridge.get() {
+            bridge.set_connected(ctx.shard_id, true);
+        }
 
         // Initialize active filters with current user ID
         if let Some(policy) = &self.params.message_direct {
@@ -126,7 +309,7 @@ impl EventHandler for Handler {
         };
 
         // Handle event (send to webhook + execute actions if needed)
-        match bridge.handle_ready(&ready).await {
+        match bridge.in_shard(ctx.shard_id, bridge.handle_ready(&ready)).await {
             Ok(Some(event_response)) if !event_response.actions.is_empty() => {
                 // Currently ready event doesn't have associated message context,
                 // so we log and skip action execution
@@ -144,9 +327,13 @@ impl EventHandler for Handler {
         }
     }
 
-    async fn resume(&self, _ctx: Context, resumed: ResumedEvent) {
+    async fn resume(&self, ctx: Context, resumed: ResumedEvent) {
         info!("Session resumed successfully");
 
+        if let Some(bridge) = self.bridge.get() {
+            bridge.set_connected(ctx.shard_id, true);
+        }
+
         // Check if RESUMED event is enabled
         if self.params.resumed.is_none() {
             return;
@@ -159,7 +346,7 @@ impl EventHandler for Handler {
         };
 
         // Handle event (send to webhook + execute actions if needed)
-        match bridge.handle_resumed(&resumed).await {
+        match bridge.in_shard(ctx.shard_id, bridge.handle_resumed(&resumed)).await {
             Ok(Some(event_response)) if !event_response.actions.is_empty() => {
                 // Currently resumed event doesn't have associated message context,
                 // so we log and skip action execution
@@ -177,7 +364,28 @@ impl EventHandler for Handler {
         }
     }
 
-    async fn message(&self, _ctx: Context, message: Message) {
+    async fn shard_stage_update(&self, _ctx: Context, event: ShardStageUpdateEvent) {
+        // Track the connection state and reconnect attempts (bridge exists after the first READY)
+        let Some(bridge) = self.bridge.get() else {
+            return;
+        };
+
+        let stage_update = bridge.handle_stage_update(event.shard_id, event.new);
+        match bridge.in_shard(event.shard_id, stage_update).await {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                tracing::warn!(
+                    action_count = event_response.actions.len(),
+                    "Reconnecting event received actions from webhook, but action execution is not supported for reconnecting events"
+                );
+            }
+            Ok(_) => {}
+            Err(err) => {
+                error!(?err, "Failed to handle shard stage update");
+            }
+        }
+    }
+
+    async fn message(&self, ctx: Context, message: Message) {
         let is_direct = message.guild_id.is_none();
 
         // Get the appropriate active filter
@@ -203,8 +411,13 @@ impl EventHandler for Handler {
             return;
         };
 
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("message") else {
+            return;
+        };
+
         // Handle event (send to webhook + execute actions)
-        match bridge.handle_message(&message).await {
+        match bridge.in_shard(ctx.shard_id, bridge.handle_message(&message)).await {
             Ok(Some(event_response)) if !event_response.actions.is_empty() => {
                 // Execute actions if webhook responded with any
                 if let Err(err) = bridge
@@ -225,7 +438,7 @@ impl EventHandler for Handler {
 
     async fn message_delete(
         &self,
-        _ctx: Context,
+        ctx: Context,
         channel_id: ChannelId,
         deleted_message_id: MessageId,
         guild_id: Option<GuildId>,
@@ -243,17 +456,24 @@ impl EventHandler for Handler {
             return;
         };
 
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("message_delete") else {
+            return;
+        };
+
         // Handle event
         match bridge
-            .handle_message_delete(channel_id, deleted_message_id, guild_id)
+            .in_shard(ctx.shard_id, bridge.handle_message_delete(channel_id, deleted_message_id, guild_id))
             .await
         {
             Ok(Some(event_response)) if !event_response.actions.is_empty() => {
-                tracing::warn!(
-                    action_count = event_response.actions.len(),
-                    "MessageDelete event received actions from webhook, \
-                     but action execution is not supported for delete events"
-                );
+                let target = ActionTarget::from_ids(deleted_message_id, channel_id, guild_id);
+                if let Err(err) = bridge
+                    .execute_contextless_actions("message_delete", target, &event_response)
+                    .await
+                {
+                    error!(?err, "Failed to execute actions from webhook response");
+                }
             }
             Ok(_) => {
                 // Success
@@ -266,7 +486,7 @@ impl EventHandler for Handler {
 
     async fn message_delete_bulk(
         &self,
-        _ctx: Context,
+        ctx: Context,
         channel_id: ChannelId,
         multiple_deleted_messages_ids: Vec<MessageId>,
         guild_id: Option<GuildId>,
@@ -282,17 +502,30 @@ impl EventHandler for Handler {
             return;
         };
 
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("message_delete_bulk") else {
+            return;
+        };
+
+        // Actions target the first deleted message
+        let first_message_id = multiple_deleted_messages_ids.first().copied();
+
         // Handle event
         match bridge
-            .handle_message_delete_bulk(channel_id, multiple_deleted_messages_ids, guild_id)
+            .in_shard(ctx.shard_id, bridge.handle_message_delete_bulk(channel_id, multiple_deleted_messages_ids, guild_id))
             .await
         {
             Ok(Some(event_response)) if !event_response.actions.is_empty() => {
-                tracing::warn!(
-                    action_count = event_response.actions.len(),
-                    "MessageDeleteBulk event received actions from webhook, \
-                     but action execution is not supported for delete events"
-                );
+                let Some(message_id) = first_message_id else {
+                    return;
+                };
+                let target = ActionTarget::from_ids(message_id, channel_id, guild_id);
+                if let Err(err) = bridge
+                    .execute_contextless_actions("message_delete_bulk", target, &event_response)
+                    .await
+                {
+                    error!(?err, "Failed to execute actions from webhook response");
+                }
             }
             Ok(_) => {
                 // Success
@@ -305,7 +538,7 @@ impl EventHandler for Handler {
 
     async fn message_update(
         &self,
-        _ctx: Context,
+        ctx: Context,
         _old_if_available: Option<Message>,
         _new: Option<Message>,
         event: MessageUpdateEvent,
@@ -323,25 +556,369 @@ impl EventHandler for Handler {
             return;
         };
 
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("message_update") else {
+            return;
+        };
+
+        let target = ActionTarget::from_ids(event.id, event.channel_id, event.guild_id);
+
+        // Handle event
+        match bridge.in_shard(ctx.shard_id, bridge.handle_message_update(event)).await {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                if let Err(err) = bridge
+                    .execute_contextless_actions("message_update", target, &event_response)
+                    .await
+                {
+                    error!(?err, "Failed to execute actions from webhook response");
+                }
+            }
+            Ok(_) => {
+                // Success
+            }
+            Err(err) => {
+                error!(?err, "Failed to handle message_update event");
+            }
+        }
+    }
+
+    async fn thread_create(&self, ctx: Context, thread: GuildChannel) {
+        // Check if event is enabled
+        if self.params.thread_create_guild.is_none() {
+            return;
+        }
+
+        // Get bridge
+        let Some(bridge) = self.bridge.get() else {
+            error!("Bridge not initialized - this should not happen");
+            return;
+        };
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("thread_create") else {
+            return;
+        };
+
         // Handle event
-        match bridge.handle_message_update(event).await {
+        match bridge.in_shard(ctx.shard_id, bridge.handle_thread_create(&thread)).await {
             Ok(Some(event_response)) if !event_response.actions.is_empty() => {
                 tracing::warn!(
                     action_count = event_response.actions.len(),
-                    "MessageUpdate event received actions from webhook, \
-                     but action execution is not supported for update events"
+                    "ThreadCreate event received actions from webhook, \
+                     but action execution is not supported for thread events"
                 );
             }
             Ok(_) => {
                 // Success
             }
             Err(err) => {
-                error!(?err, "Failed to handle message_update event");
+                error!(?err, "Failed to handle thread_create event");
             }
         }
     }
 
-    async fn reaction_add(&self, _ctx: Context, reaction: Reaction) {
+    async fn thread_delete(
+        &self,
+        ctx: Context,
+        thread: PartialGuildChannel,
+        _full_thread_data: Option<GuildChannel>,
+    ) {
+        // Check if event is enabled
+        if self.params.thread_delete_guild.is_none() {
+            return;
+        }
+
+        // Get bridge
+        let Some(bridge) = self.bridge.get() else {
+            error!("Bridge not initialized - this should not happen");
+            return;
+        };
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("thread_delete") else {
+            return;
+        };
+
+        // Handle event
+        match bridge
+            .in_shard(ctx.shard_id, bridge.handle_thread_delete(thread.id, thread.guild_id, thread.parent_id))
+            .await
+        {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                tracing::warn!(
+                    action_count = event_response.actions.len(),
+                    "ThreadDelete event received actions from webhook, \
+                     but action execution is not supported for thread events"
+                );
+            }
+            Ok(_) => {
+                // Success
+            }
+            Err(err) => {
+                error!(?err, "Failed to handle thread_delete event");
+            }
+        }
+    }
+
+    async fn guild_scheduled_event_create(&self, ctx: Context, event: ScheduledEvent) {
+        // Check if event is enabled
+        if self.params.guild_scheduled_event_create.is_none() {
+            return;
+        }
+
+        // Get bridge
+        let Some(bridge) = self.bridge.get() else {
+            error!("Bridge not initialized - this should not happen");
+            return;
+        };
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("guild_scheduled_event_create") else {
+            return;
+        };
+
+        // Handle event
+        match bridge.in_shard(ctx.shard_id, bridge.handle_guild_scheduled_event_create(&event)).await {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                tracing::warn!(
+                    action_count = event_response.actions.len(),
+                    "GuildScheduledEventCreate event received actions from webhook, \
+                     but action execution is not supported for scheduled events"
+                );
+            }
+            Ok(_) => {
+                // Success
+            }
+            Err(err) => {
+                error!(?err, "Failed to handle guild_scheduled_event_create event");
+            }
+        }
+    }
+
+    async fn guild_scheduled_event_update(&self, ctx: Context, event: ScheduledEvent) {
+        // Check if event is enabled
+        if self.params.guild_scheduled_event_update.is_none() {
+            return;
+        }
+
+        // Get bridge
+        let Some(bridge) = self.bridge.get() else {
+            error!("Bridge not initialized - this should not happen");
+            return;
+        };
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("guild_scheduled_event_update") else {
+            return;
+        };
+
+        // Handle event
+        match bridge.in_shard(ctx.shard_id, bridge.handle_guild_scheduled_event_update(&event)).await {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                tracing::warn!(
+                    action_count = event_response.actions.len(),
+                    "GuildScheduledEventUpdate event received actions from webhook, \
+                     but action execution is not supported for scheduled events"
+                );
+            }
+            Ok(_) => {
+                // Success
+            }
+            Err(err) => {
+                error!(?err, "Failed to handle guild_scheduled_event_update event");
+            }
+        }
+    }
+
+    async fn guild_scheduled_event_delete(&self, ctx: Context, event: ScheduledEvent) {
+        // Check if event is enabled
+        if self.params.guild_scheduled_event_delete.is_none() {
+            return;
+        }
+
+        // Get bridge
+        let Some(bridge) = self.bridge.get() else {
+            error!("Bridge not initialized - this should not happen");
+            return;
+        };
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("guild_scheduled_event_delete") else {
+            return;
+        };
+
+        // Handle event
+        match bridge.in_shard(ctx.shard_id, bridge.handle_guild_scheduled_event_delete(&event)).await {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                tracing::warn!(
+                    action_count = event_response.actions.len(),
+                    "GuildScheduledEventDelete event received actions from webhook, \
+                     but action execution is not supported for scheduled events"
+                );
+            }
+            Ok(_) => {
+                // Success
+            }
+            Err(err) => {
+                error!(?err, "Failed to handle guild_scheduled_event_delete event");
+            }
+        }
+    }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        // Check if event is enabled
+        if self.params.interaction_create.is_none() {
+            return;
+        }
+
+        // Only message component interactions (button clicks, select menus) are forwarded
+        let Interaction::Component(interaction) = interaction else {
+            return;
+        };
+
+        // Get bridge
+        let Some(bridge) = self.bridge.get() else {
+            error!("Bridge not initialized - this should not happen");
+            return;
+        };
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("interaction_create") else {
+            return;
+        };
+
+        // Handle event (send to webhook + execute actions)
+        match bridge.in_shard(ctx.shard_id, bridge.handle_component_interaction(&interaction)).await {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                // Execute actions if webhook responded with any
+                if let Err(err) = bridge.execute_actions(&interaction, &event_response).await {
+                    error!(?err, "Failed to execute actions from webhook response");
+                }
+            }
+            Ok(_) => {
+                // No response or empty actions - success
+            }
+            Err(err) => {
+                error!(?err, "Failed to handle interaction_create event");
+            }
+        }
+    }
+
+    async fn guild_create(&self, ctx: Context, guild: Guild, _is_new: Option<bool>) {
+        // Check if event is enabled
+        if self.params.guild_create.is_none() {
+            return;
+        }
+
+        // Get bridge
+        let Some(bridge) = self.bridge.get() else {
+            error!("Bridge not initialized - this should not happen");
+            return;
+        };
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("guild_create") else {
+            return;
+        };
+
+        // Handle event
+        match bridge.in_shard(ctx.shard_id, bridge.handle_guild_create(&guild)).await {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                tracing::warn!(
+                    action_count = event_response.actions.len(),
+                    "GuildCreate event received actions from webhook, \
+                     but action execution is not supported for guild events"
+                );
+            }
+            Ok(_) => {
+                // Success
+            }
+            Err(err) => {
+                error!(?err, "Failed to handle guild_create event");
+            }
+        }
+    }
+
+    async fn guild_member_update(
+        &self,
+        ctx: Context,
+        old_if_available: Option<Member>,
+        _new: Option<Member>,
+        event: GuildMemberUpdateEvent,
+    ) {
+        // Check if event is enabled
+        if self.params.guild_member_update.is_none() {
+            return;
+        }
+
+        // Get bridge
+        let Some(bridge) = self.bridge.get() else {
+            error!("Bridge not initialized - this should not happen");
+            return;
+        };
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("guild_member_update") else {
+            return;
+        };
+
+        // Handle event
+        match bridge
+            .in_shard(ctx.shard_id, bridge.handle_guild_member_update(old_if_available.as_ref(), &event))
+            .await
+        {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                tracing::warn!(
+                    action_count = event_response.actions.len(),
+                    "GuildMemberUpdate event received actions from webhook, \
+                     but action execution is not supported for member events"
+                );
+            }
+            Ok(_) => {
+                // Success
+            }
+            Err(err) => {
+                error!(?err, "Failed to handle guild_member_update event");
+            }
+        }
+    }
+
+    async fn auto_moderation_action_execution(&self, ctx: Context, execution: ActionExecution) {
+        // Check if event is enabled
+        if self.params.auto_moderation_action_execution.is_none() {
+            return;
+        }
+
+        // Get bridge
+        let Some(bridge) = self.bridge.get() else {
+            error!("Bridge not initialized - this should not happen");
+            return;
+        };
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("auto_moderation_action_execution") else {
+            return;
+        };
+
+        // Handle event
+        match bridge.in_shard(ctx.shard_id, bridge.handle_auto_moderation_action_execution(&execution)).await {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                tracing::warn!(
+                    action_count = event_response.actions.len(),
+                    "AutoModerationActionExecution event received actions from webhook, \
+                     but action execution is not supported for AutoMod events"
+                );
+            }
+            Ok(_) => {
+                // Success
+            }
+            Err(err) => {
+                error!(?err, "Failed to handle auto_moderation_action_execution event");
+            }
+        }
+    }
+
+    async fn reaction_add(&self, ctx: Context, reaction: Reaction) {
         // Determine filter based on context (DM vs Guild)
         let filter = match reaction.guild_id {
             None => self.reaction_add_direct_filter.get(),
@@ -362,12 +939,20 @@ impl EventHandler for Handler {
             return;
         };
 
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("reaction_add") else {
+            return;
+        };
+
         // Handle event (send to webhook + execute actions)
-        match bridge.handle_reaction_add(&reaction).await {
+        match bridge.in_shard(ctx.shard_id, bridge.handle_reaction_add(&reaction)).await {
             Ok(Some(event_response)) if !event_response.actions.is_empty() => {
                 // Execute actions if webhook responded with any
                 if let Err(err) = bridge
-                    .execute_actions(&reaction, &event_response)
+                    .execute_actions(
+                        ActionTarget::from(&reaction).with_reaction_change(ReactionChange::Added),
+                        &event_response,
+                    )
                     .await
                 {
                     error!(?err, "Failed to execute actions from webhook response");
@@ -382,7 +967,7 @@ impl EventHandler for Handler {
         }
     }
 
-    async fn reaction_remove(&self, _ctx: Context, reaction: Reaction) {
+    async fn reaction_remove(&self, ctx: Context, reaction: Reaction) {
         // Determine filter based on context (DM vs Guild)
         let filter = match reaction.guild_id {
             None => self.reaction_remove_direct_filter.get(),
@@ -403,12 +988,20 @@ impl EventHandler for Handler {
             return;
         };
 
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event("reaction_remove") else {
+            return;
+        };
+
         // Handle event (send to webhook + execute actions)
-        match bridge.handle_reaction_remove(&reaction).await {
+        match bridge.in_shard(ctx.shard_id, bridge.handle_reaction_remove(&reaction)).await {
             Ok(Some(event_response)) if !event_response.actions.is_empty() => {
                 // Execute actions if webhook responded with any
                 if let Err(err) = bridge
-                    .execute_actions(&reaction, &event_response)
+                    .execute_actions(
+                        ActionTarget::from(&reaction).with_reaction_change(ReactionChange::Removed),
+                        &event_response,
+                    )
                     .await
                 {
                     error!(?err, "Failed to execute actions from webhook response");
@@ -424,8 +1017,77 @@ impl EventHandler for Handler {
     }
 }
 
+/// Forwards allowlisted raw gateway events (`RAW_EVENTS`)
+///
+/// Shares the `Handler` so it can reuse the bridge initialized on READY.
+struct RawHandler {
+    handler: Arc<Handler>,
+}
+
+#[async_trait]
+impl RawEventHandler for RawHandler {
+    async fn raw_event(&self, ctx: Context, event: Event) {
+        // Events before the first READY have no bridge yet
+        let Some(bridge) = self.handler.bridge.get() else {
+            return;
+        };
+
+        // Check if event is enabled
+        let Some(payload) = RawEventPayload::from_event(&event) else {
+            return;
+        };
+        if !self.handler.params.raw_events.contains(&payload.kind) {
+            return;
+        }
+
+        // Shed the event when too many are already in progress (MAX_CONCURRENT_EVENTS)
+        let Some(_permit) = bridge.try_begin_event(&payload.kind) else {
+            return;
+        };
+
+        // Handle event
+        match bridge.in_shard(ctx.shard_id, bridge.handle_raw_event(&payload)).await {
+            Ok(Some(event_response)) if !event_response.actions.is_empty() => {
+                tracing::warn!(
+                    action_count = event_response.actions.len(),
+                    event_type = %payload.kind,
+                    "Raw event received actions from webhook, \
+                     but action execution is not supported for raw events"
+                );
+            }
+            Ok(_) => {
+                // Success
+            }
+            Err(err) => {
+                error!(?err, event_type = %payload.kind, "Failed to handle raw event");
+            }
+        }
+    }
+}
+
+/// CLI flag that validates a webhook response file and exits without connecting
+const VALIDATE_RESPONSE_FLAG: &str = "--validate-response";
+
+/// How long to wait for background tasks once the client has stopped
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    // Offline response validation: no configuration or Discord connection needed
+    if let Some(path) = validate_response_arg(std::env::args().skip(1))? {
+        let json = std::fs::read_to_string(&path).with_context(|| format!("Reading {path}"))?;
+        match validate_response::validate_response(&json) {
+            Ok(summary) => {
+                println!("{summary}");
+                return Ok(());
+            }
+            Err(err) => {
+                eprintln!("Invalid webhook response: {err}");
+                std::process::exit(1);
+            }
+        }
+    }
+
     // Load environment variables from .env file if it exists
     let _ = dotenvy::dotenv();
 
@@ -452,18 +1114,154 @@ async fn main() -> anyhow::Result<()> {
     // Build gateway intents based on enabled events
     let intents = build_gateway_intents(&params);
     info!(?intents, "Gateway intents configured");
+    if params.log_startup_summary {
+        let summary = serde_json::to_string(&params.summary(intents))?;
+        info!(%summary, "Startup summary");
+    }
 
     // Create a new instance of the Client, logging in as a bot.
-    let mut client = Client::builder(&params.discord_token, intents)
-        .event_handler(Handler::new(&params)?)
-        .await
-        .context("Creating Discord Client")?;
+    let handler = Arc::new(Handler::new(&params)?);
+    let mut builder = configure_client_builder(Client::builder(&params.discord_token, intents), &params)
+        .event_handler_arc(handler.clone());
+    if params.has_raw_events() {
+        builder = builder.raw_event_handler(RawHandler { handler: handler.clone() });
+    }
+    let mut client = builder.await.context("Creating Discord Client")?;
+    let _ = handler.shard_manager.set(client.shard_manager.clone());
+
+    // Stop the shards on SIGTERM (e.g. `docker stop`) or Ctrl-C, so the drain below runs
+    let shard_manager = client.shard_manager.clone();
+    tokio::spawn(async move {
+        shutdown_signal().await;
+        info!("Shutdown signal received, stopping shards");
+        shard_manager.shutdown_all().await;
+    });
 
     // Start listening for events by starting a single shard
-    client
+    let result = client
         .start_autosharded()
         .await
-        .context("Running Discord Client")
+        .context("Running Discord Client");
+
+    // Stop the heartbeat and admin endpoint, and let background tasks (outbox replay,
+    // admin requests) finish before exiting
+    if let Some(bridge) = handler.bridge.get() {
+        info!("Draining background tasks");
+        if !bridge.drain_tasks(SHUTDOWN_DRAIN_TIMEOUT).await {
+            tracing::warn!(timeout = ?SHUTDOWN_DRAIN_TIMEOUT, "Background tasks still running after drain timeout");
+        }
+    }
+
+    result
+}
+
+/// Wait for SIGTERM or Ctrl-C
+///
+/// If a handler cannot be installed, the error is logged and the other signal is still awaited.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(err) = tokio::signal::ctrl_c().await {
+            error!(?err, "Failed to listen for Ctrl-C");
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(err) => {
+                error!(?err, "Failed to listen for SIGTERM");
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        () = ctrl_c => {}
+        () = terminate => {}
+    }
+}
+
+/// File path given with `--validate-response <file>`, if the flag is present
+fn validate_response_arg(mut args: impl Iterator<Item = String>) -> anyhow::Result<Option<String>> {
+    while let Some(arg) = args.next() {
+        if arg == VALIDATE_RESPONSE_FLAG {
+            return args
+                .next()
+                .map(Some)
+                .with_context(|| format!("{VALIDATE_RESPONSE_FLAG} requires a file path"));
+        }
+    }
+    Ok(None)
+}
+
+/// Event sender used by the running bot (disk outbox over the circuit breaker)
+type SerenityEventSender =
+    OutboxEventSender<CircuitBreakerEventSender<DebugDumpEventSender<FanoutEventSender<HttpEventSender>>>>;
+
+/// Bridge type used by the running bot
+type SerenityEventBridge =
+    EventBridge<SerenityDiscordService, SerenityEventSender, SerenityChannelInfoProvider>;
+
+/// Start the admin endpoint (`ADMIN_PORT`) on the loopback interface
+///
+/// The endpoint is a bridge service, stopped by `drain_tasks()`. Bind
+/// failures are logged; the bot keeps running without the endpoint.
+fn spawn_admin_server(port: u16, token: Option<String>, bridge: Arc<SerenityEventBridge>) {
+    let addr = std::net::SocketAddr::from(([127, 0, 0, 1], port));
+    let server_bridge = bridge.clone();
+    bridge.spawn_service("admin_server", move |shutdown| async move {
+        let result = match admin_server::AdminServer::bind(addr).await {
+            Ok(server) => server.with_token(token).with_shutdown(shutdown).serve(server_bridge).await,
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result {
+            error!(?err, %addr, "Admin endpoint stopped");
+        }
+    });
+}
+
+/// Apply client identity, presence and cache configuration to the client builder
+///
+/// Serenity hardcodes the gateway identify properties (`browser`/`device` are always
+/// "serenity"), so `CLIENT_NAME` cannot be sent there. Instead it is applied as the
+/// bot's initial custom status, which is visible to server members.
+/// `INITIAL_ACTIVITY` takes precedence over `CLIENT_NAME` when both are set.
+fn configure_client_builder(builder: ClientBuilder, params: &params::Params) -> ClientBuilder {
+    let builder = builder.cache_settings(build_cache_settings(params));
+
+    let builder = match &params.initial_status {
+        Some(status) => builder.status(*status),
+        None => builder,
+    };
+
+    match (&params.initial_activity, &params.client_name) {
+        (Some(activity), _) => builder.activity(activity.clone()),
+        (None, Some(client_name)) => builder.activity(ActivityData::custom(client_name)),
+        (None, None) => builder,
+    }
+}
+
+/// Build serenity cache settings from parameters
+///
+/// `CACHE_DISABLED` turns off guild, channel and user caching entirely, trading
+/// memory for latency: channel lookups then always go through the Discord API.
+fn build_cache_settings(params: &params::Params) -> CacheSettings {
+    let mut settings = CacheSettings::default();
+    settings.max_messages = params.cache_max_messages;
+
+    if params.cache_disabled {
+        settings.cache_guilds = false;
+        settings.cache_channels = false;
+        settings.cache_users = false;
+    }
+
+    settings
 }
 
 /// Build GatewayIntents based on enabled events in parameters
@@ -481,7 +1279,8 @@ fn build_gateway_intents(params: &params::Params) -> GatewayIntents {
     }
 
     // MESSAGE_CONTENT is needed for MESSAGE and MESSAGE_UPDATE events, not DELETE or REACTION_ADD/REMOVE
-    if params.has_direct_message_events() || params.has_message_update_events() {
+    // (privileged; MESSAGE_CONTENT_INTENT=false runs without it)
+    if params.message_content_intent && (params.has_direct_message_events() || params.has_message_update_events()) {
         intents |= GatewayIntents::MESSAGE_CONTENT;
     }
 
@@ -504,7 +1303,7 @@ fn build_gateway_intents(params: &params::Params) -> GatewayIntents {
     }
 
     // MESSAGE_CONTENT is needed for MESSAGE and MESSAGE_UPDATE events, not DELETE or REACTION_ADD/REMOVE
-    if params.has_guild_message_events() || params.has_message_update_events() {
+    if params.message_content_intent && (params.has_guild_message_events() || params.has_message_update_events()) {
         intents |= GatewayIntents::MESSAGE_CONTENT;
     }
 
@@ -513,5 +1312,271 @@ fn build_gateway_intents(params: &params::Params) -> GatewayIntents {
         intents |= GatewayIntents::GUILD_MESSAGE_REACTIONS;
     }
 
+    // Thread events (THREAD_CREATE, THREAD_DELETE) are delivered via the GUILDS intent
+    if params.has_thread_events() {
+        intents |= GatewayIntents::GUILDS;
+    }
+
+    // Scheduled events (GUILD_SCHEDULED_EVENT_CREATE/UPDATE/DELETE)
+    if params.has_scheduled_events() {
+        intents |= GatewayIntents::GUILD_SCHEDULED_EVENTS;
+    }
+
+    // AutoMod action executions (AUTO_MODERATION_ACTION_EXECUTION)
+    if params.has_auto_moderation_action_events() {
+        intents |= GatewayIntents::AUTO_MODERATION_EXECUTION;
+    }
+
+    // Raw events (RAW_EVENTS) request the intents their event types need
+    for name in &params.raw_events {
+        intents |= raw_event_intents(name);
+    }
+
+    // Guild availability (GUILD_CREATE) is delivered via the GUILDS intent
+    if params.has_guild_create_events() {
+        intents |= GatewayIntents::GUILDS;
+    }
+
+    // The join_voice action needs voice state updates to complete a voice connection
+    if params.has_join_voice_action() {
+        intents |= GatewayIntents::GUILD_VOICE_STATES | GatewayIntents::GUILDS;
+    }
+
+    // Member updates (GUILD_MEMBER_UPDATE) need the privileged GUILD_MEMBERS intent;
+    // GUILDS populates the guild cache so the previous member state can be reported
+    if params.has_guild_member_update_events() {
+        intents |= GatewayIntents::GUILD_MEMBERS | GatewayIntents::GUILDS;
+    }
+
     intents
 }
+
+/// Gateway intents required to receive a raw event type
+///
+/// Unknown event names map to no intents; such events are only received if
+/// another enabled event already requests the intent they need.
+fn raw_event_intents(name: &str) -> GatewayIntents {
+    match name {
+        "CHANNEL_CREATE" | "CHANNEL_UPDATE" | "CHANNEL_DELETE" | "THREAD_CREATE"
+        | "THREAD_UPDATE" | "THREAD_DELETE" | "THREAD_LIST_SYNC" | "THREAD_MEMBER_UPDATE"
+        | "STAGE_INSTANCE_CREATE" | "STAGE_INSTANCE_UPDATE" | "STAGE_INSTANCE_DELETE"
+        | "GUILD_CREATE" | "GUILD_UPDATE" | "GUILD_DELETE" | "GUILD_ROLE_CREATE"
+        | "GUILD_ROLE_UPDATE" | "GUILD_ROLE_DELETE" => GatewayIntents::GUILDS,
+        "CHANNEL_PINS_UPDATE" => GatewayIntents::GUILDS | GatewayIntents::DIRECT_MESSAGES,
+        "GUILD_MEMBER_ADD" | "GUILD_MEMBER_UPDATE" | "GUILD_MEMBER_REMOVE"
+        | "THREAD_MEMBERS_UPDATE" => GatewayIntents::GUILD_MEMBERS,
+        "GUILD_AUDIT_LOG_ENTRY_CREATE" | "GUILD_BAN_ADD" | "GUILD_BAN_REMOVE" => {
+            GatewayIntents::GUILD_MODERATION
+        }
+        "GUILD_EMOJIS_UPDATE" | "GUILD_STICKERS_UPDATE" => GatewayIntents::GUILD_EMOJIS_AND_STICKERS,
+        "GUILD_INTEGRATIONS_UPDATE" | "INTEGRATION_CREATE" | "INTEGRATION_UPDATE"
+        | "INTEGRATION_DELETE" => GatewayIntents::GUILD_INTEGRATIONS,
+        "WEBHOOKS_UPDATE" => GatewayIntents::GUILD_WEBHOOKS,
+        "INVITE_CREATE" | "INVITE_DELETE" => GatewayIntents::GUILD_INVITES,
+        "VOICE_STATE_UPDATE" => GatewayIntents::GUILD_VOICE_STATES,
+        "PRESENCE_UPDATE" => GatewayIntents::GUILD_PRESENCES,
+        "TYPING_START" => GatewayIntents::GUILD_MESSAGE_TYPING | GatewayIntents::DIRECT_MESSAGE_TYPING,
+        "GUILD_SCHEDULED_EVENT_CREATE" | "GUILD_SCHEDULED_EVENT_UPDATE"
+        | "GUILD_SCHEDULED_EVENT_DELETE" | "GUILD_SCHEDULED_EVENT_USER_ADD"
+        | "GUILD_SCHEDULED_EVENT_USER_REMOVE" => GatewayIntents::GUILD_SCHEDULED_EVENTS,
+        "AUTO_MODERATION_RULE_CREATE" | "AUTO_MODERATION_RULE_UPDATE"
+        | "AUTO_MODERATION_RULE_DELETE" => GatewayIntents::AUTO_MODERATION_CONFIGURATION,
+        "AUTO_MODERATION_ACTION_EXECUTION" => GatewayIntents::AUTO_MODERATION_EXECUTION,
+        "MESSAGE_POLL_VOTE_ADD" | "MESSAGE_POLL_VOTE_REMOVE" => {
+            GatewayIntents::GUILD_MESSAGE_POLLS | GatewayIntents::DIRECT_MESSAGE_POLLS
+        }
+        _ => GatewayIntents::empty(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn params_from(vars: &[(&str, &str)]) -> params::Params {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        env.extend(vars.iter().map(|(k, v)| (k.to_string(), v.to_string())));
+        envy::from_iter(env).unwrap()
+    }
+
+    #[rstest]
+    #[case::absent(&[], Ok(None))]
+    #[case::with_path(&["--validate-response", "response.json"], Ok(Some("response.json")))]
+    #[case::missing_path(&["--validate-response"], Err(()))]
+    fn test_validate_response_arg(#[case] args: &[&str], #[case] expected: Result<Option<&str>, ()>) {
+        let result = validate_response_arg(args.iter().map(|arg| arg.to_string()));
+
+        assert_eq!(result.as_ref().map(Option::as_deref).map_err(|_| ()), expected);
+    }
+
+    #[test]
+    fn test_configure_client_builder_applies_client_name() {
+        let params = params_from(&[("CLIENT_NAME", "gatehook-prod-01")]);
+        let builder =
+            configure_client_builder(Client::builder("token", GatewayIntents::empty()), &params);
+
+        let activity = builder.get_presence().activity.as_ref().unwrap();
+        assert_eq!(activity.state.as_deref(), Some("gatehook-prod-01"));
+    }
+
+    #[test]
+    fn test_configure_client_builder_without_client_name() {
+        let params = params_from(&[]);
+        let builder =
+            configure_client_builder(Client::builder("token", GatewayIntents::empty()), &params);
+
+        assert!(builder.get_presence().activity.is_none());
+    }
+
+    #[test]
+    fn test_configure_client_builder_applies_presence() {
+        use serenity::model::gateway::ActivityType;
+        use serenity::model::user::OnlineStatus;
+
+        let params = params_from(&[
+            ("INITIAL_STATUS", "dnd"),
+            ("INITIAL_ACTIVITY", "Watching the gateway"),
+            ("CLIENT_NAME", "gatehook-prod-01"),
+        ]);
+        let builder =
+            configure_client_builder(Client::builder("token", GatewayIntents::empty()), &params);
+
+        let presence = builder.get_presence();
+        assert_eq!(presence.status, OnlineStatus::DoNotDisturb);
+
+        // INITIAL_ACTIVITY takes precedence over CLIENT_NAME
+        let activity = presence.activity.as_ref().unwrap();
+        assert_eq!(activity.kind, ActivityType::Watching);
+        assert_eq!(activity.name, "the gateway");
+    }
+
+    #[test]
+    fn test_configure_client_builder_default_cache_settings() {
+        let params = params_from(&[]);
+        let builder =
+            configure_client_builder(Client::builder("token", GatewayIntents::empty()), &params);
+
+        let settings = builder.get_cache_settings();
+        assert_eq!(settings.max_messages, 0);
+        assert!(settings.cache_guilds);
+        assert!(settings.cache_channels);
+        assert!(settings.cache_users);
+    }
+
+    #[test]
+    fn test_configure_client_builder_applies_cache_settings() {
+        let params = params_from(&[("CACHE_DISABLED", "true"), ("CACHE_MAX_MESSAGES", "50")]);
+        let builder =
+            configure_client_builder(Client::builder("token", GatewayIntents::empty()), &params);
+
+        let settings = builder.get_cache_settings();
+        assert_eq!(settings.max_messages, 50);
+        assert!(!settings.cache_guilds);
+        assert!(!settings.cache_channels);
+        assert!(!settings.cache_users);
+    }
+
+    #[test]
+    fn test_build_gateway_intents_scheduled_events() {
+        let params = params_from(&[("GUILD_SCHEDULED_EVENT_UPDATE", "all")]);
+        let intents = build_gateway_intents(&params);
+
+        assert!(intents.contains(GatewayIntents::GUILD_SCHEDULED_EVENTS));
+    }
+
+    #[test]
+    fn test_build_gateway_intents_raw_events() {
+        let params = params_from(&[("RAW_EVENTS", "TYPING_START,PRESENCE_UPDATE,SOME_FUTURE_EVENT")]);
+        let intents = build_gateway_intents(&params);
+
+        assert!(intents.contains(GatewayIntents::GUILD_MESSAGE_TYPING));
+        assert!(intents.contains(GatewayIntents::DIRECT_MESSAGE_TYPING));
+        assert!(intents.contains(GatewayIntents::GUILD_PRESENCES));
+        assert!(!intents.contains(GatewayIntents::GUILD_MESSAGES));
+    }
+
+    #[test]
+    fn test_build_gateway_intents_guild_create() {
+        let params = params_from(&[("GUILD_CREATE", "all")]);
+        let intents = build_gateway_intents(&params);
+
+        assert_eq!(intents, GatewayIntents::GUILDS);
+    }
+
+    #[test]
+    fn test_params_summary_reflects_events_and_intents() {
+        let params = params_from(&[
+            ("MESSAGE_GUILD", "all"),
+            ("GUILD_CREATE", "all"),
+            ("RAW_EVENTS", "TYPING_START"),
+            ("MAX_ACTIONS", "3"),
+        ]);
+        let intents = build_gateway_intents(&params);
+
+        let summary = params.summary(intents);
+
+        assert_eq!(summary.events, vec!["MESSAGE_GUILD", "GUILD_CREATE"]);
+        assert_eq!(summary.raw_events, vec!["TYPING_START"]);
+        for intent in ["GUILDS", "GUILD_MESSAGES", "MESSAGE_CONTENT", "GUILD_MESSAGE_TYPING"] {
+            assert!(summary.intents.iter().any(|name| name == intent), "missing {intent}");
+        }
+        assert!(!summary.intents.iter().any(|name| name == "DIRECT_MESSAGES"));
+        assert_eq!(summary.limits.max_actions, 3);
+    }
+
+    #[rstest]
+    #[case::default(&[("MESSAGE_GUILD", "all")], true)]
+    #[case::disabled(&[("MESSAGE_GUILD", "all"), ("MESSAGE_CONTENT_INTENT", "false")], false)]
+    fn test_build_gateway_intents_message_content(#[case] vars: &[(&str, &str)], #[case] expected: bool) {
+        let intents = build_gateway_intents(&params_from(vars));
+
+        assert_eq!(intents.contains(GatewayIntents::MESSAGE_CONTENT), expected);
+        assert!(intents.contains(GatewayIntents::GUILD_MESSAGES));
+    }
+
+    #[rstest]
+    #[case::listed(Some("reply,join_voice"), true)]
+    #[case::not_listed(Some("reply"), false)]
+    #[case::default(None, false)]
+    fn test_build_gateway_intents_join_voice(#[case] allowed_actions: Option<&str>, #[case] expected: bool) {
+        let params = match allowed_actions {
+            Some(allowed_actions) => params_from(&[("ALLOWED_ACTIONS", allowed_actions)]),
+            None => params_from(&[]),
+        };
+        let intents = build_gateway_intents(&params);
+
+        assert_eq!(intents.contains(GatewayIntents::GUILD_VOICE_STATES), expected);
+    }
+
+    #[test]
+    fn test_build_gateway_intents_guild_member_update() {
+        let params = params_from(&[("GUILD_MEMBER_UPDATE", "all")]);
+        let intents = build_gateway_intents(&params);
+
+        assert!(intents.contains(GatewayIntents::GUILD_MEMBERS));
+        assert!(intents.contains(GatewayIntents::GUILDS));
+        assert!(!intents.contains(GatewayIntents::GUILD_MESSAGES));
+    }
+
+    #[test]
+    fn test_build_gateway_intents_auto_moderation_action_execution() {
+        let params = params_from(&[("AUTO_MODERATION_ACTION_EXECUTION", "all")]);
+        let intents = build_gateway_intents(&params);
+
+        assert!(intents.contains(GatewayIntents::AUTO_MODERATION_EXECUTION));
+        assert!(!intents.contains(GatewayIntents::GUILD_MESSAGES));
+    }
+
+    #[test]
+    fn test_build_gateway_intents_thread_events() {
+        let params = params_from(&[("THREAD_CREATE_GUILD", "all")]);
+        let intents = build_gateway_intents(&params);
+
+        assert!(intents.contains(GatewayIntents::GUILDS));
+        assert!(!intents.contains(GatewayIntents::GUILD_MESSAGES));
+    }
+}
@@ -1,6 +1,18 @@
-use anyhow::Context as _;
-use serde::Deserialize;
+use reqwest::header::HeaderMap;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+use serenity::gateway::ActivityData;
+use serenity::model::gateway::GatewayIntents;
+use serenity::model::id::{ChannelId, GuildId};
+use serenity::model::user::OnlineStatus;
+use url::Url;
+use crate::adapters::event_response::ResponseAction;
+use crate::adapters::{ActionsOnStatus, DefaultAllowedMentions, FanoutFailure, FanoutMode};
+use crate::bridge::action_rate_limiter::ThreadLimitPolicy;
+use crate::bridge::action_target::ReactionReplyMode;
 use crate::bridge::sender_filter::SenderFilterPolicy;
+use crate::error::GatehookError;
 
 /// Default HTTP request timeout in seconds (5 minutes)
 fn default_http_timeout() -> u64 {
@@ -17,11 +29,347 @@ fn default_max_actions() -> usize {
     5
 }
 
+/// Default number of independent actions executed concurrently (sequential)
+fn default_action_concurrency() -> usize {
+    1
+}
+
+/// Default number of retries for a rate-limited Discord call of an action
+fn default_action_max_retries() -> u32 {
+    crate::bridge::event_bridge::DEFAULT_ACTION_MAX_RETRIES
+}
+
+/// Deserialize `ACTION_MAX_RETRIES`, rejecting values above `MAX_ACTION_MAX_RETRIES`
+fn deserialize_action_max_retries<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use crate::bridge::event_bridge::MAX_ACTION_MAX_RETRIES;
+
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    let Some(s) = s else {
+        return Ok(default_action_max_retries());
+    };
+    let retries: u32 = s
+        .trim()
+        .parse()
+        .map_err(|err| serde::de::Error::custom(format!("ACTION_MAX_RETRIES: {err}")))?;
+    if retries > MAX_ACTION_MAX_RETRIES {
+        return Err(serde::de::Error::custom(format!(
+            "ACTION_MAX_RETRIES: {retries} exceeds the maximum of {MAX_ACTION_MAX_RETRIES}"
+        )));
+    }
+    Ok(retries)
+}
+
+/// Default maximum number of background tasks running at once
+fn default_max_pending_tasks() -> usize {
+    crate::bridge::event_bridge::DEFAULT_MAX_PENDING_TASKS
+}
+
+/// Default maximum lifetime of a background task in seconds
+fn default_max_task_lifetime_secs() -> u64 {
+    crate::bridge::event_bridge::DEFAULT_MAX_TASK_LIFETIME_SECS
+}
+
+/// Default for suppressing @everyone/@here pings in bot output (enabled)
+fn default_suppress_mass_mentions() -> bool {
+    true
+}
+
+/// Default for requesting the privileged MESSAGE_CONTENT intent (enabled)
+fn default_message_content_intent() -> bool {
+    true
+}
+
+/// Default maximum number of channels in the channel → guild index
+fn default_channel_index_max_entries() -> usize {
+    crate::adapters::serenity_channel_info_provider::DEFAULT_CHANNEL_INDEX_MAX_ENTRIES
+}
+
+/// Default seconds an action idempotency key is remembered
+fn default_action_idempotency_window_secs() -> u64 {
+    300
+}
+
+/// Default minimum seconds between error notices (ERROR_NOTIFY_CHANNEL)
+fn default_error_notify_interval_secs() -> u64 {
+    60
+}
+
+/// Default seconds the webhook circuit breaker stays open before probing again
+fn default_breaker_cooldown_secs() -> u64 {
+    30
+}
+
+fn default_debug_dump_max_files() -> usize {
+    1000
+}
+
+fn default_debug_dump_max_bytes() -> u64 {
+    100 * 1024 * 1024 // 100MB
+}
+
+/// Default shortest `Retry-After` delay honored, in milliseconds
+fn default_retry_after_min_ms() -> u64 {
+    100
+}
+
 /// Default maximum HTTP response body size in bytes (128KB)
 fn default_max_response_body_size() -> usize {
     131_072
 }
 
+/// Parse a comma-separated list of gateway event names (normalized to uppercase)
+fn parse_event_names(names: &str) -> Vec<String> {
+    names
+        .split(',')
+        .map(|name| name.trim().to_uppercase())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Deserialize environment variable string into a list of gateway event names
+fn deserialize_event_names<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    Ok(s.map(|names| parse_event_names(&names)).unwrap_or_default())
+}
+
+/// Parse a comma-separated list of payload field names (kept as written)
+fn parse_field_names(names: &str) -> Vec<String> {
+    names
+        .split(',')
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Deserialize environment variable string into a list of payload field names
+fn deserialize_field_names<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    Ok(s.map(|names| parse_field_names(&names)).unwrap_or_default())
+}
+
+/// Deserialize `DEFAULT_ALLOWED_MENTIONS` (comma-separated `users`/`roles`, or `none`)
+///
+/// Unset stays `None` (built-in defaults apply).
+fn deserialize_allowed_mentions<'de, D>(deserializer: D) -> Result<Option<DefaultAllowedMentions>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    s.map(|mentions| mentions.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Deserialize `REACTION_COMMANDS` (JSON object mapping emoji to an action list)
+///
+/// Actions use the webhook response format. Unset yields an empty map.
+fn deserialize_reaction_commands<'de, D>(deserializer: D) -> Result<HashMap<String, Vec<ResponseAction>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    s.map(|json| serde_json::from_str(&json).map_err(serde::de::Error::custom))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Prefix of per-handler custom header variables (`WEBHOOK_HEADERS_<HANDLER>`)
+const HANDLER_HEADERS_PREFIX: &str = "WEBHOOK_HEADERS_";
+
+/// Parse a comma-separated list of `Name: Value` headers
+///
+/// Returns an error message for entries without `:` or invalid names/values.
+fn parse_headers(headers: &str) -> Result<HeaderMap, String> {
+    let mut map = HeaderMap::new();
+    for entry in headers.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+        let (name, value) = entry
+            .split_once(':')
+            .ok_or_else(|| format!("invalid header '{}' (expected 'Name: Value')", entry))?;
+        let name: reqwest::header::HeaderName = name
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid header name '{}'", name.trim()))?;
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| format!("invalid value for header '{}'", name))?;
+        map.insert(name, value);
+    }
+    Ok(map)
+}
+
+/// Deserialize environment variable string into custom webhook headers
+fn deserialize_headers<'de, D>(deserializer: D) -> Result<HeaderMap, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    s.map(|headers| parse_headers(&headers).map_err(serde::de::Error::custom))
+        .transpose()
+        .map(Option::unwrap_or_default)
+}
+
+/// Collect per-handler custom headers from `WEBHOOK_HEADERS_<HANDLER>` variables
+///
+/// Handler names are normalized to lowercase (e.g. `WEBHOOK_HEADERS_MESSAGE` → `message`).
+fn parse_handler_headers(
+    vars: &[(String, String)],
+) -> Result<HashMap<String, HeaderMap>, GatehookError> {
+    vars.iter()
+        .filter_map(|(key, value)| {
+            let handler = key.strip_prefix(HANDLER_HEADERS_PREFIX)?;
+            Some((handler.to_lowercase(), value))
+        })
+        .filter(|(handler, _)| !handler.is_empty())
+        .map(|(handler, value)| {
+            let headers = parse_headers(value)
+                .map_err(|err| envy::Error::Custom(format!("{}{}: {}", HANDLER_HEADERS_PREFIX, handler.to_uppercase(), err)))?;
+            Ok((handler, headers))
+        })
+        .collect()
+}
+
+/// Secret variables that may instead be read from a file named by `<NAME>_FILE`
+const FILE_SECRETS: &[&str] = &["DISCORD_TOKEN", "ADMIN_TOKEN"];
+
+/// Fill `FILE_SECRETS` from their `<NAME>_FILE` variables (`_FILE` secret convention)
+///
+/// The file contents are trimmed; an empty file is an error rather than an
+/// empty secret. A variable set directly takes precedence
+/// over its `_FILE` variant, whose file is then not read.
+fn resolve_file_secrets(vars: &mut Vec<(String, String)>) -> Result<(), GatehookError> {
+    for &name in FILE_SECRETS {
+        if vars.iter().any(|(key, _)| key == name) {
+            continue;
+        }
+        let file_key = format!("{name}_FILE");
+        let Some((_, path)) = vars.iter().find(|(key, _)| *key == file_key) else {
+            continue;
+        };
+        let contents = std::fs::read_to_string(path)
+            .map_err(|err| envy::Error::Custom(format!("{file_key}: {err}")))?;
+        let secret = contents.trim();
+        if secret.is_empty() {
+            return Err(envy::Error::Custom(format!("{file_key}: file {path} is empty")).into());
+        }
+        vars.push((name.to_string(), secret.to_string()));
+    }
+    Ok(())
+}
+
+/// Prefix of per-guild endpoint variables (`HTTP_ENDPOINT_GUILD_<GUILD_ID>`)
+const GUILD_ENDPOINT_PREFIX: &str = "HTTP_ENDPOINT_GUILD_";
+
+/// Collect per-guild endpoints from `HTTP_ENDPOINT_GUILD_<GUILD_ID>` variables
+fn parse_guild_endpoints(vars: &[(String, String)]) -> Result<HashMap<GuildId, Url>, GatehookError> {
+    vars.iter()
+        .filter_map(|(key, value)| Some((key.strip_prefix(GUILD_ENDPOINT_PREFIX)?, value)))
+        .map(|(guild_id, value)| {
+            let invalid =
+                |reason: String| envy::Error::Custom(format!("{}{}: {}", GUILD_ENDPOINT_PREFIX, guild_id, reason));
+            let guild_id = guild_id
+                .parse::<u64>()
+                .ok()
+                .filter(|&id| id != 0)
+                .map(GuildId::new)
+                .ok_or_else(|| invalid("invalid guild ID".to_string()))?;
+            let endpoint = Url::parse(value).map_err(|err| invalid(err.to_string()))?;
+            Ok((guild_id, endpoint))
+        })
+        .collect()
+}
+
+/// Prefix and suffix of per-handler webhook deadline variables (`WEBHOOK_DEADLINE_<HANDLER>_MS`)
+const HANDLER_DEADLINE_PREFIX: &str = "WEBHOOK_DEADLINE_";
+const HANDLER_DEADLINE_SUFFIX: &str = "_MS";
+
+/// Collect per-handler webhook deadlines from `WEBHOOK_DEADLINE_<HANDLER>_MS` variables
+///
+/// Handler names are normalized to lowercase (e.g. `WEBHOOK_DEADLINE_REACTION_ADD_MS` → `reaction_add`).
+fn parse_handler_deadlines(vars: &[(String, String)]) -> Result<HashMap<String, Duration>, GatehookError> {
+    vars.iter()
+        .filter_map(|(key, value)| {
+            let handler = key.strip_prefix(HANDLER_DEADLINE_PREFIX)?.strip_suffix(HANDLER_DEADLINE_SUFFIX)?;
+            Some((key, handler.to_lowercase(), value))
+        })
+        .filter(|(_, handler, _)| !handler.is_empty())
+        .map(|(key, handler, value)| {
+            let millis = value
+                .parse::<u64>()
+                .ok()
+                .filter(|&millis| millis > 0)
+                .ok_or_else(|| envy::Error::Custom(format!("{key}: expected a positive number of milliseconds")))?;
+            Ok((handler, Duration::from_millis(millis)))
+        })
+        .collect()
+}
+
+/// Prefix of per-handler field redaction variables (`REDACT_FIELDS_<HANDLER>`)
+const HANDLER_REDACT_PREFIX: &str = "REDACT_FIELDS_";
+
+/// Collect per-handler redacted field paths from `REDACT_FIELDS_<HANDLER>` variables
+///
+/// Each value is a comma-separated list of dotted JSON paths (e.g.
+/// `REDACT_FIELDS_MESSAGE=message.author.username,author_avatar_url`).
+/// Handler names are normalized to lowercase.
+fn parse_handler_redactions(vars: &[(String, String)]) -> Result<HashMap<String, Vec<String>>, GatehookError> {
+    vars.iter()
+        .filter_map(|(key, value)| Some((key, key.strip_prefix(HANDLER_REDACT_PREFIX)?.to_lowercase(), value)))
+        .filter(|(_, handler, _)| !handler.is_empty())
+        .map(|(key, handler, value)| {
+            let paths: Vec<String> = value
+                .split(',')
+                .map(str::trim)
+                .filter(|path| !path.is_empty())
+                .map(str::to_string)
+                .collect();
+            if paths.iter().any(|path| path.split('.').any(str::is_empty)) {
+                return Err(envy::Error::Custom(format!("{key}: empty path segment")).into());
+            }
+            Ok((handler, paths))
+        })
+        .collect()
+}
+
+/// Deserialize a comma-separated list of endpoint URLs (`FANOUT_ENDPOINTS`)
+fn deserialize_urls<'de, D>(deserializer: D) -> Result<Vec<Url>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    s.as_deref()
+        .map(parse_field_names)
+        .unwrap_or_default()
+        .iter()
+        .map(|url| Url::parse(url).map_err(|err| serde::de::Error::custom(format!("{url}: {err}"))))
+        .collect()
+}
+
+/// Deserialize environment variable string into an optional list of action types
+///
+/// Names are normalized to lowercase. Unset stays `None`; an empty value
+/// yields an empty list.
+fn deserialize_action_names<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    Ok(s.map(|names| {
+        parse_field_names(&names)
+            .into_iter()
+            .map(|name| name.to_lowercase())
+            .collect()
+    }))
+}
+
 /// Deserialize environment variable string into SenderFilterPolicy
 fn deserialize_sender_filter_policy<'de, D>(
     deserializer: D,
@@ -33,13 +381,129 @@ where
     Ok(s.map(|policy| SenderFilterPolicy::from_policy(&policy)))
 }
 
+/// Parse an online status string (online, idle, dnd, invisible)
+fn parse_online_status(status: &str) -> Option<OnlineStatus> {
+    match status.trim().to_lowercase().as_str() {
+        "online" => Some(OnlineStatus::Online),
+        "idle" => Some(OnlineStatus::Idle),
+        "dnd" => Some(OnlineStatus::DoNotDisturb),
+        "invisible" => Some(OnlineStatus::Invisible),
+        _ => None,
+    }
+}
+
+/// Parse an activity string into an activity type and name
+///
+/// Supported formats: `Playing <name>`, `Watching <name>`, `Listening to <name>`,
+/// `Competing in <name>`, `Custom <state>` (prefix is case-insensitive).
+fn parse_activity(activity: &str) -> Option<ActivityData> {
+    let (kind, rest) = activity.trim().split_once(char::is_whitespace)?;
+    let rest = rest.trim();
+
+    let strip_word = |word: &str| -> String {
+        rest.split_once(char::is_whitespace)
+            .filter(|(first, _)| first.eq_ignore_ascii_case(word))
+            .map_or(rest, |(_, name)| name.trim())
+            .to_string()
+    };
+
+    let activity = match kind.to_lowercase().as_str() {
+        "playing" => ActivityData::playing(rest),
+        "watching" => ActivityData::watching(rest),
+        "listening" => ActivityData::listening(strip_word("to")),
+        "competing" => ActivityData::competing(strip_word("in")),
+        "custom" => ActivityData::custom(rest),
+        _ => return None,
+    };
+
+    if activity.name.is_empty() || activity.state.as_deref() == Some("") {
+        return None;
+    }
+    Some(activity)
+}
+
+/// Deserialize environment variable string into OnlineStatus
+fn deserialize_online_status<'de, D>(deserializer: D) -> Result<Option<OnlineStatus>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    s.map(|status| {
+        parse_online_status(&status).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "invalid status '{}' (expected online, idle, dnd, invisible)",
+                status
+            ))
+        })
+    })
+    .transpose()
+}
+
+/// Deserialize environment variable string into ActivityData
+fn deserialize_activity<'de, D>(deserializer: D) -> Result<Option<ActivityData>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    s.map(|activity| {
+        parse_activity(&activity).ok_or_else(|| {
+            serde::de::Error::custom(format!(
+                "invalid activity '{}' (expected e.g. 'Playing <name>', 'Watching <name>')",
+                activity
+            ))
+        })
+    })
+    .transpose()
+}
+
+/// Startup summary of enabled events, gateway intents and key limits (`LOG_STARTUP_SUMMARY`)
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ParamsSummary {
+    /// Enabled event variables (e.g. `MESSAGE_GUILD`)
+    pub events: Vec<&'static str>,
+    /// Raw gateway event types (`RAW_EVENTS`)
+    pub raw_events: Vec<String>,
+    /// Requested gateway intents
+    pub intents: Vec<String>,
+    /// Key limits
+    pub limits: LimitsSummary,
+}
+
+/// Key limits in a startup summary (0 or `None` means unlimited/disabled, as in the variables)
+#[derive(Debug, Serialize, PartialEq)]
+pub struct LimitsSummary {
+    pub max_actions: usize,
+    pub max_actions_per_minute: u32,
+    pub max_threads_per_minute: u32,
+    pub action_concurrency: usize,
+    pub max_pending_tasks: usize,
+    pub max_task_lifetime_secs: u64,
+    pub max_payload_size: Option<usize>,
+    pub max_response_body_size: usize,
+    pub http_timeout_secs: u64,
+    pub max_event_age_secs: u64,
+    pub max_concurrent_events: usize,
+}
+
 #[derive(Deserialize, Clone)]
 pub struct Params {
     #[serde(default)]
     pub insecure_mode: bool,
+    #[serde(default)]
+    pub log_startup_summary: bool,
     pub discord_token: String,
     pub http_endpoint: String,
 
+    // Client Identity Configuration
+    #[serde(default)]
+    pub client_name: Option<String>,
+
+    // Presence Configuration
+    #[serde(default, deserialize_with = "deserialize_online_status")]
+    pub initial_status: Option<OnlineStatus>,
+    #[serde(default, deserialize_with = "deserialize_activity")]
+    pub initial_activity: Option<ActivityData>,
+
     // HTTP Client Configuration
     #[serde(default = "default_http_timeout")]
     pub http_timeout: u64,
@@ -47,10 +511,176 @@ pub struct Params {
     pub http_connect_timeout: u64,
     #[serde(default = "default_max_response_body_size")]
     pub max_response_body_size: usize,
+    #[serde(default)]
+    pub actions_on_status: ActionsOnStatus,
+    #[serde(default)]
+    pub report_parse_errors: bool,
+    #[serde(default = "default_retry_after_min_ms")]
+    pub retry_after_min_ms: u64,
+    /// Longest `Retry-After` delay honored before retrying once (0 = no retry)
+    #[serde(default)]
+    pub retry_after_max_secs: u64,
+    #[serde(default, deserialize_with = "deserialize_headers")]
+    pub webhook_headers: HeaderMap,
+    /// Per-handler headers from `WEBHOOK_HEADERS_<HANDLER>` (collected in `new()`)
+    #[serde(skip)]
+    pub handler_webhook_headers: HashMap<String, HeaderMap>,
+    /// Per-guild endpoints from `HTTP_ENDPOINT_GUILD_<GUILD_ID>` (collected in `new()`)
+    #[serde(skip)]
+    pub guild_http_endpoints: HashMap<GuildId, Url>,
+    /// Per-handler webhook deadlines from `WEBHOOK_DEADLINE_<HANDLER>_MS` (collected in `new()`)
+    #[serde(skip)]
+    pub handler_webhook_deadlines: HashMap<String, Duration>,
+    /// Per-handler redacted field paths from `REDACT_FIELDS_<HANDLER>` (collected in `new()`)
+    #[serde(skip)]
+    pub handler_redacted_fields: HashMap<String, Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_urls")]
+    pub fanout_endpoints: Vec<Url>,
+    #[serde(default)]
+    pub fanout_mode: FanoutMode,
+    #[serde(default)]
+    pub fanout_failure: FanoutFailure,
+    #[serde(default)]
+    pub fanout_suppress_actions: bool,
+
+    // Delivery Configuration
+    #[serde(default)]
+    pub outbox_path: Option<std::path::PathBuf>,
+    #[serde(default)]
+    pub breaker_failure_threshold: u32,
+    #[serde(default = "default_breaker_cooldown_secs")]
+    pub breaker_cooldown_secs: u64,
+    #[serde(default)]
+    pub breaker_buffer_size: usize,
+    #[serde(default)]
+    pub heartbeat_interval_secs: u64,
+    #[serde(default)]
+    pub debug_dump_dir: Option<std::path::PathBuf>,
+    #[serde(default = "default_debug_dump_max_files")]
+    pub debug_dump_max_files: usize,
+    #[serde(default = "default_debug_dump_max_bytes")]
+    pub debug_dump_max_bytes: u64,
+
+    // Cache Configuration
+    #[serde(default)]
+    pub cache_disabled: bool,
+    #[serde(default)]
+    pub cache_max_messages: usize,
+    #[serde(default)]
+    pub channel_lookup_max_guilds: Option<usize>,
+    #[serde(default = "default_channel_index_max_entries")]
+    pub channel_index_max_entries: usize,
 
     // Action Execution Configuration
     #[serde(default = "default_max_actions")]
     pub max_actions: usize,
+    #[serde(default)]
+    pub max_actions_per_minute: u32,
+    #[serde(default)]
+    pub max_threads_per_minute: u32,
+    #[serde(default)]
+    pub thread_limit_policy: ThreadLimitPolicy,
+    #[serde(default = "default_action_concurrency")]
+    pub action_concurrency: usize,
+    #[serde(default = "default_action_max_retries", deserialize_with = "deserialize_action_max_retries")]
+    pub action_max_retries: u32,
+    #[serde(default = "default_max_pending_tasks")]
+    pub max_pending_tasks: usize,
+    #[serde(default = "default_max_task_lifetime_secs")]
+    pub max_task_lifetime_secs: u64,
+    #[serde(default)]
+    pub reply_cooldown_secs: u64,
+    #[serde(default)]
+    pub reaction_action_cooldown_secs: u64,
+    #[serde(default = "default_action_idempotency_window_secs")]
+    pub action_idempotency_window_secs: u64,
+    #[serde(default = "default_suppress_mass_mentions")]
+    pub suppress_mass_mentions: bool,
+    #[serde(default, deserialize_with = "deserialize_allowed_mentions")]
+    pub default_allowed_mentions: Option<DefaultAllowedMentions>,
+    #[serde(default)]
+    pub precheck_permissions: bool,
+    #[serde(default)]
+    pub normalize_emoji: bool,
+    #[serde(default)]
+    pub auto_react_emoji: Option<String>,
+    #[serde(default)]
+    pub contextless_actions: bool,
+    #[serde(default, deserialize_with = "deserialize_reaction_commands")]
+    pub reaction_commands: HashMap<String, Vec<ResponseAction>>,
+    #[serde(default)]
+    pub reaction_commands_forward: bool,
+    #[serde(default, deserialize_with = "deserialize_action_names")]
+    pub allowed_actions: Option<Vec<String>>,
+    #[serde(default)]
+    pub admin_port: Option<u16>,
+    /// Bearer token required by the admin endpoint (`ADMIN_TOKEN` or `ADMIN_TOKEN_FILE`)
+    #[serde(default)]
+    pub admin_token: Option<String>,
+    #[serde(default)]
+    pub channel_denylist_path: Option<std::path::PathBuf>,
+    #[serde(default)]
+    pub error_notify_channel: Option<ChannelId>,
+    #[serde(default = "default_error_notify_interval_secs")]
+    pub error_notify_interval_secs: u64,
+    #[serde(default)]
+    pub truncation_marker: Option<String>,
+
+    // Payload Configuration
+    #[serde(default)]
+    pub message_include_mentions: bool,
+    #[serde(default)]
+    pub message_include_attachments: bool,
+    #[serde(default)]
+    pub message_include_role_names: bool,
+    #[serde(default = "default_message_content_intent")]
+    pub message_content_intent: bool,
+    #[serde(default)]
+    pub content_max_forward_chars: Option<usize>,
+    #[serde(default)]
+    pub reaction_include_message: bool,
+    #[serde(default)]
+    pub reaction_guild_on_own_messages_only: bool,
+    #[serde(default)]
+    pub reaction_reply_mode: ReactionReplyMode,
+    #[serde(default)]
+    pub reaction_remove_coalesce_ms: u64,
+    #[serde(default)]
+    pub reaction_rollup_window_ms: u64,
+    #[serde(default, deserialize_with = "deserialize_field_names")]
+    pub message_fields: Vec<String>,
+    #[serde(default)]
+    pub reply_chain_depth: u8,
+    #[serde(default)]
+    pub include_shard: bool,
+    #[serde(default)]
+    pub include_received_at: bool,
+    #[serde(default)]
+    pub max_payload_size: Option<usize>,
+
+    // Maintenance Configuration
+    #[serde(default)]
+    pub maintenance_message: Option<String>,
+
+    // Lag Protection Configuration
+    #[serde(default)]
+    pub max_event_age_secs: u64,
+    #[serde(default)]
+    pub reconnect_alert_threshold: u32,
+    #[serde(default)]
+    pub max_concurrent_events: usize,
+
+    // Sampling Configuration
+    #[serde(default)]
+    pub message_sample_rate: Option<f64>,
+    #[serde(default)]
+    pub message_update_sample_rate: Option<f64>,
+    #[serde(default)]
+    pub reaction_add_sample_rate: Option<f64>,
+    #[serde(default)]
+    pub reaction_remove_sample_rate: Option<f64>,
+    #[serde(default)]
+    pub sample_seed: Option<u64>,
 
     // ========================================
     // Event Configuration
@@ -89,6 +719,40 @@ pub struct Params {
     #[serde(default, deserialize_with = "deserialize_sender_filter_policy")]
     pub reaction_remove_guild: Option<SenderFilterPolicy>,
 
+    // Thread Events
+    #[serde(default)]
+    pub thread_create_guild: Option<String>,
+    #[serde(default)]
+    pub thread_delete_guild: Option<String>,
+
+    // Scheduled Events (guild-only)
+    #[serde(default)]
+    pub guild_scheduled_event_create: Option<String>,
+    #[serde(default)]
+    pub guild_scheduled_event_update: Option<String>,
+    #[serde(default)]
+    pub guild_scheduled_event_delete: Option<String>,
+
+    // Guild Events
+    #[serde(default)]
+    pub guild_create: Option<String>,
+
+    // Interaction Events (message components)
+    #[serde(default)]
+    pub interaction_create: Option<String>,
+
+    // Guild Member Events
+    #[serde(default)]
+    pub guild_member_update: Option<String>,
+
+    // AutoMod Events
+    #[serde(default)]
+    pub auto_moderation_action_execution: Option<String>,
+
+    // Raw Events (gateway passthrough for unmodeled event types)
+    #[serde(default, deserialize_with = "deserialize_event_names")]
+    pub raw_events: Vec<String>,
+
     // Context-Independent Events
     #[serde(default)]
     pub ready: Option<String>,
@@ -97,34 +761,147 @@ pub struct Params {
 }
 
 /// Mask sensitive strings by showing only first and last few characters
+///
+/// Counts characters, not bytes, so non-ASCII secrets are masked safely.
 fn mask_token(s: &str) -> String {
     const VISIBLE_CHARS: usize = 4;
 
-    if s.len() <= VISIBLE_CHARS * 2 {
+    if s.chars().count() <= VISIBLE_CHARS * 2 {
         // If string is too short, mask everything except first char
-        if s.is_empty() {
-            return "<empty>".to_string();
-        }
-        return format!("{}***", &s[..1]);
+        return match s.chars().next() {
+            Some(first) => format!("{first}***"),
+            None => "<empty>".to_string(),
+        };
+    }
+
+    let head: String = s.chars().take(VISIBLE_CHARS).collect();
+    let mut tail: Vec<char> = s.chars().rev().take(VISIBLE_CHARS).collect();
+    tail.reverse();
+    format!("{head}***{}", tail.into_iter().collect::<String>())
+}
+
+/// Debug wrapper printing a secret masked by `mask_token`
+///
+/// Use for every secret-bearing field in `Params`'s `Debug` impl
+/// (`Option` fields: `.as_deref().map(Masked)`).
+struct Masked<'a>(&'a str);
+
+impl std::fmt::Debug for Masked<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", mask_token(self.0))
     }
+}
 
-    format!(
-        "{}***{}",
-        &s[..VISIBLE_CHARS],
-        &s[s.len() - VISIBLE_CHARS..]
-    )
+/// Header names only, as header values may carry credentials
+fn header_names(headers: &HeaderMap) -> Vec<&str> {
+    headers.keys().map(|name| name.as_str()).collect()
 }
 
 impl std::fmt::Debug for Params {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Params")
             .field("insecure_mode", &self.insecure_mode)
-            .field("discord_token", &mask_token(&self.discord_token))
+            .field("log_startup_summary", &self.log_startup_summary)
+            .field("discord_token", &Masked(&self.discord_token))
             .field("http_endpoint", &self.http_endpoint)
+            .field(
+                "guild_http_endpoints",
+                &self
+                    .guild_http_endpoints
+                    .iter()
+                    .map(|(guild_id, url)| (guild_id, url.as_str()))
+                    .collect::<HashMap<_, _>>(),
+            )
+            .field("handler_webhook_deadlines", &self.handler_webhook_deadlines)
+            .field("handler_redacted_fields", &self.handler_redacted_fields)
+            .field(
+                "fanout_endpoints",
+                &self.fanout_endpoints.iter().map(Url::as_str).collect::<Vec<_>>(),
+            )
+            .field("fanout_mode", &self.fanout_mode)
+            .field("fanout_failure", &self.fanout_failure)
+            .field("fanout_suppress_actions", &self.fanout_suppress_actions)
+            .field("client_name", &self.client_name)
+            .field("initial_status", &self.initial_status)
+            .field("initial_activity", &self.initial_activity)
             .field("http_timeout", &self.http_timeout)
             .field("http_connect_timeout", &self.http_connect_timeout)
             .field("max_response_body_size", &self.max_response_body_size)
+            .field("actions_on_status", &self.actions_on_status)
+            .field("report_parse_errors", &self.report_parse_errors)
+            .field("retry_after_min_ms", &self.retry_after_min_ms)
+            .field("retry_after_max_secs", &self.retry_after_max_secs)
+            .field("webhook_headers", &header_names(&self.webhook_headers))
+            .field(
+                "handler_webhook_headers",
+                &self
+                    .handler_webhook_headers
+                    .iter()
+                    .map(|(handler, headers)| (handler, header_names(headers)))
+                    .collect::<HashMap<_, _>>(),
+            )
+            .field("outbox_path", &self.outbox_path)
+            .field("breaker_failure_threshold", &self.breaker_failure_threshold)
+            .field("breaker_cooldown_secs", &self.breaker_cooldown_secs)
+            .field("breaker_buffer_size", &self.breaker_buffer_size)
+            .field("heartbeat_interval_secs", &self.heartbeat_interval_secs)
+            .field("debug_dump_dir", &self.debug_dump_dir)
+            .field("debug_dump_max_files", &self.debug_dump_max_files)
+            .field("debug_dump_max_bytes", &self.debug_dump_max_bytes)
+            .field("cache_disabled", &self.cache_disabled)
+            .field("cache_max_messages", &self.cache_max_messages)
+            .field("channel_lookup_max_guilds", &self.channel_lookup_max_guilds)
+            .field("channel_index_max_entries", &self.channel_index_max_entries)
             .field("max_actions", &self.max_actions)
+            .field("max_actions_per_minute", &self.max_actions_per_minute)
+            .field("max_threads_per_minute", &self.max_threads_per_minute)
+            .field("thread_limit_policy", &self.thread_limit_policy)
+            .field("action_concurrency", &self.action_concurrency)
+            .field("action_max_retries", &self.action_max_retries)
+            .field("max_pending_tasks", &self.max_pending_tasks)
+            .field("max_task_lifetime_secs", &self.max_task_lifetime_secs)
+            .field("reply_cooldown_secs", &self.reply_cooldown_secs)
+            .field("reaction_action_cooldown_secs", &self.reaction_action_cooldown_secs)
+            .field("action_idempotency_window_secs", &self.action_idempotency_window_secs)
+            .field("suppress_mass_mentions", &self.suppress_mass_mentions)
+            .field("default_allowed_mentions", &self.default_allowed_mentions)
+            .field("precheck_permissions", &self.precheck_permissions)
+            .field("normalize_emoji", &self.normalize_emoji)
+            .field("auto_react_emoji", &self.auto_react_emoji)
+            .field("contextless_actions", &self.contextless_actions)
+            .field("reaction_commands", &self.reaction_commands)
+            .field("reaction_commands_forward", &self.reaction_commands_forward)
+            .field("allowed_actions", &self.allowed_actions)
+            .field("admin_port", &self.admin_port)
+            .field("admin_token", &self.admin_token.as_deref().map(Masked))
+            .field("channel_denylist_path", &self.channel_denylist_path)
+            .field("error_notify_channel", &self.error_notify_channel)
+            .field("error_notify_interval_secs", &self.error_notify_interval_secs)
+            .field("truncation_marker", &self.truncation_marker)
+            .field("message_include_mentions", &self.message_include_mentions)
+            .field("message_include_attachments", &self.message_include_attachments)
+            .field("message_include_role_names", &self.message_include_role_names)
+            .field("message_content_intent", &self.message_content_intent)
+            .field("content_max_forward_chars", &self.content_max_forward_chars)
+            .field("reaction_include_message", &self.reaction_include_message)
+            .field("reaction_guild_on_own_messages_only", &self.reaction_guild_on_own_messages_only)
+            .field("reaction_reply_mode", &self.reaction_reply_mode)
+            .field("reaction_remove_coalesce_ms", &self.reaction_remove_coalesce_ms)
+            .field("reaction_rollup_window_ms", &self.reaction_rollup_window_ms)
+            .field("message_fields", &self.message_fields)
+            .field("reply_chain_depth", &self.reply_chain_depth)
+            .field("include_shard", &self.include_shard)
+            .field("include_received_at", &self.include_received_at)
+            .field("max_payload_size", &self.max_payload_size)
+            .field("maintenance_message", &self.maintenance_message)
+            .field("max_event_age_secs", &self.max_event_age_secs)
+            .field("reconnect_alert_threshold", &self.reconnect_alert_threshold)
+            .field("max_concurrent_events", &self.max_concurrent_events)
+            .field("message_sample_rate", &self.message_sample_rate)
+            .field("message_update_sample_rate", &self.message_update_sample_rate)
+            .field("reaction_add_sample_rate", &self.reaction_add_sample_rate)
+            .field("reaction_remove_sample_rate", &self.reaction_remove_sample_rate)
+            .field("sample_seed", &self.sample_seed)
             .field("message_direct", &self.message_direct)
             .field("message_guild", &self.message_guild)
             .field("message_delete_direct", &self.message_delete_direct)
@@ -136,6 +913,16 @@ impl std::fmt::Debug for Params {
             .field("reaction_add_guild", &self.reaction_add_guild)
             .field("reaction_remove_direct", &self.reaction_remove_direct)
             .field("reaction_remove_guild", &self.reaction_remove_guild)
+            .field("thread_create_guild", &self.thread_create_guild)
+            .field("thread_delete_guild", &self.thread_delete_guild)
+            .field("guild_scheduled_event_create", &self.guild_scheduled_event_create)
+            .field("guild_scheduled_event_update", &self.guild_scheduled_event_update)
+            .field("guild_scheduled_event_delete", &self.guild_scheduled_event_delete)
+            .field("guild_create", &self.guild_create)
+            .field("interaction_create", &self.interaction_create)
+            .field("guild_member_update", &self.guild_member_update)
+            .field("auto_moderation_action_execution", &self.auto_moderation_action_execution)
+            .field("raw_events", &self.raw_events)
             .field("ready", &self.ready)
             .field("resumed", &self.resumed)
             .finish()
@@ -143,8 +930,23 @@ impl std::fmt::Debug for Params {
 }
 
 impl Params {
-    pub fn new() -> anyhow::Result<Params> {
-        envy::from_env::<Params>().context("Failed to load configuration")
+    pub fn new() -> Result<Params, GatehookError> {
+        Self::from_vars(std::env::vars().collect())
+    }
+
+    /// Load parameters from environment variable pairs
+    fn from_vars(mut vars: Vec<(String, String)>) -> Result<Params, GatehookError> {
+        resolve_file_secrets(&mut vars)?;
+        let handler_webhook_headers = parse_handler_headers(&vars)?;
+        let guild_http_endpoints = parse_guild_endpoints(&vars)?;
+        let handler_webhook_deadlines = parse_handler_deadlines(&vars)?;
+        let handler_redacted_fields = parse_handler_redactions(&vars)?;
+        let mut params = envy::from_iter::<_, Params>(vars)?;
+        params.handler_webhook_headers = handler_webhook_headers;
+        params.guild_http_endpoints = guild_http_endpoints;
+        params.handler_webhook_deadlines = handler_webhook_deadlines;
+        params.handler_redacted_fields = handler_redacted_fields;
+        Ok(params)
     }
 
     /// Check if Direct Message events are enabled
@@ -191,32 +993,227 @@ impl Params {
     pub fn has_message_update_events(&self) -> bool {
         self.message_update_direct.is_some() || self.message_update_guild.is_some()
     }
+
+    /// Check if any THREAD_CREATE/THREAD_DELETE events are enabled
+    pub fn has_thread_events(&self) -> bool {
+        self.thread_create_guild.is_some() || self.thread_delete_guild.is_some()
+    }
+
+    /// Check if any GUILD_SCHEDULED_EVENT_CREATE/UPDATE/DELETE events are enabled
+    pub fn has_scheduled_events(&self) -> bool {
+        self.guild_scheduled_event_create.is_some()
+            || self.guild_scheduled_event_update.is_some()
+            || self.guild_scheduled_event_delete.is_some()
+    }
+
+    /// Check if the `join_voice` action is enabled (must be listed in ALLOWED_ACTIONS)
+    pub fn has_join_voice_action(&self) -> bool {
+        self.allowed_actions
+            .as_ref()
+            .is_some_and(|actions| actions.iter().any(|action| action == "join_voice"))
+    }
+
+    /// Configured sampling rates as (handler, rate) pairs
+    pub fn sample_rates(&self) -> Vec<(&'static str, f64)> {
+        [
+            ("message", self.message_sample_rate),
+            ("message_update", self.message_update_sample_rate),
+            ("reaction_add", self.reaction_add_sample_rate),
+            ("reaction_remove", self.reaction_remove_sample_rate),
+        ]
+        .into_iter()
+        .filter_map(|(handler, rate)| rate.map(|rate| (handler, rate)))
+        .collect()
+    }
+
+    /// Check if any raw gateway events are enabled
+    pub fn has_raw_events(&self) -> bool {
+        !self.raw_events.is_empty()
+    }
+
+    /// Check if GUILD_CREATE event is enabled
+    pub fn has_guild_create_events(&self) -> bool {
+        self.guild_create.is_some()
+    }
+
+    /// Check if GUILD_MEMBER_UPDATE event is enabled
+    pub fn has_guild_member_update_events(&self) -> bool {
+        self.guild_member_update.is_some()
+    }
+
+    /// Check if AUTO_MODERATION_ACTION_EXECUTION event is enabled
+    pub fn has_auto_moderation_action_events(&self) -> bool {
+        self.auto_moderation_action_execution.is_some()
+    }
+
+    /// Summarize enabled events, the gateway `intents` built from them and key limits
+    pub fn summary(&self, intents: GatewayIntents) -> ParamsSummary {
+        let events = [
+            ("MESSAGE_DIRECT", self.message_direct.is_some()),
+            ("MESSAGE_GUILD", self.message_guild.is_some()),
+            ("MESSAGE_DELETE_DIRECT", self.message_delete_direct.is_some()),
+            ("MESSAGE_DELETE_GUILD", self.message_delete_guild.is_some()),
+            ("MESSAGE_DELETE_BULK_GUILD", self.message_delete_bulk_guild.is_some()),
+            ("MESSAGE_UPDATE_DIRECT", self.message_update_direct.is_some()),
+            ("MESSAGE_UPDATE_GUILD", self.message_update_guild.is_some()),
+            ("REACTION_ADD_DIRECT", self.reaction_add_direct.is_some()),
+            ("REACTION_ADD_GUILD", self.reaction_add_guild.is_some()),
+            ("REACTION_REMOVE_DIRECT", self.reaction_remove_direct.is_some()),
+            ("REACTION_REMOVE_GUILD", self.reaction_remove_guild.is_some()),
+            ("THREAD_CREATE_GUILD", self.thread_create_guild.is_some()),
+            ("THREAD_DELETE_GUILD", self.thread_delete_guild.is_some()),
+            ("GUILD_SCHEDULED_EVENT_CREATE", self.guild_scheduled_event_create.is_some()),
+            ("GUILD_SCHEDULED_EVENT_UPDATE", self.guild_scheduled_event_update.is_some()),
+            ("GUILD_SCHEDULED_EVENT_DELETE", self.guild_scheduled_event_delete.is_some()),
+            ("GUILD_CREATE", self.guild_create.is_some()),
+            ("INTERACTION_CREATE", self.interaction_create.is_some()),
+            ("GUILD_MEMBER_UPDATE", self.guild_member_update.is_some()),
+            ("AUTO_MODERATION_ACTION_EXECUTION", self.auto_moderation_action_execution.is_some()),
+            ("READY", self.ready.is_some()),
+            ("RESUMED", self.resumed.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, enabled)| enabled.then_some(name))
+        .collect();
+
+        ParamsSummary {
+            events,
+            raw_events: self.raw_events.clone(),
+            intents: intents.iter_names().map(|(name, _)| name.to_string()).collect(),
+            limits: LimitsSummary {
+                max_actions: self.max_actions,
+                max_actions_per_minute: self.max_actions_per_minute,
+                max_threads_per_minute: self.max_threads_per_minute,
+                action_concurrency: self.action_concurrency,
+                max_pending_tasks: self.max_pending_tasks,
+                max_task_lifetime_secs: self.max_task_lifetime_secs,
+                max_payload_size: self.max_payload_size,
+                max_response_body_size: self.max_response_body_size,
+                http_timeout_secs: self.http_timeout,
+                max_event_age_secs: self.max_event_age_secs,
+                max_concurrent_events: self.max_concurrent_events,
+            },
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::TempPath;
     use rstest::rstest;
+    use serenity::model::gateway::ActivityType;
 
     #[rstest]
     #[case::long_string("MTExMjIyMzMzNDQ0NTU1NjY2Nzc3ODg4OTk5", "MTEx***OTk5")]
     #[case::short_string("short", "s***")]
     #[case::empty_string("", "<empty>")]
+    #[case::non_ascii("abcé-secret-tokén", "abcé***okén")]
+    #[case::short_non_ascii("éclair", "é***")]
     fn test_mask_token(#[case] input: &str, #[case] expected: &str) {
         let masked = mask_token(input);
         assert_eq!(masked, expected);
     }
 
+    #[rstest]
+    #[case::value("MTExMjIyMzMzNDQ0NTU1NjY2Nzc3ODg4OTk5", "\"MTEx***OTk5\"")]
+    #[case::empty("", "\"<empty>\"")]
+    fn test_masked_debug(#[case] input: &str, #[case] expected: &str) {
+        assert_eq!(format!("{:?}", Masked(input)), expected);
+        assert_eq!(format!("{:?}", Some(Masked(input))), format!("Some({expected})"));
+    }
+
     #[test]
     fn test_params_debug_masks_sensitive_data() {
         let params = Params {
             insecure_mode: false,
+            log_startup_summary: false,
             discord_token: "MTExMjIyMzMzNDQ0NTU1NjY2Nzc3ODg4OTk5".to_string(),
             http_endpoint: "https://example.com/webhook/secret123456".to_string(),
+            client_name: None,
+            initial_status: None,
+            initial_activity: None,
             http_timeout: default_http_timeout(),
             http_connect_timeout: default_http_connect_timeout(),
             max_response_body_size: default_max_response_body_size(),
+            actions_on_status: ActionsOnStatus::Any,
+            report_parse_errors: false,
+            retry_after_min_ms: default_retry_after_min_ms(),
+            retry_after_max_secs: 0,
+            webhook_headers: parse_headers("Authorization: Bearer header-secret").unwrap(),
+            handler_webhook_headers: HashMap::from([(
+                "message".to_string(),
+                parse_headers("X-Api-Key: handler-secret").unwrap(),
+            )]),
+            guild_http_endpoints: HashMap::new(),
+            handler_webhook_deadlines: HashMap::new(),
+            handler_redacted_fields: HashMap::new(),
+            fanout_endpoints: Vec::new(),
+            fanout_mode: FanoutMode::Concurrent,
+            fanout_failure: FanoutFailure::BestEffort,
+            fanout_suppress_actions: false,
+            outbox_path: None,
+            breaker_failure_threshold: 0,
+            breaker_cooldown_secs: default_breaker_cooldown_secs(),
+            breaker_buffer_size: 0,
+            heartbeat_interval_secs: 0,
+            debug_dump_dir: None,
+            debug_dump_max_files: default_debug_dump_max_files(),
+            debug_dump_max_bytes: default_debug_dump_max_bytes(),
+            cache_disabled: false,
+            cache_max_messages: 0,
+            channel_lookup_max_guilds: None,
+            channel_index_max_entries: default_channel_index_max_entries(),
             max_actions: default_max_actions(),
+            max_actions_per_minute: 0,
+            max_threads_per_minute: 0,
+            thread_limit_policy: ThreadLimitPolicy::Skip,
+            action_concurrency: default_action_concurrency(),
+            action_max_retries: default_action_max_retries(),
+            max_pending_tasks: default_max_pending_tasks(),
+            max_task_lifetime_secs: default_max_task_lifetime_secs(),
+            reply_cooldown_secs: 0,
+            reaction_action_cooldown_secs: 0,
+            action_idempotency_window_secs: default_action_idempotency_window_secs(),
+            suppress_mass_mentions: default_suppress_mass_mentions(),
+            default_allowed_mentions: None,
+            precheck_permissions: false,
+            normalize_emoji: false,
+            auto_react_emoji: None,
+            contextless_actions: false,
+            reaction_commands: HashMap::new(),
+            reaction_commands_forward: false,
+            allowed_actions: None,
+            admin_port: None,
+            admin_token: Some("admin-secret-token".to_string()),
+            channel_denylist_path: None,
+            error_notify_channel: None,
+            error_notify_interval_secs: default_error_notify_interval_secs(),
+            truncation_marker: None,
+            message_include_mentions: false,
+            message_include_attachments: false,
+            message_include_role_names: false,
+            message_content_intent: true,
+            content_max_forward_chars: None,
+            reaction_include_message: false,
+            reaction_guild_on_own_messages_only: false,
+            reaction_reply_mode: ReactionReplyMode::Reference,
+            reaction_remove_coalesce_ms: 0,
+            reaction_rollup_window_ms: 0,
+            message_fields: Vec::new(),
+            reply_chain_depth: 0,
+            include_shard: false,
+            include_received_at: false,
+            max_payload_size: None,
+            maintenance_message: None,
+            max_event_age_secs: 0,
+            reconnect_alert_threshold: 0,
+            max_concurrent_events: 0,
+            message_sample_rate: None,
+            message_update_sample_rate: None,
+            reaction_add_sample_rate: None,
+            reaction_remove_sample_rate: None,
+            sample_seed: None,
             message_direct: None,
             message_guild: None,
             message_delete_direct: None,
@@ -228,6 +1225,16 @@ mod tests {
             reaction_add_guild: None,
             reaction_remove_direct: None,
             reaction_remove_guild: None,
+            thread_create_guild: None,
+            thread_delete_guild: None,
+            guild_scheduled_event_create: None,
+            guild_scheduled_event_update: None,
+            guild_scheduled_event_delete: None,
+            guild_create: None,
+            interaction_create: None,
+            guild_member_update: None,
+            auto_moderation_action_execution: None,
+            raw_events: Vec::new(),
             ready: None,
             resumed: None,
         };
@@ -242,5 +1249,556 @@ mod tests {
 
         // http_endpoint should be visible (not masked)
         assert!(debug_output.contains("https://example.com/webhook/secret123456"));
+
+        // Admin token is masked
+        assert!(!debug_output.contains("admin-secret-token"));
+
+        // Header names are visible, values are not
+        assert!(debug_output.contains("authorization"));
+        assert!(!debug_output.contains("header-secret"));
+        assert!(debug_output.contains("x-api-key"));
+        assert!(!debug_output.contains("handler-secret"));
+    }
+
+    #[test]
+    fn test_webhook_headers_global_and_per_handler() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("WEBHOOK_HEADERS".to_string(), "X-Env: prod, X-Team: core".to_string()),
+            ("WEBHOOK_HEADERS_MESSAGE".to_string(), "X-Env: staging".to_string()),
+        ];
+
+        let params = Params::from_vars(env).unwrap();
+
+        assert_eq!(params.webhook_headers.len(), 2);
+        assert_eq!(params.webhook_headers["x-env"], "prod");
+        assert_eq!(params.webhook_headers["x-team"], "core");
+        assert_eq!(params.handler_webhook_headers.len(), 1);
+        assert_eq!(params.handler_webhook_headers["message"]["x-env"], "staging");
+    }
+
+    #[test]
+    fn test_webhook_deadlines_per_handler() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("WEBHOOK_DEADLINE_REACTION_ADD_MS".to_string(), "500".to_string()),
+            ("WEBHOOK_DEADLINE_MESSAGE_MS".to_string(), "2000".to_string()),
+        ];
+
+        let params = Params::from_vars(env).unwrap();
+
+        assert_eq!(
+            params.handler_webhook_deadlines,
+            HashMap::from([
+                ("reaction_add".to_string(), Duration::from_millis(500)),
+                ("message".to_string(), Duration::from_millis(2000)),
+            ])
+        );
+    }
+
+    #[rstest]
+    #[case::not_a_number("fast")]
+    #[case::zero("0")]
+    fn test_webhook_deadline_invalid(#[case] value: &str) {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("WEBHOOK_DEADLINE_REACTION_ADD_MS".to_string(), value.to_string()),
+        ];
+
+        let err = Params::from_vars(env).unwrap_err();
+
+        assert!(err.to_string().contains("WEBHOOK_DEADLINE_REACTION_ADD_MS"));
+    }
+
+    #[test]
+    fn test_redacted_fields_per_handler() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("REDACT_FIELDS_MESSAGE".to_string(), "message.author.username, author_avatar_url".to_string()),
+        ];
+
+        let params = Params::from_vars(env).unwrap();
+
+        assert_eq!(
+            params.handler_redacted_fields,
+            HashMap::from([(
+                "message".to_string(),
+                vec!["message.author.username".to_string(), "author_avatar_url".to_string()]
+            )])
+        );
+    }
+
+    #[test]
+    fn test_redacted_fields_empty_segment() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("REDACT_FIELDS_MESSAGE".to_string(), "message..username".to_string()),
+        ];
+
+        let err = Params::from_vars(env).unwrap_err();
+
+        assert!(err.to_string().contains("REDACT_FIELDS_MESSAGE"));
+    }
+
+    #[test]
+    fn test_discord_token_file() {
+        let file = TempPath::new("secret-token-file");
+        std::fs::write(&file.0, "  file-token\n").unwrap();
+        let env = vec![
+            ("DISCORD_TOKEN_FILE".to_string(), file.0.display().to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+
+        let params = Params::from_vars(env).unwrap();
+
+        assert_eq!(params.discord_token, "file-token");
+    }
+
+    #[test]
+    fn test_discord_token_env_overrides_file() {
+        let file = TempPath::new("secret-token-precedence");
+        std::fs::write(&file.0, "file-token").unwrap();
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "env-token".to_string()),
+            ("DISCORD_TOKEN_FILE".to_string(), file.0.display().to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+
+        let params = Params::from_vars(env).unwrap();
+
+        assert_eq!(params.discord_token, "env-token");
+    }
+
+    #[test]
+    fn test_missing_discord_token_file_fails_config_load() {
+        let env = vec![
+            ("DISCORD_TOKEN_FILE".to_string(), "/nonexistent/gatehook/token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+
+        let err = Params::from_vars(env).unwrap_err();
+
+        assert!(err.to_string().contains("DISCORD_TOKEN_FILE"));
+    }
+
+    #[rstest]
+    #[case::empty("")]
+    #[case::whitespace_only(" \n")]
+    fn test_empty_secret_file_fails_config_load(#[case] contents: &str) {
+        let file = TempPath::new("secret-token-empty");
+        std::fs::write(&file.0, contents).unwrap();
+        let env = vec![
+            ("ADMIN_TOKEN_FILE".to_string(), file.0.display().to_string()),
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+
+        let err = Params::from_vars(env).unwrap_err();
+
+        assert!(err.to_string().contains("ADMIN_TOKEN_FILE"));
+        assert!(err.to_string().contains("empty"));
+    }
+
+    #[test]
+    fn test_guild_http_endpoints() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("HTTP_ENDPOINT_GUILD_777".to_string(), "https://tenant-a.example.com/hook".to_string()),
+        ];
+
+        let params = Params::from_vars(env).unwrap();
+
+        assert_eq!(params.guild_http_endpoints.len(), 1);
+        assert_eq!(
+            params.guild_http_endpoints[&GuildId::new(777)].as_str(),
+            "https://tenant-a.example.com/hook"
+        );
+    }
+
+    #[rstest]
+    #[case::invalid_guild_id("HTTP_ENDPOINT_GUILD_abc", "https://example.com/hook")]
+    #[case::zero_guild_id("HTTP_ENDPOINT_GUILD_0", "https://example.com/hook")]
+    #[case::invalid_url("HTTP_ENDPOINT_GUILD_777", "not a url")]
+    fn test_invalid_guild_http_endpoint_fails_config_load(#[case] key: &str, #[case] value: &str) {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            (key.to_string(), value.to_string()),
+        ];
+
+        assert!(Params::from_vars(env).is_err());
+    }
+
+    #[rstest]
+    #[case::global("WEBHOOK_HEADERS", "X-Env")]
+    #[case::per_handler("WEBHOOK_HEADERS_MESSAGE", "X-Env")]
+    #[case::invalid_name("WEBHOOK_HEADERS", "Bad Name: value")]
+    fn test_invalid_webhook_headers_fail_config_load(#[case] key: &str, #[case] value: &str) {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            (key.to_string(), value.to_string()),
+        ];
+
+        assert!(Params::from_vars(env).is_err());
+    }
+
+    #[rstest]
+    #[case::unset(None, None, (100, 0))]
+    #[case::set(Some("250"), Some("30"), (250, 30))]
+    fn test_retry_after_bounds(#[case] min_ms: Option<&str>, #[case] max_secs: Option<&str>, #[case] expected: (u64, u64)) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        if let Some(min_ms) = min_ms {
+            env.push(("RETRY_AFTER_MIN_MS".to_string(), min_ms.to_string()));
+        }
+        if let Some(max_secs) = max_secs {
+            env.push(("RETRY_AFTER_MAX_SECS".to_string(), max_secs.to_string()));
+        }
+
+        let params = envy::from_iter::<_, Params>(env).unwrap();
+        assert_eq!((params.retry_after_min_ms, params.retry_after_max_secs), expected);
+    }
+
+    #[rstest]
+    #[case::unset(None, None)]
+    #[case::set(Some("gatehook-prod-01"), Some("gatehook-prod-01"))]
+    fn test_client_name_parsing(#[case] value: Option<&str>, #[case] expected: Option<&str>) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        if let Some(value) = value {
+            env.push(("CLIENT_NAME".to_string(), value.to_string()));
+        }
+
+        let params: Params = envy::from_iter(env).unwrap();
+        assert_eq!(params.client_name.as_deref(), expected);
+    }
+
+    #[rstest]
+    #[case::unset(None, true)]
+    #[case::disabled(Some("false"), false)]
+    #[case::enabled(Some("true"), true)]
+    fn test_suppress_mass_mentions_parsing(#[case] value: Option<&str>, #[case] expected: bool) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        if let Some(value) = value {
+            env.push(("SUPPRESS_MASS_MENTIONS".to_string(), value.to_string()));
+        }
+
+        let params: Params = envy::from_iter(env).unwrap();
+        assert_eq!(params.suppress_mass_mentions, expected);
+    }
+
+    #[rstest]
+    #[case("online", OnlineStatus::Online)]
+    #[case("idle", OnlineStatus::Idle)]
+    #[case("dnd", OnlineStatus::DoNotDisturb)]
+    #[case("invisible", OnlineStatus::Invisible)]
+    #[case(" DND ", OnlineStatus::DoNotDisturb)]
+    fn test_parse_online_status(#[case] input: &str, #[case] expected: OnlineStatus) {
+        assert_eq!(parse_online_status(input), Some(expected));
+    }
+
+    #[test]
+    fn test_parse_online_status_invalid() {
+        assert_eq!(parse_online_status("busy"), None);
+    }
+
+    #[rstest]
+    #[case("Playing with webhooks", ActivityType::Playing, "with webhooks")]
+    #[case("Watching the gateway", ActivityType::Watching, "the gateway")]
+    #[case("Listening to events", ActivityType::Listening, "events")]
+    #[case("listening music", ActivityType::Listening, "music")]
+    #[case("Competing in uptime", ActivityType::Competing, "uptime")]
+    fn test_parse_activity(
+        #[case] input: &str,
+        #[case] expected_kind: ActivityType,
+        #[case] expected_name: &str,
+    ) {
+        let activity = parse_activity(input).unwrap();
+        assert_eq!(activity.kind, expected_kind);
+        assert_eq!(activity.name, expected_name);
+    }
+
+    #[test]
+    fn test_parse_activity_custom() {
+        let activity = parse_activity("Custom Bridging events").unwrap();
+        assert_eq!(activity.kind, ActivityType::Custom);
+        assert_eq!(activity.state.as_deref(), Some("Bridging events"));
+    }
+
+    #[rstest]
+    #[case::unknown_type("Streaming something")]
+    #[case::missing_name("Playing")]
+    #[case::empty("")]
+    fn test_parse_activity_invalid(#[case] input: &str) {
+        assert!(parse_activity(input).is_none());
+    }
+
+    #[rstest]
+    #[case::single("TYPING_START", &["TYPING_START"])]
+    #[case::normalized(" typing_start , Presence_Update ", &["TYPING_START", "PRESENCE_UPDATE"])]
+    #[case::empty_entries("TYPING_START,,", &["TYPING_START"])]
+    #[case::empty("", &[])]
+    fn test_parse_event_names(#[case] input: &str, #[case] expected: &[&str]) {
+        assert_eq!(parse_event_names(input), expected);
+    }
+
+    #[rstest]
+    #[case::list("id,content,author", &["id", "content", "author"])]
+    #[case::trimmed(" id , channel_id ,", &["id", "channel_id"])]
+    #[case::empty("", &[])]
+    fn test_parse_field_names(#[case] input: &str, #[case] expected: &[&str]) {
+        assert_eq!(parse_field_names(input), expected);
+    }
+
+    #[rstest]
+    #[case::unset(None, None)]
+    #[case::list(Some("reply, React,leave_guild"), Some(&["reply", "react", "leave_guild"][..]))]
+    #[case::empty(Some(""), Some(&[][..]))]
+    fn test_allowed_actions_parsing(#[case] value: Option<&str>, #[case] expected: Option<&[&str]>) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        if let Some(value) = value {
+            env.push(("ALLOWED_ACTIONS".to_string(), value.to_string()));
+        }
+
+        let params: Params = envy::from_iter(env).unwrap();
+        assert_eq!(
+            params.allowed_actions,
+            expected.map(|names| names.iter().map(|name| name.to_string()).collect())
+        );
+    }
+
+    #[test]
+    fn test_sample_rates() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("MESSAGE_SAMPLE_RATE".to_string(), "0.1".to_string()),
+            ("REACTION_REMOVE_SAMPLE_RATE".to_string(), "0".to_string()),
+        ];
+
+        let params: Params = envy::from_iter(env).unwrap();
+        assert_eq!(params.sample_rates(), vec![("message", 0.1), ("reaction_remove", 0.0)]);
+    }
+
+    #[test]
+    fn test_raw_events_unset_is_empty() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+
+        let params: Params = envy::from_iter(env).unwrap();
+        assert!(!params.has_raw_events());
+    }
+
+    #[test]
+    fn test_fanout_config() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            (
+                "FANOUT_ENDPOINTS".to_string(),
+                "https://audit.example.com/hook, https://analytics.example.com/hook".to_string(),
+            ),
+            ("FANOUT_MODE".to_string(), "sequential".to_string()),
+            ("FANOUT_FAILURE".to_string(), "all_required".to_string()),
+        ];
+
+        let params: Params = envy::from_iter(env).unwrap();
+
+        assert_eq!(
+            params.fanout_endpoints.iter().map(Url::as_str).collect::<Vec<_>>(),
+            ["https://audit.example.com/hook", "https://analytics.example.com/hook"]
+        );
+        assert_eq!(params.fanout_mode, FanoutMode::Sequential);
+        assert_eq!(params.fanout_failure, FanoutFailure::AllRequired);
+        assert!(!params.fanout_suppress_actions);
+    }
+
+    #[rstest]
+    #[case::invalid_url("FANOUT_ENDPOINTS", "https://ok.example.com/hook,not a url")]
+    #[case::invalid_mode("FANOUT_MODE", "parallel")]
+    #[case::invalid_failure("FANOUT_FAILURE", "never")]
+    fn test_invalid_fanout_config_fails(#[case] key: &str, #[case] value: &str) {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            (key.to_string(), value.to_string()),
+        ];
+
+        assert!(envy::from_iter::<_, Params>(env).is_err());
+    }
+
+    #[rstest]
+    #[case::unset(None, Some(ActionsOnStatus::Any))]
+    #[case::any(Some("any"), Some(ActionsOnStatus::Any))]
+    #[case::success_only(Some("success_only"), Some(ActionsOnStatus::SuccessOnly))]
+    #[case::invalid(Some("never"), None)]
+    fn test_actions_on_status(#[case] value: Option<&str>, #[case] expected: Option<ActionsOnStatus>) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        if let Some(value) = value {
+            env.push(("ACTIONS_ON_STATUS".to_string(), value.to_string()));
+        }
+
+        let params = envy::from_iter::<_, Params>(env).ok();
+        assert_eq!(params.map(|params| params.actions_on_status), expected);
+    }
+
+    #[rstest]
+    #[case::unset(None, Some(2))]
+    #[case::disabled(Some("0"), Some(0))]
+    #[case::maximum(Some("10"), Some(10))]
+    #[case::above_maximum(Some("11"), None)]
+    #[case::overflowing(Some("4294967296"), None)]
+    fn test_action_max_retries(#[case] value: Option<&str>, #[case] expected: Option<u32>) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        if let Some(value) = value {
+            env.push(("ACTION_MAX_RETRIES".to_string(), value.to_string()));
+        }
+
+        let params = envy::from_iter::<_, Params>(env).ok();
+        assert_eq!(params.map(|params| params.action_max_retries), expected);
+    }
+
+    #[rstest]
+    #[case::unset(None, Some(None))]
+    #[case::channel(Some("123456789012345678"), Some(Some(ChannelId::new(123456789012345678))))]
+    #[case::invalid(Some("general"), None)]
+    fn test_error_notify_channel(#[case] value: Option<&str>, #[case] expected: Option<Option<ChannelId>>) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        if let Some(value) = value {
+            env.push(("ERROR_NOTIFY_CHANNEL".to_string(), value.to_string()));
+        }
+
+        let params = envy::from_iter::<_, Params>(env).ok();
+        assert_eq!(params.as_ref().map(|params| params.error_notify_interval_secs), expected.map(|_| 60));
+        assert_eq!(params.map(|params| params.error_notify_channel), expected);
+    }
+
+    #[rstest]
+    #[case::unset(None, Some(ThreadLimitPolicy::Skip))]
+    #[case::skip(Some("skip"), Some(ThreadLimitPolicy::Skip))]
+    #[case::post_to_parent(Some("post_to_parent"), Some(ThreadLimitPolicy::PostToParent))]
+    #[case::invalid(Some("queue"), None)]
+    fn test_thread_limit_policy(#[case] value: Option<&str>, #[case] expected: Option<ThreadLimitPolicy>) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("MAX_THREADS_PER_MINUTE".to_string(), "10".to_string()),
+        ];
+        if let Some(value) = value {
+            env.push(("THREAD_LIMIT_POLICY".to_string(), value.to_string()));
+        }
+
+        let params = envy::from_iter::<_, Params>(env).ok();
+        assert_eq!(params.as_ref().map(|params| params.max_threads_per_minute), expected.map(|_| 10));
+        assert_eq!(params.map(|params| params.thread_limit_policy), expected);
+    }
+
+    #[rstest]
+    #[case::unset(None, Some(ReactionReplyMode::Reference))]
+    #[case::channel(Some("channel"), Some(ReactionReplyMode::Channel))]
+    #[case::dm(Some("dm"), Some(ReactionReplyMode::Dm))]
+    #[case::invalid(Some("thread"), None)]
+    fn test_reaction_reply_mode(#[case] value: Option<&str>, #[case] expected: Option<ReactionReplyMode>) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        if let Some(value) = value {
+            env.push(("REACTION_REPLY_MODE".to_string(), value.to_string()));
+        }
+
+        let params = envy::from_iter::<_, Params>(env).ok();
+        assert_eq!(params.map(|params| params.reaction_reply_mode), expected);
+    }
+
+    #[rstest]
+    #[case::unset(None, Some(None))]
+    #[case::users(Some("users"), Some(Some(DefaultAllowedMentions { users: true, roles: false })))]
+    #[case::none(Some("none"), Some(Some(DefaultAllowedMentions::default())))]
+    #[case::invalid(Some("everyone"), None)]
+    fn test_default_allowed_mentions(
+        #[case] value: Option<&str>,
+        #[case] expected: Option<Option<DefaultAllowedMentions>>,
+    ) {
+        let mut env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+        ];
+        if let Some(value) = value {
+            env.push(("DEFAULT_ALLOWED_MENTIONS".to_string(), value.to_string()));
+        }
+
+        let params = envy::from_iter::<_, Params>(env).ok();
+        assert_eq!(params.map(|params| params.default_allowed_mentions), expected);
+    }
+
+    #[test]
+    fn test_reaction_commands_parsing() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            (
+                "REACTION_COMMANDS".to_string(),
+                r#"{"📌": [{"type": "reply", "content": "Pinned!"}], "ok:123": []}"#.to_string(),
+            ),
+        ];
+
+        let params: Params = envy::from_iter(env).unwrap();
+
+        assert_eq!(params.reaction_commands.len(), 2);
+        assert_eq!(params.reaction_commands["📌"][0].name(), "reply");
+        assert!(params.reaction_commands["ok:123"].is_empty());
+    }
+
+    #[rstest]
+    #[case::not_json("📌=reply")]
+    #[case::unknown_action(r#"{"📌": [{"type": "shout"}]}"#)]
+    fn test_invalid_reaction_commands_fails_config_load(#[case] value: &str) {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("REACTION_COMMANDS".to_string(), value.to_string()),
+        ];
+
+        assert!(envy::from_iter::<_, Params>(env).is_err());
+    }
+
+    #[test]
+    fn test_invalid_initial_status_fails_config_load() {
+        let env = vec![
+            ("DISCORD_TOKEN".to_string(), "token".to_string()),
+            ("HTTP_ENDPOINT".to_string(), "https://example.com/webhook".to_string()),
+            ("INITIAL_STATUS".to_string(), "busy".to_string()),
+        ];
+
+        assert!(envy::from_iter::<_, Params>(env).is_err());
     }
 }
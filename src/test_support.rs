@@ -0,0 +1,102 @@
+// Helpers shared by unit tests
+
+use crate::adapters::{Delivery, EventResponse, EventSender};
+use crate::error::GatehookError;
+use serde::Serialize;
+use serenity::async_trait;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Unique temporary file or directory per test (removed on drop)
+///
+/// The path is `gatehook-<pid>-<name>` in the system temp directory, cleared
+/// on creation. Its `.tmp` sibling (atomic writes) is removed as well.
+pub(crate) struct TempPath(pub(crate) PathBuf);
+
+impl TempPath {
+    pub(crate) fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("gatehook-{}-{}", std::process::id(), name));
+        remove(&path);
+        Self(path)
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        remove(&self.0);
+        remove(&self.0.with_extension("tmp"));
+    }
+}
+
+fn remove(path: &std::path::Path) {
+    if path.is_dir() {
+        let _ = std::fs::remove_dir_all(path);
+    } else {
+        let _ = std::fs::remove_file(path);
+    }
+}
+
+/// Inner sender recording deliveries, optionally failing, answering with a status or stalling
+///
+/// Clones share their state, so a test can keep a handle to a sender it
+/// wrapped in decorators.
+#[derive(Clone, Default)]
+pub(crate) struct RecordingSender {
+    sent: Arc<Mutex<Vec<(String, serde_json::Value)>>>,
+    fail: Arc<AtomicBool>,
+    status: Arc<Mutex<Option<u16>>>,
+    delay: Arc<Mutex<Duration>>,
+}
+
+impl RecordingSender {
+    pub(crate) fn set_fail(&self, fail: bool) {
+        self.fail.store(fail, Ordering::SeqCst);
+    }
+
+    pub(crate) fn set_status(&self, status: u16) {
+        *self.status.lock().unwrap() = Some(status);
+    }
+
+    pub(crate) fn set_delay(&self, delay: Duration) {
+        *self.delay.lock().unwrap() = delay;
+    }
+
+    pub(crate) fn sent(&self) -> Vec<(String, serde_json::Value)> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl EventSender for RecordingSender {
+    async fn send<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Option<EventResponse>, GatehookError> {
+        Ok(self.deliver(handler, payload).await?.response)
+    }
+
+    async fn deliver<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Delivery, GatehookError> {
+        let delay = *self.delay.lock().unwrap();
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+        if self.fail.load(Ordering::SeqCst) {
+            return Err(std::io::Error::other("endpoint unreachable").into());
+        }
+        self.sent
+            .lock()
+            .unwrap()
+            .push((handler.to_string(), serde_json::to_value(payload)?));
+        Ok(Delivery {
+            status: *self.status.lock().unwrap(),
+            response: None,
+        })
+    }
+}
@@ -0,0 +1,100 @@
+//! Offline validation of webhook responses (`--validate-response <file>`)
+//!
+//! Parses a response body through the same `EventResponse` deserialization
+//! used for live webhook responses, without connecting to Discord.
+
+use crate::adapters::event_response::{EventResponse, InteractionResponseKind, ResponseAction};
+use std::fmt::Write as _;
+
+/// Parse a webhook response body and summarize its actions
+///
+/// Returns a human-readable summary (one line per action), or the
+/// deserialization error for an invalid body.
+pub fn validate_response(json: &str) -> Result<String, serde_json::Error> {
+    let response: EventResponse = serde_json::from_str(json)?;
+
+    let mut summary = format!("Valid webhook response: {} action(s)", response.actions.len());
+    for (index, action) in response.actions.iter().enumerate() {
+        let _ = write!(summary, "\n  {}. {}", index + 1, describe_action(action));
+    }
+    Ok(summary)
+}
+
+/// One-line description of an action and its parameters
+fn describe_action(action: &ResponseAction) -> String {
+    let details = match action {
+        ResponseAction::Reply(params) => format!(
+            "content {} chars, mention={}, delete_original={}",
+            params.content.chars().count(),
+            params.mention,
+            params.delete_original
+        ),
+        ResponseAction::React(params) => match &params.message_id {
+            Some(message_id) => format!(
+                "emoji {}, message {} in channel {}",
+                params.emoji,
+                message_id,
+                params.channel_id.as_deref().unwrap_or("(event channel)")
+            ),
+            None => format!("emoji {}", params.emoji),
+        },
+        ResponseAction::Thread(params) => format!(
+            "name {}, content {} chars, auto_archive_duration={}, private={}",
+            params.name.as_deref().unwrap_or("(auto)"),
+            params.content.chars().count(),
+            params.auto_archive_duration,
+            params.private
+        ),
+        ResponseAction::Edit(params) => format!(
+            "message {}, content {}, embed={}",
+            params.message_id,
+            params
+                .content
+                .as_deref()
+                .map_or("unchanged".to_string(), |content| format!("{} chars", content.chars().count())),
+            params.embed.is_some()
+        ),
+        ResponseAction::SendMessage(params) => format!(
+            "channel {}, content {} chars",
+            params.channel_id.as_deref().unwrap_or("(event channel)"),
+            params.content.chars().count()
+        ),
+        // The webhook URL embeds a token: only the webhook ID (first path segment) is shown
+        ResponseAction::WebhookMessage(params) => format!(
+            "webhook {}, username {}, content {} chars",
+            params
+                .webhook_url
+                .split("/webhooks/")
+                .nth(1)
+                .and_then(|rest| rest.split('/').next())
+                .unwrap_or("(invalid)"),
+            params.username.as_deref().unwrap_or("(default)"),
+            params.content.chars().count()
+        ),
+        ResponseAction::Forward(params) => format!(
+            "channel {}, include_attachments={}",
+            params.to_channel_id, params.include_attachments
+        ),
+        ResponseAction::ToggleRole(params) => format!("role {}", params.role_id),
+        ResponseAction::SuppressEmbeds => "target message".to_string(),
+        ResponseAction::RemoveAllReactions => "target message".to_string(),
+        ResponseAction::LeaveGuild(params) => {
+            format!("guild {}", params.guild_id.as_deref().unwrap_or("(event guild)"))
+        }
+        ResponseAction::JoinVoice(params) => format!("voice channel {}", params.channel_id),
+        ResponseAction::CreateChannel(params) => format!(
+            "name {}, kind {:?}, parent {}",
+            params.name,
+            params.kind,
+            params.parent_id.as_deref().unwrap_or("(none)")
+        ),
+        ResponseAction::InteractionResponse(params) => match params.kind {
+            InteractionResponseKind::DeferredUpdate => "kind deferred_update".to_string(),
+            InteractionResponseKind::Update => format!(
+                "kind update, content {} chars",
+                params.content.as_deref().map_or(0, |content| content.chars().count())
+            ),
+        },
+    };
+    format!("{}: {}", action.name(), details)
+}
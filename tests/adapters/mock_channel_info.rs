@@ -1,7 +1,8 @@
-use gatehook::adapters::ChannelInfoProvider;
+use gatehook::adapters::{ChannelInfoProvider, GuildInfo};
 use serenity::async_trait;
 use serenity::model::channel::GuildChannel;
 use serenity::model::id::{ChannelId, GuildId};
+use serenity::model::permissions::Permissions;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
@@ -10,6 +11,8 @@ pub struct MockChannelInfoProvider {
     is_thread_responses: Arc<Mutex<HashMap<ChannelId, bool>>>,
     is_thread_errors: Arc<Mutex<HashMap<ChannelId, String>>>,
     channel_responses: Arc<Mutex<HashMap<ChannelId, GuildChannel>>>,
+    bot_permissions: Arc<Mutex<HashMap<ChannelId, Permissions>>>,
+    guilds: Arc<Mutex<HashMap<GuildId, GuildInfo>>>,
 }
 
 impl MockChannelInfoProvider {
@@ -19,6 +22,8 @@ impl MockChannelInfoProvider {
             is_thread_responses: Arc::new(Mutex::new(HashMap::new())),
             is_thread_errors: Arc::new(Mutex::new(HashMap::new())),
             channel_responses: Arc::new(Mutex::new(HashMap::new())),
+            bot_permissions: Arc::new(Mutex::new(HashMap::new())),
+            guilds: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -45,6 +50,19 @@ impl MockChannelInfoProvider {
             .unwrap()
             .insert(channel_id, channel);
     }
+
+    /// Set the bot's cached permissions for a specific channel ID
+    pub fn set_bot_permissions(&self, channel_id: ChannelId, permissions: Permissions) {
+        self.bot_permissions
+            .lock()
+            .unwrap()
+            .insert(channel_id, permissions);
+    }
+
+    /// Set the cached guild information for a specific guild ID
+    pub fn set_guild(&self, guild_id: GuildId, guild: GuildInfo) {
+        self.guilds.lock().unwrap().insert(guild_id, guild);
+    }
 }
 
 impl Default for MockChannelInfoProvider {
@@ -55,6 +73,7 @@ impl Default for MockChannelInfoProvider {
 
 #[async_trait]
 impl ChannelInfoProvider for MockChannelInfoProvider {
+    #[cfg(feature = "actions")]
     async fn is_thread(
         &self,
         _guild_id: Option<GuildId>,
@@ -88,4 +107,15 @@ impl ChannelInfoProvider for MockChannelInfoProvider {
             .get(&channel_id)
             .cloned())
     }
+
+    fn get_guild(&self, guild_id: GuildId) -> Option<GuildInfo> {
+        // Return configured guild, None (not cached) if not set
+        self.guilds.lock().unwrap().get(&guild_id).cloned()
+    }
+
+    #[cfg(feature = "actions")]
+    fn bot_permissions(&self, _guild_id: GuildId, channel_id: ChannelId) -> Option<Permissions> {
+        // Return configured permissions, None (not cached) if not set
+        self.bot_permissions.lock().unwrap().get(&channel_id).copied()
+    }
 }
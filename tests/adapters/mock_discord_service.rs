@@ -1,14 +1,51 @@
 use gatehook::adapters::DiscordService;
+use gatehook::adapters::event_response::EmbedParams;
 use serenity::async_trait;
-use serenity::model::channel::{GuildChannel, Message};
-use serenity::model::id::{ChannelId, GuildId, MessageId};
+#[cfg(feature = "actions")]
+use serenity::http::{ErrorResponse, HttpError};
+#[cfg(feature = "actions")]
+use serenity::model::channel::Attachment;
+use serenity::model::channel::{ChannelType, GuildChannel, Message};
+use serenity::model::id::{ChannelId, GuildId, InteractionId, MessageId, RoleId, UserId, WebhookId};
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 pub struct MockDiscordService {
     pub replies: Arc<Mutex<Vec<RecordedReply>>>,
+    pub file_replies: Arc<Mutex<Vec<RecordedFileReply>>>,
     pub reactions: Arc<Mutex<Vec<RecordedReaction>>>,
     pub threads: Arc<Mutex<Vec<RecordedThread>>>,
     pub messages: Arc<Mutex<Vec<RecordedMessage>>>,
+    pub deletions: Arc<Mutex<Vec<RecordedDeletion>>>,
+    pub left_guilds: Arc<Mutex<Vec<GuildId>>>,
+    /// Voice channels joined, with their guild
+    pub voice_joins: Arc<Mutex<Vec<(GuildId, ChannelId)>>>,
+    pub webhook_messages: Arc<Mutex<Vec<RecordedWebhookMessage>>>,
+    /// Messages whose embeds were suppressed
+    pub suppressed_embeds: Arc<Mutex<Vec<(ChannelId, MessageId)>>>,
+    pub cleared_reactions: Arc<Mutex<Vec<(ChannelId, MessageId)>>>,
+    pub edits: Arc<Mutex<Vec<RecordedEdit>>>,
+    pub role_changes: Arc<Mutex<Vec<RecordedRoleChange>>>,
+    pub created_channels: Arc<Mutex<Vec<RecordedChannel>>>,
+    /// Users a DM channel was opened with
+    pub dm_channels: Arc<Mutex<Vec<UserId>>>,
+    pub interaction_responses: Arc<Mutex<Vec<RecordedInteractionResponse>>>,
+    /// Messages returned by `get_message` (others get a dummy message)
+    pub stored_messages: Arc<Mutex<HashMap<MessageId, Message>>>,
+    pub fetched_messages: Arc<Mutex<Vec<MessageId>>>,
+    delete_error: Arc<Mutex<bool>>,
+    /// Number of upcoming reply/react calls that fail with a rate limit (429)
+    rate_limited_calls: Arc<AtomicUsize>,
+    rate_limit_hits: Arc<AtomicUsize>,
+    get_message_error: Arc<Mutex<bool>>,
+    call_delay: Arc<Mutex<Duration>>,
+    /// Delays of the next write calls, in call order (before falling back to `call_delay`)
+    call_delays: Arc<Mutex<VecDeque<Duration>>>,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Arc<AtomicUsize>,
+    next_message_id: Arc<AtomicU64>,
 }
 
 #[derive(Debug, Clone)]
@@ -17,6 +54,16 @@ pub struct RecordedReply {
     pub message_id: MessageId,
     pub content: String,
     pub mention: bool,
+    pub allow_mass_mentions: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordedFileReply {
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    pub filename: String,
+    pub data: Vec<u8>,
+    pub mention: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -29,16 +76,77 @@ pub struct RecordedReaction {
 #[derive(Debug, Clone)]
 pub struct RecordedThread {
     pub channel_id: ChannelId,
-    pub message_id: MessageId,
+    /// Message the thread was started from (`None` for private threads)
+    pub message_id: Option<MessageId>,
     pub name: String,
     pub auto_archive_duration: u16,
+    pub kind: ChannelType,
+    /// Always `None` for public threads
+    pub invitable: Option<bool>,
+    pub rate_limit_per_user: Option<u16>,
 }
 
 #[derive(Debug, Clone)]
 pub struct RecordedMessage {
+    /// ID assigned to the sent message (unique per mock)
+    pub id: MessageId,
     pub channel_id: ChannelId,
     pub content: String,
     pub reply_to: Option<MessageId>,
+    pub allow_mass_mentions: bool,
+    /// File names of re-uploaded attachments
+    pub attachments: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordedEdit {
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    pub content: Option<String>,
+    pub embed: Option<EmbedParams>,
+    pub allow_mass_mentions: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedChannel {
+    pub guild_id: GuildId,
+    pub name: String,
+    pub kind: ChannelType,
+    pub parent_id: Option<ChannelId>,
+    pub topic: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRoleChange {
+    pub guild_id: GuildId,
+    pub user_id: UserId,
+    pub role_id: RoleId,
+    /// `true` for an added role, `false` for a removed one
+    pub added: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedInteractionResponse {
+    pub interaction_id: InteractionId,
+    pub token: String,
+    /// New message content (`None` for a deferred update)
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordedWebhookMessage {
+    pub webhook_id: WebhookId,
+    pub token: String,
+    pub username: Option<String>,
+    pub avatar_url: Option<String>,
+    pub content: String,
+    pub allow_mass_mentions: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordedDeletion {
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
 }
 
 impl Default for MockDiscordService {
@@ -51,16 +159,113 @@ impl MockDiscordService {
     pub fn new() -> Self {
         Self {
             replies: Arc::new(Mutex::new(Vec::new())),
+            file_replies: Arc::new(Mutex::new(Vec::new())),
             reactions: Arc::new(Mutex::new(Vec::new())),
             threads: Arc::new(Mutex::new(Vec::new())),
             messages: Arc::new(Mutex::new(Vec::new())),
+            deletions: Arc::new(Mutex::new(Vec::new())),
+            left_guilds: Arc::new(Mutex::new(Vec::new())),
+            voice_joins: Arc::new(Mutex::new(Vec::new())),
+            webhook_messages: Arc::new(Mutex::new(Vec::new())),
+            suppressed_embeds: Arc::new(Mutex::new(Vec::new())),
+            cleared_reactions: Arc::new(Mutex::new(Vec::new())),
+            edits: Arc::new(Mutex::new(Vec::new())),
+            role_changes: Arc::new(Mutex::new(Vec::new())),
+            created_channels: Arc::new(Mutex::new(Vec::new())),
+            dm_channels: Arc::new(Mutex::new(Vec::new())),
+            interaction_responses: Arc::new(Mutex::new(Vec::new())),
+            stored_messages: Arc::new(Mutex::new(HashMap::new())),
+            fetched_messages: Arc::new(Mutex::new(Vec::new())),
+            delete_error: Arc::new(Mutex::new(false)),
+            rate_limited_calls: Arc::new(AtomicUsize::new(0)),
+            rate_limit_hits: Arc::new(AtomicUsize::new(0)),
+            get_message_error: Arc::new(Mutex::new(false)),
+            call_delay: Arc::new(Mutex::new(Duration::ZERO)),
+            call_delays: Arc::new(Mutex::new(VecDeque::new())),
+            in_flight: Arc::new(AtomicUsize::new(0)),
+            max_in_flight: Arc::new(AtomicUsize::new(0)),
+            next_message_id: Arc::new(AtomicU64::new(10_000)),
         }
     }
 
+    /// Delay each write call to make concurrent execution observable
+    pub fn set_call_delay(&self, delay: Duration) {
+        *self.call_delay.lock().unwrap() = delay;
+    }
+
+    /// Delay the next write calls by `delays`, in call order (to make concurrent calls finish out of order)
+    #[allow(dead_code)]
+    pub fn set_call_delays(&self, delays: impl IntoIterator<Item = Duration>) {
+        *self.call_delays.lock().unwrap() = delays.into_iter().collect();
+    }
+
+    /// Highest number of write calls observed in flight at once
+    /// (only tracked while a call delay is set)
+    pub fn get_max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    #[cfg(feature = "actions")]
+    async fn simulate_latency(&self) {
+        let queued = self.call_delays.lock().unwrap().pop_front();
+        let delay = queued.unwrap_or_else(|| *self.call_delay.lock().unwrap());
+        if delay.is_zero() {
+            return;
+        }
+
+        let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+        tokio::time::sleep(delay).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+
+    /// Make get_message return an error
+    pub fn set_get_message_error(&self, fail: bool) {
+        *self.get_message_error.lock().unwrap() = fail;
+    }
+
+    /// Make delete_message return an error
+    pub fn set_delete_error(&self, fail: bool) {
+        *self.delete_error.lock().unwrap() = fail;
+    }
+
+    /// Fail the next `calls` reply/react calls with a rate limit (HTTP 429)
+    pub fn set_rate_limited_calls(&self, calls: usize) {
+        self.rate_limited_calls.store(calls, Ordering::SeqCst);
+    }
+
+    /// Number of calls rejected with a rate limit so far
+    pub fn get_rate_limit_hits(&self) -> usize {
+        self.rate_limit_hits.load(Ordering::SeqCst)
+    }
+
+    #[cfg(feature = "actions")]
+    async fn check_rate_limit(&self) -> Result<(), serenity::Error> {
+        let limited = self
+            .rate_limited_calls
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |calls| calls.checked_sub(1))
+            .is_ok();
+        if !limited {
+            return Ok(());
+        }
+
+        self.rate_limit_hits.fetch_add(1, Ordering::SeqCst);
+        let response = http_02::Response::builder()
+            .status(429)
+            .body(r#"{"message":"You are being rate limited.","retry_after":0.5,"global":false,"code":0}"#)
+            .unwrap();
+        let error = ErrorResponse::from_response(response.into(), reqwest_011::Method::POST).await;
+        Err(serenity::Error::Http(HttpError::UnsuccessfulRequest(error)))
+    }
+
     pub fn get_replies(&self) -> Vec<RecordedReply> {
         self.replies.lock().unwrap().clone()
     }
 
+    pub fn get_file_replies(&self) -> Vec<RecordedFileReply> {
+        self.file_replies.lock().unwrap().clone()
+    }
+
     pub fn get_reactions(&self) -> Vec<RecordedReaction> {
         self.reactions.lock().unwrap().clone()
     }
@@ -72,16 +277,78 @@ impl MockDiscordService {
     pub fn get_messages(&self) -> Vec<RecordedMessage> {
         self.messages.lock().unwrap().clone()
     }
+
+    pub fn get_deletions(&self) -> Vec<RecordedDeletion> {
+        self.deletions.lock().unwrap().clone()
+    }
+
+    pub fn get_left_guilds(&self) -> Vec<GuildId> {
+        self.left_guilds.lock().unwrap().clone()
+    }
+
+    pub fn get_voice_joins(&self) -> Vec<(GuildId, ChannelId)> {
+        self.voice_joins.lock().unwrap().clone()
+    }
+
+    pub fn get_webhook_messages(&self) -> Vec<RecordedWebhookMessage> {
+        self.webhook_messages.lock().unwrap().clone()
+    }
+
+    pub fn get_suppressed_embeds(&self) -> Vec<(ChannelId, MessageId)> {
+        self.suppressed_embeds.lock().unwrap().clone()
+    }
+
+    pub fn get_cleared_reactions(&self) -> Vec<(ChannelId, MessageId)> {
+        self.cleared_reactions.lock().unwrap().clone()
+    }
+
+    pub fn get_edits(&self) -> Vec<RecordedEdit> {
+        self.edits.lock().unwrap().clone()
+    }
+
+    pub fn get_role_changes(&self) -> Vec<RecordedRoleChange> {
+        self.role_changes.lock().unwrap().clone()
+    }
+
+    pub fn get_created_channels(&self) -> Vec<RecordedChannel> {
+        self.created_channels.lock().unwrap().clone()
+    }
+
+    pub fn get_dm_channels(&self) -> Vec<UserId> {
+        self.dm_channels.lock().unwrap().clone()
+    }
+
+    pub fn get_interaction_responses(&self) -> Vec<RecordedInteractionResponse> {
+        self.interaction_responses.lock().unwrap().clone()
+    }
+
+    /// Make `get_message` return this message for its ID
+    pub fn set_message(&self, message: Message) {
+        self.stored_messages.lock().unwrap().insert(message.id, message);
+    }
+
+    /// Allocate an ID for a newly sent message
+    fn next_message_id(&self) -> MessageId {
+        MessageId::new(self.next_message_id.fetch_add(1, Ordering::SeqCst))
+    }
+
+    pub fn get_fetched_messages(&self) -> Vec<MessageId> {
+        self.fetched_messages.lock().unwrap().clone()
+    }
 }
 
 #[async_trait]
 impl DiscordService for MockDiscordService {
+    #[cfg(feature = "actions")]
     async fn react_to_message(
         &self,
         channel_id: ChannelId,
         message_id: MessageId,
         emoji: &str,
     ) -> Result<(), serenity::Error> {
+        self.simulate_latency().await;
+        self.check_rate_limit().await?;
+
         self.reactions.lock().unwrap().push(RecordedReaction {
             channel_id,
             message_id,
@@ -90,18 +357,25 @@ impl DiscordService for MockDiscordService {
         Ok(())
     }
 
+    #[cfg(feature = "actions")]
     async fn create_thread_from_message(
         &self,
         channel_id: ChannelId,
         message_id: MessageId,
         name: &str,
         auto_archive_duration: u16,
+        rate_limit_per_user: Option<u16>,
     ) -> Result<GuildChannel, serenity::Error> {
+        self.simulate_latency().await;
+
         self.threads.lock().unwrap().push(RecordedThread {
             channel_id,
-            message_id,
+            message_id: Some(message_id),
             name: name.to_string(),
             auto_archive_duration,
+            kind: ChannelType::PublicThread,
+            invitable: None,
+            rate_limit_per_user,
         });
 
         // Return a dummy GuildChannel
@@ -109,51 +383,350 @@ impl DiscordService for MockDiscordService {
         Ok(create_dummy_guild_channel(channel_id))
     }
 
+    #[cfg(feature = "actions")]
+    async fn create_private_thread(
+        &self,
+        channel_id: ChannelId,
+        name: &str,
+        auto_archive_duration: u16,
+        invitable: Option<bool>,
+        rate_limit_per_user: Option<u16>,
+    ) -> Result<GuildChannel, serenity::Error> {
+        self.simulate_latency().await;
+
+        self.threads.lock().unwrap().push(RecordedThread {
+            channel_id,
+            message_id: None,
+            name: name.to_string(),
+            auto_archive_duration,
+            kind: ChannelType::PrivateThread,
+            invitable,
+            rate_limit_per_user,
+        });
+
+        Ok(create_dummy_guild_channel(channel_id))
+    }
+
+    #[cfg(feature = "actions")]
     async fn send_message_to_channel(
         &self,
         channel_id: ChannelId,
         content: &str,
+        allow_mass_mentions: bool,
     ) -> Result<Message, serenity::Error> {
+        self.simulate_latency().await;
+
+        let id = self.next_message_id();
         self.messages.lock().unwrap().push(RecordedMessage {
+            id,
             channel_id,
             content: content.to_string(),
             reply_to: None,
+            allow_mass_mentions,
+            attachments: Vec::new(),
         });
 
         // Return a dummy Message
-        Ok(create_dummy_message(channel_id, content))
+        let mut message = create_dummy_message(channel_id, content);
+        message.id = id;
+        Ok(message)
+    }
+
+    /// The DM channel ID equals the user ID
+    #[cfg(feature = "actions")]
+    async fn create_dm_channel(&self, user_id: UserId) -> Result<ChannelId, serenity::Error> {
+        self.dm_channels.lock().unwrap().push(user_id);
+        Ok(ChannelId::new(user_id.get()))
     }
 
+    #[cfg(feature = "actions")]
+    async fn send_message_with_attachments(
+        &self,
+        channel_id: ChannelId,
+        content: &str,
+        attachments: &[Attachment],
+        allow_mass_mentions: bool,
+    ) -> Result<Message, serenity::Error> {
+        self.simulate_latency().await;
+
+        let id = self.next_message_id();
+        self.messages.lock().unwrap().push(RecordedMessage {
+            id,
+            channel_id,
+            content: content.to_string(),
+            reply_to: None,
+            allow_mass_mentions,
+            attachments: attachments.iter().map(|attachment| attachment.filename.clone()).collect(),
+        });
+
+        let mut message = create_dummy_message(channel_id, content);
+        message.id = id;
+        Ok(message)
+    }
+
+    #[cfg(feature = "actions")]
     async fn reply_in_channel(
         &self,
         channel_id: ChannelId,
         message_id: MessageId,
         content: &str,
         mention: bool,
+        allow_mass_mentions: bool,
     ) -> Result<Message, serenity::Error> {
+        self.simulate_latency().await;
+        self.check_rate_limit().await?;
+
         // Record in both replies and messages for backward compatibility
         self.replies.lock().unwrap().push(RecordedReply {
             channel_id,
             message_id,
             content: content.to_string(),
             mention,
+            allow_mass_mentions,
         });
 
+        let id = self.next_message_id();
         self.messages.lock().unwrap().push(RecordedMessage {
+            id,
             channel_id,
             content: content.to_string(),
             reply_to: Some(message_id),
+            allow_mass_mentions,
+            attachments: Vec::new(),
         });
 
         // Return a dummy Message
-        Ok(create_dummy_message(channel_id, content))
+        let mut message = create_dummy_message(channel_id, content);
+        message.id = id;
+        Ok(message)
+    }
+
+    #[cfg(feature = "actions")]
+    async fn reply_with_file_in_channel(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        filename: &str,
+        data: Vec<u8>,
+        mention: bool,
+    ) -> Result<Message, serenity::Error> {
+        self.simulate_latency().await;
+
+        self.file_replies.lock().unwrap().push(RecordedFileReply {
+            channel_id,
+            message_id,
+            filename: filename.to_string(),
+            data,
+            mention,
+        });
+
+        let id = self.next_message_id();
+        self.messages.lock().unwrap().push(RecordedMessage {
+            id,
+            channel_id,
+            content: String::new(),
+            reply_to: Some(message_id),
+            allow_mass_mentions: false,
+            attachments: Vec::new(),
+        });
+
+        let mut message = create_dummy_message(channel_id, "");
+        message.id = id;
+        Ok(message)
+    }
+
+    #[cfg(feature = "actions")]
+    async fn delete_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), serenity::Error> {
+        if *self.delete_error.lock().unwrap() {
+            return Err(serenity::Error::Other("Mock delete error"));
+        }
+
+        self.deletions.lock().unwrap().push(RecordedDeletion {
+            channel_id,
+            message_id,
+        });
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn edit_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        content: Option<&str>,
+        embed: Option<&EmbedParams>,
+        allow_mass_mentions: bool,
+    ) -> Result<Message, serenity::Error> {
+        self.edits.lock().unwrap().push(RecordedEdit {
+            channel_id,
+            message_id,
+            content: content.map(str::to_string),
+            embed: embed.cloned(),
+            allow_mass_mentions,
+        });
+
+        let mut message = create_dummy_message(channel_id, content.unwrap_or_default());
+        message.id = message_id;
+        Ok(message)
+    }
+
+    #[cfg(feature = "actions")]
+    async fn execute_webhook(
+        &self,
+        webhook_id: WebhookId,
+        token: &str,
+        username: Option<&str>,
+        avatar_url: Option<&str>,
+        content: &str,
+        allow_mass_mentions: bool,
+    ) -> Result<(), serenity::Error> {
+        self.webhook_messages.lock().unwrap().push(RecordedWebhookMessage {
+            webhook_id,
+            token: token.to_string(),
+            username: username.map(str::to_string),
+            avatar_url: avatar_url.map(str::to_string),
+            content: content.to_string(),
+            allow_mass_mentions,
+        });
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn suppress_embeds(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), serenity::Error> {
+        self.suppressed_embeds.lock().unwrap().push((channel_id, message_id));
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn remove_all_reactions(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), serenity::Error> {
+        self.cleared_reactions.lock().unwrap().push((channel_id, message_id));
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn leave_guild(&self, guild_id: GuildId) -> Result<(), serenity::Error> {
+        self.left_guilds.lock().unwrap().push(guild_id);
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn join_voice_channel(&self, guild_id: GuildId, channel_id: ChannelId) -> Result<(), serenity::Error> {
+        self.voice_joins.lock().unwrap().push((guild_id, channel_id));
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn create_channel(
+        &self,
+        guild_id: GuildId,
+        name: &str,
+        kind: ChannelType,
+        parent_id: Option<ChannelId>,
+        topic: Option<&str>,
+    ) -> Result<GuildChannel, serenity::Error> {
+        self.created_channels.lock().unwrap().push(RecordedChannel {
+            guild_id,
+            name: name.to_string(),
+            kind,
+            parent_id,
+            topic: topic.map(str::to_string),
+        });
+        let mut channel = create_dummy_guild_channel(ChannelId::new(self.next_message_id().get()));
+        channel.guild_id = guild_id;
+        channel.name = name.to_string();
+        channel.kind = kind;
+        Ok(channel)
+    }
+
+    #[cfg(feature = "actions")]
+    async fn add_member_role(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId,
+    ) -> Result<(), serenity::Error> {
+        self.role_changes.lock().unwrap().push(RecordedRoleChange {
+            guild_id,
+            user_id,
+            role_id,
+            added: true,
+        });
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn remove_member_role(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        role_id: RoleId,
+    ) -> Result<(), serenity::Error> {
+        self.role_changes.lock().unwrap().push(RecordedRoleChange {
+            guild_id,
+            user_id,
+            role_id,
+            added: false,
+        });
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn acknowledge_interaction(
+        &self,
+        interaction_id: InteractionId,
+        token: &str,
+    ) -> Result<(), serenity::Error> {
+        self.interaction_responses.lock().unwrap().push(RecordedInteractionResponse {
+            interaction_id,
+            token: token.to_string(),
+            content: None,
+        });
+        Ok(())
+    }
+
+    #[cfg(feature = "actions")]
+    async fn update_interaction_message(
+        &self,
+        interaction_id: InteractionId,
+        token: &str,
+        content: &str,
+        _allow_mass_mentions: bool,
+    ) -> Result<(), serenity::Error> {
+        self.interaction_responses.lock().unwrap().push(RecordedInteractionResponse {
+            interaction_id,
+            token: token.to_string(),
+            content: Some(content.to_string()),
+        });
+        Ok(())
     }
 
     async fn get_message(
         &self,
         channel_id: ChannelId,
-        _message_id: MessageId,
+        message_id: MessageId,
     ) -> Result<Message, serenity::Error> {
+        self.fetched_messages.lock().unwrap().push(message_id);
+
+        if *self.get_message_error.lock().unwrap() {
+            return Err(serenity::Error::Other("Mock get_message error"));
+        }
+
+        if let Some(message) = self.stored_messages.lock().unwrap().get(&message_id) {
+            return Ok(message.clone());
+        }
+
         // Return a dummy message for testing
         // In real scenarios, this would retrieve from Discord API
         Ok(create_dummy_message(channel_id, "Mock message"))
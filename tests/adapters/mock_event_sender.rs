@@ -1,12 +1,26 @@
-use gatehook::adapters::{EventResponse, EventSender};
+use gatehook::adapters::{Delivery, EventResponse, EventSender};
+use gatehook::error::GatehookError;
 use serde::Serialize;
 use serde_json;
 use serenity::async_trait;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
 
 pub struct MockEventSender {
     pub sent_events: Arc<Mutex<Vec<SentEvent>>>,
     pub response: Option<EventResponse>,
+    pub fail: bool,
+    /// HTTP status reported by `deliver` (`None` for senders without one)
+    pub status: Option<u16>,
+    pub delay: Duration,
+    /// Whether events are waiting to be replayed
+    pub backlog: Arc<AtomicBool>,
+    /// Completed backlog replays
+    pub replays: Arc<AtomicUsize>,
+    /// Backlog replays wait for this notification before completing
+    pub replay_gate: Arc<Notify>,
 }
 
 #[derive(Debug, Clone)]
@@ -27,17 +41,53 @@ impl MockEventSender {
         Self {
             sent_events: Arc::new(Mutex::new(Vec::new())),
             response: None,
+            fail: false,
+            status: None,
+            delay: Duration::ZERO,
+            backlog: Arc::new(AtomicBool::new(false)),
+            replays: Arc::new(AtomicUsize::new(0)),
+            replay_gate: Arc::new(Notify::new()),
+        }
+    }
+
+    /// Create MockEventSender whose sends fail (circuit open)
+    #[allow(dead_code)]
+    pub fn failing() -> Self {
+        Self {
+            fail: true,
+            ..Self::new()
         }
     }
 
     /// Create MockEventSender with pre-configured response (for testing)
     pub fn with_response(response: EventResponse) -> Self {
         Self {
-            sent_events: Arc::new(Mutex::new(Vec::new())),
             response: Some(response),
+            ..Self::new()
         }
     }
 
+    /// Report `status` for every delivery (e.g. 503 for a rejecting endpoint)
+    #[allow(dead_code)]
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Delay every response by `delay` (slow webhook)
+    #[allow(dead_code)]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Report a backlog, replayed once `replay_gate` is notified
+    #[allow(dead_code)]
+    pub fn with_backlog(self) -> Self {
+        self.backlog.store(true, Ordering::SeqCst);
+        self
+    }
+
     pub fn get_sent_events(&self) -> Vec<SentEvent> {
         self.sent_events.lock().unwrap().clone()
     }
@@ -49,12 +99,39 @@ impl EventSender for MockEventSender {
         &self,
         handler: &str,
         payload: &T,
-    ) -> anyhow::Result<Option<EventResponse>> {
+    ) -> Result<Option<EventResponse>, GatehookError> {
         let payload_json = serde_json::to_string(payload)?;
         self.sent_events.lock().unwrap().push(SentEvent {
             handler: handler.to_string(),
             payload: payload_json,
         });
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+        if self.fail {
+            return Err(GatehookError::CircuitOpen);
+        }
         Ok(self.response.clone())
     }
+
+    async fn deliver<T: Serialize + Send + Sync>(
+        &self,
+        handler: &str,
+        payload: &T,
+    ) -> Result<Delivery, GatehookError> {
+        Ok(Delivery {
+            status: self.status,
+            response: self.send(handler, payload).await?,
+        })
+    }
+
+    fn has_backlog(&self) -> bool {
+        self.backlog.load(Ordering::SeqCst)
+    }
+
+    async fn replay_backlog(&self) {
+        self.replay_gate.notified().await;
+        self.backlog.store(false, Ordering::SeqCst);
+        self.replays.fetch_add(1, Ordering::SeqCst);
+    }
 }
@@ -9,6 +9,7 @@ pub struct MockReactionBuilder {
     emoji: ReactionType,
     guild_id: Option<GuildId>,
     member: Option<Member>,
+    message_author_id: Option<UserId>,
     message_id: MessageId,
     user_id: Option<UserId>,
 }
@@ -20,6 +21,7 @@ impl MockReactionBuilder {
             emoji: ReactionType::Unicode("👍".to_string()),
             guild_id: None,
             member: None,
+            message_author_id: None,
             message_id: MessageId::new(message_id),
             user_id: None,
         }
@@ -35,6 +37,13 @@ impl MockReactionBuilder {
         self
     }
 
+    /// Author of the reacted-to message (defaults to the reacting user)
+    #[allow(dead_code)]
+    pub fn message_author(mut self, message_author_id: u64) -> Self {
+        self.message_author_id = Some(UserId::new(message_author_id));
+        self
+    }
+
     pub fn guild(mut self, guild_id: u64, user_id: u64) -> Self {
         self.guild_id = Some(GuildId::new(guild_id));
 
@@ -95,7 +104,7 @@ impl MockReactionBuilder {
             "me_burst": false,
             "me": false,
             "burst": false,
-            "message_author_id": self.user_id.map(|id| id.to_string())
+            "message_author_id": self.message_author_id.or(self.user_id).map(|id| id.to_string())
         });
 
         serde_json::from_value(json).expect("Failed to deserialize mock Reaction")
@@ -0,0 +1,353 @@
+// Integration tests for the admin endpoint
+// These tests POST action requests over HTTP and verify the resulting Discord calls,
+// and toggle the channel denylist (served with or without the `actions` feature)
+
+// Only part of the mock set is used here
+#[allow(dead_code, unused_imports)]
+mod adapters;
+
+use adapters::{MockChannelInfoProvider, MockDiscordService, MockEventSender};
+use gatehook::admin_server::AdminServer;
+use gatehook::bridge::event_bridge::EventBridge;
+use rstest::rstest;
+use serenity::model::channel::Message;
+use serenity::model::id::{ChannelId, GuildId, MessageId};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+// Helper function to start an admin server on an ephemeral port
+#[cfg(feature = "actions")]
+async fn start_admin_server(discord_service: Arc<MockDiscordService>) -> SocketAddr {
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = Arc::new(EventBridge::new(discord_service, event_sender, channel_info, 5));
+
+    let server = AdminServer::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .await
+        .unwrap();
+    let addr = server.local_addr().unwrap();
+    tokio::spawn(server.serve(bridge));
+    addr
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_admin_post_react_action() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let addr = start_admin_server(discord_service.clone()).await;
+
+    // Execute
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/actions"))
+        .json(&serde_json::json!({
+            "channel_id": "222",
+            "message_id": "111",
+            "guild_id": "333",
+            "actions": [{"type": "react", "emoji": "👍"}]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    // Verify
+    assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+
+    let reactions = discord_service.get_reactions();
+    assert_eq!(reactions.len(), 1, "Should add one reaction");
+    assert_eq!(reactions[0].channel_id, ChannelId::new(222));
+    assert_eq!(reactions[0].message_id, MessageId::new(111));
+    assert_eq!(reactions[0].emoji, "👍");
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_admin_post_reply_action() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let addr = start_admin_server(discord_service.clone()).await;
+
+    // Execute
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/actions"))
+        .json(&serde_json::json!({
+            "channel_id": "222",
+            "message_id": "111",
+            "actions": [{"type": "reply", "content": "From control plane"}]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    // Verify
+    assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+
+    let replies = discord_service.get_replies();
+    assert_eq!(replies.len(), 1, "Should send one reply");
+    assert_eq!(replies[0].channel_id, ChannelId::new(222));
+    assert_eq!(replies[0].message_id, MessageId::new(111));
+    assert_eq!(replies[0].content, "From control plane");
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_admin_post_invalid_body() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let addr = start_admin_server(discord_service.clone()).await;
+
+    // Execute: missing target IDs
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/actions"))
+        .json(&serde_json::json!({
+            "actions": [{"type": "react", "emoji": "👍"}]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    // Verify
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+    assert_eq!(discord_service.get_reactions().len(), 0, "Should NOT execute actions");
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_admin_unknown_path_and_method() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let addr = start_admin_server(discord_service).await;
+    let client = reqwest::Client::new();
+
+    // Execute & Verify
+    let response = client.post(format!("http://{addr}/other")).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+
+    let response = client.get(format!("http://{addr}/actions")).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_admin_post_requires_json_content_type() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let addr = start_admin_server(discord_service.clone()).await;
+
+    // Execute: a JSON body sent as a form, as a cross-origin browser form would
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/actions"))
+        .header("content-type", "text/plain")
+        .body(r#"{"channel_id": "222", "message_id": "111", "actions": [{"type": "react", "emoji": "👍"}]}"#)
+        .send()
+        .await
+        .unwrap();
+
+    // Verify
+    assert_eq!(response.status(), reqwest::StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    assert_eq!(discord_service.get_reactions().len(), 0, "Should NOT execute actions");
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::valid_token(Some("Bearer s3cret"), reqwest::StatusCode::NO_CONTENT)]
+#[case::wrong_token(Some("Bearer guess"), reqwest::StatusCode::UNAUTHORIZED)]
+#[case::missing_token(None, reqwest::StatusCode::UNAUTHORIZED)]
+#[tokio::test]
+async fn test_admin_token_required(#[case] authorization: Option<&str>, #[case] expected: reqwest::StatusCode) {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = Arc::new(EventBridge::new(discord_service.clone(), event_sender, channel_info, 5));
+    let server = AdminServer::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .await
+        .unwrap()
+        .with_token(Some("s3cret".to_string()));
+    let addr = server.local_addr().unwrap();
+    tokio::spawn(server.serve(bridge));
+
+    // Execute
+    let mut request = reqwest::Client::new()
+        .post(format!("http://{addr}/actions"))
+        .json(&serde_json::json!({
+            "channel_id": "222",
+            "message_id": "111",
+            "actions": [{"type": "react", "emoji": "👍"}]
+        }));
+    if let Some(authorization) = authorization {
+        request = request.header("authorization", authorization);
+    }
+    let response = request.send().await.unwrap();
+
+    // Verify
+    assert_eq!(response.status(), expected);
+    let executed = usize::from(expected == reqwest::StatusCode::NO_CONTENT);
+    assert_eq!(discord_service.get_reactions().len(), executed);
+}
+
+// Helper function to start an admin server and return the bridge it controls
+async fn start_admin_server_with_bridge(
+    event_sender: Arc<MockEventSender>,
+) -> (SocketAddr, Arc<EventBridge<MockDiscordService, MockEventSender, MockChannelInfoProvider>>) {
+    let discord_service = Arc::new(MockDiscordService::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = Arc::new(EventBridge::new(discord_service, event_sender, channel_info, 5));
+
+    let server = AdminServer::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .await
+        .unwrap();
+    let addr = server.local_addr().unwrap();
+    tokio::spawn(server.serve(bridge.clone()));
+    (addr, bridge)
+}
+
+fn create_channel_message(channel_id: u64) -> Message {
+    let mut message = Message::default();
+    message.id = MessageId::new(111);
+    message.channel_id = ChannelId::new(channel_id);
+    message.guild_id = Some(GuildId::new(333));
+    message
+}
+
+#[tokio::test]
+async fn test_admin_channel_denylist_toggles_forwarding() {
+    // Setup
+    let event_sender = Arc::new(MockEventSender::new());
+    let (addr, bridge) = start_admin_server_with_bridge(event_sender.clone()).await;
+    let client = reqwest::Client::new();
+    let denylist_url = format!("http://{addr}/channels/denylist/222");
+
+    // Execute: deny the channel
+    let response = client.put(&denylist_url).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+    bridge.handle_message(&create_channel_message(222)).await.unwrap();
+    bridge.handle_message(&create_channel_message(444)).await.unwrap();
+
+    // Verify: only the other channel is forwarded
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1);
+    assert!(sent_events[0].payload.contains("\"channel_id\":\"444\""));
+
+    // Execute: allow the channel again
+    let response = client.delete(&denylist_url).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+    bridge.handle_message(&create_channel_message(222)).await.unwrap();
+
+    // Verify
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 2);
+    assert!(sent_events[1].payload.contains("\"channel_id\":\"222\""));
+}
+
+#[tokio::test]
+async fn test_admin_channel_denylist_list() {
+    // Setup
+    let (addr, _bridge) = start_admin_server_with_bridge(Arc::new(MockEventSender::new())).await;
+    let client = reqwest::Client::new();
+    for channel_id in [444, 222] {
+        let response = client
+            .put(format!("http://{addr}/channels/denylist/{channel_id}"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), reqwest::StatusCode::NO_CONTENT);
+    }
+
+    // Execute
+    let response = client
+        .get(format!("http://{addr}/channels/denylist"))
+        .send()
+        .await
+        .unwrap();
+
+    // Verify
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+    let body: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(body, serde_json::json!({"channel_ids": ["222", "444"]}));
+}
+
+#[rstest]
+#[case::not_a_number("abc")]
+#[case::zero("0")]
+#[case::nested("222/extra")]
+#[tokio::test]
+async fn test_admin_channel_denylist_invalid_id(#[case] channel_id: &str) {
+    // Setup
+    let (addr, bridge) = start_admin_server_with_bridge(Arc::new(MockEventSender::new())).await;
+
+    // Execute
+    let response = reqwest::Client::new()
+        .put(format!("http://{addr}/channels/denylist/{channel_id}"))
+        .send()
+        .await
+        .unwrap();
+
+    // Verify
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+    assert!(bridge.channel_denylist().channels().is_empty());
+}
+
+#[tokio::test]
+async fn test_admin_channel_denylist_unsupported_method() {
+    // Setup
+    let (addr, _bridge) = start_admin_server_with_bridge(Arc::new(MockEventSender::new())).await;
+    let client = reqwest::Client::new();
+
+    // Execute & Verify
+    let response = client.post(format!("http://{addr}/channels/denylist/222")).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::METHOD_NOT_ALLOWED);
+
+    let response = client.delete(format!("http://{addr}/channels/denylist")).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[cfg(not(feature = "actions"))]
+#[tokio::test]
+async fn test_admin_actions_not_served_without_actions_feature() {
+    // Setup
+    let (addr, _bridge) = start_admin_server_with_bridge(Arc::new(MockEventSender::new())).await;
+
+    // Execute
+    let response = reqwest::Client::new()
+        .post(format!("http://{addr}/actions"))
+        .json(&serde_json::json!({"channel_id": "222", "message_id": "111", "actions": []}))
+        .send()
+        .await
+        .unwrap();
+
+    // Verify
+    assert_eq!(response.status(), reqwest::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_admin_server_stops_on_drain() {
+    use std::time::Duration;
+
+    // Setup: the server runs as a bridge service, with a connection kept alive
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = Arc::new(EventBridge::new(discord_service, event_sender, channel_info, 5));
+    let server = AdminServer::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .await
+        .unwrap();
+    let addr = server.local_addr().unwrap();
+    let server_bridge = bridge.clone();
+    let spawned = bridge.spawn_service("admin_server", move |shutdown| async move {
+        server.with_shutdown(shutdown).serve(server_bridge).await.unwrap();
+    });
+    assert!(spawned);
+
+    let client = reqwest::Client::new();
+    let denylist_url = format!("http://{addr}/channels/denylist");
+    let response = client.get(&denylist_url).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::OK);
+
+    // Execute
+    let drained = bridge.drain_tasks(Duration::from_secs(5)).await;
+
+    // Verify: the listener and the idle connection are closed
+    assert!(drained);
+    assert!(client.get(&denylist_url).send().await.is_err());
+}
@@ -1,14 +1,25 @@
 // Unit tests for EventBridge business logic
 // These tests verify that events are correctly processed and forwarded
 
+// Action-recording mocks are unused in forward-only builds
+#[cfg_attr(not(feature = "actions"), allow(dead_code))]
 mod adapters;
 
 use adapters::{MockChannelInfoProvider, MockDiscordService, MockEventSender, MockReactionBuilder};
+#[cfg(feature = "actions")]
 use gatehook::adapters::{ReactParams, ReplyParams, ThreadParams};
+#[cfg(feature = "actions")]
+use gatehook::adapters::event_response::ChannelKind;
 use gatehook::bridge::event_bridge::EventBridge;
+#[cfg(feature = "actions")]
+use gatehook::bridge::action_rate_limiter::ThreadLimitPolicy;
+#[cfg(feature = "actions")]
+use gatehook::bridge::action_target::ReactionReplyMode;
 use rstest::rstest;
 use serenity::model::channel::Message;
-use serenity::model::id::{ChannelId, GuildId, MessageId};
+#[cfg(feature = "actions")]
+use serenity::model::channel::ChannelType;
+use serenity::model::id::{ChannelId, GuildId, MessageId, ShardId};
 use serenity::model::user::User;
 use std::sync::Arc;
 
@@ -29,10 +40,50 @@ fn create_guild_message(content: &str, message_id: u64, channel_id: u64, guild_i
     message
 }
 
+fn create_component_interaction(
+    custom_id: &str,
+    message_id: u64,
+    channel_id: u64,
+) -> serenity::model::application::ComponentInteraction {
+    let author = serde_json::json!({"id": "999", "username": "bot", "discriminator": "0000", "avatar": null});
+    serde_json::from_value(serde_json::json!({
+        "id": "4444",
+        "application_id": "5555",
+        "type": 3,
+        "data": {"custom_id": custom_id, "component_type": 2},
+        "guild_id": "333",
+        "channel_id": channel_id.to_string(),
+        "user": {"id": "7777", "username": "clicker", "discriminator": "0000", "avatar": null},
+        "token": "interaction-token",
+        "version": 1,
+        "message": {
+            "id": message_id.to_string(),
+            "channel_id": channel_id.to_string(),
+            "author": author,
+            "content": "Approve?",
+            "timestamp": "2024-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0
+        },
+        "locale": "en-US",
+        "entitlements": [],
+        "authorizing_integration_owners": {}
+    }))
+    .unwrap()
+}
+
 // Note: test_handle_ready is skipped because Ready doesn't implement Default
 // and creating a valid Ready instance requires extensive setup.
 // The ready event forwarding is tested through integration testing instead.
 
+#[cfg(feature = "actions")]
 #[rstest]
 #[case::without_mention("Reply from webhook", false)]
 #[case::with_mention("Reply with mention", true)]
@@ -56,7 +107,11 @@ async fn test_execute_actions_reply(
         actions: vec![ResponseAction::Reply(ReplyParams {
             content: expected_content.to_string(),
             mention,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
         })],
+        ..Default::default()
     };
 
     // Execute
@@ -73,6 +128,7 @@ async fn test_execute_actions_reply(
     assert_eq!(replies[0].mention, mention);
 }
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_multiple_replies() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -91,12 +147,19 @@ async fn test_execute_actions_multiple_replies() {
             ResponseAction::Reply(ReplyParams {
                 content: "First reply".to_string(),
                 mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
             }),
             ResponseAction::Reply(ReplyParams {
                 content: "Second reply".to_string(),
                 mention: true,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
             }),
         ],
+        ..Default::default()
     };
 
     // Execute
@@ -112,6 +175,7 @@ async fn test_execute_actions_multiple_replies() {
     assert!(replies[1].mention);
 }
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_long_content_truncated() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -131,7 +195,11 @@ async fn test_execute_actions_long_content_truncated() {
         actions: vec![ResponseAction::Reply(ReplyParams {
             content: long_content,
             mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
         })],
+        ..Default::default()
     };
 
     // Execute
@@ -146,6 +214,212 @@ async fn test_execute_actions_long_content_truncated() {
     assert!(replies[0].content.ends_with("..."));
 }
 
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::custom(Some(" [cut]"), 1994)]
+#[case::empty(Some(""), 2000)]
+#[case::default(None, 1997)]
+#[tokio::test]
+async fn test_execute_actions_truncation_marker(#[case] marker: Option<&str>, #[case] kept_chars: usize) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_truncation_marker(marker.map(str::to_string));
+
+    let message = create_test_message("Test", 777, 888);
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Reply(ReplyParams {
+            content: "a".repeat(2100),
+            mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    let replies = discord_service.get_replies();
+    assert_eq!(replies[0].content.chars().count(), 2000);
+    assert_eq!(
+        replies[0].content,
+        format!("{}{}", "a".repeat(kept_chars), marker.unwrap_or("..."))
+    );
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::short_content(1999, false)]
+#[case::at_limit(2000, false)]
+#[case::long_content(2100, true)]
+#[tokio::test]
+async fn test_execute_actions_reply_as_file(#[case] content_len: usize, #[case] uploaded: bool) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_test_message("Test", 777, 888);
+    let content = "a".repeat(content_len);
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Reply(ReplyParams {
+            content: content.clone(),
+            mention: true,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: true,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: over-length content is uploaded in full instead of truncated
+    assert!(result.is_ok());
+    let replies = discord_service.get_replies();
+    let file_replies = discord_service.get_file_replies();
+    if uploaded {
+        assert!(replies.is_empty());
+        assert_eq!(file_replies.len(), 1);
+        assert_eq!(file_replies[0].channel_id, ChannelId::new(888));
+        assert_eq!(file_replies[0].message_id, MessageId::new(777));
+        assert_eq!(file_replies[0].filename, "reply.txt");
+        assert_eq!(file_replies[0].data, content.into_bytes());
+        assert!(file_replies[0].mention);
+    } else {
+        assert!(file_replies.is_empty());
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0].content, content);
+    }
+}
+
+#[rstest]
+#[case::other_handler_only(None, true)]
+#[case::deadline_exceeded(Some(500), false)]
+#[case::deadline_met(Some(2000), true)]
+#[tokio::test(start_paused = true)]
+async fn test_handle_message_webhook_deadline(#[case] deadline_ms: Option<u64>, #[case] expect_response: bool) {
+    use gatehook::adapters::EventResponse;
+    use std::time::Duration;
+
+    // Setup: the webhook takes 1s to respond; reaction_add always has a short deadline
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(
+        MockEventSender::with_response(EventResponse::default()).with_delay(Duration::from_secs(1)),
+    );
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let mut bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_webhook_deadline("reaction_add", Duration::from_millis(100));
+    if let Some(deadline_ms) = deadline_ms {
+        bridge = bridge.with_webhook_deadline("message", Duration::from_millis(deadline_ms));
+    }
+
+    let message = create_test_message("Hello", 999, 1000);
+
+    // Execute
+    let response = bridge.handle_message(&message).await.unwrap();
+
+    // Verify: the event is sent either way; only a late response is dropped
+    assert_eq!(response.is_some(), expect_response);
+    assert_eq!(event_sender.get_sent_events().len(), 1);
+}
+
+#[tokio::test]
+async fn test_backlog_replay_runs_in_background() {
+    use gatehook::adapters::EventResponse;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    // Setup: the sender has a backlog whose replay blocks until released
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::with_response(EventResponse::default()).with_backlog());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    let message = create_test_message("Hello", 999, 1000);
+
+    // Execute: the live response does not wait for the replay
+    let response = tokio::time::timeout(Duration::from_secs(5), bridge.handle_message(&message))
+        .await
+        .expect("live delivery blocked on backlog replay")
+        .unwrap();
+
+    // Verify
+    assert!(response.is_some());
+    assert_eq!(event_sender.replays.load(Ordering::SeqCst), 0);
+
+    event_sender.replay_gate.notify_one();
+    assert!(bridge.drain_tasks(Duration::from_secs(5)).await);
+    assert_eq!(event_sender.replays.load(Ordering::SeqCst), 1);
+    assert!(!event_sender.backlog.load(Ordering::SeqCst));
+}
+
+#[rstest]
+#[case::intent_active(true, "", true)]
+#[case::content_withheld(false, "", false)]
+#[case::content_present(false, "Hello", true)]
+#[tokio::test]
+async fn test_handle_message_content_available(
+    #[case] content_intent: bool,
+    #[case] content: &str,
+    #[case] expected: bool,
+) {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_message_content_intent(content_intent, serenity::model::id::UserId::new(999));
+
+    let message = create_guild_message(content, 111, 222, 333);
+
+    // Execute
+    bridge.handle_message(&message).await.unwrap();
+
+    // Verify
+    let sent_events = event_sender.get_sent_events();
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(payload["content_available"], expected);
+}
+
+#[tokio::test]
+async fn test_handle_message_redacted_fields() {
+    // Setup: redact the author's username of message payloads only
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_redacted_fields("message", vec!["message.author.username".to_string()])
+        .with_redacted_fields("reaction_add", vec!["message.content".to_string()]);
+
+    let message = create_test_message("Hello", 999, 1000);
+
+    // Execute
+    bridge.handle_message(&message).await.unwrap();
+
+    // Verify: only the configured field is nulled
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1);
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(payload["message"]["author"]["username"], serde_json::Value::Null);
+    assert_eq!(payload["message"]["author"]["id"], message.author.id.to_string());
+    assert_eq!(payload["message"]["content"], "Hello");
+    assert!(payload["schema_version"].is_u64());
+}
+
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_handle_message_with_webhook_response() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -156,7 +430,11 @@ async fn test_handle_message_with_webhook_response() {
         actions: vec![ResponseAction::Reply(ReplyParams {
             content: "Webhook responded!".to_string(),
             mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
         })],
+        ..Default::default()
     };
     let event_sender = Arc::new(MockEventSender::with_response(event_response));
     let channel_info = Arc::new(MockChannelInfoProvider::new());
@@ -183,6 +461,7 @@ async fn test_handle_message_with_webhook_response() {
     );
 }
 
+#[cfg(feature = "actions")]
 #[rstest]
 #[case::unicode_emoji("👍")]
 #[case::custom_emoji("customemoji:123456789")]
@@ -202,7 +481,10 @@ async fn test_execute_actions_react(#[case] emoji: &str) {
     let event_response = EventResponse {
         actions: vec![ResponseAction::React(ReactParams {
             emoji: emoji.to_string(),
+            message_id: None,
+            channel_id: None,
         })],
+        ..Default::default()
     };
 
     // Execute
@@ -218,6 +500,7 @@ async fn test_execute_actions_react(#[case] emoji: &str) {
     assert_eq!(reactions[0].channel_id, ChannelId::new(222));
 }
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_thread_create_new() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -237,7 +520,14 @@ async fn test_execute_actions_thread_create_new() {
             name: Some("Discussion".to_string()),
             content: "Let's discuss".to_string(),
             auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
         })],
+        ..Default::default()
     };
 
     // Execute
@@ -249,7 +539,7 @@ async fn test_execute_actions_thread_create_new() {
     let threads = discord_service.get_threads();
     assert_eq!(threads.len(), 1, "Should create one thread");
     assert_eq!(threads[0].channel_id, ChannelId::new(222));
-    assert_eq!(threads[0].message_id, MessageId::new(111));
+    assert_eq!(threads[0].message_id, Some(MessageId::new(111)));
     assert_eq!(threads[0].name, "Discussion");
     assert_eq!(threads[0].auto_archive_duration, 1440);
 
@@ -260,6 +550,7 @@ async fn test_execute_actions_thread_create_new() {
     assert_eq!(messages[0].reply_to, None);
 }
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_thread_auto_name() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -279,7 +570,14 @@ async fn test_execute_actions_thread_auto_name() {
             name: None,
             content: "Response".to_string(),
             auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
         })],
+        ..Default::default()
     };
 
     // Execute
@@ -295,6 +593,7 @@ async fn test_execute_actions_thread_auto_name() {
     assert_eq!(threads[0].name, "Thread");
 }
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_thread_long_name() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -315,7 +614,14 @@ async fn test_execute_actions_thread_long_name() {
             name: Some(long_name),
             content: "Response".to_string(),
             auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
         })],
+        ..Default::default()
     };
 
     // Execute
@@ -331,6 +637,7 @@ async fn test_execute_actions_thread_long_name() {
     assert_eq!(threads[0].name, "a".repeat(100));
 }
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_thread_already_in_thread() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -350,7 +657,14 @@ async fn test_execute_actions_thread_already_in_thread() {
             name: Some("Ignored".to_string()),
             content: "Reply in thread".to_string(),
             auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
         })],
+        ..Default::default()
     };
 
     // Execute
@@ -368,6 +682,7 @@ async fn test_execute_actions_thread_already_in_thread() {
     assert_eq!(messages[0].channel_id, ChannelId::new(222));
 }
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_thread_create_with_custom_duration() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -387,7 +702,14 @@ async fn test_execute_actions_thread_create_with_custom_duration() {
             name: Some("Support".to_string()),
             content: "Help needed".to_string(),
             auto_archive_duration: 60,
+            message_id: None,
+            channel_id: None,
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
         })],
+        ..Default::default()
     };
 
     // Execute
@@ -409,6 +731,7 @@ async fn test_execute_actions_thread_create_with_custom_duration() {
 }
 
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_thread_in_dm_fails() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -429,7 +752,14 @@ async fn test_execute_actions_thread_in_dm_fails() {
             name: Some("Thread".to_string()),
             content: "Content".to_string(),
             auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
         })],
+        ..Default::default()
     };
 
     // Execute (should complete but log error)
@@ -443,6 +773,7 @@ async fn test_execute_actions_thread_in_dm_fails() {
     assert_eq!(threads.len(), 0, "Should NOT create thread in DM");
 }
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_mixed_types() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -462,16 +793,28 @@ async fn test_execute_actions_mixed_types() {
             ResponseAction::Reply(ReplyParams {
                 content: "Reply message".to_string(),
                 mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
             }),
             ResponseAction::React(ReactParams {
                 emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
             }),
             ResponseAction::Thread(ThreadParams {
                 name: Some("Discussion".to_string()),
                 content: "Thread content".to_string(),
                 auto_archive_duration: 1440,
+                message_id: None,
+                channel_id: None,
+                private: false,
+                allow_mass_mentions: false,
+                invitable: None,
+                rate_limit_per_user: None,
             }),
         ],
+        ..Default::default()
     };
 
     // Execute
@@ -536,6 +879,89 @@ async fn test_handle_message_with_channel_info() {
     );
 }
 
+#[rstest]
+#[case::cached_guild(Some(5000), 5000, Some("10"), Some("11"))]
+#[case::guild_cache_miss(Some(5000), 7000, None, None)]
+#[case::direct_message(None, 5000, None, None)]
+#[tokio::test]
+async fn test_handle_message_guild_channel_ids(
+    #[case] guild_id: Option<u64>,
+    #[case] cached_guild_id: u64,
+    #[case] expected_system: Option<&str>,
+    #[case] expected_rules: Option<&str>,
+) {
+    use gatehook::adapters::GuildInfo;
+
+    // Setup: a cached guild with system and rules channels
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    channel_info.set_guild(
+        GuildId::new(cached_guild_id),
+        GuildInfo {
+            system_channel_id: Some(ChannelId::new(10)),
+            rules_channel_id: Some(ChannelId::new(11)),
+            ..Default::default()
+        },
+    );
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    let message = match guild_id {
+        Some(guild_id) => create_guild_message("Hello", 999, 1000, guild_id),
+        None => create_test_message("Hello", 999, 1000),
+    };
+
+    // Execute
+    bridge.handle_message(&message).await.unwrap();
+
+    // Verify: the ids are present only for cached guilds
+    let payload: serde_json::Value = serde_json::from_str(&event_sender.get_sent_events()[0].payload).unwrap();
+    assert_eq!(payload.get("guild_system_channel_id").and_then(|id| id.as_str()), expected_system);
+    assert_eq!(payload.get("guild_rules_channel_id").and_then(|id| id.as_str()), expected_rules);
+}
+
+#[rstest]
+#[case::enabled(true, 5000, Some(serde_json::json!(["moderator", "2"])))]
+#[case::disabled(false, 5000, None)]
+#[case::guild_cache_miss(true, 7000, None)]
+#[tokio::test]
+async fn test_handle_message_member_role_names(
+    #[case] include_role_names: bool,
+    #[case] cached_guild_id: u64,
+    #[case] expected: Option<serde_json::Value>,
+) {
+    use gatehook::adapters::GuildInfo;
+    use serenity::model::guild::PartialMember;
+    use serenity::model::id::RoleId;
+
+    // Setup: a cached guild knowing only role 1
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    channel_info.set_guild(
+        GuildId::new(cached_guild_id),
+        GuildInfo {
+            role_names: [(RoleId::new(1), "moderator".to_string())].into(),
+            ..Default::default()
+        },
+    );
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_include_role_names(include_role_names);
+
+    let mut message = create_guild_message("Hello", 999, 1000, 5000);
+    let member: PartialMember =
+        serde_json::from_value(serde_json::json!({"roles": ["1", "2"], "deaf": false, "mute": false})).unwrap();
+    message.member = Some(Box::new(member));
+
+    // Execute
+    bridge.handle_message(&message).await.unwrap();
+
+    // Verify: names resolve from the cache, unknown roles fall back to their id
+    let payload: serde_json::Value = serde_json::from_str(&event_sender.get_sent_events()[0].payload).unwrap();
+    assert_eq!(payload.get("member_role_names").cloned(), expected);
+    assert_eq!(payload["message"]["member"]["roles"], serde_json::json!(["1", "2"]));
+}
+
 #[tokio::test]
 async fn test_handle_message_without_channel_info() {
     // Setup: MockChannelInfoProvider without pre-configured channel (simulates cache miss + API failure)
@@ -771,6 +1197,60 @@ async fn test_handle_reaction_add_with_channel_info() {
     );
 }
 
+#[tokio::test]
+async fn test_handle_reaction_add_guild_channel_ids() {
+    use gatehook::adapters::GuildInfo;
+
+    // Setup: a cached guild with only a system channel
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    channel_info.set_guild(
+        GuildId::new(6000),
+        GuildInfo {
+            system_channel_id: Some(ChannelId::new(10)),
+            ..Default::default()
+        },
+    );
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    let reaction = MockReactionBuilder::new(2222, 2000)
+        .emoji("👍")
+        .guild(6000, 1111)
+        .build();
+
+    // Execute
+    bridge.handle_reaction_add(&reaction).await.unwrap();
+
+    // Verify
+    let payload: serde_json::Value = serde_json::from_str(&event_sender.get_sent_events()[0].payload).unwrap();
+    assert_eq!(payload["guild_system_channel_id"], "10");
+    assert!(payload.get("guild_rules_channel_id").is_none());
+}
+
+#[rstest]
+#[case::on_bot_message(MockReactionBuilder::new(2222, 2000).guild(6000, 1111).message_author(9999), true)]
+#[case::on_other_message(MockReactionBuilder::new(2222, 2000).guild(6000, 1111).message_author(3333), false)]
+#[case::dm_unaffected(MockReactionBuilder::new(2222, 2000).user_id(1111).message_author(3333), true)]
+#[tokio::test]
+async fn test_handle_reaction_add_own_messages_only(#[case] reaction: MockReactionBuilder, #[case] forwarded: bool) {
+    use serenity::model::id::UserId;
+
+    // Setup: the bot is user 9999
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_reaction_own_messages_only(Some(UserId::new(9999)));
+
+    // Execute
+    let result = bridge.handle_reaction_add(&reaction.build()).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert_eq!(event_sender.get_sent_events().len(), usize::from(forwarded));
+}
+
 #[tokio::test]
 async fn test_handle_reaction_add_without_channel_info() {
     // Setup: MockChannelInfoProvider without pre-configured channel (simulates cache miss + API failure)
@@ -980,6 +1460,7 @@ async fn test_handle_reaction_remove_dm() {
     );
 }
 
+#[cfg(feature = "actions")]
 #[tokio::test]
 async fn test_execute_actions_from_reaction() {
     use gatehook::adapters::{EventResponse, ResponseAction};
@@ -1001,11 +1482,17 @@ async fn test_execute_actions_from_reaction() {
             ResponseAction::Reply(ReplyParams {
                 content: "Thanks for the reaction!".to_string(),
                 mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
             }),
             ResponseAction::React(ReactParams {
                 emoji: "✅".to_string(),
+                message_id: None,
+                channel_id: None,
             }),
         ],
+        ..Default::default()
     };
 
     // Execute actions from reaction event
@@ -1037,3 +1524,4133 @@ async fn test_execute_actions_from_reaction() {
 // 3. Posting to the existing thread instead of creating a new one
 //
 // Manual testing on Discord is recommended to verify this behavior.
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_reply_delete_original() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5);
+
+    let message = create_guild_message("Offending message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Reply(ReplyParams {
+            content: "Removed: rule violation".to_string(),
+            mention: true,
+            delete_original: true,
+            allow_mass_mentions: false,
+            as_file: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let replies = discord_service.get_replies();
+    assert_eq!(replies.len(), 1, "Should send one reply");
+    assert_eq!(replies[0].content, "Removed: rule violation");
+
+    let deletions = discord_service.get_deletions();
+    assert_eq!(deletions.len(), 1, "Should delete the original message");
+    assert_eq!(deletions[0].message_id, MessageId::new(111));
+    assert_eq!(deletions[0].channel_id, ChannelId::new(222));
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_reply_delete_original_failure_continues() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup: deletion fails (e.g. missing MANAGE_MESSAGES permission)
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_delete_error(true);
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5);
+
+    let message = create_guild_message("Offending message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::Reply(ReplyParams {
+                content: "Removed".to_string(),
+                mention: false,
+                delete_original: true,
+                allow_mass_mentions: false,
+                as_file: false,
+            }),
+            ResponseAction::React(ReactParams {
+                emoji: "🚫".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: reply sent, deletion failed, chain continued
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_replies().len(), 1, "Reply should still be sent");
+    assert_eq!(discord_service.get_deletions().len(), 0, "Deletion should not be recorded");
+    assert_eq!(
+        discord_service.get_reactions().len(),
+        1,
+        "Subsequent actions should still execute"
+    );
+}
+
+#[tokio::test]
+async fn test_handle_message_with_mentions() {
+    use serenity::model::id::{RoleId, UserId};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_include_mentions(true);
+
+    let mut mentioned = User::default();
+    mentioned.id = UserId::new(4242);
+    mentioned.name = "mentioned_user".to_string();
+
+    let mut message = create_guild_message("Hello <@4242> <@&5151>", 111, 222, 333);
+    message.mentions = vec![mentioned];
+    message.mention_roles = vec![RoleId::new(5151)];
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    let json_value: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(json_value["mentions"][0]["id"], "4242");
+    assert_eq!(json_value["mentions"][0]["name"], "mentioned_user");
+    assert_eq!(json_value["mention_roles"][0], "5151");
+    assert!(
+        json_value.get("mention_channels").is_none(),
+        "Empty mention list should be omitted"
+    );
+}
+
+#[tokio::test]
+async fn test_handle_thread_create() {
+    use serenity::model::channel::{ChannelType, GuildChannel};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    let mut thread = GuildChannel::default();
+    thread.id = ChannelId::new(5555);
+    thread.guild_id = GuildId::new(1234);
+    thread.parent_id = Some(ChannelId::new(4444));
+    thread.name = "New thread".to_string();
+    thread.kind = ChannelType::PublicThread;
+
+    // Execute
+    let result = bridge.handle_thread_create(&thread).await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1, "Should send one event to webhook");
+    assert_eq!(sent_events[0].handler, "thread_create");
+
+    let json_value: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(json_value["thread_create"]["id"], "5555");
+    assert_eq!(json_value["thread_create"]["parent_id"], "4444");
+    assert_eq!(json_value["thread_create"]["name"], "New thread");
+}
+
+#[tokio::test]
+async fn test_handle_thread_delete() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    // Execute
+    let result = bridge
+        .handle_thread_delete(ChannelId::new(5555), GuildId::new(1234), ChannelId::new(4444))
+        .await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1, "Should send one event to webhook");
+    assert_eq!(sent_events[0].handler, "thread_delete");
+
+    let json_value: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(json_value["thread_delete"]["id"], "5555");
+    assert_eq!(json_value["thread_delete"]["guild_id"], "1234");
+    assert_eq!(json_value["thread_delete"]["parent_id"], "4444");
+}
+
+#[tokio::test]
+async fn test_handle_reaction_add_include_message() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5)
+        .with_reaction_include_message(true);
+
+    let reaction = MockReactionBuilder::new(8888, 9999)
+        .guild(1234, 7777)
+        .build();
+
+    // Execute
+    let result = bridge.handle_reaction_add(&reaction).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert_eq!(
+        discord_service.get_fetched_messages(),
+        vec![MessageId::new(8888)],
+        "Should fetch the reacted message exactly once"
+    );
+
+    let sent_events = event_sender.get_sent_events();
+    let json_value: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(json_value["message"]["content"], "Mock message");
+    assert_eq!(json_value["message"]["channel_id"], "9999");
+}
+
+#[tokio::test]
+async fn test_handle_reaction_add_include_message_fetch_error() {
+    // Setup: message fetch fails
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_get_message_error(true);
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5)
+        .with_reaction_include_message(true);
+
+    let reaction = MockReactionBuilder::new(8888, 9999)
+        .guild(1234, 7777)
+        .build();
+
+    // Execute
+    let result = bridge.handle_reaction_add(&reaction).await;
+
+    // Verify: event still forwarded, message omitted
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1, "Should still send event to webhook");
+    let json_value: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert!(json_value.get("reaction").is_some());
+    assert!(
+        json_value.get("message").is_none(),
+        "Message should be omitted when fetch fails"
+    );
+}
+
+#[tokio::test]
+async fn test_handle_reaction_add_does_not_fetch_message_by_default() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5);
+
+    let reaction = MockReactionBuilder::new(8888, 9999).user_id(7777).build();
+
+    // Execute
+    let result = bridge.handle_reaction_add(&reaction).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert!(discord_service.get_fetched_messages().is_empty());
+}
+
+#[cfg(not(feature = "actions"))]
+#[tokio::test]
+async fn test_execute_actions_ignored_without_actions_feature() {
+    use gatehook::adapters::event_response::{EventResponse, ReplyParams, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_test_message("Hello", 123, 456);
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Reply(ReplyParams {
+            content: "Ignored".to_string(),
+            mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: forward-only build logs and ignores actions
+    assert!(result.is_ok());
+    assert!(discord_service.get_replies().is_empty());
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_thread_in_dm_skipped() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup: no is_thread error configured, so only the DM guard prevents creation
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5);
+
+    let message = create_test_message("DM message", 111, 222); // No guild_id
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Thread(ThreadParams {
+            name: Some("Thread".to_string()),
+            content: "Content".to_string(),
+            auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: skipped before any Discord call
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_threads().len(), 0, "Should NOT create thread in DM");
+    assert_eq!(discord_service.get_messages().len(), 0, "Should NOT post to any channel");
+}
+
+// Helper function to create a test scheduled event
+fn create_test_scheduled_event(event_id: u64, guild_id: u64) -> serenity::model::guild::ScheduledEvent {
+    serde_json::from_value(serde_json::json!({
+        "id": event_id.to_string(),
+        "guild_id": guild_id.to_string(),
+        "channel_id": null,
+        "creator_id": null,
+        "name": "Community call",
+        "description": null,
+        "scheduled_start_time": "2026-01-01T12:00:00Z",
+        "scheduled_end_time": null,
+        "privacy_level": 2,
+        "status": 1,
+        "entity_type": 3,
+        "entity_id": null,
+        "entity_metadata": { "location": "https://example.com/live" },
+        "creator": null,
+        "user_count": null,
+        "image": null
+    }))
+    .unwrap()
+}
+
+#[rstest]
+#[case::create("guild_scheduled_event_create")]
+#[case::update("guild_scheduled_event_update")]
+#[case::delete("guild_scheduled_event_delete")]
+#[tokio::test]
+async fn test_handle_guild_scheduled_event(#[case] handler: &str) {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    let event = create_test_scheduled_event(9999, 1234);
+
+    // Execute
+    let result = match handler {
+        "guild_scheduled_event_create" => bridge.handle_guild_scheduled_event_create(&event).await,
+        "guild_scheduled_event_update" => bridge.handle_guild_scheduled_event_update(&event).await,
+        _ => bridge.handle_guild_scheduled_event_delete(&event).await,
+    };
+
+    // Verify
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1, "Should send one event to webhook");
+    assert_eq!(sent_events[0].handler, handler);
+
+    let json_value: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(json_value["scheduled_event"]["id"], "9999");
+    assert_eq!(json_value["scheduled_event"]["guild_id"], "1234");
+    assert_eq!(json_value["scheduled_event"]["entity_metadata"]["location"], "https://example.com/live");
+}
+
+#[tokio::test]
+async fn test_handle_guild_member_update() {
+    use serenity::model::event::GuildMemberUpdateEvent;
+    use serenity::model::guild::Member;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    let user = serde_json::json!({"id": "555", "username": "alice", "discriminator": "0000", "avatar": null});
+    let event: GuildMemberUpdateEvent = serde_json::from_value(serde_json::json!({
+        "guild_id": "1234",
+        "nick": "Alice",
+        "joined_at": "2024-01-01T00:00:00Z",
+        "roles": ["10", "30"],
+        "user": user,
+        "premium_since": null,
+        "avatar": null,
+        "communication_disabled_until": null,
+        "unusual_dm_activity_until": null
+    }))
+    .unwrap();
+    let old: Member = serde_json::from_value(serde_json::json!({
+        "guild_id": "1234",
+        "nick": null,
+        "joined_at": "2024-01-01T00:00:00Z",
+        "roles": ["10", "20"],
+        "user": user,
+        "deaf": false,
+        "mute": false,
+        "flags": 0
+    }))
+    .unwrap();
+
+    // Execute
+    let result = bridge.handle_guild_member_update(Some(&old), &event).await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1, "Should send one event to webhook");
+    assert_eq!(sent_events[0].handler, "guild_member_update");
+
+    let json_value: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(json_value["guild_id"], "1234");
+    assert_eq!(json_value["user"]["id"], "555");
+    assert!(json_value["before"]["nick"].is_null());
+    assert_eq!(json_value["after"]["nick"], "Alice");
+    assert_eq!(json_value["roles_added"], serde_json::json!(["30"]));
+    assert_eq!(json_value["roles_removed"], serde_json::json!(["20"]));
+}
+
+#[tokio::test]
+async fn test_handle_raw_event() {
+    use gatehook::bridge::raw_event_payload::RawEventPayload;
+    use serenity::model::event::Event;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    // Synthetic gateway event of a type gatehook does not model
+    let event: Event = serde_json::from_value(serde_json::json!({
+        "t": "SOME_FUTURE_EVENT",
+        "d": {"guild_id": "1234", "detail": "value"}
+    }))
+    .unwrap();
+    let payload = RawEventPayload::from_event(&event).unwrap();
+
+    // Execute
+    let result = bridge.handle_raw_event(&payload).await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1, "Should send one event to webhook");
+    assert_eq!(sent_events[0].handler, "raw");
+
+    let json_value: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(json_value["_type"], "SOME_FUTURE_EVENT");
+    assert_eq!(json_value["raw"]["guild_id"], "1234");
+    assert_eq!(json_value["raw"]["detail"], "value");
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_sequential_by_default() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_call_delay(Duration::from_millis(20));
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_test_message("Hello", 123, 456);
+    let event_response = EventResponse {
+        actions: ["👍", "🎉", "❤️"]
+            .iter()
+            .map(|emoji| ResponseAction::React(ReactParams {
+                emoji: emoji.to_string(),
+                message_id: None,
+                channel_id: None,
+            }))
+            .collect(),
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: one call at a time, in order
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_max_in_flight(), 1);
+    let emojis: Vec<String> = discord_service.get_reactions().into_iter().map(|r| r.emoji).collect();
+    assert_eq!(emojis, vec!["👍", "🎉", "❤️"]);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::unlimited(3, 3)]
+#[case::limited(2, 2)]
+#[tokio::test]
+async fn test_execute_actions_concurrent_independent(
+    #[case] concurrency: usize,
+    #[case] expected_max_in_flight: usize,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_call_delay(Duration::from_millis(50));
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_action_concurrency(concurrency);
+
+    let message = create_test_message("Hello", 123, 456);
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+            ResponseAction::React(ReactParams {
+                emoji: "🎉".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+            ResponseAction::Reply(ReplyParams {
+                content: "Done".to_string(),
+                mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: all actions executed, up to the concurrency limit at once
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_max_in_flight(), expected_max_in_flight);
+    assert_eq!(discord_service.get_reactions().len(), 2);
+    assert_eq!(discord_service.get_replies().len(), 1);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_concurrent_serializes_dependent_actions() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_call_delay(Duration::from_millis(50));
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_action_concurrency(5);
+
+    let message = create_guild_message("Hello", 123, 456, 789);
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::Thread(ThreadParams {
+                name: Some("Topic".to_string()),
+                content: "In thread".to_string(),
+                auto_archive_duration: 1440,
+                message_id: None,
+                channel_id: None,
+                private: false,
+                allow_mass_mentions: false,
+                invitable: None,
+                rate_limit_per_user: None,
+            }),
+            ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+            ResponseAction::React(ReactParams {
+                emoji: "🎉".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+            ResponseAction::Reply(ReplyParams {
+                content: "Bye".to_string(),
+                mention: false,
+                delete_original: true,
+                allow_mass_mentions: false,
+                as_file: false,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: only the two reactions ever overlapped
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_max_in_flight(), 2);
+    assert_eq!(discord_service.get_threads().len(), 1);
+    assert_eq!(discord_service.get_reactions().len(), 2);
+    assert_eq!(discord_service.get_replies().len(), 1);
+
+    // Thread content posted first, reply (and deletion) last
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), 2);
+    assert_eq!(messages[0].content, "In thread");
+    assert_eq!(messages[1].content, "Bye");
+    assert_eq!(discord_service.get_deletions().len(), 1);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_react_trigger_emoji_from_reaction() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let reaction = MockReactionBuilder::new(8888, 9999)
+        .emoji("🎉")
+        .guild(1234, 7777)
+        .build();
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::React(ReactParams {
+            emoji: "$trigger".to_string(),
+            message_id: None,
+            channel_id: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&reaction, &event_response).await;
+
+    // Verify: the reaction's own emoji is echoed
+    assert!(result.is_ok());
+    let reactions = discord_service.get_reactions();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].emoji, "🎉");
+    assert_eq!(reactions[0].message_id, MessageId::new(8888));
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_react_trigger_emoji_skipped_for_message() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_test_message("Hello", 123, 456);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::React(ReactParams {
+                emoji: "$trigger".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+            ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: $trigger skipped, remaining actions still run
+    assert!(result.is_ok());
+    let reactions = discord_service.get_reactions();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].emoji, "👍");
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::message_only(Some("555"), None, 555, 222)]
+#[case::message_and_channel(Some("555"), Some("444"), 555, 444)]
+#[tokio::test]
+async fn test_execute_actions_thread_target_override(
+    #[case] message_id: Option<&str>,
+    #[case] channel_id: Option<&str>,
+    #[case] expected_message_id: u64,
+    #[case] expected_channel_id: u64,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Thread(ThreadParams {
+            name: Some("Elsewhere".to_string()),
+            content: "Started on another message".to_string(),
+            auto_archive_duration: 1440,
+            message_id: message_id.map(str::to_string),
+            channel_id: channel_id.map(str::to_string),
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: thread created on the override message
+    assert!(result.is_ok());
+    let threads = discord_service.get_threads();
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0].message_id, Some(MessageId::new(expected_message_id)));
+    assert_eq!(threads[0].channel_id, ChannelId::new(expected_channel_id));
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_thread_override_channel_already_thread() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup: override channel is a thread, event channel is not
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    channel_info.set_is_thread(ChannelId::new(444), true);
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Thread(ThreadParams {
+            name: None,
+            content: "Into the thread".to_string(),
+            auto_archive_duration: 1440,
+            message_id: Some("555".to_string()),
+            channel_id: Some("444".to_string()),
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: in-thread detection used the override channel
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_threads().len(), 0);
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].channel_id, ChannelId::new(444));
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::non_numeric_message(Some("abc"), None)]
+#[case::zero_message(Some("0"), None)]
+#[case::non_numeric_channel(Some("555"), Some("general"))]
+#[case::channel_without_message(None, Some("444"))]
+#[tokio::test]
+async fn test_execute_actions_thread_invalid_override_skipped(
+    #[case] message_id: Option<&str>,
+    #[case] channel_id: Option<&str>,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Thread(ThreadParams {
+            name: None,
+            content: "Never sent".to_string(),
+            auto_archive_duration: 1440,
+            message_id: message_id.map(str::to_string),
+            channel_id: channel_id.map(str::to_string),
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: skipped without any Discord call
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_threads().len(), 0);
+    assert_eq!(discord_service.get_messages().len(), 0);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_identical_reply_within_cooldown_skipped() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_reply_cooldown(Duration::from_secs(60));
+
+    let first = create_guild_message("First", 111, 222, 333);
+    let second = create_guild_message("Second", 112, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Reply(ReplyParams {
+            content: "Same reply".to_string(),
+            mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute: same reply to the same channel twice in quick succession
+    assert!(bridge.execute_actions(&first, &event_response).await.is_ok());
+    assert!(bridge.execute_actions(&second, &event_response).await.is_ok());
+
+    // Verify: second reply skipped
+    let replies = discord_service.get_replies();
+    assert_eq!(replies.len(), 1, "Identical reply within cooldown should be skipped");
+    assert_eq!(replies[0].message_id, MessageId::new(111));
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_failed_reply_does_not_start_cooldown() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_reply_cooldown(Duration::from_secs(60))
+        .with_action_max_retries(0);
+
+    let message = create_guild_message("First", 111, 222, 333);
+    let event_response = EventResponse::from(vec![ResponseAction::Reply(ReplyParams {
+        content: "Same reply".to_string(),
+        mention: false,
+        delete_original: false,
+        allow_mass_mentions: false,
+        as_file: false,
+    })]);
+
+    // Execute: the first attempt is rate limited with no retries left, then retried
+    discord_service.set_rate_limited_calls(1);
+    assert!(bridge.execute_actions(&message, &event_response).await.is_ok());
+    assert!(discord_service.get_replies().is_empty());
+    assert!(bridge.execute_actions(&message, &event_response).await.is_ok());
+
+    // Verify: the retry is sent, not suppressed by the failed attempt
+    assert_eq!(discord_service.get_replies().len(), 1);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::skip(3, ThreadLimitPolicy::Skip, 3, 3)]
+#[case::post_to_parent(3, ThreadLimitPolicy::PostToParent, 3, 8)]
+#[case::unlimited(0, ThreadLimitPolicy::Skip, 8, 8)]
+#[tokio::test]
+async fn test_execute_actions_thread_rate_limit(
+    #[case] max_threads_per_minute: u32,
+    #[case] policy: ThreadLimitPolicy,
+    #[case] expected_threads: usize,
+    #[case] expected_messages: usize,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    channel_info.set_is_thread(ChannelId::new(222), false);
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_max_threads_per_minute(max_threads_per_minute, policy);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Thread(ThreadParams {
+            name: Some("Discussion".to_string()),
+            content: "Let's discuss".to_string(),
+            auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private: false,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute: a burst of thread actions on different messages
+    for msg_id in 111..119 {
+        let message = create_guild_message("Hello", msg_id, 222, 333);
+        assert!(bridge.execute_actions(&message, &event_response).await.is_ok());
+    }
+
+    // Verify: threads beyond the rate were not created
+    assert_eq!(discord_service.get_threads().len(), expected_threads);
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), expected_messages);
+    assert!(messages.iter().all(|message| message.channel_id == ChannelId::new(222)));
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::throttled(3, 3)]
+#[case::unlimited(0, 8)]
+#[tokio::test]
+async fn test_execute_actions_global_rate_limit(#[case] max_actions_per_minute: u32, #[case] expected: usize) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_max_actions_per_minute(max_actions_per_minute);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+            ResponseAction::React(ReactParams {
+                emoji: "✅".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute: a flurry of responses, each within max_actions
+    for msg_id in 111..115 {
+        let message = create_guild_message("Hello", msg_id, 222, 333);
+        assert!(bridge.execute_actions(&message, &event_response).await.is_ok());
+    }
+
+    // Verify: only the first actions within the global rate ran
+    let reactions = discord_service.get_reactions();
+    assert_eq!(reactions.len(), expected);
+    assert_eq!(reactions[0].message_id, MessageId::new(111));
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_identical_reply_after_cooldown_sent() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_reply_cooldown(Duration::from_millis(50));
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Reply(ReplyParams {
+            content: "Same reply".to_string(),
+            mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute: same reply again once the window has passed
+    assert!(bridge.execute_actions(&message, &event_response).await.is_ok());
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(bridge.execute_actions(&message, &event_response).await.is_ok());
+
+    // Verify
+    assert_eq!(discord_service.get_replies().len(), 2, "Reply after cooldown should be sent");
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::suppressed_by_default(true, false, false)]
+#[case::action_opt_in(true, true, true)]
+#[case::suppression_disabled(false, false, true)]
+#[tokio::test]
+async fn test_execute_actions_mass_mentions(
+    #[case] suppress_mass_mentions: bool,
+    #[case] action_allows: bool,
+    #[case] expected: bool,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_suppress_mass_mentions(suppress_mass_mentions);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::Reply(ReplyParams {
+                content: "@everyone heads up".to_string(),
+                mention: false,
+                delete_original: false,
+                allow_mass_mentions: action_allows,
+                as_file: false,
+            }),
+            ResponseAction::Thread(ThreadParams {
+                name: Some("Topic".to_string()),
+                content: "@here discuss".to_string(),
+                auto_archive_duration: 1440,
+                message_id: None,
+                channel_id: None,
+                private: false,
+                allow_mass_mentions: action_allows,
+                invitable: None,
+                rate_limit_per_user: None,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: both the reply and the thread message carry the effective setting
+    assert!(result.is_ok());
+
+    let replies = discord_service.get_replies();
+    assert_eq!(replies.len(), 1);
+    assert_eq!(replies[0].allow_mass_mentions, expected);
+
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), 2, "Reply and thread message should both be recorded");
+    assert!(messages.iter().all(|message| message.allow_mass_mentions == expected));
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_precheck_skips_without_permission() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use serenity::model::permissions::Permissions;
+
+    // Setup: bot can send messages but not add reactions
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    channel_info.set_bot_permissions(
+        ChannelId::new(222),
+        Permissions::SEND_MESSAGES | Permissions::READ_MESSAGE_HISTORY,
+    );
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_precheck_permissions(true);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+            ResponseAction::Reply(ReplyParams {
+                content: "Reply".to_string(),
+                mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: react skipped without an API call, reply executed
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_reactions().len(), 0, "React should be skipped");
+    assert_eq!(discord_service.get_replies().len(), 1, "Reply should be executed");
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::public_with_public_permission(false, serenity::model::permissions::Permissions::CREATE_PUBLIC_THREADS, true)]
+#[case::private_with_public_permission(true, serenity::model::permissions::Permissions::CREATE_PUBLIC_THREADS, false)]
+#[case::private_with_private_permission(true, serenity::model::permissions::Permissions::CREATE_PRIVATE_THREADS, true)]
+#[tokio::test]
+async fn test_execute_actions_precheck_private_thread(
+    #[case] private: bool,
+    #[case] create_permission: serenity::model::permissions::Permissions,
+    #[case] expected_created: bool,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use serenity::model::permissions::Permissions;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    channel_info.set_is_thread(ChannelId::new(222), false);
+    channel_info.set_bot_permissions(ChannelId::new(222), create_permission | Permissions::SEND_MESSAGES_IN_THREADS);
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_precheck_permissions(true);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Thread(ThreadParams {
+            name: Some("Discussion".to_string()),
+            content: "Let's discuss".to_string(),
+            auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: a private thread needs Create Private Threads
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_threads().len(), usize::from(expected_created));
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::precheck_disabled(false, Some(serenity::model::permissions::Permissions::empty()))]
+#[case::has_permission(true, Some(serenity::model::permissions::Permissions::ADD_REACTIONS | serenity::model::permissions::Permissions::READ_MESSAGE_HISTORY))]
+#[case::permissions_unknown(true, None)]
+#[tokio::test]
+async fn test_execute_actions_precheck_executes(
+    #[case] precheck_permissions: bool,
+    #[case] permissions: Option<serenity::model::permissions::Permissions>,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    if let Some(permissions) = permissions {
+        channel_info.set_bot_permissions(ChannelId::new(222), permissions);
+    }
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_precheck_permissions(precheck_permissions);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::React(ReactParams {
+            emoji: "👍".to_string(),
+            message_id: None,
+            channel_id: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_reactions().len(), 1, "React should be executed");
+}
+
+#[tokio::test]
+async fn test_handle_reaction_add_rolls_up_same_emoji() {
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_reaction_rollup(Duration::from_millis(50));
+
+    let adds = [
+        MockReactionBuilder::new(2222, 2000).emoji("👍").guild(6000, 1111).build(),
+        MockReactionBuilder::new(2222, 2000).emoji("👍").guild(6000, 1112).build(),
+        MockReactionBuilder::new(2222, 2000).emoji("👍").guild(6000, 1113).build(),
+        MockReactionBuilder::new(2222, 2000).emoji("🎉").guild(6000, 1114).build(),
+    ];
+
+    // Execute: adds for one message arriving together
+    let results = futures::future::join_all(adds.iter().map(|reaction| bridge.handle_reaction_add(reaction))).await;
+
+    // Verify: the three 👍 are rolled up, the lone 🎉 is a plain reaction_add
+    assert!(results.iter().all(|result| result.is_ok()));
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 2);
+
+    let rollup_event = sent_events
+        .iter()
+        .find(|event| event.handler == "reaction_rollup")
+        .expect("reaction_rollup should be forwarded");
+    let payload: serde_json::Value = serde_json::from_str(&rollup_event.payload).unwrap();
+    let rollup = &payload["reaction_rollup"];
+    assert_eq!(rollup["message_id"], "2222");
+    assert_eq!(rollup["emoji"]["name"], "👍");
+    assert_eq!(rollup["count"], 3);
+    assert_eq!(rollup["user_ids"], serde_json::json!(["1111", "1112", "1113"]));
+
+    let single_event = sent_events
+        .iter()
+        .find(|event| event.handler == "reaction_add")
+        .expect("lone add should be forwarded as reaction_add");
+    let payload: serde_json::Value = serde_json::from_str(&single_event.payload).unwrap();
+    assert_eq!(payload["reaction"]["emoji"]["name"], "🎉");
+}
+
+#[tokio::test]
+async fn test_handle_reaction_remove_coalesces_same_message() {
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_reaction_remove_coalesce(Duration::from_millis(50));
+
+    let removals = [
+        MockReactionBuilder::new(2222, 2000).emoji("👍").guild(6000, 1111).build(),
+        MockReactionBuilder::new(2222, 2000).emoji("🎉").guild(6000, 1112).build(),
+        MockReactionBuilder::new(2222, 2000).emoji("👀").guild(6000, 1113).build(),
+    ];
+
+    // Execute: removals for one message arriving together
+    let results = futures::future::join_all(
+        removals
+            .iter()
+            .map(|reaction| bridge.handle_reaction_remove(reaction)),
+    )
+    .await;
+
+    // Verify: one batched forward listing every removal
+    assert!(results.iter().all(|result| result.is_ok()));
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1, "Removals should be forwarded once");
+    assert_eq!(sent_events[0].handler, "reaction_remove_batch");
+
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    let batch = &payload["reaction_remove_batch"];
+    assert_eq!(batch["message_id"], "2222");
+    assert_eq!(batch["reactions"].as_array().unwrap().len(), 3);
+    assert_eq!(batch["reactions"][1]["user_id"], "1112");
+    assert_eq!(batch["reactions"][1]["emoji"]["name"], "🎉");
+}
+
+#[tokio::test]
+async fn test_handle_reaction_remove_coalesce_single_and_other_messages() {
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_reaction_remove_coalesce(Duration::from_millis(50));
+
+    let first = MockReactionBuilder::new(2222, 2000).guild(6000, 1111).build();
+    let second = MockReactionBuilder::new(3333, 2000).guild(6000, 1111).build();
+
+    // Execute: one removal each for two messages
+    let (first_result, second_result) = tokio::join!(
+        bridge.handle_reaction_remove(&first),
+        bridge.handle_reaction_remove(&second)
+    );
+
+    // Verify: lone removals keep the regular reaction_remove payload
+    assert!(first_result.is_ok());
+    assert!(second_result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 2);
+    assert!(sent_events.iter().all(|event| event.handler == "reaction_remove"));
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::public(false, None, serenity::model::channel::ChannelType::PublicThread)]
+#[case::private(true, None, serenity::model::channel::ChannelType::PrivateThread)]
+#[case::private_in_text_channel(true, Some(serenity::model::channel::ChannelType::Text), serenity::model::channel::ChannelType::PrivateThread)]
+#[case::private_in_announcement_channel(true, Some(serenity::model::channel::ChannelType::News), serenity::model::channel::ChannelType::PublicThread)]
+#[tokio::test]
+async fn test_execute_actions_thread_kind(
+    #[case] private: bool,
+    #[case] channel_kind: Option<serenity::model::channel::ChannelType>,
+    #[case] expected_kind: serenity::model::channel::ChannelType,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use serenity::model::channel::{ChannelType, GuildChannel};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    if let Some(kind) = channel_kind {
+        let mut channel = GuildChannel::default();
+        channel.id = ChannelId::new(222);
+        channel.kind = kind;
+        channel_info.set_channel(ChannelId::new(222), channel);
+    }
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Thread(ThreadParams {
+            name: Some("Support".to_string()),
+            content: "How can we help?".to_string(),
+            auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private,
+            allow_mass_mentions: false,
+            invitable: None,
+            rate_limit_per_user: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: requested kind reaches Discord; only public threads are message-anchored
+    assert!(result.is_ok());
+
+    let threads = discord_service.get_threads();
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0].channel_id, ChannelId::new(222));
+    assert_eq!(threads[0].kind, expected_kind);
+    let expected_message_id = (expected_kind == ChannelType::PublicThread).then(|| MessageId::new(111));
+    assert_eq!(threads[0].message_id, expected_message_id);
+    assert_eq!(discord_service.get_messages().len(), 1, "Content should be posted to the thread");
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::public(false, Some(30), None, Some(30))]
+#[case::private(true, Some(30), Some(false), Some(30))]
+#[case::clamped(true, Some(u16::MAX), Some(false), Some(21600))]
+#[case::unset(true, None, Some(false), None)]
+#[tokio::test]
+async fn test_execute_actions_thread_invitable_and_rate_limit(
+    #[case] private: bool,
+    #[case] rate_limit_per_user: Option<u16>,
+    #[case] expected_invitable: Option<bool>,
+    #[case] expected_rate_limit: Option<u16>,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Thread(ThreadParams {
+            name: Some("Support".to_string()),
+            content: "How can we help?".to_string(),
+            auto_archive_duration: 1440,
+            message_id: None,
+            channel_id: None,
+            private,
+            allow_mass_mentions: false,
+            invitable: Some(false),
+            rate_limit_per_user,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: slowmode reaches both thread kinds (clamped); invitable only private ones
+    assert!(result.is_ok());
+
+    let threads = discord_service.get_threads();
+    assert_eq!(threads.len(), 1);
+    assert_eq!(threads[0].rate_limit_per_user, expected_rate_limit);
+    assert_eq!(threads[0].invitable, expected_invitable);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::retried_once(2, 1, 1, 1)]
+#[case::retries_exhausted(1, 2, 2, 0)]
+#[case::retries_disabled(0, 1, 1, 0)]
+#[tokio::test(start_paused = true)]
+async fn test_execute_actions_retries_rate_limited_call(
+    #[case] max_retries: u32,
+    #[case] rate_limited_calls: usize,
+    #[case] expected_hits: usize,
+    #[case] expected_replies: usize,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_rate_limited_calls(rate_limited_calls);
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_action_max_retries(max_retries);
+
+    let message = create_test_message("Test", 777, 888);
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::Reply(ReplyParams {
+                content: "Pong".to_string(),
+                mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
+            }),
+            ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: only the rate-limited reply is retried; later actions still run
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_rate_limit_hits(), expected_hits);
+    assert_eq!(discord_service.get_replies().len(), expected_replies);
+    assert_eq!(discord_service.get_reactions().len(), 1);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test(start_paused = true)]
+async fn test_execute_actions_rate_limit_backoff_is_bounded() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use std::time::Duration;
+
+    // Setup: an absurd retry count is capped, and so is the doubling delay
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_rate_limited_calls(100);
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_action_max_retries(u32::MAX);
+
+    let message = create_test_message("Test", 777, 888);
+    let event_response = EventResponse::from(vec![ResponseAction::Reply(ReplyParams {
+        content: "Pong".to_string(),
+        mention: false,
+        delete_original: false,
+        allow_mass_mentions: false,
+        as_file: false,
+    })]);
+
+    // Execute
+    let started = tokio::time::Instant::now();
+    bridge.execute_actions(&message, &event_response).await.unwrap();
+
+    // Verify: 10 retries waiting 1s, 2s, ... 32s, then 60s each
+    assert_eq!(discord_service.get_rate_limit_hits(), 11);
+    assert_eq!(started.elapsed(), Duration::from_secs(1 + 2 + 4 + 8 + 16 + 32 + 4 * 60));
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::notify(Some(ChannelId::new(999)), 1)]
+#[case::disabled(None, 0)]
+#[tokio::test]
+async fn test_error_notify_on_action_failure(#[case] notify_channel: Option<ChannelId>, #[case] expected: usize) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use std::time::Duration;
+
+    // Setup: every reply fails with a rate limit and is not retried
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_rate_limited_calls(3);
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_action_max_retries(0)
+        .with_error_notify_channel(notify_channel, Duration::from_secs(60));
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Reply(ReplyParams {
+            content: "secret reply".to_string(),
+            mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute: three failing responses within the notice interval
+    for msg_id in 111..114 {
+        let message = create_test_message("Test", msg_id, 888);
+        assert!(bridge.execute_actions(&message, &event_response).await.is_ok());
+    }
+
+    // Verify: one redacted notice, later failures suppressed by the rate limit
+    assert_eq!(discord_service.get_replies().len(), 0);
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), expected);
+    for message in messages {
+        assert_eq!(message.channel_id, ChannelId::new(999));
+        assert!(message.content.contains("Action `reply` failed"));
+        assert!(!message.content.contains("secret"));
+    }
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_error_notify_on_webhook_failure() {
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::failing());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_error_notify_channel(Some(ChannelId::new(999)), Duration::from_secs(60));
+
+    // Execute: two failing sends within the notice interval
+    for msg_id in 111..113 {
+        let message = create_test_message("Test", msg_id, 888);
+        assert!(bridge.handle_message(&message).await.is_err());
+    }
+
+    // Verify: the failure is still returned, and only one notice is posted
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].channel_id, ChannelId::new(999));
+    assert_eq!(
+        messages[0].content,
+        "⚠️ gatehook: Webhook delivery failed for `message` event (circuit breaker open) (see logs for details)"
+    );
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::server_error(503, 1)]
+#[case::rate_limited(429, 1)]
+#[case::client_error(400, 0)]
+#[tokio::test]
+async fn test_error_notify_on_rejected_delivery(#[case] status: u16, #[case] expected: usize) {
+    use std::time::Duration;
+
+    // Setup: the endpoint answers every event with `status`
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new().with_status(status));
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_error_notify_channel(Some(ChannelId::new(999)), Duration::from_secs(60));
+
+    // Execute
+    let message = create_test_message("Test", 111, 888);
+    assert!(bridge.handle_message(&message).await.is_ok());
+
+    // Verify: only rejected deliveries (5xx/429) post a notice
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), expected);
+    for message in messages {
+        assert_eq!(
+            message.content,
+            format!("⚠️ gatehook: Webhook delivery failed for `message` event (HTTP {status}) (see logs for details)")
+        );
+    }
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::content_only(false, &[])]
+#[case::with_attachments(true, &["log.txt"])]
+#[tokio::test]
+async fn test_execute_actions_forward(#[case] include_attachments: bool, #[case] expected_attachments: &[&str]) {
+    use gatehook::adapters::{EventResponse, ForwardParams, ResponseAction};
+    use serenity::model::channel::Attachment;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let mut message = create_guild_message("Build failed", 111, 222, 333);
+    let attachment: Attachment = serde_json::from_value(serde_json::json!({
+        "id": "444",
+        "filename": "log.txt",
+        "size": 128,
+        "url": "https://cdn.discordapp.com/attachments/222/444/log.txt",
+        "proxy_url": "https://media.discordapp.net/attachments/222/444/log.txt"
+    }))
+    .unwrap();
+    message.attachments = vec![attachment];
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Forward(ForwardParams {
+            to_channel_id: "999".to_string(),
+            include_attachments,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: content (and attachments on request) lands in the target channel
+    assert!(result.is_ok());
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].channel_id, ChannelId::new(999));
+    assert_eq!(messages[0].content, "Build failed");
+    assert_eq!(messages[0].reply_to, None);
+    assert_eq!(messages[0].attachments, expected_attachments);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_forward_skipped_for_reaction() {
+    use gatehook::adapters::{EventResponse, ForwardParams, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let reaction = MockReactionBuilder::new(2222, 2000)
+        .emoji("👍")
+        .guild(6000, 1111)
+        .build();
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Forward(ForwardParams {
+            to_channel_id: "999".to_string(),
+            include_attachments: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&reaction, &event_response).await;
+
+    // Verify: no message content to forward for reaction events
+    assert!(result.is_ok());
+    assert!(discord_service.get_messages().is_empty());
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case(ReactionReplyMode::Reference, false, None)]
+#[case(ReactionReplyMode::Channel, false, Some((2000, "thanks")))]
+#[case(ReactionReplyMode::Channel, true, Some((2000, "<@1111> thanks")))]
+#[case(ReactionReplyMode::Dm, true, Some((1111, "thanks")))]
+#[tokio::test]
+async fn test_execute_actions_reply_to_reaction(
+    #[case] mode: ReactionReplyMode,
+    #[case] mention: bool,
+    #[case] expected_message: Option<(u64, &str)>,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use serenity::model::id::UserId;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_reaction_reply_mode(mode);
+
+    let reaction = MockReactionBuilder::new(2222, 2000)
+        .emoji("👍")
+        .guild(6000, 1111)
+        .build();
+    let event_response = EventResponse::from(vec![ResponseAction::Reply(ReplyParams {
+        content: "thanks".to_string(),
+        mention,
+        delete_original: false,
+        allow_mass_mentions: false,
+        as_file: false,
+    })]);
+
+    // Execute
+    let result = bridge.execute_actions(&reaction, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    let messages = discord_service.get_messages();
+    match expected_message {
+        None => {
+            assert_eq!(discord_service.get_replies().len(), 1);
+            assert_eq!(messages[0].reply_to, Some(MessageId::new(2222)));
+        }
+        Some((channel_id, content)) => {
+            assert!(discord_service.get_replies().is_empty());
+            assert_eq!(messages.len(), 1);
+            assert_eq!(messages[0].channel_id, ChannelId::new(channel_id));
+            assert_eq!(messages[0].content, content);
+            assert_eq!(messages[0].reply_to, None);
+        }
+    }
+    let expected_dm_channels = if mode == ReactionReplyMode::Dm { vec![UserId::new(1111)] } else { vec![] };
+    assert_eq!(discord_service.get_dm_channels(), expected_dm_channels);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::reference(ReactionReplyMode::Reference, serenity::model::permissions::Permissions::SEND_MESSAGES, false)]
+#[case::channel(ReactionReplyMode::Channel, serenity::model::permissions::Permissions::SEND_MESSAGES, true)]
+#[case::channel_without_send(ReactionReplyMode::Channel, serenity::model::permissions::Permissions::empty(), false)]
+#[case::dm(ReactionReplyMode::Dm, serenity::model::permissions::Permissions::empty(), true)]
+#[tokio::test]
+async fn test_execute_actions_precheck_reply_to_reaction(
+    #[case] mode: ReactionReplyMode,
+    #[case] granted: serenity::model::permissions::Permissions,
+    #[case] expected_sent: bool,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    channel_info.set_bot_permissions(ChannelId::new(2000), granted);
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_precheck_permissions(true)
+        .with_reaction_reply_mode(mode);
+
+    let reaction = MockReactionBuilder::new(2222, 2000)
+        .emoji("👍")
+        .guild(6000, 1111)
+        .build();
+    let event_response = EventResponse::from(vec![ResponseAction::Reply(ReplyParams {
+        content: "thanks".to_string(),
+        mention: false,
+        delete_original: false,
+        allow_mass_mentions: false,
+        as_file: true,
+    })]);
+
+    // Execute
+    let result = bridge.execute_actions(&reaction, &event_response).await;
+
+    // Verify: channel posts need neither Read Message History nor Attach Files,
+    // DMs are not subject to the channel's permissions
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_messages().len(), usize::from(expected_sent));
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_dm_reply_cooldown_skips_dm_channel() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use serenity::model::id::UserId;
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_reaction_reply_mode(ReactionReplyMode::Dm)
+        .with_reply_cooldown(Duration::from_secs(60));
+
+    let event_response = EventResponse::from(vec![ResponseAction::Reply(ReplyParams {
+        content: "thanks".to_string(),
+        mention: false,
+        delete_original: false,
+        allow_mass_mentions: false,
+        as_file: false,
+    })]);
+
+    // Execute: a looping webhook answers every reaction of the same user identically
+    for message_id in [2222, 2223, 2224] {
+        let reaction = MockReactionBuilder::new(message_id, 2000)
+            .emoji("👍")
+            .guild(6000, 1111)
+            .build();
+        assert!(bridge.execute_actions(&reaction, &event_response).await.is_ok());
+    }
+
+    // Verify: the DM channel is opened only for the first reply
+    assert_eq!(discord_service.get_dm_channels(), vec![UserId::new(1111)]);
+    assert_eq!(discord_service.get_messages().len(), 1);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_reply_to_message_ignores_reaction_reply_mode() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_reaction_reply_mode(ReactionReplyMode::Dm);
+
+    let message = create_guild_message("hello", 2222, 2000, 6000);
+    let event_response = EventResponse::from(vec![ResponseAction::Reply(ReplyParams {
+        content: "thanks".to_string(),
+        mention: false,
+        delete_original: false,
+        allow_mass_mentions: false,
+        as_file: false,
+    })]);
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: message events still reply to the message itself
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_replies().len(), 1);
+    assert_eq!(discord_service.get_messages()[0].channel_id, ChannelId::new(2000));
+    assert!(discord_service.get_dm_channels().is_empty());
+}
+
+#[tokio::test]
+async fn test_handle_message_with_message_fields() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_message_fields(vec!["id".to_string(), "content".to_string()]);
+
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify: only the listed message fields are forwarded
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1);
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(
+        payload["message"],
+        serde_json::json!({"id": "111", "content": "Hello"})
+    );
+}
+
+// Discord channel webhook URL with a 64-character token
+#[cfg(feature = "actions")]
+const TEST_WEBHOOK_TOKEN: &str = "aBcDeFgHiJkLmNoPqRsTuVwXyZ0123456789aBcDeFgHiJkLmNoPqRsTuVwXyZ01";
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_webhook_message() {
+    use gatehook::adapters::{EventResponse, ResponseAction, WebhookMessageParams};
+    use serenity::model::id::WebhookId;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::WebhookMessage(WebhookMessageParams {
+            webhook_url: format!("https://discord.com/api/webhooks/123456789012345678/{TEST_WEBHOOK_TOKEN}"),
+            username: Some("Persona".to_string()),
+            avatar_url: Some("https://example.com/avatar.png".to_string()),
+            content: "In character".to_string(),
+            allow_mass_mentions: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: posted through the webhook, not as the bot
+    assert!(result.is_ok());
+    assert!(discord_service.get_messages().is_empty());
+
+    let webhook_messages = discord_service.get_webhook_messages();
+    assert_eq!(webhook_messages.len(), 1);
+    assert_eq!(webhook_messages[0].webhook_id, WebhookId::new(123456789012345678));
+    assert_eq!(webhook_messages[0].token, TEST_WEBHOOK_TOKEN);
+    assert_eq!(webhook_messages[0].username.as_deref(), Some("Persona"));
+    assert_eq!(webhook_messages[0].avatar_url.as_deref(), Some("https://example.com/avatar.png"));
+    assert_eq!(webhook_messages[0].content, "In character");
+    assert!(!webhook_messages[0].allow_mass_mentions);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::not_a_url("not a url")]
+#[case::other_host("https://example.com/api/webhooks/123456789012345678/token")]
+#[case::missing_token("https://discord.com/api/webhooks/123456789012345678")]
+#[case::not_a_webhook("https://discord.com/channels/123456789012345678/123456789012345678")]
+#[tokio::test]
+async fn test_execute_actions_webhook_message_invalid_url(#[case] webhook_url: &str) {
+    use gatehook::adapters::{EventResponse, ResponseAction, WebhookMessageParams};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::WebhookMessage(WebhookMessageParams {
+            webhook_url: webhook_url.to_string(),
+            username: None,
+            avatar_url: None,
+            content: "In character".to_string(),
+            allow_mass_mentions: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: skipped without a call
+    assert!(result.is_ok());
+    assert!(discord_service.get_webhook_messages().is_empty());
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_suppress_embeds() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("https://example.com", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::SuppressEmbeds],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: the suppress flag was applied to the target message
+    assert!(result.is_ok());
+    assert_eq!(
+        discord_service.get_suppressed_embeds(),
+        vec![(ChannelId::new(222), MessageId::new(111))]
+    );
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::missing_manage_messages(serenity::model::permissions::Permissions::SEND_MESSAGES, 0)]
+#[case::has_manage_messages(serenity::model::permissions::Permissions::MANAGE_MESSAGES, 1)]
+#[tokio::test]
+async fn test_execute_actions_suppress_embeds_precheck(
+    #[case] permissions: serenity::model::permissions::Permissions,
+    #[case] expected: usize,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    channel_info.set_bot_permissions(ChannelId::new(222), permissions);
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_precheck_permissions(true);
+
+    let message = create_guild_message("https://example.com", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::SuppressEmbeds],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_suppressed_embeds().len(), expected);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::guild_message(Some(333), vec![(ChannelId::new(222), MessageId::new(111))])]
+#[case::direct_message_skipped(None, vec![])]
+#[tokio::test]
+async fn test_execute_actions_remove_all_reactions(
+    #[case] guild_id: Option<u64>,
+    #[case] expected: Vec<(ChannelId, MessageId)>,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = match guild_id {
+        Some(guild_id) => create_guild_message("Hello", 111, 222, guild_id),
+        None => create_test_message("Hello", 111, 222),
+    };
+    let event_response = EventResponse::from(vec![ResponseAction::RemoveAllReactions]);
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: reactions are cleared on the target message, never in DMs
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_cleared_reactions(), expected);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_remove_all_reactions_for_reaction() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let reaction = MockReactionBuilder::new(2222, 2000)
+        .emoji("👍")
+        .guild(6000, 1111)
+        .build();
+    let event_response = EventResponse::from(vec![ResponseAction::RemoveAllReactions]);
+
+    // Execute
+    let result = bridge.execute_actions(&reaction, &event_response).await;
+
+    // Verify: the reacted-to message is cleared
+    assert!(result.is_ok());
+    assert_eq!(
+        discord_service.get_cleared_reactions(),
+        vec![(ChannelId::new(2000), MessageId::new(2222))]
+    );
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::event_guild(None, Some(GuildId::new(333)))]
+#[case::explicit_guild(Some("444"), Some(GuildId::new(444)))]
+#[case::invalid_guild(Some("not-a-snowflake"), None)]
+#[tokio::test]
+async fn test_execute_actions_leave_guild(
+    #[case] guild_id: Option<&str>,
+    #[case] expected: Option<GuildId>,
+) {
+    use gatehook::adapters::{EventResponse, LeaveGuildParams, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_allowed_actions(Some(vec!["leave_guild".to_string()]));
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::LeaveGuild(LeaveGuildParams {
+            guild_id: guild_id.map(str::to_string),
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_left_guilds(), expected.into_iter().collect::<Vec<_>>());
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_leave_guild_skipped_in_dm() {
+    use gatehook::adapters::{EventResponse, LeaveGuildParams, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_allowed_actions(Some(vec!["leave_guild".to_string()]));
+
+    let message = create_test_message("Original message", 111, 222);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::LeaveGuild(LeaveGuildParams { guild_id: None })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: no guild to leave
+    assert!(result.is_ok());
+    assert!(discord_service.get_left_guilds().is_empty());
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::no_allowlist(None)]
+#[case::not_listed(Some(vec!["reply".to_string(), "react".to_string()]))]
+#[tokio::test]
+async fn test_execute_actions_leave_guild_requires_explicit_allowlist(
+    #[case] allowed_actions: Option<Vec<String>>,
+) {
+    use gatehook::adapters::{EventResponse, LeaveGuildParams, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_allowed_actions(allowed_actions);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::LeaveGuild(LeaveGuildParams { guild_id: None }),
+            ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: leave_guild skipped, other allowed actions still run
+    assert!(result.is_ok());
+    assert!(discord_service.get_left_guilds().is_empty());
+    assert_eq!(discord_service.get_reactions().len(), 1);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::guild(create_guild_message("Play", 111, 222, 333), Some("555"), vec![(GuildId::new(333), ChannelId::new(555))])]
+#[case::invalid_channel(create_guild_message("Play", 111, 222, 333), Some("not-a-snowflake"), vec![])]
+#[case::dm(create_test_message("Play", 111, 222), Some("555"), vec![])]
+#[case::not_allowed(create_guild_message("Play", 111, 222, 333), None, vec![])]
+#[tokio::test]
+async fn test_execute_actions_join_voice(
+    #[case] message: Message,
+    #[case] channel_id: Option<&str>,
+    #[case] expected: Vec<(GuildId, ChannelId)>,
+) {
+    use gatehook::adapters::{EventResponse, JoinVoiceParams, ResponseAction};
+
+    // Setup: join_voice is only allowed when listed explicitly
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let allowed_actions = channel_id.is_some().then(|| vec!["join_voice".to_string()]);
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_allowed_actions(allowed_actions);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::JoinVoice(JoinVoiceParams {
+            channel_id: channel_id.unwrap_or("555").to_string(),
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_voice_joins(), expected);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::default_kind(None, None, ChannelType::Text, None)]
+#[case::forum_in_category(Some(ChannelKind::Forum), Some("777"), ChannelType::Forum, Some(ChannelId::new(777)))]
+#[tokio::test]
+async fn test_execute_actions_create_channel(
+    #[case] kind: Option<ChannelKind>,
+    #[case] parent_id: Option<&str>,
+    #[case] expected_kind: ChannelType,
+    #[case] expected_parent: Option<ChannelId>,
+) {
+    use gatehook::adapters::{CreateChannelParams, EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+    let message = create_guild_message("Open ticket", 111, 222, 333);
+
+    let event_response = EventResponse::from(vec![ResponseAction::CreateChannel(CreateChannelParams {
+        name: "ticket-111".to_string(),
+        kind: kind.unwrap_or_default(),
+        parent_id: parent_id.map(str::to_string),
+        topic: Some("Support ticket".to_string()),
+    })]);
+
+    // Execute
+    bridge.execute_actions(&message, &event_response).await.unwrap();
+
+    // Verify: name, kind and category reach the Discord call in the event's guild
+    let created = discord_service.get_created_channels();
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].guild_id, GuildId::new(333));
+    assert_eq!(created[0].name, "ticket-111");
+    assert_eq!(created[0].kind, expected_kind);
+    assert_eq!(created[0].parent_id, expected_parent);
+    assert_eq!(created[0].topic.as_deref(), Some("Support ticket"));
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::dm(create_test_message("Open ticket", 111, 222), None)]
+#[case::invalid_parent(create_guild_message("Open ticket", 111, 222, 333), Some("not-a-snowflake"))]
+#[tokio::test]
+async fn test_execute_actions_create_channel_skipped(#[case] message: Message, #[case] parent_id: Option<&str>) {
+    use gatehook::adapters::{CreateChannelParams, EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let event_response = EventResponse::from(vec![ResponseAction::CreateChannel(CreateChannelParams {
+        name: "ticket-111".to_string(),
+        kind: ChannelKind::Text,
+        parent_id: parent_id.map(str::to_string),
+        topic: None,
+    })]);
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: skipped without error
+    assert!(result.is_ok());
+    assert!(discord_service.get_created_channels().is_empty());
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_react_to_multiple_messages() {
+    use gatehook::adapters::EventResponse;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+    let message = create_guild_message("Summary", 111, 222, 333);
+
+    let response: EventResponse = serde_json::from_str(
+        r#"{"actions":[
+            {"type":"react","emoji":"👍","message_id":"444"},
+            {"type":"react","emoji":"🎉","message_id":"555","channel_id":"666"},
+            {"type":"react","emoji":"👀"},
+            {"type":"react","emoji":"❌","channel_id":"666"},
+            {"type":"react","emoji":"❌","message_id":"not-a-snowflake"}
+        ]}"#,
+    )
+    .unwrap();
+
+    // Execute
+    bridge.execute_actions(&message, &response).await.unwrap();
+
+    // Verify: each reaction lands on its own message; invalid overrides are skipped
+    let reactions: Vec<_> = discord_service
+        .get_reactions()
+        .into_iter()
+        .map(|reaction| (reaction.channel_id.get(), reaction.message_id.get(), reaction.emoji))
+        .collect();
+    assert_eq!(
+        reactions,
+        vec![
+            (222, 444, "👍".to_string()),
+            (666, 555, "🎉".to_string()),
+            (222, 111, "👀".to_string()),
+        ]
+    );
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_skips_repeated_idempotency_key() {
+    use gatehook::adapters::EventResponse;
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_action_idempotency_window(Duration::from_secs(300));
+    let message = create_test_message("Ship it", 111, 222);
+
+    // A retried delivery returns the same keyed action again, next to an unkeyed one
+    let response: EventResponse = serde_json::from_str(
+        r#"{"actions":[
+            {"type":"reply","content":"Shipped!","idempotency_key":"order-42"},
+            {"type":"react","emoji":"📦"}
+        ]}"#,
+    )
+    .unwrap();
+
+    // Execute
+    bridge.execute_actions(&message, &response).await.unwrap();
+    bridge.execute_actions(&message, &response).await.unwrap();
+
+    // Verify: the keyed reply ran once, the unkeyed reaction both times
+    assert_eq!(discord_service.get_replies().len(), 1);
+    assert_eq!(discord_service.get_reactions().len(), 2);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_failed_idempotent_action_runs_on_retry() {
+    use gatehook::adapters::EventResponse;
+    use std::time::Duration;
+
+    // Setup: the first reply is rate limited and not retried
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_rate_limited_calls(1);
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_action_idempotency_window(Duration::from_secs(300))
+        .with_action_max_retries(0);
+    let message = create_test_message("Ship it", 111, 222);
+
+    let response: EventResponse =
+        serde_json::from_str(r#"{"actions":[{"type":"reply","content":"Shipped!","idempotency_key":"order-42"}]}"#)
+            .unwrap();
+
+    // Execute: the first attempt fails, the retried delivery runs the action
+    bridge.execute_actions(&message, &response).await.unwrap();
+    assert!(discord_service.get_replies().is_empty());
+    bridge.execute_actions(&message, &response).await.unwrap();
+    bridge.execute_actions(&message, &response).await.unwrap();
+
+    // Verify: executed once the key succeeded, then skipped
+    assert_eq!(discord_service.get_rate_limit_hits(), 1);
+    assert_eq!(discord_service.get_replies().len(), 1);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_idempotency_disabled_with_zero_window() {
+    use gatehook::adapters::EventResponse;
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_action_idempotency_window(Duration::ZERO);
+    let message = create_test_message("Ship it", 111, 222);
+
+    let response: EventResponse =
+        serde_json::from_str(r#"{"actions":[{"type":"reply","content":"Shipped!","idempotency_key":"order-42"}]}"#)
+            .unwrap();
+
+    // Execute
+    bridge.execute_actions(&message, &response).await.unwrap();
+    bridge.execute_actions(&message, &response).await.unwrap();
+
+    // Verify
+    assert_eq!(discord_service.get_replies().len(), 2);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_allowlist_skips_unlisted_actions() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_allowed_actions(Some(vec!["react".to_string()]));
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::Reply(ReplyParams {
+                content: "Reply".to_string(),
+                mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
+            }),
+            ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert_eq!(discord_service.get_replies().len(), 0, "Reply is not allowed");
+    assert_eq!(discord_service.get_reactions().len(), 1);
+}
+
+// Helper to forward `count` messages through a bridge sampling at `rate` (seeded)
+async fn forward_sampled_messages(rate: f64, seed: u64, count: u64) -> usize {
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_sample_rate("message", rate)
+        .with_sample_seed(seed);
+
+    for id in 1..=count {
+        let message = create_guild_message("Hello", id, 222, 333);
+        let result = bridge.handle_message(&message).await;
+        assert!(result.is_ok());
+    }
+
+    event_sender.get_sent_events().len()
+}
+
+#[rstest]
+#[case::none(0.0, 0)]
+#[case::all(1.0, 200)]
+#[tokio::test]
+async fn test_handle_message_sample_rate_bounds(#[case] rate: f64, #[case] expected: usize) {
+    assert_eq!(forward_sampled_messages(rate, 7, 200).await, expected);
+}
+
+#[tokio::test]
+async fn test_handle_message_sample_rate_fractional_with_seed() {
+    // Execute: same seed twice
+    let first = forward_sampled_messages(0.5, 42, 200).await;
+    let second = forward_sampled_messages(0.5, 42, 200).await;
+
+    // Verify: reproducible, roughly half forwarded
+    assert_eq!(first, second, "Seeded sampling should be reproducible");
+    assert!((70..130).contains(&first), "Expected roughly 100 of 200, got {first}");
+}
+
+#[tokio::test]
+async fn test_sample_rate_only_applies_to_configured_handler() {
+    // Setup: drop all messages, reactions unaffected
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_sample_rate("message", 0.0);
+
+    let message = create_guild_message("Hello", 111, 222, 333);
+    let reaction = MockReactionBuilder::new(111, 222).guild(333, 1111).build();
+
+    // Execute
+    let message_result = bridge.handle_message(&message).await;
+    let reaction_result = bridge.handle_reaction_add(&reaction).await;
+
+    // Verify: sampled-out events return no response
+    assert!(matches!(message_result, Ok(None)));
+    assert!(reaction_result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1);
+    assert_eq!(sent_events[0].handler, "reaction_add");
+}
+
+// Helper to run a message through the sender filter and bridge like the message handler
+#[cfg(feature = "actions")]
+async fn process_message_with_auto_react(
+    policy: &str,
+    webhook_response: Option<gatehook::adapters::EventResponse>,
+    message: &Message,
+) -> (Arc<MockDiscordService>, Arc<MockEventSender>) {
+    use gatehook::bridge::sender_filter::SenderFilterPolicy;
+    use serenity::model::id::UserId;
+
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(match webhook_response {
+        Some(response) => MockEventSender::with_response(response),
+        None => MockEventSender::new(),
+    });
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5)
+        .with_auto_react_emoji(Some("✅".to_string()));
+    let filter = SenderFilterPolicy::from_policy(policy).for_message(UserId::new(999));
+
+    if filter.should_process(message)
+        && let Some(event_response) = bridge.handle_message(message).await.unwrap()
+    {
+        bridge.execute_actions(message, &event_response).await.unwrap();
+    }
+
+    (discord_service, event_sender)
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_auto_react_emoji_reacts_to_forwarded_message() {
+    // Setup: webhook responds without actions
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let (discord_service, event_sender) = process_message_with_auto_react("user", None, &message).await;
+
+    // Verify
+    assert_eq!(event_sender.get_sent_events().len(), 1);
+    let reactions = discord_service.get_reactions();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].message_id, MessageId::new(111));
+    assert_eq!(reactions[0].emoji, "✅");
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_auto_react_emoji_runs_alongside_webhook_actions() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup: webhook responds with a reply
+    let response = EventResponse {
+        actions: vec![ResponseAction::Reply(ReplyParams {
+            content: "Got it".to_string(),
+            mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
+        })],
+        ..Default::default()
+    };
+    let message = create_test_message("Hello", 111, 222);
+
+    // Execute
+    let (discord_service, _) = process_message_with_auto_react("user", Some(response), &message).await;
+
+    // Verify: both the auto-reaction and the webhook's reply ran
+    let reactions = discord_service.get_reactions();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].emoji, "✅");
+    assert_eq!(discord_service.get_replies().len(), 1);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_auto_react_emoji_skips_filtered_message() {
+    // Setup: only bot senders are processed, the message is from a user
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let (discord_service, event_sender) = process_message_with_auto_react("bot", None, &message).await;
+
+    // Verify
+    assert!(event_sender.get_sent_events().is_empty());
+    assert!(discord_service.get_reactions().is_empty());
+}
+
+#[rstest]
+#[case::payload_too_large(Some(16), None)]
+#[case::deadline_exceeded(None, Some(100))]
+#[tokio::test(start_paused = true)]
+async fn test_auto_react_emoji_skips_unforwarded_message(
+    #[case] max_payload_size: Option<usize>,
+    #[case] deadline_ms: Option<u64>,
+) {
+    use std::time::Duration;
+
+    // Setup: the webhook takes 1s to respond
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new().with_delay(Duration::from_secs(1)));
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let mut bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_auto_react_emoji(Some("✅".to_string()))
+        .with_max_payload_size(max_payload_size);
+    if let Some(deadline_ms) = deadline_ms {
+        bridge = bridge.with_webhook_deadline("message", Duration::from_millis(deadline_ms));
+    }
+
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify: no answer from the endpoint, so no reaction action
+    assert!(matches!(result, Ok(None)));
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_auto_react_emoji_exempt_from_max_actions() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup: max_actions of 1, webhook responds with one reply
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::with_response(EventResponse::from(vec![ResponseAction::Reply(
+        ReplyParams {
+            content: "Got it".to_string(),
+            mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
+        },
+    )])));
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 1)
+        .with_auto_react_emoji(Some("✅".to_string()));
+
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let event_response = bridge.handle_message(&message).await.unwrap().unwrap();
+    bridge.execute_actions(&message, &event_response).await.unwrap();
+
+    // Verify: the webhook's action is not truncated by the auto-reaction
+    assert_eq!(discord_service.get_reactions().len(), 1);
+    assert_eq!(discord_service.get_replies().len(), 1);
+}
+
+#[tokio::test]
+async fn test_auto_react_emoji_skips_sampled_out_message() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_auto_react_emoji(Some("✅".to_string()))
+        .with_sample_rate("message", 0.0);
+
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify: not forwarded, so no reaction action
+    assert!(matches!(result, Ok(None)));
+    assert!(event_sender.get_sent_events().is_empty());
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_edit_last_reply() {
+    use gatehook::adapters::{EditParams, EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::Reply(ReplyParams {
+                content: "Working on it...".to_string(),
+                mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
+            }),
+            ResponseAction::Edit(EditParams {
+                message_id: "$last".to_string(),
+                channel_id: None,
+                content: Some("Done!".to_string()),
+                embed: None,
+                allow_mass_mentions: false,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: the reply sent earlier in the response is edited
+    assert!(result.is_ok());
+
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), 1);
+    let edits = discord_service.get_edits();
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].channel_id, ChannelId::new(222));
+    assert_eq!(edits[0].message_id, messages[0].id);
+    assert_eq!(edits[0].content.as_deref(), Some("Done!"));
+    assert!(!edits[0].allow_mass_mentions, "Mass mentions are suppressed by default");
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_edit_last_follows_response_order() {
+    use gatehook::adapters::{EditParams, EventResponse, ResponseAction, SendMessageParams};
+    use std::time::Duration;
+
+    // Setup: the reply finishes after the message sent after it
+    let discord_service = Arc::new(MockDiscordService::new());
+    discord_service.set_call_delays([Duration::from_millis(50), Duration::ZERO]);
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5).with_action_concurrency(2);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::Reply(ReplyParams {
+                content: "First".to_string(),
+                mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
+            }),
+            ResponseAction::SendMessage(SendMessageParams {
+                channel_id: None,
+                content: "Second".to_string(),
+                allow_mass_mentions: false,
+            }),
+            ResponseAction::Edit(EditParams {
+                message_id: "$last".to_string(),
+                channel_id: None,
+                content: Some("Done!".to_string()),
+                embed: None,
+                allow_mass_mentions: false,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: `$last` is the last message in response order, not the last to finish
+    assert!(result.is_ok());
+
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.last().unwrap().content, "First");
+    let second = messages.iter().find(|message| message.content == "Second").unwrap();
+    let edits = discord_service.get_edits();
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].message_id, second.id);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_edit_last_thread_message() {
+    use gatehook::adapters::{EditParams, EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::Thread(ThreadParams {
+                name: None,
+                content: "Working on it...".to_string(),
+                auto_archive_duration: 1440,
+                message_id: None,
+                channel_id: None,
+                private: false,
+                allow_mass_mentions: false,
+                invitable: None,
+                rate_limit_per_user: None,
+            }),
+            ResponseAction::Edit(EditParams {
+                message_id: "$last".to_string(),
+                channel_id: None,
+                content: Some("Done!".to_string()),
+                embed: None,
+                allow_mass_mentions: false,
+            }),
+        ],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: the message posted to the thread is edited
+    assert!(result.is_ok());
+
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), 1);
+    let edits = discord_service.get_edits();
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].channel_id, messages[0].channel_id);
+    assert_eq!(edits[0].message_id, messages[0].id);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::last_without_prior_send("$last", None, None)]
+#[case::explicit_id("999", None, Some((ChannelId::new(222), MessageId::new(999))))]
+#[case::explicit_channel("999", Some("444"), Some((ChannelId::new(444), MessageId::new(999))))]
+#[case::invalid_id("not-a-snowflake", None, None)]
+#[tokio::test]
+async fn test_execute_actions_edit_target(
+    #[case] message_id: &str,
+    #[case] channel_id: Option<&str>,
+    #[case] expected: Option<(ChannelId, MessageId)>,
+) {
+    use gatehook::adapters::{EditParams, EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Edit(EditParams {
+            message_id: message_id.to_string(),
+            channel_id: channel_id.map(str::to_string),
+            content: Some("Edited".to_string()),
+            embed: None,
+            allow_mass_mentions: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    let edits: Vec<_> = discord_service
+        .get_edits()
+        .iter()
+        .map(|edit| (edit.channel_id, edit.message_id))
+        .collect();
+    assert_eq!(edits, expected.into_iter().collect::<Vec<_>>());
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::content_only(Some("Done!"), false)]
+#[case::embed_only(None, true)]
+#[case::content_and_embed(Some("Done!"), true)]
+#[tokio::test]
+async fn test_execute_actions_edit_content_and_embed(#[case] content: Option<&str>, #[case] with_embed: bool) {
+    use gatehook::adapters::{EditParams, EmbedParams, EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+    let embed = with_embed.then(|| EmbedParams {
+        title: Some("Status".to_string()),
+        description: Some("All good".to_string()),
+        ..Default::default()
+    });
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Edit(EditParams {
+            message_id: "999".to_string(),
+            channel_id: None,
+            content: content.map(str::to_string),
+            embed: embed.clone(),
+            allow_mass_mentions: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: only the given parts are passed on
+    assert!(result.is_ok());
+    let edits = discord_service.get_edits();
+    assert_eq!(edits.len(), 1);
+    assert_eq!(edits[0].message_id, MessageId::new(999));
+    assert_eq!(edits[0].content.as_deref(), content);
+    assert_eq!(edits[0].embed, embed);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_edit_without_content_or_embed_is_skipped() {
+    use gatehook::adapters::{EditParams, EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::Edit(EditParams {
+            message_id: "999".to_string(),
+            channel_id: None,
+            content: None,
+            embed: None,
+            allow_mass_mentions: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert!(discord_service.get_edits().is_empty());
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::reaction_add(gatehook::bridge::action_target::ReactionChange::Added, true)]
+#[case::reaction_remove(gatehook::bridge::action_target::ReactionChange::Removed, false)]
+#[tokio::test]
+async fn test_execute_actions_toggle_role(
+    #[case] change: gatehook::bridge::action_target::ReactionChange,
+    #[case] expected_added: bool,
+) {
+    use gatehook::adapters::{EventResponse, ResponseAction, ToggleRoleParams};
+    use gatehook::bridge::action_target::ActionTarget;
+    use serenity::model::id::{RoleId, UserId};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let reaction = MockReactionBuilder::new(2222, 2000)
+        .emoji("👍")
+        .guild(6000, 1111)
+        .build();
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::ToggleRole(ToggleRoleParams {
+            role_id: "4444".to_string(),
+        })],
+        ..Default::default()
+    };
+
+    // Execute: the same response for either reaction direction
+    let target = ActionTarget::from(&reaction).with_reaction_change(change);
+    let result = bridge.execute_actions(target, &event_response).await;
+
+    // Verify: direction follows the event
+    assert!(result.is_ok());
+
+    let role_changes = discord_service.get_role_changes();
+    assert_eq!(role_changes.len(), 1);
+    assert_eq!(role_changes[0].guild_id, GuildId::new(6000));
+    assert_eq!(role_changes[0].user_id, UserId::new(1111));
+    assert_eq!(role_changes[0].role_id, RoleId::new(4444));
+    assert_eq!(role_changes[0].added, expected_added);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_toggle_role_skipped_for_message() {
+    use gatehook::adapters::{EventResponse, ResponseAction, ToggleRoleParams};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Original message", 111, 222, 333);
+
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::ToggleRole(ToggleRoleParams {
+            role_id: "4444".to_string(),
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify: no reaction direction, so nothing to toggle
+    assert!(result.is_ok());
+    assert!(discord_service.get_role_changes().is_empty());
+}
+
+// Helper function to create a guild message replying to another message in channel 222
+fn create_reply_message(content: &str, message_id: u64, parent_id: u64) -> Message {
+    let mut message = create_guild_message(content, message_id, 222, 333);
+    message.message_reference = Some((ChannelId::new(222), MessageId::new(parent_id)).into());
+    message
+}
+
+// Bridge whose Discord service holds the chain 3 → 2 → 1 (1 is not a reply)
+fn reply_chain_bridge(
+    depth: u8,
+) -> (
+    EventBridge<MockDiscordService, MockEventSender, MockChannelInfoProvider>,
+    Arc<MockDiscordService>,
+    Arc<MockEventSender>,
+) {
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    discord_service.set_message(create_guild_message("Root", 1, 222, 333));
+    discord_service.set_message(create_reply_message("Middle", 2, 1));
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5)
+        .with_reply_chain_depth(depth);
+    (bridge, discord_service, event_sender)
+}
+
+#[rstest]
+#[case::disabled(0, &[])]
+#[case::capped_by_depth(1, &["2"])]
+#[case::full_chain(5, &["2", "1"])]
+#[tokio::test]
+async fn test_handle_message_reply_chain(#[case] depth: u8, #[case] expected_ids: &[&str]) {
+    // Setup
+    let (bridge, discord_service, event_sender) = reply_chain_bridge(depth);
+    let message = create_reply_message("Latest", 3, 2);
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify: ancestors newest first, one fetch per ancestor
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    let chain_ids: Vec<&str> = payload
+        .get("reply_chain")
+        .map(|chain| chain.as_array().unwrap().iter().map(|m| m["id"].as_str().unwrap()).collect())
+        .unwrap_or_default();
+    assert_eq!(chain_ids, expected_ids);
+    assert_eq!(discord_service.get_fetched_messages().len(), expected_ids.len());
+}
+
+#[tokio::test]
+async fn test_handle_message_reply_chain_fetch_error_truncates() {
+    // Setup
+    let (bridge, discord_service, event_sender) = reply_chain_bridge(5);
+    discord_service.set_get_message_error(true);
+    let message = create_reply_message("Latest", 3, 2);
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify: event still forwarded, without a chain
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1);
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert!(payload.get("reply_chain").is_none());
+}
+
+#[tokio::test]
+async fn test_handle_message_reply_chain_depth_is_capped() {
+    use gatehook::bridge::event_bridge::MAX_REPLY_CHAIN_DEPTH;
+
+    // Setup: a message replying to itself never ends the chain
+    let (bridge, discord_service, _event_sender) = reply_chain_bridge(u8::MAX);
+    discord_service.set_message(create_reply_message("Loop", 2, 2));
+    let message = create_reply_message("Latest", 3, 2);
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert_eq!(
+        discord_service.get_fetched_messages().len(),
+        usize::from(MAX_REPLY_CHAIN_DEPTH)
+    );
+}
+
+// Bridge in maintenance mode replying with "Down for maintenance"
+fn maintenance_bridge() -> (
+    EventBridge<MockDiscordService, MockEventSender, MockChannelInfoProvider>,
+    Arc<MockDiscordService>,
+    Arc<MockEventSender>,
+) {
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5)
+        .with_maintenance_message(Some("Down for maintenance".to_string()));
+    (bridge, discord_service, event_sender)
+}
+
+#[tokio::test]
+async fn test_handle_message_maintenance_returns_canned_reply() {
+    use gatehook::adapters::event_response::{ReplyParams, ResponseAction};
+
+    // Setup
+    let (bridge, _discord_service, event_sender) = maintenance_bridge();
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let response = bridge.handle_message(&message).await.unwrap().unwrap();
+
+    // Verify: reply action without a webhook call
+    assert_eq!(
+        response.actions,
+        vec![ResponseAction::Reply(ReplyParams {
+            content: "Down for maintenance".to_string(),
+            mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
+        })]
+    );
+    assert!(event_sender.get_sent_events().is_empty(), "Should NOT call the webhook");
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_maintenance_reply_is_executed() {
+    // Setup
+    let (bridge, discord_service, event_sender) = maintenance_bridge();
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let response = bridge.handle_message(&message).await.unwrap().unwrap();
+    let result = bridge.execute_actions(&message, &response).await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let replies = discord_service.get_replies();
+    assert_eq!(replies.len(), 1);
+    assert_eq!(replies[0].message_id, MessageId::new(111));
+    assert_eq!(replies[0].content, "Down for maintenance");
+    assert!(event_sender.get_sent_events().is_empty());
+}
+
+#[tokio::test]
+async fn test_maintenance_drops_other_events() {
+    // Setup
+    let (bridge, _discord_service, event_sender) = maintenance_bridge();
+    let reaction = MockReactionBuilder::new(111, 222).guild(333, 1111).build();
+
+    // Execute
+    let result = bridge.handle_reaction_add(&reaction).await;
+
+    // Verify
+    assert!(matches!(result, Ok(None)));
+    assert!(event_sender.get_sent_events().is_empty());
+}
+
+#[tokio::test]
+async fn test_empty_maintenance_message_disables_maintenance() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_maintenance_message(Some(String::new()));
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify: forwarded as usual
+    assert!(result.is_ok());
+    assert_eq!(event_sender.get_sent_events().len(), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_run_heartbeat_emits_at_interval() {
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = Arc::new(EventBridge::new(discord_service, event_sender.clone(), channel_info, 5));
+    bridge.set_connected(ShardId(0), true);
+    bridge
+        .handle_message(&create_guild_message("Hello", 111, 222, 333))
+        .await
+        .unwrap();
+
+    // Execute: run for three and a half intervals on the paused clock
+    let heartbeat_bridge = bridge.clone();
+    let spawned = bridge.spawn_service("heartbeat", move |shutdown| async move {
+        heartbeat_bridge.run_heartbeat(Duration::from_secs(60), shutdown).await;
+    });
+    assert!(spawned);
+    tokio::time::sleep(Duration::from_secs(210)).await;
+    assert!(bridge.drain_tasks(Duration::from_secs(5)).await);
+
+    // Verify: one heartbeat per elapsed interval, not counting themselves
+    let heartbeats: Vec<serde_json::Value> = event_sender
+        .get_sent_events()
+        .iter()
+        .filter(|event| event.handler == "heartbeat")
+        .map(|event| serde_json::from_str(&event.payload).unwrap())
+        .collect();
+    assert_eq!(heartbeats.len(), 3);
+
+    let uptimes: Vec<u64> = heartbeats
+        .iter()
+        .map(|payload| payload["heartbeat"]["uptime_secs"].as_u64().unwrap())
+        .collect();
+    assert_eq!(uptimes, vec![60, 120, 180]);
+
+    for payload in &heartbeats {
+        assert_eq!(payload["heartbeat"]["connected"], true);
+        assert_eq!(payload["heartbeat"]["event_counts"], serde_json::json!({"message": 1}));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_drain_tasks_stops_heartbeat() {
+    use std::time::Duration;
+
+    // Setup: a heartbeat every minute, with tasks capped at a 10 minute lifetime
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = Arc::new(
+        EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+            .with_max_task_lifetime(Duration::from_secs(600)),
+    );
+    let heartbeat_bridge = bridge.clone();
+    bridge.spawn_service("heartbeat", move |shutdown| async move {
+        heartbeat_bridge.run_heartbeat(Duration::from_secs(60), shutdown).await;
+    });
+
+    // Execute: the heartbeat outlives the task lifetime, then the drain stops it
+    tokio::time::sleep(Duration::from_secs(690)).await;
+    let drained = bridge.drain_tasks(Duration::from_secs(5)).await;
+    tokio::time::sleep(Duration::from_secs(600)).await;
+
+    // Verify: no heartbeat after the drain
+    assert!(drained);
+    let heartbeats = event_sender
+        .get_sent_events()
+        .iter()
+        .filter(|event| event.handler == "heartbeat")
+        .count();
+    assert_eq!(heartbeats, 11);
+}
+
+#[tokio::test]
+async fn test_handle_heartbeat_reports_disconnected() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+    bridge.set_connected(ShardId(0), true);
+    bridge.set_connected(ShardId(0), false);
+
+    // Execute
+    let result = bridge.handle_heartbeat().await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1);
+    assert_eq!(sent_events[0].handler, "heartbeat");
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(payload["heartbeat"]["connected"], false);
+    assert_eq!(payload["heartbeat"]["event_counts"], serde_json::json!({}));
+}
+
+#[rstest]
+#[case::below_threshold(3, 0)]
+#[case::threshold_reached(2, 1)]
+#[case::disabled(0, 0)]
+#[tokio::test(start_paused = true)]
+async fn test_handle_stage_update_reconnecting_event(#[case] threshold: u32, #[case] expected_events: usize) {
+    use serenity::gateway::ConnectionStage;
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_reconnect_alert_threshold(threshold);
+
+    // Execute: connection lost, three reconnect attempts 30s apart, then back
+    bridge.handle_stage_update(ShardId(0), ConnectionStage::Disconnected).await.unwrap();
+    for _ in 0..3 {
+        tokio::time::advance(Duration::from_secs(30)).await;
+        bridge.handle_stage_update(ShardId(0), ConnectionStage::Connecting).await.unwrap();
+        bridge.handle_stage_update(ShardId(0), ConnectionStage::Handshake).await.unwrap();
+    }
+    bridge.handle_stage_update(ShardId(0), ConnectionStage::Connected).await.unwrap();
+
+    // Verify: one event per outage, for the attempt that follows `threshold` failures
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), expected_events);
+    if let Some(event) = sent_events.first() {
+        assert_eq!(event.handler, "reconnecting");
+        let payload: serde_json::Value = serde_json::from_str(&event.payload).unwrap();
+        assert_eq!(payload["reconnecting"]["attempt"], 3);
+        assert_eq!(payload["reconnecting"]["consecutive_failures"], 2);
+        assert_eq!(payload["reconnecting"]["downtime_secs"], 90);
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_handle_stage_update_tracks_shards_separately() {
+    use serenity::gateway::ConnectionStage;
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_reconnect_alert_threshold(2);
+    bridge.set_connected(ShardId(0), true);
+    bridge.set_connected(ShardId(1), true);
+
+    // Execute: shard 1 keeps failing while shard 0 reconnects between its attempts
+    bridge.handle_stage_update(ShardId(1), ConnectionStage::Disconnected).await.unwrap();
+    for _ in 0..3 {
+        tokio::time::advance(Duration::from_secs(30)).await;
+        bridge.handle_stage_update(ShardId(1), ConnectionStage::Connecting).await.unwrap();
+        bridge.handle_stage_update(ShardId(0), ConnectionStage::Connecting).await.unwrap();
+        bridge.handle_stage_update(ShardId(0), ConnectionStage::Connected).await.unwrap();
+    }
+    bridge.handle_heartbeat().await.unwrap();
+
+    // Verify: shard 0 does not reset shard 1's outage or hide it from heartbeats
+    let sent_events = event_sender.get_sent_events();
+    let handlers: Vec<&str> = sent_events.iter().map(|event| event.handler.as_str()).collect();
+    assert_eq!(handlers, vec!["reconnecting", "heartbeat"]);
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(payload["reconnecting"]["attempt"], 3);
+    assert_eq!(payload["reconnecting"]["downtime_secs"], 90);
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[1].payload).unwrap();
+    assert_eq!(payload["heartbeat"]["connected"], false);
+}
+
+#[tokio::test]
+async fn test_handle_auto_moderation_action_execution() {
+    use serenity::model::guild::automod::ActionExecution;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    let execution: ActionExecution = serde_json::from_value(serde_json::json!({
+        "guild_id": "1234",
+        "action": {"type": 2, "metadata": {"channel_id": "4321"}},
+        "rule_id": "9999",
+        "rule_trigger_type": 1,
+        "user_id": "5555",
+        "channel_id": "888",
+        "message_id": "777",
+        "alert_system_message_id": "666",
+        "content": "some bad word here",
+        "matched_keyword": "bad*",
+        "matched_content": "bad"
+    }))
+    .unwrap();
+
+    // Execute
+    let result = bridge.handle_auto_moderation_action_execution(&execution).await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1, "Should send one event to webhook");
+    assert_eq!(sent_events[0].handler, "auto_moderation_action_execution");
+
+    let json_value: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    let execution = &json_value["auto_moderation_action_execution"];
+    assert_eq!(execution["rule_id"], "9999");
+    assert_eq!(execution["action"]["type"], 2);
+    assert_eq!(execution["action"]["metadata"]["channel_id"], "4321");
+    assert_eq!(execution["matched_content"], "bad");
+}
+
+#[rstest]
+#[case::enabled_in_shard(true, true, Some(3))]
+#[case::disabled(false, true, None)]
+#[case::outside_shard_scope(true, false, None)]
+#[tokio::test]
+async fn test_include_shard(#[case] include_shard: bool, #[case] in_shard: bool, #[case] expected: Option<u64>) {
+    use serenity::model::id::ShardId;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_include_shard(include_shard);
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let result = if in_shard {
+        bridge.in_shard(ShardId(3), bridge.handle_message(&message)).await
+    } else {
+        bridge.handle_message(&message).await
+    };
+
+    // Verify: `_shard` sits next to the regular payload fields
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(payload.get("_shard").and_then(|shard| shard.as_u64()), expected);
+    assert_eq!(payload["message"]["id"], "111");
+}
+
+#[tokio::test]
+async fn test_include_shard_on_raw_event() {
+    use gatehook::bridge::raw_event_payload::RawEventPayload;
+    use serenity::model::id::ShardId;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_include_shard(true);
+    let payload = RawEventPayload {
+        kind: "TYPING_START".to_string(),
+        raw: serde_json::json!({"channel_id": "888"}),
+    };
+
+    // Execute
+    let result = bridge.in_shard(ShardId(1), bridge.handle_raw_event(&payload)).await;
+
+    // Verify
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(
+        payload,
+        serde_json::json!({"_type": "TYPING_START", "raw": {"channel_id": "888"}, "_shard": 1, "schema_version": 1})
+    );
+}
+
+#[rstest]
+#[case::in_shard(true)]
+#[case::outside_shard_scope(false)]
+#[tokio::test]
+async fn test_include_received_at(#[case] in_shard: bool) {
+    use serenity::model::Timestamp;
+    use serenity::model::id::ShardId;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_include_received_at(true);
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let before = Timestamp::now().unix_timestamp();
+    let result = if in_shard {
+        bridge.in_shard(ShardId(0), bridge.handle_message(&message)).await
+    } else {
+        bridge.handle_message(&message).await
+    };
+    let after = Timestamp::now().unix_timestamp();
+
+    // Verify: a valid RFC 3339 timestamp of the receipt, next to the regular payload fields
+    assert!(result.is_ok());
+
+    let sent_events = event_sender.get_sent_events();
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    let received_at = Timestamp::parse(payload["received_at"].as_str().unwrap()).unwrap();
+    assert!((before..=after).contains(&received_at.unix_timestamp()));
+    assert_eq!(payload["message"]["id"], "111");
+}
+
+#[tokio::test]
+async fn test_include_received_at_disabled_or_heartbeat() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let plain_bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info.clone(), 5);
+    let tagging_bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_include_received_at(true);
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    assert!(plain_bridge.handle_message(&message).await.is_ok());
+    assert!(tagging_bridge.handle_heartbeat().await.is_ok());
+
+    // Verify: neither payload carries the field
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 2);
+    for event in sent_events {
+        let payload: serde_json::Value = serde_json::from_str(&event.payload).unwrap();
+        assert!(payload.get("received_at").is_none(), "{} has received_at", event.handler);
+    }
+}
+
+#[tokio::test]
+async fn test_maintenance_drops_reaction_remove_batch() {
+    use std::time::Duration;
+
+    // Setup
+    let (bridge, _discord_service, event_sender) = maintenance_bridge();
+    let bridge = bridge.with_reaction_remove_coalesce(Duration::from_millis(20));
+    let removals = [
+        MockReactionBuilder::new(2222, 2000).emoji("👍").guild(6000, 1111).build(),
+        MockReactionBuilder::new(2222, 2000).emoji("🎉").guild(6000, 1112).build(),
+    ];
+
+    // Execute
+    let results = futures::future::join_all(
+        removals
+            .iter()
+            .map(|reaction| bridge.handle_reaction_remove(reaction)),
+    )
+    .await;
+
+    // Verify
+    assert!(results.iter().all(|result| matches!(result, Ok(None))));
+    assert!(event_sender.get_sent_events().is_empty());
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_message_delete_executes_send_message() {
+    use gatehook::adapters::{EventResponse, ResponseAction, SendMessageParams};
+    use gatehook::bridge::action_target::ActionTarget;
+
+    // Setup: webhook answers the delete with a message to a log channel
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::with_response(EventResponse {
+        actions: vec![ResponseAction::SendMessage(SendMessageParams {
+            channel_id: Some("555".to_string()),
+            content: "Message 888 was deleted".to_string(),
+            allow_mass_mentions: false,
+        })],
+        ..Default::default()
+    }));
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_contextless_actions(true);
+
+    let (channel_id, message_id, guild_id) = (ChannelId::new(999), MessageId::new(888), Some(GuildId::new(777)));
+
+    // Execute
+    let event_response = bridge
+        .handle_message_delete(channel_id, message_id, guild_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let target = ActionTarget::from_ids(message_id, channel_id, guild_id);
+    let result = bridge
+        .execute_contextless_actions("message_delete", target, &event_response)
+        .await;
+
+    // Verify
+    assert!(result.is_ok());
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].channel_id, ChannelId::new(555));
+    assert_eq!(messages[0].content, "Message 888 was deleted");
+    assert_eq!(messages[0].reply_to, None);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::enabled(true, 1)]
+#[case::disabled(false, 0)]
+#[tokio::test]
+async fn test_contextless_actions_policy(#[case] enabled: bool, #[case] expected_messages: usize) {
+    use gatehook::adapters::{EventResponse, ReactParams, ResponseAction, SendMessageParams};
+    use gatehook::bridge::action_target::ActionTarget;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_contextless_actions(enabled);
+
+    let event_response = EventResponse {
+        actions: vec![
+            ResponseAction::Reply(ReplyParams {
+                content: "Too late".to_string(),
+                mention: false,
+                delete_original: false,
+                allow_mass_mentions: false,
+                as_file: false,
+            }),
+            ResponseAction::React(ReactParams {
+                emoji: "👍".to_string(),
+                message_id: None,
+                channel_id: None,
+            }),
+            ResponseAction::SendMessage(SendMessageParams {
+                channel_id: None,
+                content: "Message edited".to_string(),
+                allow_mass_mentions: false,
+            }),
+        ],
+        ..Default::default()
+    };
+    let target = ActionTarget::from_ids(MessageId::new(888), ChannelId::new(999), None);
+
+    // Execute
+    let result = bridge
+        .execute_contextless_actions("message_update", target, &event_response)
+        .await;
+
+    // Verify: only send_message runs, in the event's channel
+    assert!(result.is_ok());
+    assert!(discord_service.get_replies().is_empty());
+    assert!(discord_service.get_reactions().is_empty());
+    let messages = discord_service.get_messages();
+    assert_eq!(messages.len(), expected_messages);
+    if enabled {
+        assert_eq!(messages[0].channel_id, ChannelId::new(999));
+    }
+}
+
+#[rstest]
+#[case::within_limit(4096, 0, 1)]
+#[case::large_embeds(4096, 500, 0)]
+#[tokio::test]
+async fn test_max_payload_size_guard(
+    #[case] max_bytes: usize,
+    #[case] embed_count: usize,
+    #[case] expected_sent: usize,
+) {
+    use serenity::model::channel::Embed;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_max_payload_size(Some(max_bytes));
+
+    let mut message = create_test_message("Hello", 111, 222);
+    let embed: Embed = serde_json::from_value(serde_json::json!({"description": "x".repeat(1000)})).unwrap();
+    message.embeds = vec![embed; embed_count];
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify
+    assert!(matches!(result, Ok(None)));
+    assert_eq!(event_sender.get_sent_events().len(), expected_sent);
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::normalized(true, "\u{00C5}")]
+#[case::unchanged(false, "A\u{030A}")]
+#[tokio::test]
+async fn test_execute_actions_react_normalize_emoji(#[case] normalize_emoji: bool, #[case] expected: &str) {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5)
+        .with_normalize_emoji(normalize_emoji);
+
+    let message = create_test_message("Hello", 111, 222);
+    // Decomposed form: "A" followed by a combining ring above
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::React(ReactParams {
+            emoji: "A\u{030A}".to_string(),
+            message_id: None,
+            channel_id: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    assert!(bridge.execute_actions(&message, &event_response).await.is_ok());
+
+    // Verify
+    let reactions = discord_service.get_reactions();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].emoji, expected);
+}
+
+#[cfg(feature = "actions")]
+fn reaction_command_bridge(
+    forward: bool,
+    webhook_response: Option<gatehook::adapters::EventResponse>,
+) -> (
+    EventBridge<MockDiscordService, MockEventSender, MockChannelInfoProvider>,
+    Arc<MockDiscordService>,
+    Arc<MockEventSender>,
+) {
+    use gatehook::adapters::ResponseAction;
+    use std::collections::HashMap;
+
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(match webhook_response {
+        Some(response) => MockEventSender::with_response(response),
+        None => MockEventSender::new(),
+    });
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let commands = HashMap::from([(
+        "📌".to_string(),
+        vec![ResponseAction::React(ReactParams {
+            emoji: "✅".to_string(),
+            message_id: None,
+            channel_id: None,
+        })],
+    )]);
+    let bridge = EventBridge::new(discord_service.clone(), event_sender.clone(), channel_info, 5)
+        .with_reaction_commands(commands)
+        .with_reaction_commands_forward(forward);
+    (bridge, discord_service, event_sender)
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_reaction_command_executes_without_webhook() {
+    use gatehook::adapters::ResponseAction;
+
+    // Setup
+    let (bridge, discord_service, event_sender) = reaction_command_bridge(false, None);
+    let reaction = MockReactionBuilder::new(8888, 9999).emoji("📌").guild(1234, 7777).build();
+
+    // Execute
+    let event_response = bridge.handle_reaction_add(&reaction).await.unwrap().unwrap();
+    bridge.execute_actions(&reaction, &event_response).await.unwrap();
+
+    // Verify: mapped action ran, nothing was forwarded
+    assert_eq!(
+        event_response.actions,
+        vec![ResponseAction::React(ReactParams {
+            emoji: "✅".to_string(),
+            message_id: None,
+            channel_id: None,
+        })]
+    );
+    assert!(event_sender.get_sent_events().is_empty());
+    let reactions = discord_service.get_reactions();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].emoji, "✅");
+    assert_eq!(reactions[0].message_id, MessageId::new(8888));
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_reaction_command_unmapped_emoji_forwards() {
+    // Setup
+    let (bridge, _discord_service, event_sender) = reaction_command_bridge(false, None);
+    let reaction = MockReactionBuilder::new(8888, 9999).emoji("👍").guild(1234, 7777).build();
+
+    // Execute
+    let result = bridge.handle_reaction_add(&reaction).await;
+
+    // Verify
+    assert!(matches!(result, Ok(None)));
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1);
+    assert_eq!(sent_events[0].handler, "reaction_add");
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_reaction_action_cooldown_skips_rapid_re_reaction() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::with_response(EventResponse {
+        actions: vec![ResponseAction::React(ReactParams {
+            emoji: "✅".to_string(),
+            message_id: None,
+            channel_id: None,
+        })],
+        ..Default::default()
+    }));
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_reaction_action_cooldown(Duration::from_millis(50));
+    let reaction = MockReactionBuilder::new(8888, 9999).guild(1234, 7777).build();
+    let other_user = MockReactionBuilder::new(8888, 9999).guild(1234, 7778).build();
+
+    // Execute: re-react immediately, then again after the window
+    let first = bridge.handle_reaction_add(&reaction).await.unwrap().unwrap();
+    let rapid = bridge.handle_reaction_add(&reaction).await.unwrap().unwrap();
+    let other = bridge.handle_reaction_add(&other_user).await.unwrap().unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    let later = bridge.handle_reaction_add(&reaction).await.unwrap().unwrap();
+
+    // Verify: every reaction is forwarded, only the rapid one loses its actions
+    assert_eq!(event_sender.get_sent_events().len(), 4);
+    assert_eq!(first.actions.len(), 1);
+    assert!(rapid.actions.is_empty(), "Re-reaction within cooldown should skip actions");
+    assert_eq!(other.actions.len(), 1);
+    assert_eq!(later.actions.len(), 1, "Re-reaction after cooldown should keep actions");
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_reaction_command_forward_appends_webhook_actions() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let webhook_response = EventResponse {
+        actions: vec![ResponseAction::Reply(ReplyParams {
+            content: "Pinned by webhook".to_string(),
+            mention: false,
+            delete_original: false,
+            allow_mass_mentions: false,
+            as_file: false,
+        })],
+        ..Default::default()
+    };
+    let (bridge, _discord_service, event_sender) = reaction_command_bridge(true, Some(webhook_response));
+    let reaction = MockReactionBuilder::new(8888, 9999).emoji("📌").guild(1234, 7777).build();
+
+    // Execute
+    let event_response = bridge.handle_reaction_add(&reaction).await.unwrap().unwrap();
+
+    // Verify: local action first, then the webhook's
+    assert_eq!(event_sender.get_sent_events().len(), 1);
+    let names: Vec<_> = event_response.actions.iter().map(ResponseAction::name).collect();
+    assert_eq!(names, ["react", "reply"]);
+}
+
+#[tokio::test]
+async fn test_handle_component_interaction_forwards_payload() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+
+    let interaction = create_component_interaction("approve", 111, 222);
+
+    // Execute
+    let result = bridge.handle_component_interaction(&interaction).await;
+
+    // Verify
+    assert!(result.is_ok());
+    let sent_events = event_sender.get_sent_events();
+    assert_eq!(sent_events.len(), 1);
+    assert_eq!(sent_events[0].handler, "interaction_create");
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(payload["custom_id"], "approve");
+    assert_eq!(payload["message_id"], "111");
+    assert_eq!(payload["user"]["id"], "7777");
+}
+
+#[cfg(feature = "actions")]
+#[rstest]
+#[case::deferred_update(r#"{"type":"interaction_response"}"#, None)]
+#[case::update(r#"{"type":"interaction_response","kind":"update","content":"Approved"}"#, Some("Approved"))]
+#[tokio::test]
+async fn test_execute_actions_interaction_response(#[case] action: &str, #[case] expected_content: Option<&str>) {
+    use gatehook::adapters::EventResponse;
+    use serenity::model::id::InteractionId;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let interaction = create_component_interaction("approve", 111, 222);
+    let event_response = EventResponse {
+        actions: vec![serde_json::from_str(action).unwrap()],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&interaction, &event_response).await;
+
+    // Verify: responded through the interaction token
+    assert!(result.is_ok());
+    let responses = discord_service.get_interaction_responses();
+    assert_eq!(responses.len(), 1);
+    assert_eq!(responses[0].interaction_id, InteractionId::new(4444));
+    assert_eq!(responses[0].token, "interaction-token");
+    assert_eq!(responses[0].content.as_deref(), expected_content);
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_interaction_response_without_interaction_skipped() {
+    use gatehook::adapters::{EventResponse, InteractionResponseKind, InteractionResponseParams, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let message = create_guild_message("Hello", 111, 222, 333);
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::InteractionResponse(InteractionResponseParams {
+            kind: InteractionResponseKind::DeferredUpdate,
+            content: None,
+            allow_mass_mentions: false,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&message, &event_response).await;
+
+    // Verify
+    assert!(result.is_ok());
+    assert!(discord_service.get_interaction_responses().is_empty());
+}
+
+#[cfg(feature = "actions")]
+#[tokio::test]
+async fn test_execute_actions_component_interaction_reply_targets_message() {
+    use gatehook::adapters::{EventResponse, ResponseAction};
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service.clone(), event_sender, channel_info, 5);
+
+    let interaction = create_component_interaction("approve", 111, 222);
+    let event_response = EventResponse {
+        actions: vec![ResponseAction::React(ReactParams {
+            emoji: "✅".to_string(),
+            message_id: None,
+            channel_id: None,
+        })],
+        ..Default::default()
+    };
+
+    // Execute
+    let result = bridge.execute_actions(&interaction, &event_response).await;
+
+    // Verify: message actions apply to the component's message
+    assert!(result.is_ok());
+    let reactions = discord_service.get_reactions();
+    assert_eq!(reactions.len(), 1);
+    assert_eq!(reactions[0].message_id, MessageId::new(111));
+    assert_eq!(reactions[0].channel_id, ChannelId::new(222));
+}
+
+#[rstest]
+#[case::fresh(0, 1)]
+#[case::older_than_max_age(600, 0)]
+#[tokio::test]
+async fn test_max_event_age(#[case] age_secs: i64, #[case] expected_sent: usize) {
+    use serenity::model::Timestamp;
+    use std::time::Duration;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_max_event_age(Duration::from_secs(300));
+    let mut message = create_guild_message("Hello", 111, 222, 333);
+    message.timestamp = Timestamp::from_unix_timestamp(Timestamp::now().unix_timestamp() - age_secs).unwrap();
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify: stale events are dropped before forwarding
+    assert!(result.unwrap().is_none());
+    assert_eq!(event_sender.get_sent_events().len(), expected_sent);
+}
+
+#[tokio::test]
+async fn test_max_concurrent_events_sheds_when_saturated() {
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5)
+        .with_max_concurrent_events(2);
+    let reaction = MockReactionBuilder::new(2222, 2000).guild(6000, 1111).build();
+
+    // Two events in progress saturate the limit
+    let first = bridge.try_begin_event("reaction_add");
+    let second = bridge.try_begin_event("reaction_add");
+    assert!(first.is_some() && second.is_some());
+
+    // Execute: a storm of reactions while saturated is shed
+    for _ in 0..10 {
+        if let Some(_permit) = bridge.try_begin_event("reaction_add") {
+            bridge.handle_reaction_add(&reaction).await.unwrap();
+        }
+    }
+    assert!(event_sender.get_sent_events().is_empty());
+
+    // Finishing an event frees a permit for the next one
+    drop(first);
+    let permit = bridge.try_begin_event("reaction_add");
+    assert!(permit.is_some());
+    bridge.handle_reaction_add(&reaction).await.unwrap();
+    assert_eq!(event_sender.get_sent_events().len(), 1);
+}
+
+#[tokio::test]
+async fn test_payload_includes_schema_version() {
+    use gatehook::adapters::PAYLOAD_SCHEMA_VERSION;
+
+    // Setup
+    let discord_service = Arc::new(MockDiscordService::new());
+    let event_sender = Arc::new(MockEventSender::new());
+    let channel_info = Arc::new(MockChannelInfoProvider::new());
+    let bridge = EventBridge::new(discord_service, event_sender.clone(), channel_info, 5);
+    let message = create_guild_message("Hello", 111, 222, 333);
+
+    // Execute
+    let result = bridge.handle_message(&message).await;
+
+    // Verify: the version sits next to the regular payload fields
+    assert!(result.is_ok());
+    let sent_events = event_sender.get_sent_events();
+    let payload: serde_json::Value = serde_json::from_str(&sent_events[0].payload).unwrap();
+    assert_eq!(payload["schema_version"], PAYLOAD_SCHEMA_VERSION);
+    assert_eq!(payload["message"]["content"], "Hello");
+}
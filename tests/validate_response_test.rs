@@ -0,0 +1,37 @@
+//! Tests for offline webhook response validation (`--validate-response`)
+
+use gatehook::validate_response::validate_response;
+
+#[test]
+fn test_validate_valid_fixture() {
+    let json = include_str!("fixtures/valid_response.json");
+
+    let summary = validate_response(json).unwrap();
+
+    assert_eq!(
+        summary,
+        "Valid webhook response: 3 action(s)\n  \
+         1. reply: content 7 chars, mention=true, delete_original=false\n  \
+         2. react: emoji 👍\n  \
+         3. thread: name Discussion, content 11 chars, auto_archive_duration=1440, private=false"
+    );
+}
+
+#[test]
+fn test_validate_invalid_fixture() {
+    let json = include_str!("fixtures/invalid_response.json");
+
+    let err = validate_response(json).unwrap_err();
+
+    assert!(err.to_string().contains("unknown variant `shout`"), "unexpected error: {err}");
+}
+
+#[test]
+fn test_validate_empty_response() {
+    assert_eq!(validate_response("{}").unwrap(), "Valid webhook response: 0 action(s)");
+}
+
+#[test]
+fn test_validate_malformed_json() {
+    assert!(validate_response(r#"{"actions": ["#).unwrap_err().is_eof());
+}